@@ -3,7 +3,7 @@ mod env;
 use crate::config;
 use crate::error::{Result, VexError};
 use crate::project::{self, LoadedProjectConfig};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 use env::{
@@ -18,14 +18,25 @@ pub struct ActivationPlan {
     pub shared_path_entries: Vec<PathBuf>,
     pub exec_path_entries: Vec<PathBuf>,
     pub project: Option<LoadedProjectConfig>,
+    /// Resolved tool versions the plan activates.
+    pub versions: BTreeMap<String, String>,
 }
 
 pub fn build_activation_plan(cwd: &Path) -> Result<ActivationPlan> {
+    build_activation_plan_with_tools(cwd, &HashMap::new())
+}
+
+/// Build the plan for `cwd` with `tools` replacing the resolved versions, as a task's `tools`
+/// table does.
+pub fn build_activation_plan_with_tools(
+    cwd: &Path,
+    tools: &HashMap<String, String>,
+) -> Result<ActivationPlan> {
     let settings = config::load_effective_settings(cwd)?;
     let vex_dir = config::vex_home().ok_or(VexError::HomeDirectoryNotFound)?;
     let toolchains_dir = config::toolchains_dir().ok_or(VexError::HomeDirectoryNotFound)?;
     let project = project::load_nearest_project_config(cwd)?;
-    let versions = resolve_active_versions(cwd, &vex_dir)?;
+    let versions = resolve_active_versions(cwd, &vex_dir, tools)?;
     let venv_dir = resolve_venv_dir(cwd, project.as_ref())?;
     let shared_path_entries = collect_shared_path_entries(
         cwd,
@@ -56,6 +67,7 @@ pub fn build_activation_plan(cwd: &Path) -> Result<ActivationPlan> {
         shared_path_entries,
         exec_path_entries,
         project,
+        versions,
     })
 }
 
@@ -5,7 +5,7 @@ use crate::requested_versions;
 use crate::resolver;
 use crate::tools;
 use crate::version_state;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
 const SUPPORTED_TOOLS: &[&str] = &["go", "java", "node", "python", "rust"];
@@ -35,23 +35,39 @@ pub(super) fn resolve_venv_dir(
 pub(super) fn resolve_active_versions(
     cwd: &Path,
     vex_dir: &Path,
+    overrides: &HashMap<String, String>,
 ) -> Result<BTreeMap<String, String>> {
     let requested = resolver::resolve_versions(cwd);
-    if requested.is_empty() {
-        return Ok(version_state::read_current_versions(vex_dir)?
+    let mut versions = if requested.is_empty() {
+        version_state::read_current_versions(vex_dir)?
             .into_iter()
-            .collect());
-    }
+            .collect()
+    } else {
+        requested
+            .into_iter()
+            .map(|(tool, requested)| {
+                let resolved =
+                    requested_versions::resolve_installed_version(vex_dir, &tool, &requested)?
+                        .unwrap_or(requested);
+                Ok((tool, resolved))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?
+    };
 
-    requested
-        .into_iter()
-        .map(|(tool, requested)| {
-            let resolved =
-                requested_versions::resolve_installed_version(vex_dir, &tool, &requested)?
-                    .unwrap_or(requested);
-            Ok((tool, resolved))
-        })
-        .collect()
+    // Overrides come from task `tools` tables and must already be installed.
+    for (tool, requested) in overrides {
+        let resolved = requested_versions::resolve_installed_version(vex_dir, tool, requested)?
+            .ok_or_else(|| VexError::VersionNotFound {
+                tool: tool.clone(),
+                version: requested.clone(),
+                suggestions: format!(
+                    "\n\nInstall it first with 'vex install {}@{}'.",
+                    tool, requested
+                ),
+            })?;
+        versions.insert(tool.clone(), resolved);
+    }
+    Ok(versions)
 }
 
 pub(super) fn collect_shared_path_entries(
@@ -2,17 +2,26 @@
 //!
 //! Provides lifecycle status (EOL, LTS, security updates) for installed versions.
 //! Used by `outdated`, `doctor`, `install`, and `use` commands to warn users about
-//! problematic versions.
+//! problematic versions. Lifecycle data comes from upstream release schedules cached by
+//! [`refresh_lifecycle`], with compiled tables as the offline fallback. Known vulnerabilities
+//! come from cached upstream feeds, see [`get_vulnerabilities`].
 
+mod go;
 mod java;
+mod lifecycle;
 mod node;
 mod python;
+mod rust;
 #[cfg(test)]
 mod tests;
+mod vulnerabilities;
 
+use crate::tools::Version;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+pub use vulnerabilities::VulnerabilityMatch;
+
 /// Advisory status for a version
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -81,6 +90,32 @@ pub fn get_advisory(tool: &str, version: &str) -> Advisory {
         "node" => node::node_advisory(version),
         "java" => java::java_advisory(version),
         "python" => python::python_advisory(version),
+        "go" => go::go_advisory(version),
+        "rust" => rust::rust_advisory(version),
         _ => Advisory::new(AdvisoryStatus::Unknown),
     }
 }
+
+/// Refresh cached lifecycle data after a live remote version fetch (failures are ignored)
+pub fn refresh_lifecycle(tool: &str, versions: &[Version]) {
+    if let Err(err) = lifecycle::refresh(tool, versions) {
+        tracing::debug!("Keeping cached {} lifecycle data: {}", tool, err);
+    }
+}
+
+/// Get known vulnerabilities affecting a specific tool version, most severe first
+///
+/// Only reads the cached feed, so this never touches the network.
+pub fn get_vulnerabilities(tool: &str, version: &str) -> Vec<VulnerabilityMatch> {
+    vulnerabilities::cached_matches(tool, version)
+}
+
+/// Refresh the cached vulnerability feed for a tool once it is a day old (failures are ignored)
+///
+/// Feeds can take several requests, so only `outdated`, `doctor`, and the TUI refresh them;
+/// install and switch paths read whatever is cached.
+pub fn refresh_vulnerabilities(tool: &str) {
+    if let Err(err) = vulnerabilities::refresh(tool) {
+        tracing::debug!("Keeping cached {} vulnerability feed: {}", tool, err);
+    }
+}
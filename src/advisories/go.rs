@@ -0,0 +1,84 @@
+use super::{lifecycle, Advisory, AdvisoryStatus};
+use crate::tools::Version;
+use crate::versioning::version_sort_key;
+use serde::{Deserialize, Serialize};
+
+/// Go supports the two most recent major releases; older lines receive no security fixes.
+const SUPPORTED_RELEASES: usize = 2;
+
+/// Latest patch of each supported release line, newest first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct GoLifecycle {
+    pub(super) supported: Vec<String>,
+}
+
+impl GoLifecycle {
+    pub(super) fn from_versions(versions: &[Version]) -> Option<Self> {
+        let mut latest_per_line: Vec<(Vec<u32>, &str)> = Vec::new();
+        for version in versions {
+            let key = version_sort_key(&version.version);
+            if key.len() < 2 {
+                continue;
+            }
+            match latest_per_line
+                .iter_mut()
+                .find(|(line_key, _)| line_key[..2] == key[..2])
+            {
+                Some(entry) if entry.0 < key => *entry = (key, &version.version),
+                Some(_) => {}
+                None => latest_per_line.push((key, &version.version)),
+            }
+        }
+
+        latest_per_line.sort_by(|left, right| right.0.cmp(&left.0));
+        let supported: Vec<String> = latest_per_line
+            .into_iter()
+            .take(SUPPORTED_RELEASES)
+            .map(|(_, version)| version.to_string())
+            .collect();
+        (!supported.is_empty()).then_some(Self { supported })
+    }
+}
+
+pub(super) fn go_advisory(version: &str) -> Advisory {
+    match lifecycle::cached::<GoLifecycle>("go") {
+        Some(data) => go_advisory_with(version, &data),
+        None => Advisory::new(AdvisoryStatus::Unknown),
+    }
+}
+
+pub(super) fn go_advisory_with(version: &str, data: &GoLifecycle) -> Advisory {
+    let key = version_sort_key(version);
+    let (Some(newest), Some(oldest)) = (data.supported.first(), data.supported.last()) else {
+        return Advisory::new(AdvisoryStatus::Unknown);
+    };
+    if key.len() < 2 {
+        return Advisory::new(AdvisoryStatus::Unknown);
+    }
+    let line = format!("{}.{}", key[0], key[1]);
+
+    if let Some(latest_patch) = data
+        .supported
+        .iter()
+        .find(|supported| version_sort_key(supported).get(..2) == Some(&key[..2]))
+    {
+        return if key < version_sort_key(latest_patch) {
+            Advisory::new(AdvisoryStatus::SecurityUpdateAvailable)
+                .with_message(format!(
+                    "go@{} has a newer patch release {}",
+                    version, latest_patch
+                ))
+                .with_recommendation(format!("upgrade to go@{}", latest_patch))
+        } else {
+            Advisory::new(AdvisoryStatus::Current)
+        };
+    }
+
+    if key < version_sort_key(oldest) {
+        Advisory::new(AdvisoryStatus::Eol)
+            .with_message(format!("go@{} is no longer supported", line))
+            .with_recommendation(format!("upgrade to go@{} (latest release)", newest))
+    } else {
+        Advisory::new(AdvisoryStatus::Current)
+    }
+}
@@ -1,6 +1,85 @@
-use super::{Advisory, AdvisoryStatus};
+use super::{lifecycle, Advisory, AdvisoryStatus};
+use crate::tools::Version;
+use serde::{Deserialize, Serialize};
+
+/// Feature releases known to Adoptium, taken from the `available_releases` info.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct JavaLifecycle {
+    pub(super) lts: Vec<u32>,
+    pub(super) latest: u32,
+}
+
+impl JavaLifecycle {
+    pub(super) fn from_versions(versions: &[Version]) -> Option<Self> {
+        let majors = versions
+            .iter()
+            .filter_map(|version| Some((major_of(&version.version)?, version.lts.is_some())));
+        let mut lts = Vec::new();
+        let mut latest = None;
+        for (major, is_lts) in majors {
+            latest = latest.max(Some(major));
+            if is_lts {
+                lts.push(major);
+            }
+        }
+        lts.sort_unstable();
+        lts.dedup();
+        Some(Self {
+            lts,
+            latest: latest?,
+        })
+    }
+}
+
+fn major_of(version: &str) -> Option<u32> {
+    version
+        .trim_start_matches('v')
+        .split(['.', '+', '-'])
+        .next()
+        .and_then(|s| s.parse::<u32>().ok())
+}
 
 pub(super) fn java_advisory(version: &str) -> Advisory {
+    match lifecycle::cached::<JavaLifecycle>("java") {
+        Some(data) => java_advisory_with(version, &data),
+        None => java_fallback_advisory(version),
+    }
+}
+
+/// Only the newest LTS and the latest feature release are current; superseded non-LTS
+/// releases no longer receive updates.
+pub(super) fn java_advisory_with(version: &str, data: &JavaLifecycle) -> Advisory {
+    let major = major_of(version).unwrap_or(0);
+    let Some(current_lts) = data
+        .lts
+        .iter()
+        .copied()
+        .filter(|lts| *lts <= data.latest)
+        .max()
+    else {
+        return Advisory::new(AdvisoryStatus::Unknown);
+    };
+
+    if major >= data.latest || major == current_lts {
+        return Advisory::new(AdvisoryStatus::Current);
+    }
+
+    if data.lts.contains(&major) {
+        return Advisory::new(AdvisoryStatus::LtsAvailable)
+            .with_message(format!("java@{} is an older LTS", major))
+            .with_recommendation(format!(
+                "consider upgrading to java@{} (current LTS)",
+                current_lts
+            ));
+    }
+
+    Advisory::new(AdvisoryStatus::Eol)
+        .with_message(format!("java@{} is end-of-life", major))
+        .with_recommendation(format!("upgrade to java@{} (current LTS)", current_lts))
+}
+
+/// Offline fallback used until Adoptium release info has been cached.
+pub(super) fn java_fallback_advisory(version: &str) -> Advisory {
     let version = version.trim_start_matches('v');
     let major = version
         .split('.')
@@ -0,0 +1,63 @@
+//! Upstream lifecycle data
+//!
+//! Release schedules are cached in `~/.vex/cache/lifecycle-<tool>.json` and refreshed at most
+//! once a day, piggybacking on live remote version fetches so advisories never add network
+//! latency of their own. When nothing has been cached yet, advisories use compiled tables.
+
+use super::{go, java, node, rust};
+use crate::cache::RemoteCache;
+use crate::config;
+use crate::error::{Result, VexError};
+use crate::tools::{self, Version};
+use serde::de::DeserializeOwned;
+
+const LIFECYCLE_TTL_SECS: u64 = 24 * 60 * 60;
+
+fn document_name(tool: &str) -> String {
+    format!("lifecycle-{}", tool)
+}
+
+/// Read cached lifecycle data regardless of age; stale data still beats the compiled tables.
+pub(super) fn cached<T: DeserializeOwned>(tool: &str) -> Option<T> {
+    let vex = config::vex_home()?;
+    RemoteCache::new(&vex)
+        .get_cached_document(&document_name(tool))
+        .map(|(data, _)| data)
+}
+
+pub(super) fn refresh(tool: &str, versions: &[Version]) -> Result<()> {
+    let vex = config::vex_home().ok_or(VexError::HomeDirectoryNotFound)?;
+    refresh_in(&RemoteCache::new(&vex), tool, versions)
+}
+
+pub(super) fn refresh_in(cache: &RemoteCache, tool: &str, versions: &[Version]) -> Result<()> {
+    let name = document_name(tool);
+    if cache
+        .get_cached_document::<serde_json::Value>(&name)
+        .is_some_and(|(_, age)| age < LIFECYCLE_TTL_SECS)
+    {
+        return Ok(());
+    }
+
+    match tool {
+        "node" => cache.set_cached_document(&name, &node::fetch_lines()?),
+        "python" => cache.set_cached_document(&name, &tools::python::fetch_lifecycle_statuses()?),
+        "java" => {
+            if let Some(data) = java::JavaLifecycle::from_versions(versions) {
+                cache.set_cached_document(&name, &data);
+            }
+        }
+        "go" => {
+            if let Some(data) = go::GoLifecycle::from_versions(versions) {
+                cache.set_cached_document(&name, &data);
+            }
+        }
+        "rust" => {
+            if let Some(data) = rust::RustLifecycle::from_versions(versions) {
+                cache.set_cached_document(&name, &data);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
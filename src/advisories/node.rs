@@ -1,8 +1,15 @@
-use super::{Advisory, AdvisoryStatus};
+use super::{lifecycle, Advisory, AdvisoryStatus};
+use crate::error::Result;
+use crate::http;
 use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Clone, Copy)]
-struct NodeLine {
+const NODE_SCHEDULE_URL: &str =
+    "https://raw.githubusercontent.com/nodejs/Release/main/schedule.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct NodeLine {
     major: u32,
     start: NaiveDate,
     lts: Option<NaiveDate>,
@@ -18,6 +25,16 @@ enum NodePhase {
     Eol,
 }
 
+/// Entry of the upstream Node.js release schedule, keyed by `v<major>`.
+#[derive(Deserialize)]
+pub(super) struct ScheduleEntry {
+    start: NaiveDate,
+    lts: Option<NaiveDate>,
+    maintenance: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+/// Offline fallback for the upstream release schedule.
 const NODE_LINES: &[NodeLine] = &[
     node_line(
         16,
@@ -105,11 +122,47 @@ const fn parse_date_const(date: &str) -> NaiveDate {
     }
 }
 
+pub(super) fn fetch_lines() -> Result<Vec<NodeLine>> {
+    let schedule: BTreeMap<String, ScheduleEntry> = http::get_json_in_current_context(
+        NODE_SCHEDULE_URL,
+        concat!("vex/", env!("CARGO_PKG_VERSION")),
+    )?;
+    Ok(lines_from_schedule(schedule))
+}
+
+/// Convert the release schedule into release lines, skipping the pre-io.js `v0.x` entries.
+pub(super) fn lines_from_schedule(schedule: BTreeMap<String, ScheduleEntry>) -> Vec<NodeLine> {
+    let mut lines: Vec<NodeLine> = schedule
+        .into_iter()
+        .filter_map(|(key, entry)| {
+            let major = key.strip_prefix('v')?.parse::<u32>().ok()?;
+            (major > 0).then_some(NodeLine {
+                major,
+                start: entry.start,
+                lts: entry.lts,
+                maintenance: entry.maintenance,
+                end: entry.end,
+            })
+        })
+        .collect();
+    lines.sort_by_key(|line| line.major);
+    lines
+}
+
 pub(super) fn node_advisory(version: &str) -> Advisory {
-    node_advisory_at(version, Utc::now().date_naive())
+    let today = Utc::now().date_naive();
+    match lifecycle::cached::<Vec<NodeLine>>("node") {
+        Some(lines) if !lines.is_empty() => node_advisory_with(version, &lines, today),
+        _ => node_advisory_with(version, NODE_LINES, today),
+    }
 }
 
+#[cfg(test)]
 pub(super) fn node_advisory_at(version: &str, today: NaiveDate) -> Advisory {
+    node_advisory_with(version, NODE_LINES, today)
+}
+
+pub(super) fn node_advisory_with(version: &str, lines: &[NodeLine], today: NaiveDate) -> Advisory {
     let version = version.trim_start_matches('v');
     let major = version
         .split('.')
@@ -117,11 +170,14 @@ pub(super) fn node_advisory_at(version: &str, today: NaiveDate) -> Advisory {
         .and_then(|s| s.parse::<u32>().ok())
         .unwrap_or(0);
 
-    let Some(line) = NODE_LINES.iter().find(|line| line.major == major).copied() else {
-        return if major < NODE_LINES[0].major {
+    let Some(oldest) = lines.first() else {
+        return Advisory::new(AdvisoryStatus::Unknown);
+    };
+    let Some(line) = lines.iter().find(|line| line.major == major).copied() else {
+        return if major < oldest.major {
             Advisory::new(AdvisoryStatus::Eol)
                 .with_message(format!("node@{} is end-of-life", major))
-                .with_recommendation(recommendation(today, "upgrade to"))
+                .with_recommendation(recommendation(lines, today, "upgrade to"))
         } else {
             Advisory::new(AdvisoryStatus::Current)
         };
@@ -130,10 +186,10 @@ pub(super) fn node_advisory_at(version: &str, today: NaiveDate) -> Advisory {
     match phase_for(line, today) {
         NodePhase::Eol => Advisory::new(AdvisoryStatus::Eol)
             .with_message(format!("node@{} is end-of-life", major))
-            .with_recommendation(recommendation(today, "upgrade to")),
+            .with_recommendation(recommendation(lines, today, "upgrade to")),
         NodePhase::MaintenanceLts => Advisory::new(AdvisoryStatus::LtsAvailable)
             .with_message(format!("node@{} is in maintenance mode", major))
-            .with_recommendation(recommendation(today, "consider upgrading to")),
+            .with_recommendation(recommendation(lines, today, "consider upgrading to")),
         NodePhase::ActiveLts | NodePhase::Current => Advisory::new(AdvisoryStatus::Current),
     }
 }
@@ -162,16 +218,16 @@ fn phase_for(line: NodeLine, today: NaiveDate) -> NodePhase {
     NodePhase::Current
 }
 
-fn recommendation(today: NaiveDate, prefix: &str) -> String {
-    let Some((major, label)) = recommended_target(today) else {
+fn recommendation(lines: &[NodeLine], today: NaiveDate, prefix: &str) -> String {
+    let Some((major, label)) = recommended_target(lines, today) else {
         return "upgrade to the latest supported Node.js release".to_string();
     };
 
     format!("{prefix} node@{major} ({label})")
 }
 
-fn recommended_target(today: NaiveDate) -> Option<(u32, &'static str)> {
-    if let Some(line) = NODE_LINES
+fn recommended_target(lines: &[NodeLine], today: NaiveDate) -> Option<(u32, &'static str)> {
+    if let Some(line) = lines
         .iter()
         .copied()
         .filter(|line| phase_for(*line, today) == NodePhase::ActiveLts)
@@ -180,7 +236,7 @@ fn recommended_target(today: NaiveDate) -> Option<(u32, &'static str)> {
         return Some((line.major, "current LTS"));
     }
 
-    NODE_LINES
+    lines
         .iter()
         .copied()
         .filter(|line| phase_for(*line, today) == NodePhase::Current)
@@ -1,6 +1,77 @@
-use super::{Advisory, AdvisoryStatus};
+use super::{lifecycle, Advisory, AdvisoryStatus};
+use std::collections::BTreeMap;
 
 pub(super) fn python_advisory(version: &str) -> Advisory {
+    match lifecycle::cached::<BTreeMap<String, String>>("python") {
+        Some(statuses) if !statuses.is_empty() => python_advisory_with(version, &statuses),
+        _ => python_fallback_advisory(version),
+    }
+}
+
+/// Classify a version using devguide branch statuses such as `{"3.13": "bugfix"}`.
+pub(super) fn python_advisory_with(version: &str, statuses: &BTreeMap<String, String>) -> Advisory {
+    let (major, minor) = major_minor(version);
+    let branches: Vec<(u32, &str)> = statuses
+        .iter()
+        .filter_map(|(branch, status)| {
+            let (branch_major, branch_minor) = major_minor(branch);
+            (branch_major == 3).then_some((branch_minor, status.as_str()))
+        })
+        .collect();
+
+    if major == 2 {
+        return Advisory::new(AdvisoryStatus::Eol)
+            .with_message("python@2 is end-of-life".to_string())
+            .with_recommendation(recommendation(&branches, "upgrade to"));
+    }
+
+    let status = branches
+        .iter()
+        .find(|(branch_minor, _)| *branch_minor == minor)
+        .map(|(_, status)| *status);
+    let oldest_known = branches.iter().map(|(branch_minor, _)| *branch_minor).min();
+
+    match status {
+        Some("end-of-life") => Advisory::new(AdvisoryStatus::Eol)
+            .with_message(format!("python@3.{} is end-of-life", minor))
+            .with_recommendation(recommendation(&branches, "upgrade to")),
+        Some("security") => Advisory::new(AdvisoryStatus::LtsAvailable)
+            .with_message(format!("python@3.{} is in security-only mode", minor))
+            .with_recommendation(recommendation(&branches, "consider upgrading to")),
+        Some(_) => Advisory::new(AdvisoryStatus::Current),
+        None if oldest_known.is_some_and(|oldest| minor < oldest) => {
+            Advisory::new(AdvisoryStatus::Eol)
+                .with_message(format!("python@3.{} is end-of-life", minor))
+                .with_recommendation(recommendation(&branches, "upgrade to"))
+        }
+        None => Advisory::new(AdvisoryStatus::Current),
+    }
+}
+
+/// Recommend the two newest bugfix branches, matching the devguide's "supported" releases.
+fn recommendation(branches: &[(u32, &str)], prefix: &str) -> String {
+    let mut bugfix: Vec<u32> = branches
+        .iter()
+        .filter(|(_, status)| *status == "bugfix")
+        .map(|(minor, _)| *minor)
+        .collect();
+    bugfix.sort_unstable();
+    let targets: Vec<String> = bugfix
+        .iter()
+        .rev()
+        .take(2)
+        .rev()
+        .map(|minor| format!("python@3.{}", minor))
+        .collect();
+
+    if targets.is_empty() {
+        format!("{prefix} the latest supported Python release")
+    } else {
+        format!("{prefix} {}", targets.join(" or "))
+    }
+}
+
+fn major_minor(version: &str) -> (u32, u32) {
     let version = version.trim_start_matches('v');
     let parts: Vec<&str> = version.split('.').collect();
     let major = parts
@@ -11,6 +82,12 @@ pub(super) fn python_advisory(version: &str) -> Advisory {
         .get(1)
         .and_then(|s| s.parse::<u32>().ok())
         .unwrap_or(0);
+    (major, minor)
+}
+
+/// Offline fallback used until devguide statuses have been cached.
+pub(super) fn python_fallback_advisory(version: &str) -> Advisory {
+    let (major, minor) = major_minor(version);
 
     if major == 2 {
         return Advisory::new(AdvisoryStatus::Eol)
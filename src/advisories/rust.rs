@@ -0,0 +1,50 @@
+use super::{lifecycle, Advisory, AdvisoryStatus};
+use crate::tools::Version;
+use crate::versioning::version_sort_key;
+use serde::{Deserialize, Serialize};
+
+/// Rust only patches the latest stable release, so anything older is behind on fixes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct RustLifecycle {
+    pub(super) latest_stable: String,
+}
+
+impl RustLifecycle {
+    pub(super) fn from_versions(versions: &[Version]) -> Option<Self> {
+        versions
+            .iter()
+            .filter(|version| version_sort_key(&version.version).len() >= 2)
+            .max_by_key(|version| version_sort_key(&version.version))
+            .map(|version| Self {
+                latest_stable: version.version.clone(),
+            })
+    }
+}
+
+pub(super) fn rust_advisory(version: &str) -> Advisory {
+    match lifecycle::cached::<RustLifecycle>("rust") {
+        Some(data) => rust_advisory_with(version, &data),
+        None => Advisory::new(AdvisoryStatus::Unknown),
+    }
+}
+
+pub(super) fn rust_advisory_with(version: &str, data: &RustLifecycle) -> Advisory {
+    // Channel names such as `stable` or `nightly` always track upstream.
+    if !version.starts_with(|c: char| c.is_ascii_digit()) {
+        return Advisory::new(AdvisoryStatus::Unknown);
+    }
+
+    if version_sort_key(version) < version_sort_key(&data.latest_stable) {
+        Advisory::new(AdvisoryStatus::SecurityUpdateAvailable)
+            .with_message(format!(
+                "rust@{} is older than the latest stable release {}",
+                version, data.latest_stable
+            ))
+            .with_recommendation(format!(
+                "upgrade to rust@{} (only the latest stable release receives fixes)",
+                data.latest_stable
+            ))
+    } else {
+        Advisory::new(AdvisoryStatus::Current)
+    }
+}
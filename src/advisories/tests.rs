@@ -1,4 +1,5 @@
 use super::*;
+use crate::tools::Version;
 use chrono::NaiveDate;
 
 fn date(value: &str) -> NaiveDate {
@@ -60,49 +61,217 @@ fn test_node_current_release_when_no_active_lts_exists() {
 
 #[test]
 fn test_java_eol_versions() {
-    let advisory = java::java_advisory("10.0.0");
+    let advisory = java::java_fallback_advisory("10.0.0");
     assert_eq!(advisory.status, AdvisoryStatus::Eol);
     assert!(advisory.is_warning());
 }
 
 #[test]
 fn test_java_current_lts() {
-    let advisory = java::java_advisory("21.0.0");
+    let advisory = java::java_fallback_advisory("21.0.0");
     assert_eq!(advisory.status, AdvisoryStatus::Current);
     assert!(!advisory.is_warning());
 }
 
 #[test]
 fn test_java_older_lts() {
-    let advisory = java::java_advisory("17.0.0");
+    let advisory = java::java_fallback_advisory("17.0.0");
     assert_eq!(advisory.status, AdvisoryStatus::LtsAvailable);
     assert!(advisory.is_warning());
 }
 
 #[test]
 fn test_python_eol_versions() {
-    let advisory = python::python_advisory("3.7.0");
+    let advisory = python::python_fallback_advisory("3.7.0");
     assert_eq!(advisory.status, AdvisoryStatus::Eol);
     assert!(advisory.is_warning());
 }
 
 #[test]
 fn test_python_current() {
-    let advisory = python::python_advisory("3.12.0");
+    let advisory = python::python_fallback_advisory("3.12.0");
     assert_eq!(advisory.status, AdvisoryStatus::Current);
     assert!(!advisory.is_warning());
 }
 
 #[test]
 fn test_python2_eol() {
-    let advisory = python::python_advisory("2.7.18");
+    let advisory = python::python_fallback_advisory("2.7.18");
     assert_eq!(advisory.status, AdvisoryStatus::Eol);
     assert!(advisory.is_warning());
 }
 
 #[test]
 fn test_unsupported_tool() {
-    let advisory = get_advisory("go", "1.21.0");
+    let advisory = get_advisory("deno", "1.21.0");
     assert_eq!(advisory.status, AdvisoryStatus::Unknown);
     assert!(!advisory.is_warning());
 }
+
+fn versions(entries: &[(&str, Option<&str>)]) -> Vec<Version> {
+    entries
+        .iter()
+        .map(|(version, lts)| Version {
+            version: version.to_string(),
+            lts: lts.map(ToString::to_string),
+        })
+        .collect()
+}
+
+#[test]
+fn test_node_schedule_drives_advisories() {
+    let schedule = serde_json::from_str(
+        r#"{
+            "v0.12": {"start": "2015-02-06", "end": "2016-12-31"},
+            "v20": {"start": "2023-04-18", "lts": "2023-10-24", "maintenance": "2024-10-22", "end": "2026-04-30", "codename": "Iron"},
+            "v24": {"start": "2025-05-06", "lts": "2025-10-28", "maintenance": "2026-10-20", "end": "2028-04-30", "codename": "Krypton"},
+            "v26": {"start": "2026-04-22", "lts": "2026-10-28", "maintenance": "2027-10-20", "end": "2029-04-30"}
+        }"#,
+    )
+    .unwrap();
+    let lines = node::lines_from_schedule(schedule);
+    assert_eq!(lines.len(), 3);
+
+    let eol = node::node_advisory_with("20.19.0", &lines, date("2026-05-01"));
+    assert_eq!(eol.status, AdvisoryStatus::Eol);
+    assert_eq!(
+        eol.recommendation.as_deref(),
+        Some("upgrade to node@24 (current LTS)")
+    );
+
+    let active = node::node_advisory_with("24.1.0", &lines, date("2026-05-01"));
+    assert_eq!(active.status, AdvisoryStatus::Current);
+}
+
+#[test]
+fn test_python_devguide_statuses() {
+    let statuses: std::collections::BTreeMap<String, String> = [
+        ("3.15", "feature"),
+        ("3.14", "bugfix"),
+        ("3.13", "bugfix"),
+        ("3.12", "security"),
+        ("3.9", "end-of-life"),
+    ]
+    .into_iter()
+    .map(|(branch, status)| (branch.to_string(), status.to_string()))
+    .collect();
+
+    let security = python::python_advisory_with("3.12.8", &statuses);
+    assert_eq!(security.status, AdvisoryStatus::LtsAvailable);
+    assert_eq!(
+        security.recommendation.as_deref(),
+        Some("consider upgrading to python@3.13 or python@3.14")
+    );
+    assert_eq!(
+        python::python_advisory_with("3.9.21", &statuses).status,
+        AdvisoryStatus::Eol
+    );
+    assert_eq!(
+        python::python_advisory_with("3.7.17", &statuses).status,
+        AdvisoryStatus::Eol
+    );
+    assert_eq!(
+        python::python_advisory_with("3.14.0", &statuses).status,
+        AdvisoryStatus::Current
+    );
+}
+
+#[test]
+fn test_java_lifecycle_from_adoptium_releases() {
+    let data = java::JavaLifecycle::from_versions(&versions(&[
+        ("26", None),
+        ("25", Some("LTS")),
+        ("24", None),
+        ("21", Some("LTS")),
+        ("17", Some("LTS")),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        java::java_advisory_with("25.0.1", &data).status,
+        AdvisoryStatus::Current
+    );
+    assert_eq!(
+        java::java_advisory_with("26", &data).status,
+        AdvisoryStatus::Current
+    );
+    let older_lts = java::java_advisory_with("21.0.5", &data);
+    assert_eq!(older_lts.status, AdvisoryStatus::LtsAvailable);
+    assert_eq!(
+        older_lts.recommendation.as_deref(),
+        Some("consider upgrading to java@25 (current LTS)")
+    );
+    assert_eq!(
+        java::java_advisory_with("24.0.2", &data).status,
+        AdvisoryStatus::Eol
+    );
+}
+
+#[test]
+fn test_go_supports_two_latest_releases() {
+    let data = go::GoLifecycle::from_versions(&versions(&[
+        ("1.25.3", None),
+        ("1.25.2", None),
+        ("1.24.9", None),
+        ("1.24.8", None),
+        ("1.23.12", None),
+    ]))
+    .unwrap();
+    assert_eq!(data.supported, vec!["1.25.3", "1.24.9"]);
+
+    assert_eq!(
+        go::go_advisory_with("1.25.3", &data).status,
+        AdvisoryStatus::Current
+    );
+    let patch = go::go_advisory_with("1.24.2", &data);
+    assert_eq!(patch.status, AdvisoryStatus::SecurityUpdateAvailable);
+    assert_eq!(
+        patch.recommendation.as_deref(),
+        Some("upgrade to go@1.24.9")
+    );
+    let eol = go::go_advisory_with("1.23.12", &data);
+    assert_eq!(eol.status, AdvisoryStatus::Eol);
+    assert_eq!(
+        eol.message.as_deref(),
+        Some("go@1.23 is no longer supported")
+    );
+}
+
+#[test]
+fn test_rust_only_latest_stable_is_current() {
+    let data = rust::RustLifecycle::from_versions(&versions(&[
+        ("1.90.0", None),
+        ("1.91.1", None),
+        ("1.89.0", None),
+    ]))
+    .unwrap();
+    assert_eq!(data.latest_stable, "1.91.1");
+
+    assert_eq!(
+        rust::rust_advisory_with("1.91.1", &data).status,
+        AdvisoryStatus::Current
+    );
+    assert_eq!(
+        rust::rust_advisory_with("1.90.0", &data).status,
+        AdvisoryStatus::SecurityUpdateAvailable
+    );
+    assert_eq!(
+        rust::rust_advisory_with("nightly", &data).status,
+        AdvisoryStatus::Unknown
+    );
+}
+
+#[test]
+fn test_lifecycle_refresh_skips_fresh_cache() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let cache = crate::cache::RemoteCache::new(tmp.path());
+    let rust_versions = versions(&[("1.91.1", None)]);
+
+    lifecycle::refresh_in(&cache, "rust", &rust_versions).unwrap();
+    lifecycle::refresh_in(&cache, "rust", &versions(&[("1.92.0", None)])).unwrap();
+
+    let (data, _) = cache
+        .get_cached_document::<rust::RustLifecycle>("lifecycle-rust")
+        .unwrap();
+    assert_eq!(data.latest_stable, "1.91.1");
+}
@@ -0,0 +1,292 @@
+//! Known vulnerabilities for toolchain versions
+//!
+//! Upstream feeds are normalized into [`Vulnerability`] entries and cached in
+//! `~/.vex/cache/vulnerabilities-<tool>.json`:
+//! - Node.js: the security working group's core vulnerability index
+//! - Go: the Go vulnerability database (`stdlib` and `toolchain`) via OSV
+//! - Python: PSF advisories for CPython via OSV
+//! - Java: OpenJDK Critical Patch Update advisories
+//!
+//! Setting `[advisories] vulnerability_feed` replaces the upstream sources with a mirror that
+//! serves `<url>/<tool>.json` in the normalized format, which is also how tests stand in for
+//! the network.
+
+mod java;
+mod node;
+mod osv;
+#[cfg(test)]
+mod tests;
+
+use crate::cache::RemoteCache;
+use crate::config;
+use crate::error::{Result, VexError};
+use crate::http;
+use crate::versioning::version_sort_key;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const VULNERABILITY_TTL_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Unknown,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// Map a CVSS base score to its qualitative rating.
+    pub(super) fn from_cvss_score(score: f32) -> Self {
+        match score {
+            s if s >= 9.0 => Self::Critical,
+            s if s >= 7.0 => Self::High,
+            s if s >= 4.0 => Self::Medium,
+            s if s > 0.0 => Self::Low,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub(super) fn from_label(label: &str) -> Self {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "critical" => Self::Critical,
+            "high" => Self::High,
+            "medium" | "moderate" => Self::Medium,
+            "low" => Self::Low,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// Versions from `introduced` (inclusive) up to `fixed` or `limit` (exclusive).
+///
+/// `fixed` names the release that ships the fix; `limit` only bounds a release line that
+/// never received one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AffectedRange {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub introduced: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<String>,
+}
+
+impl AffectedRange {
+    fn contains(&self, version: &[u32]) -> bool {
+        let after_start = self
+            .introduced
+            .as_deref()
+            .is_none_or(|introduced| version >= comparable(introduced).as_slice());
+        let before_end = self
+            .fixed
+            .as_deref()
+            .or(self.limit.as_deref())
+            .is_none_or(|end| version < comparable(end).as_slice());
+        after_start && before_end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vulnerability {
+    pub id: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default = "unknown_severity")]
+    pub severity: Severity,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub affected: Vec<AffectedRange>,
+    /// Exact affected versions, for sources that enumerate releases instead of ranges.
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+fn unknown_severity() -> Severity {
+    Severity::Unknown
+}
+
+impl Vulnerability {
+    pub fn affects(&self, version: &str) -> bool {
+        let key = comparable(version);
+        if key.is_empty() {
+            return false;
+        }
+        self.affected.iter().any(|range| range.contains(&key))
+            || self
+                .versions
+                .iter()
+                .any(|affected| comparable(affected) == key)
+    }
+
+    /// The fix release for the line `version` belongs to, if upstream published one.
+    fn fixed_version_for(&self, version: &str) -> Option<String> {
+        let key = comparable(version);
+        self.affected
+            .iter()
+            .filter(|range| range.contains(&key))
+            .filter_map(|range| range.fixed.clone())
+            .min_by_key(|fixed| comparable(fixed))
+    }
+}
+
+/// Normalized feed format, both for the cache and for `vulnerability_feed` mirrors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VulnerabilityFeed {
+    #[serde(default)]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+/// A vulnerability that applies to one specific version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VulnerabilityMatch {
+    pub id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    pub severity: Severity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_in: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl VulnerabilityMatch {
+    /// `CVE-2024-27983 (high, fixed in 20.12.1)`
+    pub fn label(&self) -> String {
+        match &self.fixed_in {
+            Some(fixed) => format!(
+                "{} ({}, fixed in {})",
+                self.id,
+                self.severity.as_str(),
+                fixed
+            ),
+            None => format!("{} ({})", self.id, self.severity.as_str()),
+        }
+    }
+}
+
+fn document_name(tool: &str) -> String {
+    format!("vulnerabilities-{}", tool)
+}
+
+/// Versions are compared numerically; Java's `8u432` is read as `8.0.432` and build or
+/// pre-release suffixes are ignored.
+pub(super) fn comparable(version: &str) -> Vec<u32> {
+    let version = version.trim().trim_start_matches('v');
+    let version = version.split(['+', '-']).next().unwrap_or(version);
+    if let Some((major, update)) = version.split_once('u') {
+        if let (Ok(major), Ok(update)) = (major.parse::<u32>(), update.parse::<u32>()) {
+            return vec![major, 0, update];
+        }
+    }
+    version_sort_key(version)
+}
+
+/// Match a version against a feed, most severe first.
+pub(super) fn matches_in(feed: &VulnerabilityFeed, version: &str) -> Vec<VulnerabilityMatch> {
+    let mut matches: Vec<VulnerabilityMatch> = feed
+        .vulnerabilities
+        .iter()
+        .filter(|vulnerability| vulnerability.affects(version))
+        .map(|vulnerability| VulnerabilityMatch {
+            id: vulnerability.id.clone(),
+            aliases: vulnerability.aliases.clone(),
+            severity: vulnerability.severity,
+            summary: vulnerability.summary.clone(),
+            fixed_in: vulnerability.fixed_version_for(version),
+            url: vulnerability.url.clone(),
+        })
+        .collect();
+    matches.sort_by(|left, right| {
+        right
+            .severity
+            .cmp(&left.severity)
+            .then_with(|| left.id.cmp(&right.id))
+    });
+    matches.dedup_by(|left, right| left.id == right.id);
+    matches
+}
+
+/// Read the cached feed regardless of age, so results stay available offline.
+pub(super) fn cached_matches(tool: &str, version: &str) -> Vec<VulnerabilityMatch> {
+    let Some(vex) = config::vex_home() else {
+        return Vec::new();
+    };
+    let Some(version) = comparable_installed_version(&vex, tool, version) else {
+        return Vec::new();
+    };
+    RemoteCache::new(&vex)
+        .get_cached_document::<VulnerabilityFeed>(&document_name(tool))
+        .map(|(feed, _)| matches_in(&feed, &version))
+        .unwrap_or_default()
+}
+
+/// The version to match for an installed toolchain, or `None` when its patch level is unknown.
+pub(super) fn comparable_installed_version(
+    vex: &Path,
+    tool: &str,
+    version: &str,
+) -> Option<String> {
+    match tool {
+        "java" => java::installed_release(vex, version),
+        _ => Some(version.to_string()),
+    }
+}
+
+pub(super) fn refresh(tool: &str) -> Result<()> {
+    let vex = config::vex_home().ok_or(VexError::HomeDirectoryNotFound)?;
+    let cache = RemoteCache::new(&vex);
+    let name = document_name(tool);
+    if cache
+        .get_cached_document::<serde_json::Value>(&name)
+        .is_some_and(|(_, age)| age < VULNERABILITY_TTL_SECS)
+    {
+        return Ok(());
+    }
+
+    let settings = config::load_settings()?;
+    let feed = match settings.advisories.vulnerability_feed {
+        Some(base) => fetch_mirror_feed(&base, tool)?,
+        None => match fetch_upstream_feed(tool)? {
+            Some(feed) => feed,
+            None => return Ok(()),
+        },
+    };
+    cache.set_cached_document(&name, &feed);
+    Ok(())
+}
+
+fn fetch_mirror_feed(base: &str, tool: &str) -> Result<VulnerabilityFeed> {
+    http::get_json_in_current_context(
+        &format!("{}/{}.json", base.trim_end_matches('/'), tool),
+        concat!("vex/", env!("CARGO_PKG_VERSION")),
+    )
+}
+
+fn fetch_upstream_feed(tool: &str) -> Result<Option<VulnerabilityFeed>> {
+    let vulnerabilities = match tool {
+        "node" => node::fetch()?,
+        "go" => osv::fetch_go()?,
+        "python" => osv::fetch_python()?,
+        "java" => java::fetch()?,
+        _ => return Ok(None),
+    };
+    Ok(Some(VulnerabilityFeed { vulnerabilities }))
+}
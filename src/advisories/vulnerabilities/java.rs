@@ -0,0 +1,250 @@
+//! OpenJDK Critical Patch Update advisories.
+//!
+//! Each advisory page lists the fixed releases per feature line (e.g. `21.0.5`, `8u432`) and
+//! a table of CVEs with their CVSS score and the feature lines they affect.
+
+use super::{comparable, AffectedRange, Severity, Vulnerability};
+use crate::error::Result;
+use crate::http;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const OPENJDK_ADVISORIES_URL: &str = "https://openjdk.org/groups/vulnerability/advisories/";
+
+/// Two years of quarterly updates.
+const MAX_ADVISORIES: usize = 8;
+
+pub(super) fn fetch() -> Result<Vec<Vulnerability>> {
+    let user_agent = concat!("vex/", env!("CARGO_PKG_VERSION"));
+    let index = http::get_text_in_current_context(OPENJDK_ADVISORIES_URL, user_agent)?;
+    let mut vulnerabilities = Vec::new();
+    for date in advisory_dates(&index).into_iter().take(MAX_ADVISORIES) {
+        let url = format!("{}{}", OPENJDK_ADVISORIES_URL, date);
+        let page = http::get_text_in_current_context(&url, user_agent)?;
+        vulnerabilities.extend(parse_advisory(&page, &url));
+    }
+    Ok(vulnerabilities)
+}
+
+/// vex installs JDKs under their feature number (`toolchains/java/21`), which would fall inside
+/// every range of that line. Read the patch level from the JDK's `release` file instead; without
+/// one the version is unknown.
+pub(super) fn installed_release(vex: &Path, version: &str) -> Option<String> {
+    if !is_number(version) {
+        return Some(version.to_string());
+    }
+    let release = fs::read_to_string(
+        vex.join("toolchains")
+            .join("java")
+            .join(version)
+            .join("Contents/Home/release"),
+    )
+    .ok()?;
+    release_version(&release)
+}
+
+/// `JAVA_VERSION="21.0.4"`, with Java 8's `1.8.0_422` read as `8u422`.
+pub(super) fn release_version(release: &str) -> Option<String> {
+    let value = release
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("JAVA_VERSION="))?
+        .trim()
+        .trim_matches('"');
+    if let Some((major, update)) = value
+        .strip_prefix("1.")
+        .and_then(|legacy| legacy.split_once(".0_"))
+    {
+        return Some(format!("{}u{}", major, update));
+    }
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Advisory pages are named by release date (`2024-10-15`); newest first.
+pub(super) fn advisory_dates(index_html: &str) -> Vec<String> {
+    let mut dates: Vec<String> = index_html
+        .split("href=\"")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .map(|href| {
+            href.trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or(href)
+        })
+        .filter(|name| is_date(name))
+        .map(ToString::to_string)
+        .collect();
+    dates.sort_unstable_by(|left, right| right.cmp(left));
+    dates.dedup();
+    dates
+}
+
+fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(index, byte)| index == 4 || index == 7 || byte.is_ascii_digit())
+}
+
+pub(super) fn parse_advisory(html: &str, url: &str) -> Vec<Vulnerability> {
+    let fixed = fixed_releases(&strip_html_tags(html));
+    let mut features: Vec<Option<u32>> = Vec::new();
+    let mut vulnerabilities = Vec::new();
+
+    for row in table_rows(html) {
+        let Some(cve) = row.iter().find_map(|cell| cve_id(cell)) else {
+            if features.is_empty() && row.iter().any(|cell| leading_number(cell).is_some()) {
+                features = row.iter().map(|cell| leading_number(cell)).collect();
+            }
+            continue;
+        };
+
+        let score = row.iter().skip(1).find_map(|cell| {
+            cell.trim()
+                .parse::<f32>()
+                .ok()
+                .filter(|score| *score <= 10.0)
+        });
+        let mut affected_features: Vec<u32> = row
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| !cell.trim().is_empty())
+            .filter_map(|(index, _)| features.get(index).copied().flatten())
+            .collect();
+        if affected_features.is_empty() {
+            affected_features = fixed.keys().copied().collect();
+        }
+
+        let affected = affected_features
+            .into_iter()
+            .map(|feature| {
+                let fix = fixed.get(&feature).cloned();
+                AffectedRange {
+                    introduced: Some(feature.to_string()),
+                    limit: fix.is_none().then(|| (feature + 1).to_string()),
+                    fixed: fix,
+                }
+            })
+            .collect();
+
+        vulnerabilities.push(Vulnerability {
+            id: cve,
+            aliases: Vec::new(),
+            severity: score
+                .map(Severity::from_cvss_score)
+                .unwrap_or(Severity::Unknown),
+            summary: row
+                .get(1)
+                .map(|component| component.trim().to_string())
+                .filter(|component| !component.is_empty() && cve_id(component).is_none()),
+            url: Some(url.to_string()),
+            affected,
+            versions: Vec::new(),
+        });
+    }
+
+    vulnerabilities
+}
+
+/// Collect release numbers such as `21.0.5` or `8u432`, keyed by feature line.
+fn fixed_releases(text: &str) -> BTreeMap<u32, String> {
+    let mut fixed = BTreeMap::new();
+    for word in text.split(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')') {
+        let word = word.trim_end_matches(['.', ';', ':']);
+        let is_update = word
+            .split_once('u')
+            .is_some_and(|(major, update)| is_number(major) && is_number(update));
+        let is_release = word.split('.').count() == 3 && word.split('.').all(is_number);
+        if is_update || is_release {
+            if let Some(feature) = comparable(word).first().copied() {
+                fixed.entry(feature).or_insert_with(|| word.to_string());
+            }
+        }
+    }
+    fixed
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn leading_number(cell: &str) -> Option<u32> {
+    let digits: String = cell
+        .trim()
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+fn cve_id(cell: &str) -> Option<String> {
+    let start = cell.find("CVE-")?;
+    let id: String = cell[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    (id.len() > "CVE-".len()).then_some(id)
+}
+
+fn table_rows(html: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut remaining = html;
+    while let Some(start) = remaining.find("<tr") {
+        remaining = &remaining[start..];
+        let end = remaining.find("</tr>").unwrap_or(remaining.len());
+        rows.push(row_cells(&remaining[..end]));
+        remaining = &remaining[end..];
+        if remaining.len() <= "</tr>".len() {
+            break;
+        }
+        remaining = &remaining["</tr>".len()..];
+    }
+    rows
+}
+
+fn row_cells(row_html: &str) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut remaining = row_html;
+    while let Some(start) = remaining.find("<t") {
+        remaining = &remaining[start..];
+        let tag = &remaining[..remaining.len().min(3)];
+        if tag != "<td" && tag != "<th" {
+            remaining = &remaining[2..];
+            continue;
+        }
+        let closing = if tag == "<td" { "</td>" } else { "</th>" };
+        let Some(open_end) = remaining.find('>') else {
+            break;
+        };
+        remaining = &remaining[open_end + 1..];
+        let end = remaining.find(closing).unwrap_or(remaining.len());
+        cells.push(strip_html_tags(&remaining[..end]));
+        remaining = &remaining[end..];
+    }
+    cells
+}
+
+fn strip_html_tags(text: &str) -> String {
+    let mut out = String::new();
+    let mut inside_tag = false;
+    for ch in text.chars() {
+        match ch {
+            '<' => inside_tag = true,
+            '>' => {
+                inside_tag = false;
+                out.push(' ');
+            }
+            _ if !inside_tag => out.push(ch),
+            _ => {}
+        }
+    }
+    out.replace("&nbsp;", " ")
+        .replace("&bull;", "•")
+        .trim()
+        .to_string()
+}
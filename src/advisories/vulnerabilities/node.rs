@@ -0,0 +1,221 @@
+//! Node.js security working group feed (`vuln/core/index.json`).
+//!
+//! Entries describe affected and patched versions as semver ranges such as
+//! `^18.0.0 || ^20.0.0` and `^18.19.1 || ^20.11.1`.
+
+use super::{comparable, AffectedRange, Severity, Vulnerability};
+use crate::error::Result;
+use crate::http;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+const NODE_VULN_INDEX_URL: &str =
+    "https://raw.githubusercontent.com/nodejs/security-wg/main/vuln/core/index.json";
+
+#[derive(Deserialize)]
+pub(super) struct NodeVulnEntry {
+    #[serde(default)]
+    cve: Vec<String>,
+    #[serde(default)]
+    vulnerable: String,
+    #[serde(default)]
+    patched: String,
+    #[serde(default, rename = "ref")]
+    reference: Option<String>,
+    #[serde(default)]
+    overview: Option<String>,
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+/// Half-open version interval `[lower, upper)`; `None` means unbounded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Interval {
+    lower: Option<Vec<u32>>,
+    upper: Option<Vec<u32>>,
+}
+
+pub(super) fn fetch() -> Result<Vec<Vulnerability>> {
+    let index: BTreeMap<String, NodeVulnEntry> = http::get_json_in_current_context(
+        NODE_VULN_INDEX_URL,
+        concat!("vex/", env!("CARGO_PKG_VERSION")),
+    )?;
+    Ok(from_index(index))
+}
+
+pub(super) fn from_index(index: BTreeMap<String, NodeVulnEntry>) -> Vec<Vulnerability> {
+    index
+        .into_iter()
+        .filter_map(|(key, entry)| {
+            let affected = affected_ranges(&entry.vulnerable, &entry.patched);
+            if affected.is_empty() {
+                return None;
+            }
+            let mut ids = entry.cve.into_iter().filter(|cve| !cve.trim().is_empty());
+            let id = ids.next().unwrap_or_else(|| format!("NSWG-CORE-{}", key));
+            Some(Vulnerability {
+                id,
+                aliases: ids.collect(),
+                severity: entry
+                    .severity
+                    .as_deref()
+                    .map(Severity::from_label)
+                    .unwrap_or(Severity::Unknown),
+                summary: entry.overview.map(|overview| overview.trim().to_string()),
+                url: entry.reference,
+                affected,
+                versions: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Subtract the patched ranges from each vulnerable range. The first patched lower bound
+/// inside a vulnerable line becomes that line's fix.
+fn affected_ranges(vulnerable: &str, patched: &str) -> Vec<AffectedRange> {
+    let patched = parse_range_set(patched);
+    parse_range_set(vulnerable)
+        .into_iter()
+        .filter(|interval| {
+            // Skip lines that are entirely covered by a patched range.
+            !patched
+                .iter()
+                .any(|fix| fix.lower <= interval.lower && covers(fix, interval))
+        })
+        .map(|interval| {
+            let fix = patched
+                .iter()
+                .filter_map(|fix| fix.lower.clone())
+                .filter(|lower| {
+                    interval.lower.as_ref().is_none_or(|start| lower > start)
+                        && interval.upper.as_ref().is_none_or(|end| lower < end)
+                })
+                .min();
+            AffectedRange {
+                introduced: interval.lower.as_deref().map(format_version),
+                fixed: fix.as_deref().map(format_version),
+                limit: match fix {
+                    Some(_) => None,
+                    None => interval.upper.as_deref().map(format_version),
+                },
+            }
+        })
+        .collect()
+}
+
+fn covers(outer: &Interval, inner: &Interval) -> bool {
+    match (&outer.upper, &inner.upper) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(outer), Some(inner)) => outer >= inner,
+    }
+}
+
+fn parse_range_set(range: &str) -> Vec<Interval> {
+    range
+        .split("||")
+        .map(str::trim)
+        .filter(|set| !set.is_empty())
+        .filter_map(parse_comparator_set)
+        .collect()
+}
+
+/// Parse a space-separated comparator set such as `>= 12.0.0 < 12.22.2` or `^18.0.0`.
+fn parse_comparator_set(set: &str) -> Option<Interval> {
+    let mut tokens = Vec::new();
+    let mut pending = String::new();
+    for token in set.split_whitespace() {
+        if matches!(token, ">" | ">=" | "<" | "<=" | "=") {
+            pending = token.to_string();
+            continue;
+        }
+        tokens.push(format!("{}{}", pending, token));
+        pending.clear();
+    }
+
+    let mut interval = Interval {
+        lower: None,
+        upper: None,
+    };
+    for token in tokens {
+        let (lower, upper) = parse_comparator(&token)?;
+        if let Some(lower) = lower {
+            interval.lower = Some(
+                interval
+                    .lower
+                    .map_or(lower.clone(), |current| current.max(lower)),
+            );
+        }
+        if let Some(upper) = upper {
+            interval.upper = Some(
+                interval
+                    .upper
+                    .map_or(upper.clone(), |current| current.min(upper)),
+            );
+        }
+    }
+    Some(interval)
+}
+
+type Bounds = (Option<Vec<u32>>, Option<Vec<u32>>);
+
+fn parse_comparator(token: &str) -> Option<Bounds> {
+    if token == "*" || token == "x" {
+        return Some((None, None));
+    }
+    let (operator, version) = match token.find(|c: char| c.is_ascii_digit()) {
+        Some(index) => token.split_at(index),
+        None => return None,
+    };
+    let parts = comparable(version);
+    if parts.is_empty() {
+        return None;
+    }
+    let exact = pad(&parts);
+
+    Some(match operator.trim_start_matches('v') {
+        ">=" => (Some(exact), None),
+        ">" => (Some(next_patch(&exact)), None),
+        "<" => (None, Some(exact)),
+        "<=" => (None, Some(next_patch(&exact))),
+        "^" => (Some(exact.clone()), Some(caret_upper(&exact))),
+        "~" => (Some(exact.clone()), Some(vec![exact[0], exact[1] + 1, 0])),
+        "" | "=" => {
+            // Partial versions such as `18` or `18.1` cover the whole line.
+            let upper = match parts.len() {
+                1 => vec![parts[0] + 1, 0, 0],
+                2 => vec![parts[0], parts[1] + 1, 0],
+                _ => next_patch(&exact),
+            };
+            (Some(exact), Some(upper))
+        }
+        _ => return None,
+    })
+}
+
+fn pad(parts: &[u32]) -> Vec<u32> {
+    let mut padded = parts.to_vec();
+    padded.resize(3, 0);
+    padded.truncate(3);
+    padded
+}
+
+fn next_patch(version: &[u32]) -> Vec<u32> {
+    vec![version[0], version[1], version[2] + 1]
+}
+
+fn caret_upper(version: &[u32]) -> Vec<u32> {
+    if version[0] > 0 {
+        vec![version[0] + 1, 0, 0]
+    } else {
+        vec![0, version[1] + 1, 0]
+    }
+}
+
+fn format_version(parts: &[u32]) -> String {
+    parts
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
@@ -0,0 +1,227 @@
+//! OSV-format sources: the Go vulnerability database and PSF advisories for CPython.
+
+use super::{AffectedRange, Severity, Vulnerability};
+use crate::error::Result;
+use crate::http;
+use serde::{Deserialize, Serialize};
+
+const OSV_QUERY_URL: &str = "https://api.osv.dev/v1/query";
+const CPYTHON_REPO: &str = "https://github.com/python/cpython";
+const MAX_PAGES: usize = 20;
+
+#[derive(Serialize)]
+struct OsvQuery<'a> {
+    package: OsvPackage<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<String>,
+}
+
+#[derive(Serialize)]
+struct OsvPackage<'a> {
+    name: &'a str,
+    ecosystem: &'a str,
+}
+
+#[derive(Deserialize)]
+struct OsvQueryResponse {
+    #[serde(default)]
+    vulns: Vec<OsvRecord>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(super) struct OsvRecord {
+    id: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    details: Option<String>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+    #[serde(default)]
+    references: Vec<OsvReference>,
+    #[serde(default)]
+    database_specific: Option<OsvDatabaseSpecific>,
+}
+
+#[derive(Deserialize)]
+struct OsvAffected {
+    #[serde(default)]
+    package: Option<OsvAffectedPackage>,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+    #[serde(default)]
+    versions: Vec<String>,
+    #[serde(default)]
+    database_specific: Option<OsvDatabaseSpecific>,
+}
+
+#[derive(Deserialize)]
+struct OsvAffectedPackage {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    events: Vec<OsvEvent>,
+}
+
+#[derive(Deserialize)]
+struct OsvEvent {
+    introduced: Option<String>,
+    fixed: Option<String>,
+    last_affected: Option<String>,
+    limit: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OsvReference {
+    #[serde(rename = "type")]
+    kind: String,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct OsvDatabaseSpecific {
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+pub(super) fn fetch_go() -> Result<Vec<Vulnerability>> {
+    let mut vulnerabilities = Vec::new();
+    for package in ["stdlib", "toolchain"] {
+        vulnerabilities.extend(
+            query(package, "Go")?
+                .into_iter()
+                .filter_map(|record| from_record(record, Some(package))),
+        );
+    }
+    Ok(vulnerabilities)
+}
+
+pub(super) fn fetch_python() -> Result<Vec<Vulnerability>> {
+    Ok(query(CPYTHON_REPO, "GIT")?
+        .into_iter()
+        .filter_map(|record| from_record(record, None))
+        .collect())
+}
+
+fn query(package: &str, ecosystem: &str) -> Result<Vec<OsvRecord>> {
+    let mut records = Vec::new();
+    let mut page_token = None;
+    for _ in 0..MAX_PAGES {
+        let response: OsvQueryResponse = http::post_json_in_current_context(
+            OSV_QUERY_URL,
+            &OsvQuery {
+                package: OsvPackage {
+                    name: package,
+                    ecosystem,
+                },
+                page_token: page_token.take(),
+            },
+            concat!("vex/", env!("CARGO_PKG_VERSION")),
+        )?;
+        records.extend(response.vulns);
+        match response.next_page_token {
+            Some(token) if !token.is_empty() => page_token = Some(token),
+            _ => break,
+        }
+    }
+    Ok(records)
+}
+
+/// Convert an OSV record, keeping only `affected` entries for `package` when one is given.
+/// Git commit ranges are skipped in favour of the enumerated release tags.
+pub(super) fn from_record(record: OsvRecord, package: Option<&str>) -> Option<Vulnerability> {
+    let mut affected = Vec::new();
+    let mut versions = Vec::new();
+    let mut severity = record
+        .database_specific
+        .as_ref()
+        .and_then(|specific| specific.severity.as_deref())
+        .map(Severity::from_label)
+        .unwrap_or(Severity::Unknown);
+
+    for entry in &record.affected {
+        let name = entry.package.as_ref().map(|package| package.name.as_str());
+        if package.is_some_and(|package| name != Some(package)) {
+            continue;
+        }
+        if let Some(label) = entry
+            .database_specific
+            .as_ref()
+            .and_then(|specific| specific.severity.as_deref())
+        {
+            severity = severity.max(Severity::from_label(label));
+        }
+        for range in entry.ranges.iter().filter(|range| range.kind != "GIT") {
+            affected.extend(ranges_from_events(&range.events));
+        }
+        versions.extend(entry.versions.iter().cloned());
+    }
+
+    if affected.is_empty() && versions.is_empty() {
+        return None;
+    }
+
+    let url = record
+        .references
+        .iter()
+        .find(|reference| reference.kind == "ADVISORY")
+        .or_else(|| record.references.first())
+        .map(|reference| reference.url.clone())
+        .or_else(|| Some(format!("https://osv.dev/vulnerability/{}", record.id)));
+    let summary = record
+        .summary
+        .or(record.details)
+        .map(|text| text.lines().next().unwrap_or_default().trim().to_string())
+        .filter(|text| !text.is_empty());
+
+    Some(Vulnerability {
+        id: record.id,
+        aliases: record.aliases,
+        severity,
+        summary,
+        url,
+        affected,
+        versions,
+    })
+}
+
+fn ranges_from_events(events: &[OsvEvent]) -> Vec<AffectedRange> {
+    let mut ranges = Vec::new();
+    let mut current: Option<AffectedRange> = None;
+    for event in events {
+        if let Some(introduced) = &event.introduced {
+            if let Some(open) = current.take() {
+                ranges.push(open);
+            }
+            current = Some(AffectedRange {
+                introduced: (introduced != "0").then(|| introduced.clone()),
+                ..AffectedRange::default()
+            });
+            continue;
+        }
+
+        let Some(mut open) = current.take() else {
+            continue;
+        };
+        if let Some(fixed) = &event.fixed {
+            open.fixed = Some(fixed.clone());
+        } else if let Some(last) = &event.last_affected {
+            open.limit = Some(format!("{}.1", last));
+        } else if let Some(limit) = &event.limit {
+            open.limit = Some(limit.clone());
+        }
+        ranges.push(open);
+    }
+    ranges.extend(current);
+    ranges
+}
@@ -0,0 +1,233 @@
+use super::*;
+use std::collections::BTreeMap;
+
+fn node_feed() -> VulnerabilityFeed {
+    let index: BTreeMap<String, node::NodeVulnEntry> = serde_json::from_str(
+        r#"{
+            "130": {
+                "cve": ["CVE-2024-27983"],
+                "vulnerable": "^18.0.0 || ^20.0.0 || ^21.0.0",
+                "patched": "^18.20.1 || ^20.12.1 || ^21.7.2",
+                "ref": "https://nodejs.org/en/blog/vulnerability/april-2024-security-releases",
+                "overview": "An attacker can make the Node.js HTTP/2 server unavailable.",
+                "severity": "high"
+            },
+            "95": {
+                "cve": ["CVE-2023-30581", "CVE-2023-30582"],
+                "vulnerable": ">= 16.0.0 < 16.20.1 || >= 18.0.0 < 18.16.1",
+                "patched": "^16.20.1 || ^18.16.1",
+                "overview": "mainModule.__proto__ bypass",
+                "severity": "medium"
+            },
+            "12": {
+                "cve": [],
+                "vulnerable": "^16.0.0",
+                "patched": "",
+                "overview": "Unpatched issue in an end-of-life line"
+            }
+        }"#,
+    )
+    .unwrap();
+    VulnerabilityFeed {
+        vulnerabilities: node::from_index(index),
+    }
+}
+
+#[test]
+fn test_node_ranges_map_to_installed_versions() {
+    let feed = node_feed();
+
+    let matches = matches_in(&feed, "20.11.0");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].id, "CVE-2024-27983");
+    assert_eq!(matches[0].severity, Severity::High);
+    assert_eq!(matches[0].fixed_in.as_deref(), Some("20.12.1"));
+    assert_eq!(
+        matches[0].label(),
+        "CVE-2024-27983 (high, fixed in 20.12.1)"
+    );
+
+    assert!(matches_in(&feed, "20.12.1").is_empty());
+    assert!(matches_in(&feed, "22.1.0").is_empty());
+
+    let older = matches_in(&feed, "v18.16.0");
+    assert_eq!(
+        older.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(),
+        vec!["CVE-2024-27983", "CVE-2023-30581"]
+    );
+    assert_eq!(older[1].aliases, vec!["CVE-2023-30582".to_string()]);
+}
+
+#[test]
+fn test_node_unpatched_line_is_bounded() {
+    let feed = node_feed();
+    let matches = matches_in(&feed, "16.20.2");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].id, "NSWG-CORE-12");
+    assert_eq!(matches[0].fixed_in, None);
+    assert!(matches_in(&feed, "17.0.0").is_empty());
+}
+
+#[test]
+fn test_osv_go_record_uses_semver_events() {
+    let record: osv::OsvRecord = serde_json::from_str(
+        r#"{
+            "id": "GO-2024-2687",
+            "aliases": ["CVE-2023-45288"],
+            "summary": "HTTP/2 CONTINUATION flood in net/http",
+            "affected": [{
+                "package": {"name": "stdlib", "ecosystem": "Go"},
+                "ranges": [{"type": "SEMVER", "events": [
+                    {"introduced": "0"}, {"fixed": "1.21.9"},
+                    {"introduced": "1.22.0-0"}, {"fixed": "1.22.2"}
+                ]}]
+            }, {
+                "package": {"name": "golang.org/x/net", "ecosystem": "Go"},
+                "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "0.23.0"}]}]
+            }],
+            "references": [{"type": "REPORT", "url": "https://go.dev/issue/65051"}]
+        }"#,
+    )
+    .unwrap();
+    let vulnerability = osv::from_record(record, Some("stdlib")).unwrap();
+    assert_eq!(vulnerability.affected.len(), 2);
+    let feed = VulnerabilityFeed {
+        vulnerabilities: vec![vulnerability],
+    };
+
+    let matches = matches_in(&feed, "1.22.1");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].fixed_in.as_deref(), Some("1.22.2"));
+    assert_eq!(matches[0].aliases, vec!["CVE-2023-45288".to_string()]);
+    assert_eq!(
+        matches_in(&feed, "1.21.3")[0].fixed_in.as_deref(),
+        Some("1.21.9")
+    );
+    assert!(matches_in(&feed, "1.22.2").is_empty());
+}
+
+#[test]
+fn test_osv_python_record_uses_enumerated_tags() {
+    let record: osv::OsvRecord = serde_json::from_str(
+        r#"{
+            "id": "CVE-2024-6232",
+            "summary": "Regular expression DoS in tarfile headers",
+            "database_specific": {"severity": "HIGH"},
+            "affected": [{
+                "ranges": [{"type": "GIT", "repo": "https://github.com/python/cpython", "events": [{"introduced": "0"}, {"fixed": "4ae94af"}]}],
+                "versions": ["v3.12.4", "v3.12.5"]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let feed = VulnerabilityFeed {
+        vulnerabilities: vec![osv::from_record(record, None).unwrap()],
+    };
+
+    let matches = matches_in(&feed, "3.12.5");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].severity, Severity::High);
+    assert!(matches_in(&feed, "3.12.6").is_empty());
+}
+
+#[test]
+fn test_openjdk_advisory_page() {
+    let index = r#"<ul><li><a href="2024-07-16">2024/07/16</a></li><li><a href="2024-10-15">2024/10/15</a></li></ul>"#;
+    assert_eq!(
+        java::advisory_dates(index),
+        vec!["2024-10-15", "2024-07-16"]
+    );
+
+    let page = r#"
+        <p>The following vulnerabilities are fixed in OpenJDK 23.0.1, 21.0.5, 17.0.13, 11.0.25, and 8u432.</p>
+        <table>
+          <tr><th>CVE ID</th><th>Component</th><th>CVSS</th><th>8</th><th>11</th><th>17</th><th>21</th><th>23</th></tr>
+          <tr><td><a href="https://www.cve.org/CVERecord?id=CVE-2024-21235">CVE-2024-21235</a></td><td>hotspot/compiler</td><td>4.8</td><td>&bull;</td><td>&bull;</td><td>&bull;</td><td>&bull;</td><td>&bull;</td></tr>
+          <tr><td>CVE-2024-21217</td><td>core-libs/java.net</td><td>7.5</td><td></td><td></td><td></td><td>&bull;</td><td>&bull;</td></tr>
+        </table>"#;
+    let feed = VulnerabilityFeed {
+        vulnerabilities: java::parse_advisory(
+            page,
+            "https://openjdk.org/groups/vulnerability/advisories/2024-10-15",
+        ),
+    };
+    assert_eq!(feed.vulnerabilities.len(), 2);
+
+    let java21 = matches_in(&feed, "21.0.4+7");
+    assert_eq!(
+        java21.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(),
+        vec!["CVE-2024-21217", "CVE-2024-21235"]
+    );
+    assert_eq!(java21[0].severity, Severity::High);
+    assert_eq!(java21[0].fixed_in.as_deref(), Some("21.0.5"));
+    assert_eq!(java21[1].summary.as_deref(), Some("hotspot/compiler"));
+
+    let java8 = matches_in(&feed, "8u422");
+    assert_eq!(java8.len(), 1);
+    assert_eq!(java8[0].fixed_in.as_deref(), Some("8u432"));
+    assert!(matches_in(&feed, "21.0.5").is_empty());
+}
+
+#[test]
+fn test_bare_java_major_uses_release_file() {
+    let page = r#"
+        <p>The following vulnerabilities are fixed in OpenJDK 21.0.5 and 8u432.</p>
+        <table>
+          <tr><th>CVE ID</th><th>Component</th><th>CVSS</th><th>8</th><th>21</th></tr>
+          <tr><td>CVE-2024-21217</td><td>core-libs/java.net</td><td>7.5</td><td></td><td>&bull;</td></tr>
+        </table>"#;
+    let feed = VulnerabilityFeed {
+        vulnerabilities: java::parse_advisory(page, "https://openjdk.org/"),
+    };
+    let vex = tempfile::TempDir::new().unwrap();
+    let home = vex.path().join("toolchains/java/21/Contents/Home");
+    std::fs::create_dir_all(&home).unwrap();
+
+    assert_eq!(comparable_installed_version(vex.path(), "java", "21"), None);
+    assert_eq!(
+        comparable_installed_version(vex.path(), "java", "21.0.4+7").as_deref(),
+        Some("21.0.4+7")
+    );
+
+    std::fs::write(
+        home.join("release"),
+        "IMPLEMENTOR=\"Eclipse Adoptium\"\nJAVA_VERSION=\"21.0.5\"\n",
+    )
+    .unwrap();
+    let patched = comparable_installed_version(vex.path(), "java", "21").unwrap();
+    assert_eq!(patched, "21.0.5");
+    assert!(matches_in(&feed, &patched).is_empty());
+
+    std::fs::write(home.join("release"), "JAVA_VERSION=\"21.0.4\"\n").unwrap();
+    let outdated = comparable_installed_version(vex.path(), "java", "21").unwrap();
+    assert_eq!(matches_in(&feed, &outdated)[0].id, "CVE-2024-21217");
+
+    assert_eq!(
+        java::release_version("JAVA_VERSION=\"1.8.0_422\"").as_deref(),
+        Some("8u422")
+    );
+}
+
+#[test]
+fn test_normalized_feed_roundtrip() {
+    let feed = node_feed();
+    let json = serde_json::to_string(&feed).unwrap();
+    let parsed: VulnerabilityFeed = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, feed);
+
+    let minimal: VulnerabilityFeed = serde_json::from_str(
+        r#"{"vulnerabilities": [{"id": "CVE-2025-0001", "affected": [{"fixed": "1.0.1"}]}]}"#,
+    )
+    .unwrap();
+    assert_eq!(minimal.vulnerabilities[0].severity, Severity::Unknown);
+    assert_eq!(matches_in(&minimal, "1.0.0").len(), 1);
+}
+
+#[test]
+fn test_severity_from_cvss_score() {
+    assert_eq!(Severity::from_cvss_score(9.8), Severity::Critical);
+    assert_eq!(Severity::from_cvss_score(7.5), Severity::High);
+    assert_eq!(Severity::from_cvss_score(4.8), Severity::Medium);
+    assert_eq!(Severity::from_cvss_score(3.1), Severity::Low);
+    assert_eq!(Severity::from_label("MODERATE"), Severity::Medium);
+}
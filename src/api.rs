@@ -0,0 +1,226 @@
+//! Library API
+//!
+//! The curated, semver-stable surface of the `vex` crate, re-exported from the crate root.
+//! Functions take tool names such as `"node"` rather than exposing the internal `Tool` trait,
+//! and they never print: human-readable output is silenced on the calling thread while they
+//! run, and progress is reported through an [`Event`] callback.
+
+use crate::activation;
+use crate::commands::versions::{self, RemoteFilter};
+use crate::error::{Result, VexError};
+use crate::installer;
+use crate::output::{self, Event};
+use crate::paths::vex_dir;
+use crate::policy::Policy;
+use crate::requested_versions;
+use crate::resolver;
+use crate::switcher;
+use crate::tools;
+use crate::versioning::version_sort_key;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub use crate::tools::SUPPORTED_TOOLS;
+
+/// The version a directory requests for one tool, and the file that requests it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ToolRequest {
+    pub tool: String,
+    /// Requested version as written, e.g. `20` or `lts`; not necessarily installed.
+    pub version: String,
+    /// Version file that wins the precedence order, or `~/.vex/tool-versions`.
+    pub source: PathBuf,
+}
+
+/// Toolchain requests visible from a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Context {
+    pub dir: PathBuf,
+    /// Nearest ancestor with a version file, if any.
+    pub project_root: Option<PathBuf>,
+    /// One entry per requested tool, in [`SUPPORTED_TOOLS`] order.
+    pub requests: Vec<ToolRequest>,
+}
+
+/// Environment that activates the installed versions requested for a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Activation {
+    /// Resolved installed version per tool.
+    pub versions: BTreeMap<String, String>,
+    /// Toolchain and project directories to put in front of `PATH`, highest priority first.
+    pub path_entries: Vec<PathBuf>,
+    /// `path_entries` merged with the current `PATH`, ready to pass to a child process.
+    pub path: String,
+    pub set_env: BTreeMap<String, String>,
+    pub unset_env: Vec<String>,
+}
+
+/// Options for [`install`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstallOptions {
+    offline: bool,
+    switch: bool,
+}
+
+impl InstallOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install from `~/.vex/cache` only; the version must then be exact.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Make the version the global default after installing it.
+    pub fn switch(mut self, switch: bool) -> Self {
+        self.switch = switch;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InstalledVersion {
+    pub version: String,
+    /// Whether this is the global default in `~/.vex/current`.
+    pub is_current: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RemoteVersion {
+    pub version: String,
+    /// LTS codename for Node.js, or the support status for Python.
+    pub label: Option<String>,
+}
+
+/// Resolve which version of each tool `dir` requests, following the CLI's precedence order.
+pub fn resolve_context(dir: &Path) -> Context {
+    let requests = SUPPORTED_TOOLS
+        .iter()
+        .filter_map(|tool| {
+            let request = resolver::version_requests(dir, tool).into_iter().next()?;
+            Some(ToolRequest {
+                tool: tool.to_string(),
+                version: request.version,
+                source: request.path,
+            })
+        })
+        .collect();
+
+    Context {
+        dir: dir.to_path_buf(),
+        project_root: resolver::find_project_root(dir),
+        requests,
+    }
+}
+
+/// Plan the environment `vex exec` would use in `dir`, without changing any state.
+///
+/// Fails with [`VexError::VersionNotFound`] when a requested version is not installed.
+pub fn plan_activation(dir: &Path) -> Result<Activation> {
+    output::capture(
+        |_| {},
+        || {
+            let plan = activation::build_activation_plan(dir)?;
+            Ok(Activation {
+                path: activation::exec_path(&plan),
+                path_entries: plan
+                    .shared_path_entries
+                    .iter()
+                    .chain(plan.exec_path_entries.iter())
+                    .cloned()
+                    .collect(),
+                versions: plan.versions,
+                set_env: plan.set_env,
+                unset_env: plan.unset_env,
+            })
+        },
+    )
+}
+
+/// Install `tool` at `version`, which may be exact, partial (`20`), or an alias (`lts`).
+///
+/// An installed version that already matches is reused. `[policy]` rules for the current
+/// directory apply as they do for `vex install`. Returns the installed version.
+pub fn install(
+    tool: &str,
+    version: &str,
+    options: &InstallOptions,
+    on_event: impl FnMut(&Event) + 'static,
+) -> Result<String> {
+    output::capture(on_event, || {
+        let tool = tools::get_tool(tool)?;
+        let installed =
+            requested_versions::resolve_installed_version(&vex_dir()?, tool.name(), version)?;
+        let resolved = match installed {
+            Some(installed) => installed,
+            None if options.offline => version.to_string(),
+            None => requested_versions::resolve_for_install(tool.as_ref(), version)?,
+        };
+
+        Policy::load(&resolver::current_dir())?.check(tool.name(), &resolved)?;
+        installer::install_with_mode(tool.as_ref(), &resolved, options.offline)?;
+        if options.switch {
+            switcher::switch_version(tool.as_ref(), &resolved)?;
+        }
+        Ok(resolved)
+    })
+}
+
+/// Make an installed version of `tool` the global default; `version` may be partial.
+///
+/// Returns the version switched to.
+pub fn switch(tool: &str, version: &str, on_event: impl FnMut(&Event) + 'static) -> Result<String> {
+    output::capture(on_event, || {
+        let tool = tools::get_tool(tool)?;
+        let resolved =
+            requested_versions::resolve_installed_version(&vex_dir()?, tool.name(), version)?
+                .ok_or_else(|| VexError::VersionNotFound {
+                    tool: tool.name().to_string(),
+                    version: version.to_string(),
+                    suggestions: String::new(),
+                })?;
+
+        Policy::load(&resolver::current_dir())?.check(tool.name(), &resolved)?;
+        switcher::switch_version(tool.as_ref(), &resolved)?;
+        Ok(resolved)
+    })
+}
+
+/// Installed versions of `tool`, newest first.
+pub fn list_installed(tool: &str) -> Result<Vec<InstalledVersion>> {
+    tools::get_tool(tool)?;
+    let report = output::capture(|_| {}, || versions::installed_versions(tool))?;
+    let mut installed = report
+        .versions
+        .into_iter()
+        .map(|entry| InstalledVersion {
+            version: entry.version,
+            is_current: entry.is_current,
+        })
+        .collect::<Vec<_>>();
+    installed.sort_by_key(|entry| std::cmp::Reverse(version_sort_key(&entry.version)));
+    Ok(installed)
+}
+
+/// Versions of `tool` published upstream, newest first, using the remote cache when fresh.
+pub fn list_remote(tool: &str) -> Result<Vec<RemoteVersion>> {
+    let report = output::capture(
+        |_| {},
+        || versions::remote_versions(tool, RemoteFilter::All),
+    )?;
+    Ok(report
+        .versions
+        .into_iter()
+        .map(|entry| RemoteVersion {
+            version: entry.version,
+            label: entry.label,
+        })
+        .collect())
+}
@@ -1,14 +1,28 @@
 use crate::cli::{Cli, Commands};
 use crate::error;
 use crate::error::Result;
-use crate::{commands, output, shell, updater};
-use clap::Parser;
+use crate::{commands, output, shell, templates, updater};
+use clap::{ArgMatches, CommandFactory, FromArgMatches};
 
 pub fn run() -> Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    output::init(cli.output, schema_name(&matches))?;
     dispatch(cli.command)
 }
 
+/// Schema of the machine-readable output, named after the subcommand path, e.g. `vex.rust.target.add`.
+fn schema_name(matches: &ArgMatches) -> String {
+    let mut name = String::from("vex");
+    let mut current = matches;
+    while let Some((subcommand, next)) = current.subcommand() {
+        name.push('.');
+        name.push_str(subcommand);
+        current = next;
+    }
+    name
+}
+
 fn dispatch(command: Commands) -> Result<()> {
     match command {
         Commands::Init(args) => commands::init::run(
@@ -17,6 +31,10 @@ fn dispatch(command: Commands) -> Result<()> {
             args.list_templates,
             args.dry_run,
             args.add_only,
+            &templates::TemplateInputs {
+                variables: args.vars.iter().cloned().collect(),
+                from: args.from.clone(),
+            },
         )?,
         Commands::Install(args) => {
             if !args.specs.is_empty() {
@@ -38,6 +56,8 @@ fn dispatch(command: Commands) -> Result<()> {
         Commands::Sync(args) => {
             if let Some(source) = args.from {
                 commands::toolchain::sync_from_source(&source, args.offline)?;
+            } else if args.workspace {
+                commands::toolchain::sync_workspace(args.offline)?;
             } else {
                 commands::toolchain::sync_from_current_context_with_frozen(
                     args.frozen,
@@ -85,6 +105,16 @@ fn dispatch(command: Commands) -> Result<()> {
                 args.verbose,
             )?;
         }
+        Commands::Which(args) => {
+            commands::which::which(
+                &args.command,
+                args.all,
+                output::OutputMode::from_json_flag(args.json),
+            )?;
+        }
+        Commands::Where(args) => {
+            commands::which::where_spec(&args.spec, output::OutputMode::from_json_flag(args.json))?;
+        }
         Commands::Uninstall(args) => {
             commands::manage::uninstall_spec(&args.spec)?;
         }
@@ -98,6 +128,12 @@ fn dispatch(command: Commands) -> Result<()> {
                 }
             }
         }
+        Commands::Completions(args) => {
+            commands::completions::print_script(&args.shell)?;
+        }
+        Commands::Complete(args) => {
+            commands::completions::complete(&args.words)?;
+        }
         Commands::Local(args) => {
             commands::manage::set_project_version(&args.spec)?;
         }
@@ -108,7 +144,12 @@ fn dispatch(command: Commands) -> Result<()> {
             commands::toolchain::generate_lockfile()?;
         }
         Commands::Upgrade(args) => {
-            commands::updates::upgrade(args.tool.as_deref(), args.all)?;
+            commands::updates::upgrade(
+                args.tool.as_deref(),
+                args.all,
+                args.strategy,
+                args.dry_run,
+            )?;
         }
         Commands::Outdated(args) => {
             commands::updates::outdated(
@@ -122,14 +163,49 @@ fn dispatch(command: Commands) -> Result<()> {
         Commands::Alias(subcmd) => {
             commands::aliases::run(&subcmd)?;
         }
-        Commands::Exec(args) => exit_on_failure(commands::process::exec_command(&args.command)?),
-        Commands::Run(args) => {
-            exit_on_failure(commands::process::run_task(&args.task, &args.args)?)
+        Commands::Exec(args) => exit_on_failure(commands::process::exec_command(
+            &args.command,
+            args.offline,
+        )?),
+        Commands::Run(args) => match &args.task {
+            Some(task) if args.workspace => exit_on_failure(commands::process::run_workspace_task(
+                task,
+                &args.args,
+                args.force,
+                &args.with,
+                args.offline,
+            )?),
+            Some(task) if !args.list => exit_on_failure(commands::process::run_task(
+                task,
+                &args.args,
+                args.force,
+                &args.with,
+                args.offline,
+            )?),
+            _ => commands::process::list_tasks()?,
+        },
+        Commands::Sbom(args) => {
+            let scope = if args.all {
+                commands::sbom::SbomScope::All
+            } else if args.project {
+                commands::sbom::SbomScope::Project
+            } else {
+                commands::sbom::SbomScope::Current
+            };
+            commands::sbom::run(scope, args.format)?;
+        }
+        Commands::Verify(args) => {
+            commands::verify::run(
+                args.spec.as_deref(),
+                output::OutputMode::from_json_flag(args.json),
+            )?;
         }
         Commands::Doctor(args) => {
             commands::doctor::run(output::OutputMode::from_json_flag(args.json), args.verbose)?;
         }
         Commands::Repair(args) => commands::repair::run(&args)?,
+        Commands::Import(args) => commands::import::run(&args)?,
+        Commands::Projects(args) => commands::projects::run(&args)?,
         Commands::SelfUpdate => {
             updater::self_update()?;
         }
@@ -143,8 +219,15 @@ fn dispatch(command: Commands) -> Result<()> {
     Ok(())
 }
 
+#[derive(serde::Serialize)]
+struct ExitReport {
+    exit_code: i32,
+}
+
 fn exit_on_failure(code: i32) {
+    let _ = output::report(&ExitReport { exit_code: code });
     if code != 0 {
+        output::finish(&Ok(()));
         std::process::exit(code);
     }
 }
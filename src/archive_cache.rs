@@ -88,7 +88,6 @@ impl ArchiveCache {
     }
 
     /// Clean up cache for a specific tool version
-    #[cfg(test)]
     pub fn remove_version(&self, tool_name: &str, version: &str) -> Result<()> {
         let cache_dir = self.tool_cache_dir(tool_name, version);
         if cache_dir.exists() {
@@ -2,8 +2,10 @@
 //!
 //! Caches tool remote version lists to `~/.vex/cache/remote-<tool>.json`,
 //! default TTL 300 seconds, configurable via `~/.vex/config.toml`.
+//! Other upstream documents (such as lifecycle data) are stored as `~/.vex/cache/<name>.json`.
 
 use crate::tools::Version;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
@@ -23,6 +25,12 @@ struct CacheFile {
     cached_at: u64,
 }
 
+#[derive(Serialize, Deserialize)]
+struct DocumentFile<T> {
+    data: T,
+    cached_at: u64,
+}
+
 /// Remote version list cache manager
 ///
 /// Serializes tool version lists as JSON and stores them in `~/.vex/cache/remote-<tool>.json`.
@@ -45,6 +53,10 @@ impl RemoteCache {
         self.cache_dir.join(format!("remote-{}.json", tool_name))
     }
 
+    fn document_path(&self, name: &str) -> PathBuf {
+        self.cache_dir.join(format!("{}.json", name))
+    }
+
     fn now_secs() -> u64 {
         SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -104,6 +116,29 @@ impl RemoteCache {
             let _ = fs::write(self.cache_path(tool_name), json);
         }
     }
+
+    /// Get a cached JSON document together with its age in seconds
+    ///
+    /// Expiry is left to the caller so stale data can still serve as an offline fallback.
+    pub fn get_cached_document<T: DeserializeOwned>(&self, name: &str) -> Option<(T, u64)> {
+        let data = fs::read_to_string(self.document_path(name)).ok()?;
+        let document: DocumentFile<T> = serde_json::from_str(&data).ok()?;
+        let age = Self::now_secs().saturating_sub(document.cached_at);
+        Some((document.data, age))
+    }
+
+    /// Write a JSON document to cache (silently ignores write failures)
+    pub fn set_cached_document<T: Serialize>(&self, name: &str, data: &T) {
+        let document = DocumentFile {
+            data,
+            cached_at: Self::now_secs(),
+        };
+
+        let _ = fs::create_dir_all(&self.cache_dir);
+        if let Ok(json) = serde_json::to_string(&document) {
+            let _ = fs::write(self.document_path(name), json);
+        }
+    }
 }
 
 /// Read cache TTL from `~/.vex/config.toml` for tests.
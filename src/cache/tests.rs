@@ -215,3 +215,31 @@ fn test_cache_file_format() {
     assert!(parsed.get("versions").is_some());
     assert!(parsed.get("cached_at").is_some());
 }
+
+#[test]
+fn test_cached_document_roundtrip_reports_age() {
+    let tmp = TempDir::new().unwrap();
+    let cache = RemoteCache::new(tmp.path());
+
+    cache.set_cached_document("lifecycle-go", &vec!["1.25.3".to_string()]);
+    let (data, age) = cache
+        .get_cached_document::<Vec<String>>("lifecycle-go")
+        .unwrap();
+
+    assert_eq!(data, vec!["1.25.3".to_string()]);
+    assert!(age < 60);
+    assert!(tmp.path().join("cache/lifecycle-go.json").exists());
+}
+
+#[test]
+fn test_cached_document_invalid_json_degrades() {
+    let tmp = TempDir::new().unwrap();
+    let cache_dir = tmp.path().join("cache");
+    fs::create_dir_all(&cache_dir).unwrap();
+    fs::write(cache_dir.join("lifecycle-node.json"), "{").unwrap();
+
+    let cache = RemoteCache::new(tmp.path());
+    assert!(cache
+        .get_cached_document::<Vec<String>>("lifecycle-node")
+        .is_none());
+}
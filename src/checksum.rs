@@ -9,6 +9,10 @@ fn encode_hex(bytes: &[u8]) -> String {
     bytes.iter().map(|byte| format!("{byte:02x}")).collect()
 }
 
+pub(crate) fn sha256_hex_bytes(bytes: &[u8]) -> String {
+    encode_hex(sha2::Sha256::digest(bytes).as_ref())
+}
+
 pub(crate) fn sha256_hex(file_path: &Path) -> Result<String> {
     let mut file = File::open(file_path)?;
     let mut hasher = sha2::Sha256::new();
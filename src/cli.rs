@@ -1,13 +1,17 @@
 mod alias;
+mod completions;
+pub(crate) mod import;
 mod init;
 mod listing;
 mod manage;
 mod process;
+pub(crate) mod projects;
 mod python;
 pub(crate) mod repair;
 pub(crate) mod rust;
 mod toolchain;
 
+use crate::output::OutputFormat;
 pub(crate) use alias::AliasCommands;
 use clap::{Parser, Subcommand};
 
@@ -16,6 +20,10 @@ use clap::{Parser, Subcommand};
 #[command(name = "vex", version)]
 #[command(about = "A fast version manager for macOS", long_about = None)]
 pub(crate) struct Cli {
+    /// Output format: human-readable text, one JSON envelope, or an NDJSON event stream
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub(crate) output: OutputFormat,
+
     #[command(subcommand)]
     pub(crate) command: Commands,
 }
@@ -50,12 +58,25 @@ pub(crate) enum Commands {
     /// List global CLIs, shared npm globals, and Java build-tool state managed or detected by vex
     Globals(listing::GlobalsArgs),
 
+    /// Show which executable a command resolves to here, and which version file selected it
+    Which(listing::WhichArgs),
+
+    /// Print the toolchain directory of a tool's active or given version
+    Where(listing::WhereArgs),
+
     /// Uninstall a version
     Uninstall(manage::UninstallArgs),
 
     /// Output shell hook for auto-switching
     Env(process::EnvArgs),
 
+    /// Output a shell completion script with tool, version, alias, and task completion
+    Completions(completions::CompletionsArgs),
+
+    /// Print completion candidates for the completion scripts
+    #[command(name = "__complete", hide = true)]
+    Complete(completions::CompleteArgs),
+
     /// Pin a tool version in the current directory (.tool-versions)
     Local(toolchain::PinArgs),
 
@@ -85,18 +106,31 @@ pub(crate) enum Commands {
     /// Run a named task from .vex.toml inside the resolved vex-managed environment
     Run(process::RunArgs),
 
+    /// Print a CycloneDX or SPDX SBOM of installed toolchains and locked Python packages
+    Sbom(manage::SbomArgs),
+
+    /// Recompute recorded checksums of installed toolchains against the archive cache
+    Verify(manage::VerifyArgs),
+
     /// Check vex installation health
     Doctor(manage::DoctorArgs),
 
     /// Repair or migrate supported home-directory state into ~/.vex
     Repair(repair::RepairArgs),
 
+    /// Import installed toolchains and global pins from another version manager
+    Import(import::ImportArgs),
+
+    /// List or forget projects whose pins are retained by prune and doctor
+    Projects(projects::ProjectsArgs),
+
     /// Update vex itself to the latest release
     SelfUpdate,
 
     /// Launch interactive TUI dashboard
     ///
-    /// Shows current versions, health warnings, disk usage, and quick actions.
+    /// Shows current versions, health warnings, and disk usage. Browse remote versions,
+    /// install, switch, uninstall, and prune from the keyboard.
     /// Requires an interactive terminal.
     Tui,
 
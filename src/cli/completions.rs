@@ -0,0 +1,15 @@
+use clap::Args;
+
+#[derive(Args)]
+pub(crate) struct CompletionsArgs {
+    /// Shell type (zsh, bash, fish, or nu)
+    #[arg(value_parser = ["zsh", "bash", "fish", "nu", "nushell"])]
+    pub(crate) shell: String,
+}
+
+#[derive(Args)]
+pub(crate) struct CompleteArgs {
+    /// Words after `vex` on the command line, ending with the word being completed
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub(crate) words: Vec<String>,
+}
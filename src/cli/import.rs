@@ -0,0 +1,15 @@
+use clap::Args;
+
+#[derive(Args)]
+pub(crate) struct ImportArgs {
+    /// Manager to import from (nvm, pyenv, asdf, mise, rustup, sdkman, goenv, volta)
+    pub(crate) manager: String,
+
+    /// Reinstall every version from vex's sources instead of adopting existing installs
+    #[arg(long)]
+    pub(crate) reinstall: bool,
+
+    /// Apply the import instead of showing a dry-run preview
+    #[arg(long)]
+    pub(crate) apply: bool,
+}
@@ -6,14 +6,23 @@ pub(crate) struct InitArgs {
     #[arg(long, conflicts_with_all = ["template", "list_templates", "add_only"])]
     pub(crate) shell: Option<String>,
 
-    /// Initialize the current directory with an official project template
+    /// Initialize the current directory with a project template (built-in id, user template,
+    /// template directory, or Git URL)
     #[arg(long, conflicts_with_all = ["shell", "list_templates"])]
     pub(crate) template: Option<String>,
 
-    /// List the built-in project templates
+    /// List the built-in and user project templates
     #[arg(long = "list-templates", conflicts_with_all = ["shell", "template", "add_only"])]
     pub(crate) list_templates: bool,
 
+    /// Set a template variable (repeatable), e.g. `--var service_name=billing`
+    #[arg(long = "var", value_name = "NAME=VALUE", requires = "template", value_parser = parse_template_var)]
+    pub(crate) vars: Vec<(String, String)>,
+
+    /// Team config source whose `[templates]` table names additional templates
+    #[arg(long, conflicts_with = "shell")]
+    pub(crate) from: Option<String>,
+
     /// Preview changes without modifying files
     #[arg(long, conflicts_with = "list_templates")]
     pub(crate) dry_run: bool,
@@ -22,3 +31,12 @@ pub(crate) struct InitArgs {
     #[arg(long, requires = "template", conflicts_with_all = ["shell", "list_templates"])]
     pub(crate) add_only: bool,
 }
+
+fn parse_template_var(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("expected NAME=VALUE, got '{}'", value)),
+    }
+}
@@ -61,3 +61,27 @@ pub(crate) struct GlobalsArgs {
     #[arg(long)]
     pub(crate) verbose: bool,
 }
+
+#[derive(Args)]
+pub(crate) struct WhichArgs {
+    /// Command name to resolve (e.g., node, npm, cargo)
+    pub(crate) command: String,
+
+    /// Also list candidates further down PATH that the winner shadows
+    #[arg(long)]
+    pub(crate) all: bool,
+
+    /// Output machine-readable JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct WhereArgs {
+    /// Tool, optionally with a version (e.g., node or node@20)
+    pub(crate) spec: String,
+
+    /// Output machine-readable JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
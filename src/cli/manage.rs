@@ -14,6 +14,14 @@ pub(crate) struct UpgradeArgs {
     /// Upgrade every managed tool in the current context
     #[arg(long)]
     pub(crate) all: bool,
+
+    /// How far to upgrade; defaults to `[upgrade]` in config.toml, then `major`
+    #[arg(long, value_enum)]
+    pub(crate) strategy: Option<crate::config::UpgradeStrategy>,
+
+    /// Show the upgrade plan without installing, switching, or rewriting anything
+    #[arg(long)]
+    pub(crate) dry_run: bool,
 }
 
 #[derive(Args)]
@@ -49,3 +57,28 @@ pub(crate) struct RelinkArgs {
     /// Tool name to relink. Currently only `node` is supported.
     pub(crate) tool: String,
 }
+
+#[derive(Args)]
+pub(crate) struct SbomArgs {
+    /// Only the versions pinned by the project's version files, without global defaults
+    #[arg(long, conflicts_with = "all")]
+    pub(crate) project: bool,
+
+    /// Every installed toolchain instead of the versions active in this directory
+    #[arg(long)]
+    pub(crate) all: bool,
+
+    /// Document format
+    #[arg(long, value_enum, default_value_t = crate::commands::sbom::SbomFormat::Cyclonedx)]
+    pub(crate) format: crate::commands::sbom::SbomFormat,
+}
+
+#[derive(Args)]
+pub(crate) struct VerifyArgs {
+    /// Tool or tool@version to verify (e.g., node or node@20). Omit to verify every toolchain.
+    pub(crate) spec: Option<String>,
+
+    /// Output machine-readable JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
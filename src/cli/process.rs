@@ -12,7 +12,12 @@ pub(crate) struct EnvArgs {
 
 #[derive(Args)]
 pub(crate) struct ExecArgs {
-    /// Command to run after '--' (for example: vex exec -- node -v)
+    /// Install missing ad-hoc versions from the archive cache only
+    #[arg(long)]
+    pub(crate) offline: bool,
+
+    /// Optional tool@version specs that override the resolved versions, then the command
+    /// after '--' (for example: vex exec node@18 -- node -v)
     #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
     pub(crate) command: Vec<String>,
 }
@@ -20,7 +25,28 @@ pub(crate) struct ExecArgs {
 #[derive(Args)]
 pub(crate) struct RunArgs {
     /// Task name from `[commands]` in `.vex.toml`
-    pub(crate) task: String,
+    #[arg(required_unless_present = "list")]
+    pub(crate) task: Option<String>,
+
+    /// List the tasks in `.vex.toml` with their descriptions
+    #[arg(long, conflicts_with = "task")]
+    pub(crate) list: bool,
+
+    /// Run tasks even when their outputs are newer than their sources
+    #[arg(long)]
+    pub(crate) force: bool,
+
+    /// Run the task in every `[workspace]` member that defines or inherits it
+    #[arg(long, conflicts_with = "list")]
+    pub(crate) workspace: bool,
+
+    /// Run with this tool@version instead of the pinned one; repeatable, installs it if missing
+    #[arg(long = "with", value_name = "SPEC", conflicts_with = "list")]
+    pub(crate) with: Vec<String>,
+
+    /// Install missing `--with` versions from the archive cache only
+    #[arg(long, conflicts_with = "list")]
+    pub(crate) offline: bool,
 
     /// Extra arguments appended to the task command
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
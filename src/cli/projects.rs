@@ -0,0 +1,27 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args)]
+pub(crate) struct ProjectsArgs {
+    #[command(subcommand)]
+    pub(crate) command: ProjectsCommands,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum ProjectsCommands {
+    /// List registered projects and the versions they pin
+    List {
+        /// Output machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Remove a project from the registry so its pins no longer retain toolchains
+    Forget {
+        /// Project root to forget (defaults to the current project)
+        path: Option<String>,
+
+        /// Forget every project whose directory or version files no longer exist
+        #[arg(long, conflicts_with = "path")]
+        stale: bool,
+    },
+}
@@ -10,6 +10,9 @@ pub(crate) struct RepairArgs {
 pub(crate) enum RepairCommands {
     /// Audit and migrate supported legacy home directories into ~/.vex
     MigrateHome(MigrateHomeArgs),
+
+    /// Reinstall toolchains that fail 'vex verify' from the archive cache or network
+    Toolchain(RepairToolchainArgs),
 }
 
 #[derive(Args)]
@@ -22,3 +25,13 @@ pub(crate) struct MigrateHomeArgs {
     #[arg(long)]
     pub(crate) apply: bool,
 }
+
+#[derive(Args)]
+pub(crate) struct RepairToolchainArgs {
+    /// Toolchain to repair (e.g. node or node@20); defaults to every installed toolchain
+    pub(crate) spec: Option<String>,
+
+    /// Reinstall even when verification passes or no install manifest was recorded
+    #[arg(long)]
+    pub(crate) force: bool,
+}
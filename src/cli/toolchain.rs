@@ -39,6 +39,10 @@ pub(crate) struct SyncArgs {
     /// Use offline mode (only use cached data, fail if unavailable)
     #[arg(long)]
     pub(crate) offline: bool,
+
+    /// Install the union of the versions pinned by every `[workspace]` member
+    #[arg(long, conflicts_with_all = ["from", "frozen"])]
+    pub(crate) workspace: bool,
 }
 
 #[derive(Args)]
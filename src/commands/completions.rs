@@ -0,0 +1,193 @@
+//! Shell completions
+//!
+//! `vex completions <shell>` prints a small script that calls the hidden `vex __complete`
+//! command on every Tab press. Subcommands and flags come from the clap definition, and values
+//! come from local state only — installed toolchains, the remote-version cache, aliases,
+//! `.vex.toml` tasks, and templates — so completing never touches the network.
+
+mod scripts;
+mod sources;
+#[cfg(test)]
+mod tests;
+
+use crate::cli::Cli;
+use crate::error::{Result, VexError};
+use crate::resolver;
+use clap::{Arg, ArgAction, Command, CommandFactory};
+use std::path::Path;
+
+/// Print the completion script for `shell`.
+pub fn print_script(shell: &str) -> Result<()> {
+    let script = scripts::script(shell).ok_or_else(|| {
+        VexError::Parse(format!(
+            "Unsupported shell: {}. Supported: zsh, bash, fish, nu",
+            shell
+        ))
+    })?;
+    print!("{}", script);
+    Ok(())
+}
+
+/// Print the candidates for the last of `words`, the command line after `vex`.
+pub fn complete(words: &[String]) -> Result<()> {
+    let mut command = Cli::command();
+    command.build();
+    for candidate in candidates(&command, words, &resolver::current_dir()) {
+        println!("{}", candidate);
+    }
+    Ok(())
+}
+
+/// Candidates for the last word of `words`, given the words before it.
+fn candidates(root: &Command, words: &[String], cwd: &Path) -> Vec<String> {
+    let (current, previous) = match words.split_last() {
+        Some((current, previous)) => (current.as_str(), previous),
+        None => ("", words),
+    };
+    let position = walk(root, previous);
+    let command = position.command;
+
+    let mut values = if let Some(arg) = position.pending_value {
+        arg_values(arg, &position, current, cwd)
+    } else if current.starts_with('-') && !position.after_separator {
+        command
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set())
+            .filter_map(|arg| arg.get_long())
+            .map(|long| format!("--{}", long))
+            .collect()
+    } else if command.has_subcommands() && position.positionals.is_empty() {
+        command
+            .get_subcommands()
+            .filter(|sub| !sub.is_hide_set())
+            .map(|sub| sub.get_name().to_string())
+            .collect()
+    } else {
+        match positional_arg(command, position.positionals.len()) {
+            Some(arg) => arg_values(arg, &position, current, cwd),
+            None => Vec::new(),
+        }
+    };
+
+    values.retain(|value| value.starts_with(current));
+    let mut seen = std::collections::HashSet::new();
+    values.retain(|value| seen.insert(value.clone()));
+    values
+}
+
+struct Position<'a> {
+    command: &'a Command,
+    /// Positional values already given to `command`.
+    positionals: Vec<String>,
+    /// Option whose value is being completed, e.g. after `--template`.
+    pending_value: Option<&'a Arg>,
+    after_separator: bool,
+}
+
+/// Follow subcommands and skip options and their values to find where the cursor is.
+fn walk<'a>(root: &'a Command, words: &[String]) -> Position<'a> {
+    let mut position = Position {
+        command: root,
+        positionals: Vec::new(),
+        pending_value: None,
+        after_separator: false,
+    };
+
+    for word in words {
+        if position.pending_value.take().is_some() {
+            continue;
+        }
+        if position.after_separator {
+            position.positionals.push(word.clone());
+            continue;
+        }
+        if word == "--" {
+            position.after_separator = true;
+            continue;
+        }
+        if let Some(long) = word.strip_prefix("--") {
+            if !long.contains('=') {
+                position.pending_value = position
+                    .command
+                    .get_arguments()
+                    .find(|arg| arg.get_long() == Some(long))
+                    .filter(|arg| takes_value(arg));
+            }
+            continue;
+        }
+        if word.len() > 1 && word.starts_with('-') {
+            let short = word.chars().last();
+            position.pending_value = position
+                .command
+                .get_arguments()
+                .find(|arg| arg.get_short() == short)
+                .filter(|arg| takes_value(arg) && word.len() == 2);
+            continue;
+        }
+        if position.positionals.is_empty() {
+            if let Some(sub) = position.command.find_subcommand(word) {
+                position.command = sub;
+                continue;
+            }
+        }
+        position.positionals.push(word.clone());
+    }
+
+    position
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    matches!(arg.get_action(), ArgAction::Set | ArgAction::Append)
+}
+
+/// The positional argument at `index`, or the last one when it takes several values.
+fn positional_arg(command: &Command, index: usize) -> Option<&Arg> {
+    let positionals = command.get_positionals().collect::<Vec<_>>();
+    positionals.get(index).copied().or_else(|| {
+        positionals
+            .last()
+            .copied()
+            .filter(|arg| matches!(arg.get_action(), ArgAction::Append))
+    })
+}
+
+fn arg_values(arg: &Arg, position: &Position, current: &str, cwd: &Path) -> Vec<String> {
+    let positionals = &position.positionals;
+    let possible = arg
+        .get_possible_values()
+        .into_iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| value.get_name().to_string())
+        .collect::<Vec<_>>();
+    if !possible.is_empty() {
+        return possible;
+    }
+
+    match arg.get_id().as_str() {
+        "spec" | "specs" | "with" => sources::specs(current),
+        // `vex exec node@18 -- cmd`: specs until the command starts.
+        "command"
+            if position.command.get_name() == "exec"
+                && !position.after_separator
+                && positionals.iter().all(|word| word.contains('@')) =>
+        {
+            sources::specs(current)
+        }
+        "tool" => sources::tools(),
+        "task" => sources::tasks(cwd),
+        "template" => sources::templates(),
+        "alias" => positionals
+            .first()
+            .map(|tool| sources::aliases(tool))
+            .unwrap_or_default(),
+        "version" => positionals
+            .first()
+            .map(|tool| sources::versions(tool))
+            .unwrap_or_default(),
+        "shell" => ["zsh", "bash", "fish", "nu"]
+            .iter()
+            .map(|shell| shell.to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
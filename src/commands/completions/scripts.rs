@@ -0,0 +1,85 @@
+//! Completion scripts. Each one forwards the words after `vex`, plus the word under the cursor
+//! (possibly empty), to `vex __complete --` and offers the lines it prints.
+
+const ZSH: &str = r#"# vex completions for zsh
+_vex() {
+    local -a candidates specs values
+    local candidate
+    candidates=("${(@f)$(command vex __complete -- "${(@)words[2,CURRENT-1]}" "${words[CURRENT]}" 2>/dev/null)}")
+    candidates=(${candidates:#})
+    if (( ${#candidates} == 0 )); then
+        _files
+        return
+    fi
+    for candidate in "${candidates[@]}"; do
+        if [[ $candidate == *@ ]]; then
+            specs+=("$candidate")
+        else
+            values+=("$candidate")
+        fi
+    done
+    (( ${#specs} )) && compadd -S '' -- "${specs[@]}"
+    (( ${#values} )) && compadd -- "${values[@]}"
+}
+
+if (( ! $+functions[compdef] )); then
+    autoload -Uz compinit && compinit
+fi
+compdef _vex vex
+"#;
+
+const BASH: &str = r#"# vex completions for bash
+_vex() {
+    local line="${COMP_LINE:0:COMP_POINT}"
+    local -a words candidates
+    read -r -a words <<< "$line"
+    [[ $line == *[[:space:]] ]] && words+=("")
+    local cur="${words[${#words[@]}-1]}"
+    local IFS=$'\n'
+    candidates=($(command vex __complete -- "${words[@]:1}" 2>/dev/null))
+    # bash splits words at '@', so only the part after it is replaced.
+    if [[ $cur == *@* && $COMP_WORDBREAKS == *@* ]]; then
+        candidates=("${candidates[@]#*@}")
+    fi
+    COMPREPLY=("${candidates[@]}")
+    if [[ ${#COMPREPLY[@]} -eq 1 && ${COMPREPLY[0]} == *@ ]]; then
+        compopt -o nospace 2>/dev/null
+    fi
+}
+complete -o default -F _vex vex
+"#;
+
+const FISH: &str = r#"# vex completions for fish
+function __vex_complete
+    set -l words (commandline -opc)
+    set -e words[1]
+    command vex __complete -- $words (commandline -ct) 2>/dev/null
+end
+
+complete -c vex -f -a '(__vex_complete)'
+"#;
+
+const NUSHELL: &str = r#"# vex completions for nushell
+let __vex_previous_completer = ($env.config.completions?.external?.completer?)
+$env.config = ($env.config
+    | upsert completions.external.enable true
+    | upsert completions.external.completer {|spans|
+        if ($spans.0 == "vex") {
+            ^vex __complete -- ...($spans | skip 1) | lines | each {|value| { value: $value } }
+        } else if ($__vex_previous_completer != null) {
+            do $__vex_previous_completer $spans
+        } else {
+            null
+        }
+    })
+"#;
+
+pub(super) fn script(shell: &str) -> Option<&'static str> {
+    match shell {
+        "zsh" => Some(ZSH),
+        "bash" => Some(BASH),
+        "fish" => Some(FISH),
+        "nu" | "nushell" => Some(NUSHELL),
+        _ => None,
+    }
+}
@@ -0,0 +1,100 @@
+use crate::alias::AliasManager;
+use crate::cache::RemoteCache;
+use crate::paths::vex_dir;
+use crate::project;
+use crate::templates;
+use crate::tools::SUPPORTED_TOOLS;
+use crate::versioning::version_sort_key;
+use std::fs;
+use std::path::Path;
+
+/// `tool@` while the tool is being typed, then `tool@<version>` for every known version.
+pub(super) fn specs(current: &str) -> Vec<String> {
+    match current.split_once('@') {
+        Some((tool, _)) => versions(tool)
+            .into_iter()
+            .map(|version| format!("{}@{}", tool, version))
+            .collect(),
+        None => SUPPORTED_TOOLS
+            .iter()
+            .map(|tool| format!("{}@", tool))
+            .collect(),
+    }
+}
+
+pub(super) fn tools() -> Vec<String> {
+    SUPPORTED_TOOLS
+        .iter()
+        .map(|tool| tool.to_string())
+        .collect()
+}
+
+/// Installed versions newest first, then aliases, then cached remote versions.
+pub(super) fn versions(tool: &str) -> Vec<String> {
+    if !SUPPORTED_TOOLS.contains(&tool) {
+        return Vec::new();
+    }
+    let Ok(vex) = vex_dir() else {
+        return Vec::new();
+    };
+
+    let mut installed = fs::read_dir(vex.join("toolchains").join(tool))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    installed.sort_by_key(|version| std::cmp::Reverse(version_sort_key(version)));
+
+    let mut versions = installed;
+    versions.extend(aliases(tool));
+    versions.extend(builtin_aliases(tool).iter().map(|alias| alias.to_string()));
+    // Completion must stay instant and offline, so stale cache entries are still offered.
+    if let Some(remote) = RemoteCache::new(&vex).get_cached_versions(tool, u64::MAX) {
+        versions.extend(remote.into_iter().map(|version| version.version));
+    }
+    versions
+}
+
+/// User aliases for `tool` from `~/.vex/aliases.toml` and `.vex.toml` in the current directory.
+pub(super) fn aliases(tool: &str) -> Vec<String> {
+    let Ok(vex) = vex_dir() else {
+        return Vec::new();
+    };
+    let manager = AliasManager::new(&vex);
+    let global = manager.list_global(Some(tool)).unwrap_or_default();
+    let project = manager.list_project(Some(tool)).unwrap_or_default();
+    let mut names = global
+        .into_values()
+        .chain(project.into_values())
+        .flat_map(|aliases| aliases.into_keys())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+fn builtin_aliases(tool: &str) -> &'static [&'static str] {
+    match tool {
+        "node" | "java" => &["latest", "lts"],
+        "python" => &["latest", "stable", "bugfix", "security"],
+        "rust" => &["latest", "stable"],
+        _ => &["latest"],
+    }
+}
+
+/// Task names from the nearest `.vex.toml`, including inherited workspace tasks.
+pub(super) fn tasks(cwd: &Path) -> Vec<String> {
+    let Ok(Some(project)) = project::load_nearest_project_config(cwd) else {
+        return Vec::new();
+    };
+    let mut names = project.config.commands.into_keys().collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+pub(super) fn templates() -> Vec<String> {
+    templates::template_ids()
+}
@@ -0,0 +1,77 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn complete_in(cwd: &Path, words: &[&str]) -> Vec<String> {
+    let mut command = Cli::command();
+    command.build();
+    let words = words
+        .iter()
+        .map(|word| word.to_string())
+        .collect::<Vec<_>>();
+    candidates(&command, &words, cwd)
+}
+
+fn complete_words(words: &[&str]) -> Vec<String> {
+    complete_in(Path::new("/"), words)
+}
+
+#[test]
+fn test_subcommands_are_offered_without_hidden_ones() {
+    let subcommands = complete_words(&[""]);
+    assert!(subcommands.contains(&"install".to_string()));
+    assert!(subcommands.contains(&"completions".to_string()));
+    assert!(!subcommands.contains(&"__complete".to_string()));
+
+    assert_eq!(complete_words(&["ins"]), vec!["install"]);
+}
+
+#[test]
+fn test_flags_and_option_values_are_offered() {
+    assert_eq!(
+        complete_words(&["install", "--f"]),
+        vec!["--force", "--from", "--frozen"]
+    );
+    assert_eq!(
+        complete_words(&["list-remote", "node", "--filter", ""]),
+        vec!["all", "lts", "major", "latest"]
+    );
+    assert_eq!(complete_words(&["install", "--output", "j"]), vec!["json"]);
+    assert_eq!(complete_words(&["completions", "f"]), vec!["fish"]);
+}
+
+#[test]
+fn test_spec_positions_offer_tool_prefixes() {
+    assert_eq!(
+        complete_words(&["install", "node@18", "py"]),
+        vec!["python@"]
+    );
+    assert_eq!(complete_words(&["exec", "go"]), vec!["go@"]);
+    assert_eq!(
+        complete_words(&["run", "build", "--with", "ru"]),
+        vec!["rust@"]
+    );
+    assert_eq!(complete_words(&["list", "ja"]), vec!["java"]);
+}
+
+#[test]
+fn test_exec_command_after_separator_is_not_completed() {
+    assert!(complete_words(&["exec", "node@18", "--", ""]).is_empty());
+    assert!(complete_words(&["which", ""]).is_empty());
+}
+
+#[test]
+fn test_run_offers_task_names_from_vex_toml() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join(".vex.toml"),
+        "[commands]\nbuild = \"cargo build\"\ntest = \"cargo test\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(complete_in(dir.path(), &["run", ""]), vec!["build", "test"]);
+    assert_eq!(
+        complete_in(dir.path(), &["run", "--force", "t"]),
+        vec!["test"]
+    );
+}
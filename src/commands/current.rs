@@ -17,10 +17,19 @@ pub struct CurrentEntry {
     pub version: String,
     pub source: String,
     pub source_path: Option<String>,
+    /// Every file requesting a version of this tool, highest precedence first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub requests: Vec<RequestEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ToolchainMetadata>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct RequestEntry {
+    pub path: String,
+    pub version: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CurrentReport {
     pub cwd: String,
@@ -83,8 +92,15 @@ pub fn collect_current() -> Result<CurrentReport> {
         );
 
         tools.push(CurrentEntry {
-            tool: tool_name,
             version: version_str,
+            requests: resolver::version_requests(&pwd, &tool_name)
+                .into_iter()
+                .map(|request| RequestEntry {
+                    path: request.path.display().to_string(),
+                    version: request.version,
+                })
+                .collect(),
+            tool: tool_name,
             source,
             source_path,
             metadata: tool_metadata::read_metadata(&install_dir)?,
@@ -30,6 +30,31 @@ pub(super) fn render_text(report: &CurrentReport, verbose: bool) {
             ]);
         }
 
+        if verbose && !tool.requests.is_empty() {
+            table = table.row(vec![
+                "".to_string(),
+                "".to_string(),
+                format!("{}:", "Requested by".dimmed()),
+            ]);
+            for (index, request) in tool.requests.iter().enumerate() {
+                let note = if index == 0 {
+                    "takes precedence"
+                } else {
+                    "shadowed"
+                };
+                table = table.row(vec![
+                    "".to_string(),
+                    "".to_string(),
+                    format!(
+                        "  {} {} ({})",
+                        request.path.dimmed(),
+                        request.version,
+                        note.dimmed()
+                    ),
+                ]);
+            }
+        }
+
         if verbose {
             if let Some(metadata) = &tool.metadata {
                 table = table.row(vec![
@@ -69,4 +94,9 @@ pub(super) fn render_text(report: &CurrentReport, verbose: bool) {
     table.render();
 
     println!();
+    if verbose {
+        ui::dimmed(
+            "Precedence: .tool-versions > .vex.toml [tools] > language files (.nvmrc, ...) > ~/.vex/tool-versions; nearer directories win within each kind. Task `tools` in .vex.toml override all of them while the task runs.",
+        );
+    }
 }
@@ -32,6 +32,8 @@ pub(super) fn collect() -> Result<DoctorReport> {
     let disk_usage = analysis::collect_disk_usage(&vex_dir)?;
     let unused_versions = analysis::collect_unused_versions(&vex_dir, &retained)?;
     let lifecycle_warnings = analysis::collect_lifecycle_warnings(&vex_dir)?;
+    let vulnerabilities = analysis::collect_vulnerabilities(&vex_dir)?;
+    push_vulnerability_check(&vulnerabilities, &mut warnings, &mut checks);
     let global_clis = globals::collect(None)?.entries;
     push_global_cli_inventory_check(&global_clis, &mut checks);
 
@@ -59,12 +61,59 @@ pub(super) fn collect() -> Result<DoctorReport> {
         disk_usage,
         unused_versions,
         lifecycle_warnings,
+        vulnerabilities,
         total_disk_bytes,
         reclaimable_bytes,
         suggestions,
     })
 }
 
+fn push_vulnerability_check(
+    findings: &[super::types::VulnerabilityFinding],
+    warnings: &mut usize,
+    checks: &mut Vec<super::types::DoctorCheck>,
+) {
+    if findings.is_empty() {
+        super::types::push_check(
+            checks,
+            "vulnerabilities",
+            super::types::CheckStatus::Ok,
+            "no known vulnerabilities in installed toolchains",
+            Vec::new(),
+        );
+        return;
+    }
+
+    *warnings += 1;
+    let mut details = findings
+        .iter()
+        .map(|finding| {
+            format!(
+                "{}@{}: {}",
+                finding.tool,
+                finding.version,
+                finding.vulnerability.label()
+            )
+        })
+        .collect::<Vec<_>>();
+    details.push("Run 'vex outdated' to see fixed releases for the active tools.".to_string());
+    super::types::push_check(
+        checks,
+        "vulnerabilities",
+        super::types::CheckStatus::Warn,
+        &format!(
+            "{} known {} affect installed toolchains",
+            findings.len(),
+            if findings.len() == 1 {
+                "vulnerability"
+            } else {
+                "vulnerabilities"
+            }
+        ),
+        details,
+    );
+}
+
 fn push_global_cli_inventory_check(
     global_clis: &[globals::GlobalCliEntry],
     checks: &mut Vec<super::types::DoctorCheck>,
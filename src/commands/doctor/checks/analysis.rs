@@ -1,7 +1,8 @@
 mod lifecycle;
 mod usage;
+mod vulnerabilities;
 
-use super::super::types::{LifecycleWarning, ToolDiskUsage, UnusedVersion};
+use super::super::types::{LifecycleWarning, ToolDiskUsage, UnusedVersion, VulnerabilityFinding};
 use crate::error::Result;
 use std::collections::HashMap;
 use std::path::Path;
@@ -20,3 +21,7 @@ pub(super) fn collect_unused_versions(
 pub(super) fn collect_lifecycle_warnings(vex_dir: &Path) -> Result<Vec<LifecycleWarning>> {
     lifecycle::collect_lifecycle_warnings(vex_dir)
 }
+
+pub(super) fn collect_vulnerabilities(vex_dir: &Path) -> Result<Vec<VulnerabilityFinding>> {
+    vulnerabilities::collect_vulnerabilities(vex_dir)
+}
@@ -11,7 +11,7 @@ pub(super) fn collect_lifecycle_warnings(vex_dir: &Path) -> Result<Vec<Lifecycle
     }
 
     let mut warnings = Vec::new();
-    for tool_name in &["node", "java", "python"] {
+    for tool_name in &["node", "go", "java", "rust", "python"] {
         let tool_dir = toolchains_dir.join(tool_name);
         if !tool_dir.exists() {
             continue;
@@ -0,0 +1,55 @@
+use crate::advisories;
+use crate::commands::doctor::types::VulnerabilityFinding;
+use crate::error::Result;
+use crate::versioning::version_sort_key;
+use std::fs;
+use std::path::Path;
+
+pub(super) fn collect_vulnerabilities(vex_dir: &Path) -> Result<Vec<VulnerabilityFinding>> {
+    let toolchains_dir = vex_dir.join("toolchains");
+    if !toolchains_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut findings = Vec::new();
+    for tool_name in &["node", "go", "java", "python"] {
+        let tool_dir = toolchains_dir.join(tool_name);
+        if !tool_dir.exists() {
+            continue;
+        }
+        advisories::refresh_vulnerabilities(tool_name);
+
+        for version_entry in fs::read_dir(&tool_dir)?.filter_map(|entry| entry.ok()) {
+            if !version_entry
+                .file_type()
+                .ok()
+                .map(|file_type| file_type.is_dir())
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            let version = version_entry.file_name().to_string_lossy().to_string();
+            for vulnerability in advisories::get_vulnerabilities(tool_name, &version) {
+                findings.push(VulnerabilityFinding {
+                    tool: tool_name.to_string(),
+                    version: version.clone(),
+                    vulnerability,
+                });
+            }
+        }
+    }
+
+    findings.sort_by(|left, right| {
+        left.tool
+            .cmp(&right.tool)
+            .then_with(|| version_sort_key(&left.version).cmp(&version_sort_key(&right.version)))
+            .then_with(|| {
+                right
+                    .vulnerability
+                    .severity
+                    .cmp(&left.vulnerability.severity)
+            })
+    });
+    Ok(findings)
+}
@@ -2,6 +2,8 @@ use super::super::types::{push_check, CheckStatus, DoctorCheck};
 use super::system;
 use crate::config::{self, StrictMode};
 use crate::home_state::{self, AuditKind};
+use crate::policy::Policy;
+use crate::resolver;
 use crate::tools::python;
 use crate::version_state;
 use std::path::Path;
@@ -199,6 +201,7 @@ pub(super) fn collect_environment_checks(
     collect_captured_env_check(vex_dir, warnings, issues, checks);
     collect_python_base_check(vex_dir, warnings, checks);
     collect_manager_conflict_check(warnings, issues, checks);
+    collect_policy_check(vex_dir, warnings, issues, checks);
 }
 
 fn collect_home_hygiene_check(
@@ -461,6 +464,69 @@ fn collect_python_base_check(vex_dir: &Path, warnings: &mut usize, checks: &mut
     push_check(checks, "python_base_env", status, &summary, details);
 }
 
+fn collect_policy_check(
+    vex_dir: &Path,
+    warnings: &mut usize,
+    issues: &mut usize,
+    checks: &mut Vec<DoctorCheck>,
+) {
+    let policy = match Policy::load(&resolver::current_dir()) {
+        Ok(policy) => policy,
+        Err(err) => {
+            *warnings += 1;
+            push_check(
+                checks,
+                "policy",
+                CheckStatus::Warn,
+                "version policy could not be loaded",
+                vec![err
+                    .to_string()
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string()],
+            );
+            return;
+        }
+    };
+    if policy.is_empty() {
+        push_check(
+            checks,
+            "policy",
+            CheckStatus::Ok,
+            "no version policy is configured",
+            Vec::new(),
+        );
+        return;
+    }
+
+    let violations = policy.installed_violations(vex_dir).unwrap_or_default();
+    if violations.is_empty() {
+        push_check(
+            checks,
+            "policy",
+            CheckStatus::Ok,
+            "installed toolchains satisfy the version policy",
+            Vec::new(),
+        );
+        return;
+    }
+
+    let status = strict_status(policy.mode(), warnings, issues);
+    let mut details = violations
+        .iter()
+        .map(|violation| violation.summary())
+        .collect::<Vec<_>>();
+    details.push("Run 'vex uninstall <tool@version>' to remove blocked versions.".to_string());
+    push_check(
+        checks,
+        "policy",
+        status,
+        "installed toolchains violate the version policy",
+        details,
+    );
+}
+
 fn strict_status(mode: StrictMode, warnings: &mut usize, issues: &mut usize) -> CheckStatus {
     match mode {
         StrictMode::Warn => {
@@ -9,7 +9,7 @@ use crate::error::{Result, VexError};
 use binaries::{push_binary_permissions_check, push_binary_runnability_check};
 use network::push_network_check;
 use std::path::Path;
-use toolchains::{push_installed_tools_check, push_symlink_check};
+use toolchains::{push_installed_tools_check, push_integrity_check, push_symlink_check};
 
 pub(super) fn collect_runtime_checks(
     vex_dir: &Path,
@@ -21,6 +21,7 @@ pub(super) fn collect_runtime_checks(
     let toolchains_dir = config::toolchains_dir().ok_or(VexError::HomeDirectoryNotFound)?;
     push_installed_tools_check(checks, &toolchains_dir, warnings, issues);
     push_symlink_check(checks, vex_dir, warnings);
+    push_integrity_check(checks, vex_dir, warnings);
     push_binary_permissions_check(checks, vex_bin, warnings);
     push_binary_runnability_check(checks, vex_bin, warnings);
 
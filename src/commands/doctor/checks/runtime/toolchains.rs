@@ -1,5 +1,7 @@
 use super::super::super::types::{push_check, CheckStatus, DoctorCheck};
 use super::super::system;
+use crate::tool_metadata;
+use crate::toolchain_manifest::{self, CheckMode};
 use std::fs;
 use std::path::Path;
 
@@ -68,6 +70,75 @@ pub(super) fn push_symlink_check(
     checks.push(symlink_check);
 }
 
+/// Size-and-presence comparison against each install manifest; `vex verify` re-hashes files.
+pub(super) fn push_integrity_check(
+    checks: &mut Vec<DoctorCheck>,
+    vex_dir: &Path,
+    warnings: &mut usize,
+) {
+    let mut changed = Vec::new();
+    let mut without_manifest = 0usize;
+    for (tool, version) in tool_metadata::installed_toolchains(vex_dir).unwrap_or_default() {
+        let install_dir = vex_dir.join("toolchains").join(&tool).join(&version);
+        let Ok(Some(manifest)) = toolchain_manifest::read_manifest(&install_dir) else {
+            without_manifest += 1;
+            continue;
+        };
+        match toolchain_manifest::compare(&install_dir, &manifest, CheckMode::Quick) {
+            Ok(diff) if diff.is_clean() => {}
+            Ok(diff) => changed.push(format!(
+                "{}@{}: {} modified, {} missing, {} extra files",
+                tool,
+                version,
+                diff.modified.len(),
+                diff.missing.len(),
+                diff.extra.len()
+            )),
+            Err(err) => changed.push(format!("{}@{}: {}", tool, version, err)),
+        }
+    }
+
+    let mut details = Vec::new();
+    if without_manifest > 0 {
+        details.push(format!(
+            "{} toolchain(s) predate install manifests and were not checked",
+            without_manifest
+        ));
+    }
+    if changed.is_empty() {
+        push_check(
+            checks,
+            "toolchain_integrity",
+            CheckStatus::Ok,
+            "installed toolchain files match their install manifests",
+            details,
+        );
+        return;
+    }
+
+    *warnings += 1;
+    let summary = format!(
+        "{} installed {} changed since install",
+        changed.len(),
+        if changed.len() == 1 {
+            "toolchain has"
+        } else {
+            "toolchains have"
+        }
+    );
+    changed.extend(details);
+    changed.push(
+        "Run 'vex verify' for details and 'vex repair toolchain' to reinstall them.".to_string(),
+    );
+    push_check(
+        checks,
+        "toolchain_integrity",
+        CheckStatus::Warn,
+        &summary,
+        changed,
+    );
+}
+
 fn count_installed_toolchains(toolchains_dir: &Path) -> usize {
     if !toolchains_dir.exists() {
         return 0;
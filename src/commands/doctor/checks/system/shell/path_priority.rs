@@ -1,4 +1,5 @@
 use crate::commands::doctor::types::{CheckStatus, DoctorCheck};
+use crate::shell::detect_tool_manager;
 use std::path::Path;
 
 fn managed_npm_bin(vex_bin: &Path) -> Option<String> {
@@ -12,28 +13,6 @@ fn managed_npm_bin(vex_bin: &Path) -> Option<String> {
     })
 }
 
-fn detect_tool_manager(entry: &str) -> Option<&'static str> {
-    if entry.contains("/.pyenv/shims") {
-        Some("pyenv shims")
-    } else if entry.contains("/.pyenv/bin") {
-        Some("pyenv")
-    } else if entry.contains("/.nvm/") {
-        Some("nvm")
-    } else if entry.contains("/.fnm/") {
-        Some("fnm")
-    } else if entry.contains("/.volta/bin") {
-        Some("volta")
-    } else if entry.contains("/.asdf/") {
-        Some("asdf")
-    } else if entry.contains("/.cargo/bin") && !entry.contains("/.vex/cargo/bin") {
-        Some("rustup cargo env")
-    } else if entry.contains("/.vex/cargo/bin") {
-        Some("cargo env")
-    } else {
-        None
-    }
-}
-
 pub(super) fn collect_path_priority_check(vex_bin: &Path) -> DoctorCheck {
     let Ok(path_var) = std::env::var("PATH") else {
         return DoctorCheck {
@@ -48,8 +48,11 @@ fn check_display_name(id: &str) -> &'static str {
         "python_base_env" => "Python base environment",
         "global_cli_inventory" => "global CLI inventory",
         "manager_conflicts" => "manager conflicts",
+        "policy" => "version policy",
+        "vulnerabilities" => "known vulnerabilities",
         "installed_tools" => "installed tools",
         "symlinks" => "symlinks integrity",
+        "toolchain_integrity" => "toolchain integrity",
         "binary_permissions" => "binary executability",
         "binary_runnability" => "binary runnability",
         "cache_integrity" => "cache integrity",
@@ -1,5 +1,6 @@
 use serde::Serialize;
 
+use crate::advisories::VulnerabilityMatch;
 use crate::commands::globals::GlobalCliEntry;
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
@@ -40,6 +41,14 @@ pub struct LifecycleWarning {
     pub message: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct VulnerabilityFinding {
+    pub tool: String,
+    pub version: String,
+    #[serde(flatten)]
+    pub vulnerability: VulnerabilityMatch,
+}
+
 #[derive(Debug, Serialize)]
 pub struct DoctorReport {
     pub root: String,
@@ -50,6 +59,7 @@ pub struct DoctorReport {
     pub disk_usage: Vec<ToolDiskUsage>,
     pub unused_versions: Vec<UnusedVersion>,
     pub lifecycle_warnings: Vec<LifecycleWarning>,
+    pub vulnerabilities: Vec<VulnerabilityFinding>,
     pub total_disk_bytes: u64,
     pub reclaimable_bytes: u64,
     pub suggestions: Vec<String>,
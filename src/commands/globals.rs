@@ -32,6 +32,19 @@ pub struct GlobalsReport {
     pub entries: Vec<GlobalCliEntry>,
 }
 
+/// Shared bin directories for globally installed CLIs, relative to `~/.vex`:
+/// `(tool, kind, description, dir)`.
+pub(super) const MANAGED_BIN_DIRS: &[(&str, &str, &str, &str)] = &[
+    ("node", "npm_global", "shared npm globals", "npm/prefix/bin"),
+    ("go", "go_global", "managed GOBIN (go install)", "go/bin"),
+    (
+        "rust",
+        "cargo_global",
+        "managed CARGO_HOME bin (cargo install)",
+        "cargo/bin",
+    ),
+];
+
 #[derive(Debug, Clone)]
 struct VersionContext {
     version: String,
@@ -56,10 +69,8 @@ pub fn collect(tool_filter: Option<&str>) -> Result<GlobalsReport> {
     let contexts = current_contexts().unwrap_or_default();
     let mut entries = Vec::new();
 
-    collect_node_entries(&vex_dir, &contexts, tool_filter, &mut entries);
+    collect_managed_bin_entries(&vex_dir, &contexts, tool_filter, &mut entries);
     collect_python_entries(&vex_dir, &contexts, tool_filter, &mut entries)?;
-    collect_go_entries(&vex_dir, &contexts, tool_filter, &mut entries);
-    collect_rust_entries(&vex_dir, &contexts, tool_filter, &mut entries);
     collect_java_entries(&contexts, tool_filter, &mut entries);
 
     entries.sort_by(|left, right| {
@@ -93,25 +104,26 @@ fn current_contexts() -> Result<BTreeMap<String, VersionContext>> {
         .collect())
 }
 
-fn collect_node_entries(
+fn collect_managed_bin_entries(
     vex_dir: &Path,
     contexts: &BTreeMap<String, VersionContext>,
     filter: Option<&str>,
     entries: &mut Vec<GlobalCliEntry>,
 ) {
-    if !matches_filter(filter, "node", "") {
-        return;
+    for (tool, kind, source, dir) in MANAGED_BIN_DIRS {
+        if !matches_filter(filter, tool, "") {
+            continue;
+        }
+        push_bin_entries(
+            entries,
+            tool,
+            kind,
+            source,
+            &vex_dir.join(dir),
+            contexts.get(*tool),
+            |_| true,
+        );
     }
-    let bin_dir = vex_dir.join("npm/prefix/bin");
-    push_bin_entries(
-        entries,
-        "node",
-        "npm_global",
-        "shared npm globals",
-        &bin_dir,
-        contexts.get("node"),
-        |_| true,
-    );
 }
 
 fn collect_python_entries(
@@ -172,48 +184,6 @@ fn collect_python_entries(
     Ok(())
 }
 
-fn collect_go_entries(
-    vex_dir: &Path,
-    contexts: &BTreeMap<String, VersionContext>,
-    filter: Option<&str>,
-    entries: &mut Vec<GlobalCliEntry>,
-) {
-    if !matches_filter(filter, "go", "") {
-        return;
-    }
-    let bin_dir = vex_dir.join("go/bin");
-    push_bin_entries(
-        entries,
-        "go",
-        "go_global",
-        "managed GOBIN (go install)",
-        &bin_dir,
-        contexts.get("go"),
-        |_| true,
-    );
-}
-
-fn collect_rust_entries(
-    vex_dir: &Path,
-    contexts: &BTreeMap<String, VersionContext>,
-    filter: Option<&str>,
-    entries: &mut Vec<GlobalCliEntry>,
-) {
-    if !matches_filter(filter, "rust", "") {
-        return;
-    }
-    let bin_dir = vex_dir.join("cargo/bin");
-    push_bin_entries(
-        entries,
-        "rust",
-        "cargo_global",
-        "managed CARGO_HOME bin (cargo install)",
-        &bin_dir,
-        contexts.get("rust"),
-        |_| true,
-    );
-}
-
 fn collect_java_entries(
     contexts: &BTreeMap<String, VersionContext>,
     filter: Option<&str>,
@@ -339,7 +309,7 @@ fn is_user_python_cli(name: &str) -> bool {
         || name.starts_with("pip3"))
 }
 
-fn is_executable_file(path: &Path) -> bool {
+pub(super) fn is_executable_file(path: &Path) -> bool {
     let Ok(metadata) = fs::metadata(path) else {
         return false;
     };
@@ -0,0 +1,350 @@
+mod managers;
+#[cfg(test)]
+mod tests;
+
+use crate::cli::import::ImportArgs;
+use crate::error::{Result, VexError};
+use crate::paths::vex_dir;
+use crate::policy::Policy;
+use crate::resolver;
+use crate::tool_metadata::{self, OwnershipMetadata, ProvenanceMetadata, ToolchainMetadata};
+use crate::toolchain_manifest;
+use crate::tools::{self, Tool};
+use crate::version_files;
+use chrono::Utc;
+use managers::{discover, DiscoveredInstall, Discovery, Manager};
+use owo_colors::OwoColorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InstallAction {
+    /// Copy the existing install into `~/.vex/toolchains` and record where it came from.
+    Adopt,
+    /// Install the same version from vex's own upstream sources.
+    Reinstall,
+    AlreadyInstalled,
+    Blocked(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PinAction {
+    Write,
+    AlreadyPinned(String),
+}
+
+#[derive(Debug)]
+struct ImportPlan {
+    installs: Vec<(DiscoveredInstall, InstallAction)>,
+    pins: Vec<(String, String, PinAction)>,
+    notes: Vec<String>,
+}
+
+pub fn run(args: &ImportArgs) -> Result<()> {
+    let manager = Manager::parse(&args.manager)?;
+    let home = dirs::home_dir().ok_or(VexError::HomeDirectoryNotFound)?;
+    let root = manager.root(&home);
+    let vex = vex_dir()?;
+
+    let discovery = discover(manager, &home, &root)?;
+    if discovery.installs.is_empty() && discovery.pins.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "No {} installs or pins were found in {}.",
+                manager.name(),
+                root.display()
+            )
+            .green()
+        );
+        return Ok(());
+    }
+
+    let policy = Policy::load(&resolver::current_dir())?;
+    let plan = build_plan(discovery, &vex, &policy, args.reinstall);
+
+    println!(
+        "{} {}",
+        if args.apply { "Applying" } else { "Previewing" }.cyan(),
+        format!("{} import plan", manager.name()).cyan()
+    );
+    println!();
+
+    render_plan(&plan, &vex, args.apply);
+
+    if !args.apply {
+        println!();
+        println!(
+            "{} run {} to apply this import.",
+            "Dry run complete.".dimmed(),
+            format!(
+                "vex import {}{} --apply",
+                manager.name(),
+                if args.reinstall { " --reinstall" } else { "" }
+            )
+            .cyan()
+        );
+        return Ok(());
+    }
+
+    apply_plan(&plan, manager, &vex)
+}
+
+fn build_plan(discovery: Discovery, vex: &Path, policy: &Policy, reinstall: bool) -> ImportPlan {
+    let installs = discovery
+        .installs
+        .into_iter()
+        .map(|install| {
+            let action = install_action(&install, vex, policy, reinstall);
+            (install, action)
+        })
+        .collect();
+
+    let existing = resolver::read_tool_versions_file(&vex.join("tool-versions"));
+    let pins = discovery
+        .pins
+        .into_iter()
+        .map(|(tool, version)| {
+            let action = match existing.get(&tool) {
+                Some(pinned) => PinAction::AlreadyPinned(pinned.clone()),
+                None => PinAction::Write,
+            };
+            (tool, version, action)
+        })
+        .collect();
+
+    ImportPlan {
+        installs,
+        pins,
+        notes: discovery.notes,
+    }
+}
+
+fn install_action(
+    install: &DiscoveredInstall,
+    vex: &Path,
+    policy: &Policy,
+    reinstall: bool,
+) -> InstallAction {
+    if toolchain_dir(vex, install).exists() {
+        return InstallAction::AlreadyInstalled;
+    }
+    if let Err(error) = policy.check(&install.tool, &install.version) {
+        return InstallAction::Blocked(
+            error
+                .to_string()
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        );
+    }
+    if reinstall || install.other_vendor.is_some() {
+        return InstallAction::Reinstall;
+    }
+
+    match tools::get_tool(&install.tool) {
+        Ok(tool) if is_adoptable(tool.as_ref(), &install.source) => InstallAction::Adopt,
+        _ => InstallAction::Reinstall,
+    }
+}
+
+/// Only relocatable installs whose layout matches what vex links are adopted in place.
+/// Python and Rust installs embed their original prefix (pip shebangs, rustup proxies and
+/// component layout), so they are always reinstalled from vex's sources.
+fn is_adoptable(tool: &dyn Tool, source: &Path) -> bool {
+    if !matches!(tool.name(), "node" | "go" | "java") {
+        return false;
+    }
+
+    tool.bin_paths()
+        .first()
+        .map(|(name, subpath)| source.join(subpath).join(name).is_file())
+        .unwrap_or(false)
+}
+
+fn toolchain_dir(vex: &Path, install: &DiscoveredInstall) -> PathBuf {
+    vex.join("toolchains")
+        .join(&install.tool)
+        .join(&install.version)
+}
+
+fn render_plan(plan: &ImportPlan, vex: &Path, apply: bool) {
+    let verb = |done: &'static str, preview: &'static str| {
+        if apply {
+            done.green().to_string()
+        } else {
+            preview.cyan().to_string()
+        }
+    };
+
+    for (install, action) in &plan.installs {
+        let spec = format!("{}@{}", install.tool, install.version);
+        match action {
+            InstallAction::Adopt => {
+                println!("{} {}", verb("adopt", "would adopt"), spec);
+                println!(
+                    "  {} -> {}",
+                    install.source.display(),
+                    toolchain_dir(vex, install).display()
+                );
+            }
+            InstallAction::Reinstall => {
+                println!("{} {}", verb("reinstall", "would reinstall"), spec);
+                println!("  replaces {}", install.source.display());
+                if let Some(build) = &install.other_vendor {
+                    println!(
+                        "  {} {} is not an Eclipse Temurin build; vex installs Temurin",
+                        "vendor switch:".yellow(),
+                        build
+                    );
+                }
+            }
+            InstallAction::AlreadyInstalled => {
+                println!("{} {} (already installed in vex)", "skip".yellow(), spec);
+            }
+            InstallAction::Blocked(reason) => {
+                println!("{} {} ({})", "skip".yellow(), spec, reason);
+            }
+        }
+    }
+
+    for (tool, version, action) in &plan.pins {
+        match action {
+            PinAction::Write => println!(
+                "{} {} {} in {}",
+                verb("pin", "would pin"),
+                tool,
+                version,
+                vex.join("tool-versions").display()
+            ),
+            PinAction::AlreadyPinned(existing) => println!(
+                "{} pin {} {} (already pinned to {})",
+                "skip".yellow(),
+                tool,
+                version,
+                existing
+            ),
+        }
+    }
+
+    for note in &plan.notes {
+        println!("{} {}", "manual".yellow(), note);
+    }
+}
+
+fn apply_plan(plan: &ImportPlan, manager: Manager, vex: &Path) -> Result<()> {
+    let mut adopted = 0usize;
+    let mut reinstall_specs = Vec::new();
+
+    for (install, action) in &plan.installs {
+        match action {
+            InstallAction::Adopt => {
+                let tool = tools::get_tool(&install.tool)?;
+                adopt_install(tool.as_ref(), install, manager, vex)?;
+                adopted += 1;
+            }
+            InstallAction::Reinstall => {
+                reinstall_specs.push(format!("{}@{}", install.tool, install.version))
+            }
+            InstallAction::AlreadyInstalled | InstallAction::Blocked(_) => {}
+        }
+    }
+
+    let reinstall_result = if reinstall_specs.is_empty() {
+        Ok(())
+    } else {
+        println!();
+        crate::commands::toolchain::install_specs(&reinstall_specs, true, false, false)
+    };
+
+    let mut pinned = 0usize;
+    for (tool, version, action) in &plan.pins {
+        if *action == PinAction::Write {
+            version_files::write_tool_version(&vex.join("tool-versions"), tool, version)?;
+            pinned += 1;
+        }
+    }
+
+    println!();
+    println!(
+        "{} {} adopted, {} reinstalled, {} pin(s) written",
+        "Done.".green(),
+        adopted,
+        reinstall_specs.len(),
+        pinned
+    );
+    println!(
+        "{}",
+        format!(
+            "Remove {}'s shell setup once you have switched; run 'vex use --auto' to activate.",
+            manager.name()
+        )
+        .dimmed()
+    );
+
+    reinstall_result
+}
+
+fn adopt_install(
+    tool: &dyn Tool,
+    install: &DiscoveredInstall,
+    manager: Manager,
+    vex: &Path,
+) -> Result<()> {
+    let destination = toolchain_dir(vex, install);
+    let tool_dir = destination
+        .parent()
+        .ok_or_else(|| VexError::Parse("Invalid toolchain path".to_string()))?;
+    fs::create_dir_all(tool_dir)?;
+
+    let staging = tool_dir.join(format!(".{}.importing", install.version));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    if let Err(error) = copy_tree(&install.source, &staging) {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(error);
+    }
+    fs::rename(&staging, &destination)?;
+
+    let managed = tool.managed_environment(vex, Some(&destination));
+    let metadata = ToolchainMetadata {
+        tool: install.tool.clone(),
+        version: install.version.clone(),
+        provenance: ProvenanceMetadata {
+            source_url: Some(install.source.display().to_string()),
+            mirror_url: None,
+            checksum: None,
+            installed_at: Utc::now().to_rfc3339(),
+            imported_from: Some(manager.name().to_string()),
+        },
+        ownership: OwnershipMetadata {
+            vex_owned: vec![destination.display().to_string()],
+            managed_user_state: managed.owned_home_dirs,
+            project_owned: managed.project_owned_dirs,
+        },
+        extensions: Vec::new(),
+        managed_env: managed.managed_env,
+    };
+    tool_metadata::write_metadata(&destination, &metadata)?;
+    toolchain_manifest::write_manifest(&destination)
+}
+
+/// Copy a directory tree, recreating symlinks instead of following them.
+fn copy_tree(source: &Path, destination: &Path) -> Result<()> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = destination.join(entry.file_name());
+        if file_type.is_symlink() {
+            std::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+        } else if file_type.is_dir() {
+            copy_tree(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,459 @@
+use crate::error::{Result, VexError};
+use crate::resolver;
+use crate::versioning::version_sort_key;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(super) const SUPPORTED_MANAGERS: &str = "nvm, pyenv, asdf, mise, rustup, sdkman, goenv, volta";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Manager {
+    Nvm,
+    Pyenv,
+    Asdf,
+    Mise,
+    Rustup,
+    Sdkman,
+    Goenv,
+    Volta,
+}
+
+impl Manager {
+    pub(super) fn parse(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "nvm" => Ok(Self::Nvm),
+            "pyenv" => Ok(Self::Pyenv),
+            "asdf" => Ok(Self::Asdf),
+            "mise" | "rtx" => Ok(Self::Mise),
+            "rustup" => Ok(Self::Rustup),
+            "sdkman" | "sdk" => Ok(Self::Sdkman),
+            "goenv" => Ok(Self::Goenv),
+            "volta" => Ok(Self::Volta),
+            _ => Err(VexError::Parse(format!(
+                "Unsupported import source '{}'. Supported managers: {}",
+                name, SUPPORTED_MANAGERS
+            ))),
+        }
+    }
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Nvm => "nvm",
+            Self::Pyenv => "pyenv",
+            Self::Asdf => "asdf",
+            Self::Mise => "mise",
+            Self::Rustup => "rustup",
+            Self::Sdkman => "sdkman",
+            Self::Goenv => "goenv",
+            Self::Volta => "volta",
+        }
+    }
+
+    /// Data directory of the manager, honouring the same environment overrides it does.
+    pub(super) fn root(self, home: &Path) -> PathBuf {
+        let (env_key, default) = match self {
+            Self::Nvm => ("NVM_DIR", home.join(".nvm")),
+            Self::Pyenv => ("PYENV_ROOT", home.join(".pyenv")),
+            Self::Asdf => ("ASDF_DATA_DIR", home.join(".asdf")),
+            Self::Mise => ("MISE_DATA_DIR", home.join(".local/share/mise")),
+            Self::Rustup => ("RUSTUP_HOME", home.join(".rustup")),
+            Self::Sdkman => ("SDKMAN_DIR", home.join(".sdkman")),
+            Self::Goenv => ("GOENV_ROOT", home.join(".goenv")),
+            Self::Volta => ("VOLTA_HOME", home.join(".volta")),
+        };
+
+        std::env::var_os(env_key)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or(default)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DiscoveredInstall {
+    pub(super) tool: String,
+    pub(super) version: String,
+    pub(super) source: PathBuf,
+    /// Manager's name for a Java build that is not Eclipse Temurin, the only vendor vex installs.
+    pub(super) other_vendor: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Discovery {
+    pub(super) installs: Vec<DiscoveredInstall>,
+    pub(super) pins: Vec<(String, String)>,
+    pub(super) notes: Vec<String>,
+}
+
+impl Discovery {
+    fn push_install(&mut self, tool: &str, raw_version: &str, source: PathBuf) {
+        match normalize_version(tool, raw_version) {
+            Some(version) => self.installs.push(DiscoveredInstall {
+                tool: tool.to_string(),
+                version,
+                source,
+                other_vendor: (tool == "java" && !is_temurin_build(raw_version))
+                    .then(|| raw_version.to_string()),
+            }),
+            None => self.notes.push(format!(
+                "{} {} is not a release vex can manage ({})",
+                tool,
+                raw_version,
+                source.display()
+            )),
+        }
+    }
+
+    fn push_pin(&mut self, tool: &str, raw_version: &str) {
+        match self.resolve_pin(tool, raw_version) {
+            Some(version) => {
+                self.pins.retain(|(pinned, _)| pinned != tool);
+                self.pins.push((tool.to_string(), version));
+            }
+            None => self.notes.push(format!(
+                "global {} pin '{}' does not map to a vex version",
+                tool, raw_version
+            )),
+        }
+    }
+
+    /// Resolve a manager pin such as `20`, `v20.11.0` or `node` to a concrete version,
+    /// preferring the newest discovered install that matches it.
+    fn resolve_pin(&self, tool: &str, raw_version: &str) -> Option<String> {
+        let newest = |prefix: Option<&str>| {
+            self.installs
+                .iter()
+                .filter(|install| install.tool == tool)
+                .filter(|install| {
+                    prefix.is_none_or(|prefix| {
+                        install.version == prefix
+                            || install.version.starts_with(&format!("{}.", prefix))
+                    })
+                })
+                .max_by_key(|install| version_sort_key(&install.version))
+                .map(|install| install.version.clone())
+        };
+
+        if matches!(raw_version, "node" | "stable" | "latest") {
+            return newest(None);
+        }
+
+        let normalized = normalize_version(tool, raw_version)?;
+        newest(Some(&normalized)).or(Some(normalized))
+    }
+}
+
+pub(super) fn discover(manager: Manager, home: &Path, root: &Path) -> Result<Discovery> {
+    let mut discovery = Discovery::default();
+
+    match manager {
+        Manager::Nvm => {
+            for (name, path) in list_dirs(&root.join("versions/node"))? {
+                discovery.push_install("node", &name, path);
+            }
+            if let Some(pin) = read_nvm_alias(root, "default") {
+                discovery.push_pin("node", &pin);
+            }
+        }
+        Manager::Pyenv | Manager::Goenv => {
+            let tool = if manager == Manager::Pyenv {
+                "python"
+            } else {
+                "go"
+            };
+            for (name, path) in list_dirs(&root.join("versions"))? {
+                discovery.push_install(tool, &name, path);
+            }
+            if let Some(pin) = read_first_line(&root.join("version")).filter(|pin| pin != "system")
+            {
+                discovery.push_pin(tool, &pin);
+            }
+        }
+        Manager::Asdf | Manager::Mise => {
+            for (plugin, plugin_dir) in list_dirs(&root.join("installs"))? {
+                let Some(tool) = vex_tool_name(&plugin) else {
+                    discovery.notes.push(format!(
+                        "{} plugin '{}' is not supported by vex",
+                        manager.name(),
+                        plugin
+                    ));
+                    continue;
+                };
+                for (name, path) in list_dirs(&plugin_dir)? {
+                    discovery.push_install(tool, &name, path);
+                }
+            }
+
+            let pins = if manager == Manager::Asdf {
+                read_asdf_pins(home)
+            } else {
+                read_mise_pins(home)
+            };
+            for (plugin, version) in pins {
+                if let Some(tool) = vex_tool_name(&plugin) {
+                    discovery.push_pin(tool, &version);
+                }
+            }
+        }
+        Manager::Rustup => {
+            for (name, path) in list_dirs(&root.join("toolchains"))? {
+                match rustup_toolchain_version(&name, &path) {
+                    Some(version) => discovery.push_install("rust", &version, path),
+                    None => discovery.notes.push(format!(
+                        "rustup toolchain {} is not a stable release vex can manage",
+                        name
+                    )),
+                }
+            }
+            if let Some(default) = read_rustup_default(root) {
+                let path = root.join("toolchains").join(&default);
+                match rustup_toolchain_version(&default, &path) {
+                    Some(version) => discovery.push_pin("rust", &version),
+                    None => discovery.notes.push(format!(
+                        "rustup default toolchain {} does not map to a vex version",
+                        default
+                    )),
+                }
+            }
+        }
+        Manager::Sdkman => {
+            let java_dir = root.join("candidates/java");
+            for (name, path) in list_dirs(&java_dir)? {
+                discovery.push_install("java", &name, path);
+            }
+            if let Some(current) = fs::read_link(java_dir.join("current"))
+                .ok()
+                .and_then(|target| {
+                    target
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                })
+            {
+                discovery.push_pin("java", &current);
+            }
+        }
+        Manager::Volta => {
+            for (name, path) in list_dirs(&root.join("tools/image/node"))? {
+                discovery.push_install("node", &name, path);
+            }
+            if let Some(pin) = read_volta_default(root) {
+                discovery.push_pin("node", &pin);
+            }
+        }
+    }
+
+    // Temurin sorts first, so it wins when several Java builds share a feature release.
+    discovery.installs.sort_by(|left, right| {
+        left.tool
+            .cmp(&right.tool)
+            .then_with(|| version_sort_key(&left.version).cmp(&version_sort_key(&right.version)))
+            .then_with(|| {
+                left.other_vendor
+                    .is_some()
+                    .cmp(&right.other_vendor.is_some())
+            })
+            .then_with(|| left.source.cmp(&right.source))
+    });
+    let mut installs: Vec<DiscoveredInstall> = Vec::new();
+    for install in std::mem::take(&mut discovery.installs) {
+        match installs.last() {
+            Some(kept) if kept.tool == install.tool && kept.version == install.version => {
+                discovery.notes.push(format!(
+                    "{} also maps to {}@{}; only {} is imported",
+                    install.source.display(),
+                    install.tool,
+                    install.version,
+                    kept.source.display()
+                ));
+            }
+            _ => installs.push(install),
+        }
+    }
+    discovery.installs = installs;
+    discovery.pins.sort();
+
+    Ok(discovery)
+}
+
+/// Map asdf/mise plugin names onto vex tool names.
+fn vex_tool_name(plugin: &str) -> Option<&'static str> {
+    match plugin {
+        "node" | "nodejs" => Some("node"),
+        "python" => Some("python"),
+        "go" | "golang" => Some("go"),
+        "java" => Some("java"),
+        "rust" => Some("rust"),
+        _ => None,
+    }
+}
+
+/// Whether a Java build name is Eclipse Temurin: SDKMAN's `-tem` suffix, or asdf/mise's
+/// `temurin-` and HotSpot `adoptopenjdk-` prefixes. Bare versions have no known vendor.
+fn is_temurin_build(raw: &str) -> bool {
+    let raw = raw.trim();
+    raw.ends_with("-tem")
+        || raw.starts_with("temurin-")
+        || (raw.starts_with("adoptopenjdk-") && !raw.starts_with("adoptopenjdk-openj9-"))
+}
+
+/// Convert a manager's directory or pin name into the version vex would install.
+///
+/// Java is managed per feature release in vex, so vendor-qualified names such as
+/// `21.0.2-tem` or `temurin-21.0.2+13.0.LTS` collapse to their major version.
+pub(super) fn normalize_version(tool: &str, raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    match tool {
+        "java" => {
+            let start = raw.find(|c: char| c.is_ascii_digit())?;
+            let major = raw[start..]
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .filter(|major| !major.is_empty())?;
+            Some(major.to_string())
+        }
+        _ => {
+            let version = raw.strip_prefix('v').unwrap_or(raw);
+            let numeric = version.starts_with(|c: char| c.is_ascii_digit())
+                && version.chars().all(|c| c.is_ascii_digit() || c == '.')
+                && !version.ends_with('.');
+            numeric.then(|| version.to_string())
+        }
+    }
+}
+
+fn list_dirs(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // Symlinks are aliases (`current`, pyenv virtualenvs) rather than installs.
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        entries.push((name, entry.path()));
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+fn read_first_line(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().and_then(|content| {
+        content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .and_then(|line| line.split_whitespace().next())
+            .map(str::to_string)
+    })
+}
+
+/// Follow nvm alias files (`default` -> `lts/iron` -> `v20.19.0`) to a version-like value.
+fn read_nvm_alias(root: &Path, name: &str) -> Option<String> {
+    let mut current = name.to_string();
+    for _ in 0..8 {
+        let value = read_first_line(&root.join("alias").join(&current))?;
+        let alias = value.trim_end_matches("/*");
+        if root.join("alias").join(alias).is_file() && alias != current {
+            current = alias.to_string();
+            continue;
+        }
+        return Some(value);
+    }
+    None
+}
+
+fn read_asdf_pins(home: &Path) -> Vec<(String, String)> {
+    let path = std::env::var_os("ASDF_DEFAULT_TOOL_VERSIONS_FILENAME")
+        .filter(|name| !name.is_empty())
+        .map(|name| home.join(name))
+        .unwrap_or_else(|| home.join(".tool-versions"));
+    fs::read_to_string(path)
+        .map(|content| resolver::parse_tool_versions(&content))
+        .unwrap_or_default()
+}
+
+fn read_mise_pins(home: &Path) -> Vec<(String, String)> {
+    let config_dir = std::env::var_os("MISE_CONFIG_DIR")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".config/mise"));
+    let Some(value) = fs::read_to_string(config_dir.join("config.toml"))
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+    else {
+        return Vec::new();
+    };
+    let Some(tools) = value.get("tools").and_then(toml::Value::as_table) else {
+        return Vec::new();
+    };
+
+    tools
+        .iter()
+        .filter_map(|(tool, spec)| {
+            let version = match spec {
+                toml::Value::String(version) => Some(version.clone()),
+                toml::Value::Array(versions) => versions
+                    .first()
+                    .and_then(toml::Value::as_str)
+                    .map(str::to_string),
+                toml::Value::Table(table) => table
+                    .get("version")
+                    .and_then(toml::Value::as_str)
+                    .map(str::to_string),
+                _ => None,
+            }?;
+            Some((tool.clone(), version))
+        })
+        .collect()
+}
+
+fn read_rustup_default(root: &Path) -> Option<String> {
+    let content = fs::read_to_string(root.join("settings.toml")).ok()?;
+    let settings = content.parse::<toml::Table>().ok()?;
+    settings
+        .get("default_toolchain")
+        .and_then(toml::Value::as_str)
+        .map(str::to_string)
+}
+
+/// Resolve a rustup toolchain directory (`1.77.0-aarch64-apple-darwin`, `stable-…`) to
+/// its rustc release, reading the channel manifest for channel toolchains.
+fn rustup_toolchain_version(name: &str, path: &Path) -> Option<String> {
+    let channel = name.split('-').next().unwrap_or(name);
+    if normalize_version("rust", channel).is_some() {
+        return Some(channel.to_string());
+    }
+    if channel != "stable" {
+        return None;
+    }
+
+    let manifest = fs::read_to_string(path.join("lib/rustlib/multirust-channel-manifest.toml"))
+        .ok()?
+        .parse::<toml::Table>()
+        .ok()?;
+    manifest
+        .get("pkg")?
+        .get("rustc")?
+        .get("version")?
+        .as_str()?
+        .split_whitespace()
+        .next()
+        .map(str::to_string)
+}
+
+fn read_volta_default(root: &Path) -> Option<String> {
+    let content = fs::read_to_string(root.join("tools/user/platform.json")).ok()?;
+    let platform: serde_json::Value = serde_json::from_str(&content).ok()?;
+    platform
+        .get("node")?
+        .get("runtime")?
+        .as_str()
+        .map(str::to_string)
+}
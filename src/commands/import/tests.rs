@@ -0,0 +1,339 @@
+use super::managers::{discover, normalize_version, Manager};
+use super::*;
+use crate::config::StrictMode;
+use crate::policy::{PolicyConfig, ToolPolicy};
+use std::collections::BTreeMap;
+use tempfile::TempDir;
+
+fn touch_executable(path: &Path) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, "#!/bin/sh\n").unwrap();
+}
+
+#[test]
+fn test_normalize_version_per_tool() {
+    assert_eq!(
+        normalize_version("node", "v20.11.0").as_deref(),
+        Some("20.11.0")
+    );
+    assert_eq!(
+        normalize_version("python", "3.12.1").as_deref(),
+        Some("3.12.1")
+    );
+    assert_eq!(normalize_version("python", "miniconda3-latest"), None);
+    assert_eq!(normalize_version("go", "1.22rc1"), None);
+    assert_eq!(
+        normalize_version("java", "21.0.2-tem").as_deref(),
+        Some("21")
+    );
+    assert_eq!(
+        normalize_version("java", "temurin-17.0.10+7").as_deref(),
+        Some("17")
+    );
+    assert_eq!(normalize_version("java", "zulu"), None);
+}
+
+#[test]
+fn test_discover_nvm_follows_default_alias_chain() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".nvm");
+    fs::create_dir_all(root.join("versions/node/v18.19.0")).unwrap();
+    fs::create_dir_all(root.join("versions/node/v20.11.0")).unwrap();
+    fs::create_dir_all(root.join("versions/node/v20.12.2")).unwrap();
+    fs::create_dir_all(root.join("alias/lts")).unwrap();
+    fs::write(root.join("alias/default"), "lts/iron\n").unwrap();
+    fs::write(root.join("alias/lts/iron"), "v20\n").unwrap();
+
+    let discovery = discover(Manager::Nvm, home.path(), &root).unwrap();
+
+    let versions = discovery
+        .installs
+        .iter()
+        .map(|install| install.version.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(versions, vec!["18.19.0", "20.11.0", "20.12.2"]);
+    assert_eq!(
+        discovery.pins,
+        vec![("node".to_string(), "20.12.2".to_string())]
+    );
+}
+
+#[test]
+fn test_discover_pyenv_skips_virtualenvs_and_system_pin() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".pyenv");
+    fs::create_dir_all(root.join("versions/3.12.1")).unwrap();
+    fs::create_dir_all(root.join("versions/miniconda3-latest")).unwrap();
+    std::os::unix::fs::symlink(
+        root.join("versions/3.12.1"),
+        root.join("versions/project-venv"),
+    )
+    .unwrap();
+    fs::write(root.join("version"), "system\n").unwrap();
+
+    let discovery = discover(Manager::Pyenv, home.path(), &root).unwrap();
+
+    assert_eq!(discovery.installs.len(), 1);
+    assert_eq!(discovery.installs[0].tool, "python");
+    assert_eq!(discovery.installs[0].version, "3.12.1");
+    assert!(discovery.pins.is_empty());
+    assert!(discovery
+        .notes
+        .iter()
+        .any(|note| note.contains("miniconda3-latest")));
+}
+
+#[test]
+fn test_discover_asdf_maps_plugins_and_global_tool_versions() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".asdf");
+    fs::create_dir_all(root.join("installs/nodejs/20.11.0")).unwrap();
+    fs::create_dir_all(root.join("installs/golang/1.22.0")).unwrap();
+    fs::create_dir_all(root.join("installs/java/temurin-21.0.2+13.0.LTS")).unwrap();
+    fs::create_dir_all(root.join("installs/terraform/1.7.0")).unwrap();
+    fs::write(
+        home.path().join(".tool-versions"),
+        "nodejs 20.11.0\ngolang 1.22.0\njava temurin-21.0.2+13.0.LTS\n",
+    )
+    .unwrap();
+
+    let discovery = discover(Manager::Asdf, home.path(), &root).unwrap();
+
+    let specs = discovery
+        .installs
+        .iter()
+        .map(|install| format!("{}@{}", install.tool, install.version))
+        .collect::<Vec<_>>();
+    assert_eq!(specs, vec!["go@1.22.0", "java@21", "node@20.11.0"]);
+    assert_eq!(
+        discovery.pins,
+        vec![
+            ("go".to_string(), "1.22.0".to_string()),
+            ("java".to_string(), "21".to_string()),
+            ("node".to_string(), "20.11.0".to_string()),
+        ]
+    );
+    assert!(discovery
+        .notes
+        .iter()
+        .any(|note| note.contains("terraform")));
+}
+
+#[test]
+fn test_discover_mise_reads_global_config_tools() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".local/share/mise");
+    fs::create_dir_all(root.join("installs/python/3.11.9")).unwrap();
+    fs::create_dir_all(root.join("installs/python/3.12.4")).unwrap();
+    fs::create_dir_all(home.path().join(".config/mise")).unwrap();
+    fs::write(
+        home.path().join(".config/mise/config.toml"),
+        "[tools]\npython = [\"3.12\", \"3.11\"]\n",
+    )
+    .unwrap();
+
+    let discovery = discover(Manager::Mise, home.path(), &root).unwrap();
+
+    assert_eq!(discovery.installs.len(), 2);
+    assert_eq!(
+        discovery.pins,
+        vec![("python".to_string(), "3.12.4".to_string())]
+    );
+}
+
+#[test]
+fn test_discover_rustup_resolves_stable_channel_from_manifest() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".rustup");
+    let stable = root.join("toolchains/stable-aarch64-apple-darwin");
+    fs::create_dir_all(stable.join("lib/rustlib")).unwrap();
+    fs::write(
+        stable.join("lib/rustlib/multirust-channel-manifest.toml"),
+        "[pkg.rustc]\nversion = \"1.90.0 (1159e78c4 2025-09-14)\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("toolchains/1.85.0-aarch64-apple-darwin")).unwrap();
+    fs::create_dir_all(root.join("toolchains/nightly-aarch64-apple-darwin")).unwrap();
+    fs::write(
+        root.join("settings.toml"),
+        "default_toolchain = \"stable-aarch64-apple-darwin\"\n",
+    )
+    .unwrap();
+
+    let discovery = discover(Manager::Rustup, home.path(), &root).unwrap();
+
+    let versions = discovery
+        .installs
+        .iter()
+        .map(|install| install.version.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(versions, vec!["1.85.0", "1.90.0"]);
+    assert_eq!(
+        discovery.pins,
+        vec![("rust".to_string(), "1.90.0".to_string())]
+    );
+    assert!(discovery.notes.iter().any(|note| note.contains("nightly")));
+}
+
+#[test]
+fn test_discover_sdkman_uses_current_symlink_as_pin() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".sdkman");
+    let java = root.join("candidates/java");
+    fs::create_dir_all(java.join("17.0.10-tem")).unwrap();
+    fs::create_dir_all(java.join("21.0.2-tem")).unwrap();
+    std::os::unix::fs::symlink(java.join("21.0.2-tem"), java.join("current")).unwrap();
+
+    let discovery = discover(Manager::Sdkman, home.path(), &root).unwrap();
+
+    assert_eq!(discovery.installs.len(), 2);
+    assert_eq!(discovery.pins, vec![("java".to_string(), "21".to_string())]);
+}
+
+#[test]
+fn test_sdkman_java_vendor_builds_are_reinstalled_as_temurin() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".sdkman");
+    let vex = home.path().join(".vex");
+    let java = root.join("candidates/java");
+    touch_executable(&java.join("21.0.2-zulu/Contents/Home/bin/java"));
+    touch_executable(&java.join("21.0.3-tem/Contents/Home/bin/java"));
+    touch_executable(&java.join("17.0.10-amzn/Contents/Home/bin/java"));
+
+    let discovery = discover(Manager::Sdkman, home.path(), &root).unwrap();
+    assert_eq!(discovery.installs.len(), 2);
+    assert!(discovery
+        .notes
+        .iter()
+        .any(|note| note.contains("21.0.2-zulu") && note.contains("java@21")));
+
+    let plan = build_plan(discovery, &vex, &Policy::new(StrictMode::Warn), false);
+    let actions = plan
+        .installs
+        .iter()
+        .map(|(install, action)| (install.version.as_str(), (install, action)))
+        .collect::<BTreeMap<_, _>>();
+    let (java21, action) = actions["21"];
+    assert_eq!(action, &InstallAction::Adopt);
+    assert!(java21.source.ends_with("21.0.3-tem"));
+    let (java17, action) = actions["17"];
+    assert_eq!(action, &InstallAction::Reinstall);
+    assert_eq!(java17.other_vendor.as_deref(), Some("17.0.10-amzn"));
+}
+
+#[test]
+fn test_discover_volta_reads_platform_default() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".volta");
+    fs::create_dir_all(root.join("tools/image/node/22.3.0")).unwrap();
+    fs::create_dir_all(root.join("tools/user")).unwrap();
+    fs::write(
+        root.join("tools/user/platform.json"),
+        r#"{"node":{"runtime":"22.3.0","npm":null}}"#,
+    )
+    .unwrap();
+
+    let discovery = discover(Manager::Volta, home.path(), &root).unwrap();
+
+    assert_eq!(discovery.installs[0].version, "22.3.0");
+    assert_eq!(
+        discovery.pins,
+        vec![("node".to_string(), "22.3.0".to_string())]
+    );
+}
+
+#[test]
+fn test_unknown_manager_is_rejected() {
+    assert!(Manager::parse("fnm").is_err());
+    assert_eq!(Manager::parse("SDKMAN").unwrap(), Manager::Sdkman);
+}
+
+#[test]
+fn test_build_plan_adopts_matching_layouts_and_keeps_existing_pins() {
+    let home = TempDir::new().unwrap();
+    let root = home.path().join(".asdf");
+    let vex = home.path().join(".vex");
+    touch_executable(&root.join("installs/nodejs/20.11.0/bin/node"));
+    fs::create_dir_all(root.join("installs/nodejs/18.19.0")).unwrap();
+    fs::create_dir_all(root.join("installs/python/3.12.1/bin")).unwrap();
+    fs::create_dir_all(root.join("installs/golang/1.22.0")).unwrap();
+    fs::create_dir_all(vex.join("toolchains/go/1.22.0")).unwrap();
+    fs::write(vex.join("tool-versions"), "node 22.0.0\n").unwrap();
+    fs::write(
+        home.path().join(".tool-versions"),
+        "nodejs 20.11.0\npython 3.12.1\n",
+    )
+    .unwrap();
+
+    let mut config = PolicyConfig::default();
+    config.tools.insert(
+        "node".to_string(),
+        ToolPolicy {
+            min: Some("20".to_string()),
+            ..ToolPolicy::default()
+        },
+    );
+    let mut policy = Policy::new(StrictMode::Enforce);
+    policy.push_layer("test".to_string(), config).unwrap();
+
+    let discovery = discover(Manager::Asdf, home.path(), &root).unwrap();
+    let plan = build_plan(discovery, &vex, &policy, false);
+
+    let actions = plan
+        .installs
+        .iter()
+        .map(|(install, action)| (format!("{}@{}", install.tool, install.version), action))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(actions["node@20.11.0"], &InstallAction::Adopt);
+    assert!(matches!(actions["node@18.19.0"], InstallAction::Blocked(_)));
+    assert_eq!(actions["python@3.12.1"], &InstallAction::Reinstall);
+    assert_eq!(actions["go@1.22.0"], &InstallAction::AlreadyInstalled);
+
+    assert!(plan.pins.contains(&(
+        "node".to_string(),
+        "20.11.0".to_string(),
+        PinAction::AlreadyPinned("22.0.0".to_string())
+    )));
+    assert!(plan
+        .pins
+        .contains(&("python".to_string(), "3.12.1".to_string(), PinAction::Write)));
+}
+
+#[test]
+fn test_adopt_install_copies_tree_with_symlinks_and_records_source() {
+    let home = TempDir::new().unwrap();
+    let vex = home.path().join(".vex");
+    let source = home.path().join(".nvm/versions/node/v20.11.0");
+    touch_executable(&source.join("bin/node"));
+    touch_executable(&source.join("lib/node_modules/npm/bin/npm-cli.js"));
+    std::os::unix::fs::symlink(
+        "../lib/node_modules/npm/bin/npm-cli.js",
+        source.join("bin/npm"),
+    )
+    .unwrap();
+
+    let install = DiscoveredInstall {
+        tool: "node".to_string(),
+        version: "20.11.0".to_string(),
+        source: source.clone(),
+        other_vendor: None,
+    };
+    let tool = tools::get_tool("node").unwrap();
+    adopt_install(tool.as_ref(), &install, Manager::Nvm, &vex).unwrap();
+
+    let destination = vex.join("toolchains/node/20.11.0");
+    assert!(destination.join("bin/node").is_file());
+    assert_eq!(
+        fs::read_link(destination.join("bin/npm")).unwrap(),
+        PathBuf::from("../lib/node_modules/npm/bin/npm-cli.js")
+    );
+    assert!(!vex.join("toolchains/node/.20.11.0.importing").exists());
+    assert!(source.join("bin/node").exists());
+
+    let metadata = tool_metadata::read_metadata(&destination).unwrap().unwrap();
+    assert_eq!(metadata.provenance.imported_from.as_deref(), Some("nvm"));
+    assert_eq!(
+        metadata.provenance.source_url,
+        Some(source.display().to_string())
+    );
+}
@@ -10,10 +10,10 @@ pub fn run(
     list_templates: bool,
     dry_run: bool,
     add_only: bool,
+    inputs: &templates::TemplateInputs,
 ) -> Result<()> {
     if list_templates {
-        templates::print_templates();
-        return Ok(());
+        return templates::print_templates(&resolver::current_dir(), inputs.from.as_deref());
     }
 
     if let Some(template_name) = template {
@@ -27,6 +27,7 @@ pub fn run(
             template_name,
             dry_run,
             conflict_mode,
+            inputs,
         )?;
         return Ok(());
     }
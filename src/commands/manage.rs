@@ -4,7 +4,9 @@ mod uninstall;
 use crate::advisories;
 use crate::error::{Result, VexError};
 use crate::paths::vex_dir;
+use crate::policy::Policy;
 use crate::requested_versions;
+use crate::resolver;
 use crate::spec::parse_spec;
 use crate::switcher;
 use crate::tools;
@@ -45,6 +47,7 @@ pub fn use_spec(spec: &str) -> Result<()> {
         Some(installed) => installed,
         None => tools::resolve_fuzzy_version(tool.as_ref(), &version)?,
     };
+    Policy::load(&resolver::current_dir())?.check(&tool_name, &resolved)?;
     switcher::switch_version(tool.as_ref(), &resolved)?;
 
     let advisory = advisories::get_advisory(&tool_name, &resolved);
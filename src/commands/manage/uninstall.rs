@@ -1,4 +1,5 @@
 use crate::error::{Result, VexError};
+use crate::output::{self, Event};
 use crate::paths::vex_dir;
 use crate::spec::parse_spec;
 use crate::tools;
@@ -36,6 +37,10 @@ pub fn uninstall(tool_name: &str, version: &str) -> Result<()> {
         remove_active_links(&vex_dir, tool_name)?;
     }
 
+    output::emit(Event::Uninstalled {
+        tool: tool_name.to_string(),
+        version: version.to_string(),
+    });
     println!(
         "{} Uninstalled {} {}",
         "✓".green(),
@@ -1,15 +1,21 @@
 pub mod aliases;
+pub mod completions;
 pub mod current;
 pub mod doctor;
 pub mod globals;
+pub mod import;
 pub mod init;
 pub mod manage;
 pub mod process;
+pub mod projects;
 pub mod prune;
 pub mod python;
 pub mod repair;
 pub mod rust;
+pub mod sbom;
 pub mod toolchain;
 pub mod tui;
 pub mod updates;
+pub mod verify;
 pub mod versions;
+pub mod which;
@@ -1,11 +1,21 @@
+mod adhoc;
+mod task;
+
 use crate::activation::{self, ActivationPlan};
 use crate::config;
 use crate::error::{Result, VexError};
+use crate::policy::Policy;
 use crate::project;
+use crate::registry;
 use crate::resolver;
+use crate::toolchain_usage;
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
 use std::process::Command;
 
-pub fn exec_command(command: &[String]) -> Result<i32> {
+/// Run `args` in the resolved environment; leading `tool@version` specs override it.
+pub fn exec_command(args: &[String], offline: bool) -> Result<i32> {
+    let (specs, command) = adhoc::split_exec_args(args);
     if command.is_empty() {
         return Err(VexError::Parse(
             "Please provide a command after '--' (for example: 'vex exec -- node -v')".to_string(),
@@ -13,59 +23,191 @@ pub fn exec_command(command: &[String]) -> Result<i32> {
     }
 
     let cwd = resolver::current_dir();
-    let plan = activation::build_activation_plan(&cwd)?;
+    let overrides = adhoc::resolve_overrides(&cwd, specs, offline)?;
+    let plan = activation::build_activation_plan_with_tools(&cwd, &overrides)?;
+    check_policy(&cwd, &plan)?;
+    record_usage(&plan);
     spawn_direct_command(&plan, &cwd, command)
 }
 
-pub fn run_task(task: &str, args: &[String]) -> Result<i32> {
+/// Run `task`; `with` specs such as `node@18` override every version it runs with.
+pub fn run_task(
+    task: &str,
+    args: &[String],
+    force: bool,
+    with: &[String],
+    offline: bool,
+) -> Result<i32> {
     let cwd = resolver::current_dir();
-    let plan = activation::build_activation_plan(&cwd)?;
-    let project = plan.project.as_ref().ok_or_else(|| {
-        VexError::Config(
-            "No .vex.toml found in the current project tree. Create one before using 'vex run'."
-                .to_string(),
-        )
-    })?;
-
-    let command =
-        project.config.commands.get(task).ok_or_else(|| {
-            VexError::Config(format!("Task '{}' was not found in .vex.toml", task))
-        })?;
-
-    let shell = resolve_shell(project::load_nearest_project_config(&cwd)?.as_ref())?;
-    // Use a non-login shell so rc/profile files cannot overwrite the activation
-    // environment we inject for the task process.
-    let shell_flag = "-c";
-
-    let mut full_command = command.clone();
-    if !args.is_empty() {
-        full_command.push(' ');
-        full_command.push_str(
-            &args
-                .iter()
-                .map(|arg| shell_quote(arg))
-                .collect::<Vec<_>>()
-                .join(" "),
-        );
+    registry::record_current_project();
+    let overrides = adhoc::resolve_overrides(&cwd, with, offline)?;
+    let plan = activation::build_activation_plan_with_tools(&cwd, &overrides)?;
+    let project = plan.project.as_ref().ok_or_else(no_project_config)?;
+
+    if !project.config.commands.contains_key(task) {
+        return Err(VexError::Config(format!(
+            "Task '{}' was not found in .vex.toml",
+            task
+        )));
     }
+    run_task_in(&cwd, &plan, &overrides, task, args, force)
+}
 
-    let mut process = Command::new(shell);
-    process.arg(shell_flag).arg(full_command);
-    process.current_dir(&project.root);
-    apply_activation_environment(&mut process, &plan);
+/// Run `task` in every workspace member that defines or inherits it, each with its own
+/// activation plan. Every member runs even after a failure; the first failing exit code wins, and
+/// a member that cannot be activated or run (missing toolchain, policy block, task cycle) fails
+/// with exit code 1.
+pub fn run_workspace_task(
+    task: &str,
+    args: &[String],
+    force: bool,
+    with: &[String],
+    offline: bool,
+) -> Result<i32> {
+    let cwd = resolver::current_dir();
+    let root = project::find_workspace_root(&cwd)?.ok_or_else(no_workspace_root)?;
+    let members = project::workspace_members(&root)?;
+    let overrides = adhoc::resolve_overrides(&cwd, with, offline)?;
 
-    let status = process.status()?;
-    Ok(status.code().unwrap_or(1))
+    let mut ran = 0;
+    let mut failures = Vec::new();
+    for member in &members {
+        let label = member
+            .strip_prefix(&root.root)
+            .unwrap_or(member)
+            .display()
+            .to_string();
+        let plan = match activation::build_activation_plan_with_tools(member, &overrides) {
+            Ok(plan) => plan,
+            Err(err) => {
+                eprintln!("{} {} {}: {}", "✗".red(), label, task, err);
+                ran += 1;
+                failures.push((label, 1));
+                continue;
+            }
+        };
+        let has_task = plan
+            .project
+            .as_ref()
+            .is_some_and(|project| project.config.commands.contains_key(task));
+        if !has_task {
+            eprintln!("{} {} has no task {}", "-".dimmed(), label, task);
+            continue;
+        }
+
+        eprintln!("{} {} {}", "→".cyan(), label.cyan(), task);
+        ran += 1;
+        let code = match run_task_in(member, &plan, &overrides, task, args, force) {
+            Ok(code) => code,
+            Err(err) => {
+                eprintln!("{} {} {}: {}", "✗".red(), label, task, err);
+                1
+            }
+        };
+        if code != 0 {
+            failures.push((label, code));
+        }
+    }
+
+    if ran == 0 {
+        return Err(VexError::Config(format!(
+            "No workspace member of {} defines task '{}'.",
+            root.root.display(),
+            task
+        )));
+    }
+    let Some((_, code)) = failures.first() else {
+        eprintln!("{} {} succeeded in {} member(s)", "✓".green(), task, ran);
+        return Ok(0);
+    };
+    eprintln!(
+        "{} {} failed in {} of {} member(s): {}",
+        "✗".red(),
+        task,
+        failures.len(),
+        ran,
+        failures
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(*code)
+}
+
+fn run_task_in(
+    cwd: &std::path::Path,
+    plan: &ActivationPlan,
+    overrides: &HashMap<String, String>,
+    task: &str,
+    args: &[String],
+    force: bool,
+) -> Result<i32> {
+    let project = plan.project.as_ref().ok_or_else(no_project_config)?;
+    let waves = task::plan_waves(&project.config.commands, task)?;
+    check_policy(cwd, plan)?;
+    record_usage(plan);
+
+    let shell = resolve_shell(Some(project))?;
+    let context = task::TaskContext {
+        cwd,
+        root: &project.root,
+        shell: &shell,
+        plan,
+        overrides,
+        target: task,
+        args,
+        force,
+    };
+    task::run_waves(&project.config.commands, &waves, &context)
+}
+
+pub fn list_tasks() -> Result<()> {
+    let cwd = resolver::current_dir();
+    let project = project::load_nearest_project_config(&cwd)?.ok_or_else(no_project_config)?;
+    task::print_tasks(&project);
+    Ok(())
+}
+
+fn no_workspace_root() -> VexError {
+    VexError::Config(
+        "No .vex.toml with a [workspace] section found in the current directory or its parents."
+            .to_string(),
+    )
+}
+
+fn no_project_config() -> VexError {
+    VexError::Config(
+        "No .vex.toml found in the current project tree. Create one before using 'vex run'."
+            .to_string(),
+    )
 }
 
 pub fn print_exports(shell: &str) -> Result<()> {
     let cwd = resolver::current_dir();
     let plan = activation::build_activation_plan(&cwd)?;
+    record_usage(&plan);
     let exports = crate::shell::generate_exports(shell, &plan).map_err(VexError::Parse)?;
     print!("{}", exports);
     Ok(())
 }
 
+fn record_usage(plan: &ActivationPlan) {
+    toolchain_usage::record_quietly(
+        plan.versions
+            .iter()
+            .map(|(tool, version)| (tool.as_str(), version.as_str())),
+    );
+}
+
+fn check_policy(cwd: &std::path::Path, plan: &ActivationPlan) -> Result<()> {
+    let policy = Policy::load(cwd)?;
+    for (tool, version) in &plan.versions {
+        policy.check(tool, version)?;
+    }
+    Ok(())
+}
+
 fn spawn_direct_command(
     plan: &ActivationPlan,
     cwd: &std::path::Path,
@@ -0,0 +1,119 @@
+//! Ad-hoc tool versions for `vex exec <spec>... --` and `vex run --with <spec>`
+//!
+//! The specs replace the resolved versions for one process only; nothing is pinned or switched.
+//! Missing versions are installed first, after a prompt unless `non_interactive` is set. Without
+//! a terminal to prompt on and without `non_interactive`, they are never installed.
+
+use crate::config;
+use crate::error::{Result, VexError};
+use crate::installer;
+use crate::paths::vex_dir;
+use crate::policy::Policy;
+use crate::requested_versions;
+use crate::spec::parse_spec;
+use crate::tools::{self, SUPPORTED_TOOLS};
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+
+/// Split `vex exec` arguments into leading `tool@version` specs and the command after them.
+///
+/// A `--` right after the specs is dropped, so `node@18 -- node -v` and `-- node -v` both leave
+/// `node -v`.
+pub(super) fn split_exec_args(args: &[String]) -> (&[String], &[String]) {
+    let specs = args.iter().take_while(|arg| is_tool_spec(arg)).count();
+    let (specs, rest) = args.split_at(specs);
+    let command = match rest.first() {
+        Some(first) if first == "--" => &rest[1..],
+        _ => rest,
+    };
+    (specs, command)
+}
+
+fn is_tool_spec(arg: &str) -> bool {
+    arg.split_once('@').is_some_and(|(tool, version)| {
+        SUPPORTED_TOOLS.contains(&tool) && !version.is_empty() && !version.contains('@')
+    })
+}
+
+/// Resolve `specs` to installed versions, installing missing ones, keyed by tool.
+///
+/// A later spec for the same tool wins. With `offline`, missing versions must be exact and are
+/// installed from the archive cache.
+pub(super) fn resolve_overrides(
+    cwd: &Path,
+    specs: &[String],
+    offline: bool,
+) -> Result<HashMap<String, String>> {
+    let mut overrides = HashMap::new();
+    if specs.is_empty() {
+        return Ok(overrides);
+    }
+
+    let vex = vex_dir()?;
+    let policy = Policy::load(cwd)?;
+    for spec in specs {
+        let (tool_name, version) = parse_spec(spec)?;
+        if version.is_empty() {
+            return Err(VexError::Parse(format!(
+                "Version required for '{}'. Use tool@version, for example node@18.",
+                spec
+            )));
+        }
+        let tool = tools::get_tool(&tool_name)?;
+
+        let resolved =
+            match requested_versions::resolve_installed_version(&vex, &tool_name, &version)? {
+                Some(installed) => installed,
+                None => {
+                    let resolved = if offline {
+                        version.clone()
+                    } else {
+                        requested_versions::resolve_for_install(tool.as_ref(), &version)?
+                    };
+                    policy.check(&tool_name, &resolved)?;
+                    if !confirm_install(cwd, &tool_name, &resolved)? {
+                        return Err(VexError::VersionNotFound {
+                            tool: tool_name,
+                            version,
+                            suggestions: format!(
+                                "\n\nInstall it first with 'vex install {}@{} --no-switch'.",
+                                tool.name(),
+                                resolved
+                            ),
+                        });
+                    }
+                    installer::install_with_mode(tool.as_ref(), &resolved, offline)?;
+                    resolved
+                }
+            };
+        overrides.insert(tool_name, resolved);
+    }
+    Ok(overrides)
+}
+
+/// Ask before installing unless `non_interactive` is set; piped runs without it never install.
+fn confirm_install(cwd: &Path, tool: &str, version: &str) -> Result<bool> {
+    let settings = config::load_effective_settings(cwd)?;
+    if settings.behavior.non_interactive {
+        return Ok(true);
+    }
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+
+    eprint!(
+        "{} {} is not installed. Install it now? [Y/n]: ",
+        "→".cyan(),
+        format!("{}@{}", tool, version).yellow()
+    );
+    io::stderr().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let answer = input.trim();
+    Ok(answer.is_empty() || answer.eq_ignore_ascii_case("y"))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+use super::*;
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn test_split_exec_args_takes_leading_specs() {
+    let input = args(&["node@18", "python@3.13", "--", "node", "-v"]);
+    let (specs, command) = split_exec_args(&input);
+    assert_eq!(specs, args(&["node@18", "python@3.13"]).as_slice());
+    assert_eq!(command, args(&["node", "-v"]).as_slice());
+}
+
+#[test]
+fn test_split_exec_args_without_specs_keeps_command() {
+    let input = args(&["node", "-v"]);
+    let (specs, command) = split_exec_args(&input);
+    assert!(specs.is_empty());
+    assert_eq!(command, input.as_slice());
+
+    let input = args(&["--", "npm@latest", "x"]);
+    let (specs, command) = split_exec_args(&input);
+    assert!(specs.is_empty());
+    assert_eq!(command, args(&["npm@latest", "x"]).as_slice());
+}
+
+#[test]
+fn test_split_exec_args_ignores_unknown_tools_and_empty_versions() {
+    let input = args(&["ruby@3", "node@", "-v"]);
+    let (specs, command) = split_exec_args(&input);
+    assert!(specs.is_empty());
+    assert_eq!(command, input.as_slice());
+}
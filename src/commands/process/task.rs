@@ -0,0 +1,275 @@
+//! `vex run` task execution
+//!
+//! Tasks run after their `depends_on` tasks. Tasks are grouped into waves whose members only
+//! depend on earlier waves; the members of a wave run in parallel with prefixed output.
+
+mod freshness;
+mod output;
+
+use super::{apply_activation_environment, check_policy, record_usage, shell_quote};
+use crate::activation::{self, ActivationPlan};
+use crate::error::{Result, VexError};
+use crate::project::{LoadedProjectConfig, TaskDef};
+use owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+
+pub(super) struct TaskContext<'a> {
+    /// Directory the activation plan was built for; task `tools` overrides are resolved here.
+    pub(super) cwd: &'a Path,
+    pub(super) root: &'a Path,
+    pub(super) shell: &'a str,
+    pub(super) plan: &'a ActivationPlan,
+    /// `vex run --with` versions; they win over a task's own `tools` table.
+    pub(super) overrides: &'a HashMap<String, String>,
+    /// The task named on the command line; only it receives the extra arguments.
+    pub(super) target: &'a str,
+    pub(super) args: &'a [String],
+    pub(super) force: bool,
+}
+
+/// Order `target` and its dependencies into waves, rejecting dependency cycles.
+pub(super) fn plan_waves(
+    commands: &HashMap<String, TaskDef>,
+    target: &str,
+) -> Result<Vec<Vec<String>>> {
+    let mut levels = BTreeMap::new();
+    let mut stack = Vec::new();
+    assign_level(commands, target, &mut levels, &mut stack)?;
+
+    let depth = levels.values().copied().max().map_or(0, |level| level + 1);
+    let mut waves = vec![Vec::new(); depth];
+    for (name, level) in levels {
+        waves[level].push(name);
+    }
+    Ok(waves)
+}
+
+fn assign_level(
+    commands: &HashMap<String, TaskDef>,
+    name: &str,
+    levels: &mut BTreeMap<String, usize>,
+    stack: &mut Vec<String>,
+) -> Result<usize> {
+    if let Some(level) = levels.get(name) {
+        return Ok(*level);
+    }
+    if let Some(start) = stack.iter().position(|entry| entry == name) {
+        let mut cycle = stack[start..].to_vec();
+        cycle.push(name.to_string());
+        return Err(VexError::Config(format!(
+            "Task dependency cycle in .vex.toml: {}",
+            cycle.join(" -> ")
+        )));
+    }
+    let task = commands
+        .get(name)
+        .ok_or_else(|| VexError::Config(format!("Task '{}' was not found in .vex.toml", name)))?;
+
+    stack.push(name.to_string());
+    let mut level = 0;
+    for dependency in task.depends_on() {
+        level = level.max(assign_level(commands, dependency, levels, stack)? + 1);
+    }
+    stack.pop();
+
+    levels.insert(name.to_string(), level);
+    Ok(level)
+}
+
+/// Run every wave in order and stop after the first wave with a failing task.
+pub(super) fn run_waves(
+    commands: &HashMap<String, TaskDef>,
+    waves: &[Vec<String>],
+    context: &TaskContext,
+) -> Result<i32> {
+    let announce = waves.iter().map(Vec::len).sum::<usize>() > 1;
+
+    for wave in waves {
+        let mut runnable = Vec::new();
+        for name in wave {
+            let task = &commands[name];
+            let Some(command) = task.command() else {
+                continue;
+            };
+            let dir = task_dir(context.root, name, task)?;
+            if !context.force && is_up_to_date(&dir, task) {
+                eprintln!("{} {} is up to date, skipping", "✓".green(), name.cyan());
+                continue;
+            }
+            if announce {
+                eprintln!("{} {}", "→".cyan(), name.cyan());
+            }
+            runnable.push((name, task_command(context, name, task, command, &dir)?));
+        }
+
+        let results = match runnable.len() {
+            0 => Vec::new(),
+            1 => {
+                let (name, mut command) = runnable.pop().expect("one runnable task");
+                vec![(name, command.status()?.code().unwrap_or(1))]
+            }
+            _ => {
+                let width = runnable.iter().map(|(name, _)| name.len()).max();
+                thread::scope(|scope| {
+                    let handles = runnable
+                        .into_iter()
+                        .map(|(name, command)| {
+                            let width = width.unwrap_or_default();
+                            scope.spawn(move || {
+                                output::run_prefixed(name, width, command).map(|code| (name, code))
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.join().expect("task output thread panicked"))
+                        .collect::<Result<Vec<_>>>()
+                })?
+            }
+        };
+
+        if let Some((name, code)) = results.into_iter().find(|(_, code)| *code != 0) {
+            if announce {
+                eprintln!(
+                    "{} Task {} failed with exit code {}",
+                    "✗".red(),
+                    name.cyan(),
+                    code
+                );
+            }
+            return Ok(code);
+        }
+    }
+
+    Ok(0)
+}
+
+pub(super) fn print_tasks(project: &LoadedProjectConfig) {
+    let commands = &project.config.commands;
+    if commands.is_empty() {
+        println!("No tasks defined in {}", project.path.display());
+        return;
+    }
+
+    println!(
+        "{}",
+        format!("Tasks in {}:", project.path.display())
+            .cyan()
+            .bold()
+    );
+    let mut names = commands.keys().collect::<Vec<_>>();
+    names.sort();
+    let width = names
+        .iter()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or_default();
+    for name in names {
+        let task = &commands[name];
+        let summary = match (task.description(), task.command()) {
+            (Some(description), _) => description.to_string(),
+            (None, Some(command)) => command.dimmed().to_string(),
+            (None, None) => String::new(),
+        };
+        let dependencies = if task.depends_on().is_empty() {
+            String::new()
+        } else {
+            format!(" (after {})", task.depends_on().join(", "))
+                .dimmed()
+                .to_string()
+        };
+        let tools = task
+            .tools()
+            .map(|tools| {
+                let mut pins = tools
+                    .iter()
+                    .map(|(tool, version)| format!("{}@{}", tool, version))
+                    .collect::<Vec<_>>();
+                pins.sort();
+                format!(" [{}]", pins.join(", ")).yellow().to_string()
+            })
+            .unwrap_or_default();
+        println!(
+            "  {}  {}{}{}",
+            format!("{:width$}", name).cyan(),
+            summary,
+            dependencies,
+            tools
+        );
+    }
+}
+
+fn task_dir(root: &Path, name: &str, task: &TaskDef) -> Result<PathBuf> {
+    let TaskDef::Task(config) = task else {
+        return Ok(root.to_path_buf());
+    };
+    let Some(cwd) = &config.cwd else {
+        return Ok(root.to_path_buf());
+    };
+
+    let dir = root.join(cwd);
+    if !dir.is_dir() {
+        return Err(VexError::Config(format!(
+            "Task '{}' uses cwd '{}', but {} is not a directory.",
+            name,
+            cwd,
+            dir.display()
+        )));
+    }
+    Ok(dir)
+}
+
+fn is_up_to_date(dir: &Path, task: &TaskDef) -> bool {
+    match task {
+        TaskDef::Command(_) => false,
+        TaskDef::Task(config) => freshness::is_up_to_date(dir, &config.sources, &config.outputs),
+    }
+}
+
+fn task_command(
+    context: &TaskContext,
+    name: &str,
+    task: &TaskDef,
+    command: &str,
+    dir: &Path,
+) -> Result<Command> {
+    let mut full_command = command.to_string();
+    if name == context.target && !context.args.is_empty() {
+        full_command.push(' ');
+        full_command.push_str(
+            &context
+                .args
+                .iter()
+                .map(|arg| shell_quote(arg))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+
+    // Use a non-login shell so rc/profile files cannot overwrite the activation
+    // environment we inject for the task process.
+    let mut process = Command::new(context.shell);
+    process.arg("-c").arg(full_command);
+    process.current_dir(dir);
+    match task.tools() {
+        Some(tools) => {
+            let mut tools = tools.clone();
+            tools.extend(context.overrides.clone());
+            let plan = activation::build_activation_plan_with_tools(context.cwd, &tools)?;
+            check_policy(context.cwd, &plan)?;
+            record_usage(&plan);
+            apply_activation_environment(&mut process, &plan);
+        }
+        None => apply_activation_environment(&mut process, context.plan),
+    }
+    if let TaskDef::Task(config) = task {
+        process.envs(&config.env);
+    }
+    Ok(process)
+}
+
+#[cfg(test)]
+mod tests;
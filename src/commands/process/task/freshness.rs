@@ -0,0 +1,113 @@
+use crate::fs_utils::wildcard_match;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A task is up to date when every output pattern matches a file and no source file is newer
+/// than the oldest output. Tasks without both `sources` and `outputs` always run.
+pub(super) fn is_up_to_date(dir: &Path, sources: &[String], outputs: &[String]) -> bool {
+    if sources.is_empty() || outputs.is_empty() {
+        return false;
+    }
+
+    let Some(newest_source) = expand(dir, sources)
+        .iter()
+        .filter_map(|path| modified(path))
+        .max()
+    else {
+        return false;
+    };
+
+    let mut oldest_output = None;
+    for pattern in outputs {
+        let matches = expand(dir, std::slice::from_ref(pattern));
+        if matches.is_empty() {
+            return false;
+        }
+        for time in matches.iter().filter_map(|path| modified(path)) {
+            oldest_output = Some(oldest_output.map_or(time, |oldest: SystemTime| oldest.min(time)));
+        }
+    }
+
+    oldest_output.is_some_and(|oldest| newest_source <= oldest)
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Files matched by glob patterns relative to `dir`. `*` and `?` match within one path
+/// component, `**` matches any number of directories, and a directory matches every file in it.
+pub(super) fn expand(dir: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut matches = Vec::new();
+    for pattern in patterns {
+        let components = pattern
+            .split('/')
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect::<Vec<_>>();
+        collect(dir, &components, &mut matches);
+    }
+    matches.sort();
+    matches.dedup();
+    matches
+}
+
+fn collect(base: &Path, components: &[&str], matches: &mut Vec<PathBuf>) {
+    let Some((first, rest)) = components.split_first() else {
+        collect_files(base, matches);
+        return;
+    };
+
+    if *first == "**" {
+        collect(base, rest, matches);
+        for entry in subdirectories(base) {
+            collect(&entry, components, matches);
+        }
+    } else if first.contains(['*', '?']) {
+        let Ok(entries) = fs::read_dir(base) else {
+            return;
+        };
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            if wildcard_match(first, &entry.file_name().to_string_lossy()) {
+                collect(&entry.path(), rest, matches);
+            }
+        }
+    } else {
+        let path = base.join(first);
+        if path.exists() {
+            collect(&path, rest, matches);
+        }
+    }
+}
+
+fn collect_files(path: &Path, matches: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        matches.push(path.to_path_buf());
+        return;
+    }
+    for entry in subdirectories(path) {
+        collect_files(&entry, matches);
+    }
+    if let Ok(entries) = fs::read_dir(path) {
+        matches.extend(
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+                .map(|entry| entry.path()),
+        );
+    }
+}
+
+/// Real subdirectories, skipping symlinks and `.git`.
+fn subdirectories(path: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(path) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_type().is_ok_and(|kind| kind.is_dir()) && entry.file_name() != ".git"
+        })
+        .map(|entry| entry.path())
+        .collect()
+}
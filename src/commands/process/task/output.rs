@@ -0,0 +1,34 @@
+use crate::error::Result;
+use owo_colors::OwoColorize;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Run a task with its stdout and stderr lines prefixed by `[name]`.
+pub(super) fn run_prefixed(name: &str, width: usize, mut command: Command) -> Result<i32> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let prefix = format!("[{:width$}]", name).cyan().to_string();
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let stderr = child.stderr.take().expect("stderr is piped");
+
+    thread::scope(|scope| {
+        scope.spawn(|| forward_lines(stdout, |line| println!("{} {}", prefix, line)));
+        scope.spawn(|| forward_lines(stderr, |line| eprintln!("{} {}", prefix, line)));
+    });
+
+    Ok(child.wait()?.code().unwrap_or(1))
+}
+
+fn forward_lines(stream: impl Read, emit: impl Fn(&str)) {
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+    while reader.read_until(b'\n', &mut line).unwrap_or(0) > 0 {
+        let text = String::from_utf8_lossy(&line);
+        emit(text.trim_end_matches(['\n', '\r']));
+        line.clear();
+    }
+}
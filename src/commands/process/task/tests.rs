@@ -0,0 +1,111 @@
+use super::*;
+use crate::project::TaskConfig;
+use std::fs;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+fn task(run: Option<&str>, depends_on: &[&str]) -> TaskDef {
+    TaskDef::Task(Box::new(TaskConfig {
+        run: run.map(str::to_string),
+        depends_on: depends_on.iter().map(|name| name.to_string()).collect(),
+        ..TaskConfig::default()
+    }))
+}
+
+fn touch(path: &Path, age_secs: u64) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, "x").unwrap();
+    let time = SystemTime::now() - Duration::from_secs(age_secs);
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(time)
+        .unwrap();
+}
+
+#[test]
+fn test_plan_waves_groups_independent_dependencies() {
+    let commands = HashMap::from([
+        (
+            "lint".to_string(),
+            TaskDef::Command("cargo clippy".to_string()),
+        ),
+        ("gen".to_string(), task(Some("make gen"), &[])),
+        ("build".to_string(), task(Some("cargo build"), &["gen"])),
+        ("ci".to_string(), task(None, &["lint", "build"])),
+        ("unused".to_string(), task(Some("true"), &[])),
+    ]);
+
+    let waves = plan_waves(&commands, "ci").unwrap();
+    assert_eq!(
+        waves,
+        vec![
+            vec!["gen".to_string(), "lint".to_string()],
+            vec!["build".to_string()],
+            vec!["ci".to_string()],
+        ]
+    );
+    assert_eq!(
+        plan_waves(&commands, "lint").unwrap(),
+        vec![vec!["lint".to_string()]]
+    );
+}
+
+#[test]
+fn test_plan_waves_rejects_cycles() {
+    let commands = HashMap::from([
+        ("a".to_string(), task(Some("true"), &["b"])),
+        ("b".to_string(), task(Some("true"), &["c"])),
+        ("c".to_string(), task(Some("true"), &["a"])),
+    ]);
+
+    let err = plan_waves(&commands, "a").unwrap_err().to_string();
+    assert!(err.contains("a -> b -> c -> a"), "unexpected error: {err}");
+}
+
+#[test]
+fn test_glob_expansion() {
+    let temp = TempDir::new().unwrap();
+    touch(&temp.path().join("src/main.rs"), 0);
+    touch(&temp.path().join("src/nested/lib.rs"), 0);
+    touch(&temp.path().join("src/nested/notes.md"), 0);
+    touch(&temp.path().join(".git/objects/a.rs"), 0);
+
+    let relative = |paths: Vec<PathBuf>| {
+        paths
+            .into_iter()
+            .map(|path| {
+                path.strip_prefix(temp.path())
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        relative(freshness::expand(temp.path(), &["**/*.rs".to_string()])),
+        vec!["src/main.rs", "src/nested/lib.rs"]
+    );
+    assert_eq!(
+        relative(freshness::expand(temp.path(), &["src/nested".to_string()])),
+        vec!["src/nested/lib.rs", "src/nested/notes.md"]
+    );
+}
+
+#[test]
+fn test_task_is_up_to_date_only_when_outputs_are_newer() {
+    let temp = TempDir::new().unwrap();
+    let sources = vec!["src/**/*.ts".to_string()];
+    let outputs = vec!["dist".to_string()];
+
+    touch(&temp.path().join("src/index.ts"), 100);
+    assert!(!freshness::is_up_to_date(temp.path(), &sources, &outputs));
+
+    touch(&temp.path().join("dist/index.js"), 10);
+    assert!(freshness::is_up_to_date(temp.path(), &sources, &outputs));
+    assert!(!freshness::is_up_to_date(temp.path(), &sources, &[]));
+
+    touch(&temp.path().join("src/util/format.ts"), 0);
+    assert!(!freshness::is_up_to_date(temp.path(), &sources, &outputs));
+}
@@ -0,0 +1,140 @@
+use crate::cli::projects::{ProjectsArgs, ProjectsCommands};
+use crate::error::{Result, VexError};
+use crate::output::{self, OutputMode};
+use crate::paths::vex_dir;
+use crate::registry;
+use crate::resolver;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize)]
+struct ProjectListing {
+    root: String,
+    last_seen: String,
+    stale: bool,
+    versions: BTreeMap<String, String>,
+}
+
+pub fn run(args: &ProjectsArgs) -> Result<()> {
+    match &args.command {
+        ProjectsCommands::List { json } => list(OutputMode::from_json_flag(*json)),
+        ProjectsCommands::Forget { path, stale } => {
+            if *stale {
+                forget_stale()
+            } else {
+                forget(path.as_deref())
+            }
+        }
+    }
+}
+
+fn list(mode: OutputMode) -> Result<()> {
+    let vex = vex_dir()?;
+    let projects = registry::load(&vex)
+        .into_iter()
+        .map(|entry| {
+            let stale = entry.is_stale();
+            let versions = if stale {
+                BTreeMap::new()
+            } else {
+                resolver::resolve_project_versions(&entry.root)
+                    .into_iter()
+                    .collect()
+            };
+            ProjectListing {
+                root: entry.root.display().to_string(),
+                last_seen: entry.last_seen,
+                stale,
+                versions,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if mode == OutputMode::Json {
+        return output::print_json(&projects);
+    }
+
+    if projects.is_empty() {
+        println!("No projects registered yet.");
+        println!(
+            "{}",
+            "Projects are registered by the shell hook, 'vex install', 'vex use --auto', and 'vex run'."
+                .dimmed()
+        );
+        return Ok(());
+    }
+
+    for project in &projects {
+        if project.stale {
+            println!("{} {}", project.root.yellow(), "(stale)".yellow());
+            continue;
+        }
+
+        let pins = project
+            .versions
+            .iter()
+            .map(|(tool, version)| format!("{}@{}", tool, version))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("{}", project.root.cyan());
+        println!("  {}", pins);
+    }
+
+    if projects.iter().any(|project| project.stale) {
+        println!();
+        println!(
+            "{}",
+            "Run 'vex projects forget --stale' to drop projects that no longer exist.".dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+fn forget(path: Option<&str>) -> Result<()> {
+    let vex = vex_dir()?;
+    let cwd = resolver::current_dir();
+    let root = match path {
+        Some(path) => PathBuf::from(path),
+        None => resolver::find_project_root(&cwd).ok_or_else(|| {
+            VexError::Config(
+                "The current directory is not inside a project with version files. Pass the project path, e.g. 'vex projects forget ~/src/app'."
+                    .to_string(),
+            )
+        })?,
+    };
+    let root = if root.is_absolute() {
+        root
+    } else {
+        cwd.join(root)
+    };
+
+    if registry::forget(&vex, &root)? {
+        println!(
+            "{} Forgot {}",
+            "✓".green(),
+            root.display().to_string().cyan()
+        );
+    } else {
+        println!("{} is not registered.", root.display());
+    }
+    Ok(())
+}
+
+fn forget_stale() -> Result<()> {
+    let vex = vex_dir()?;
+    let removed = registry::forget_stale(&vex)?;
+
+    if removed.is_empty() {
+        println!("{}", "No stale projects found.".green());
+        return Ok(());
+    }
+
+    for entry in &removed {
+        println!("{} {}", "forget".yellow(), entry.root.display());
+    }
+    println!("{} Removed {} stale project(s)", "✓".green(), removed.len());
+    Ok(())
+}
@@ -2,6 +2,7 @@ mod collect;
 mod render;
 
 use crate::error::Result;
+use crate::output::{self, Event};
 use collect::collect_plan;
 use render::{render_completed, render_dry_run};
 use serde::Serialize;
@@ -16,6 +17,8 @@ pub struct RemovalCandidate {
     pub kind: String,
     pub path: String,
     pub bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -37,6 +40,7 @@ pub struct PruneReport {
 
 pub fn run(dry_run: bool) -> Result<()> {
     let report = collect_plan(dry_run)?;
+    output::report(&report)?;
 
     if dry_run {
         render_dry_run(&report);
@@ -50,6 +54,11 @@ pub fn run(dry_run: bool) -> Result<()> {
         } else if path.exists() {
             fs::remove_file(&path)?;
         }
+        output::emit(Event::Removed {
+            kind: candidate.kind.clone(),
+            path: candidate.path.clone(),
+            bytes: candidate.bytes,
+        });
     }
 
     render_completed(&report);
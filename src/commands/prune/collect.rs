@@ -3,17 +3,20 @@ mod locks;
 mod toolchains;
 
 use super::{PruneReport, RetainedToolchain};
-use crate::config;
+use crate::config::{self, PruneSettings};
 use crate::error::{Result, VexError};
+use crate::fs_utils::format_bytes;
 use crate::resolver;
 use crate::version_state;
+use chrono::{DateTime, Utc};
 use std::path::Path;
 use std::time::SystemTime;
 
 pub(super) fn collect_plan(dry_run: bool) -> Result<PruneReport> {
     let vex_dir = config::vex_home().ok_or(VexError::HomeDirectoryNotFound)?;
     let cwd = resolver::current_dir();
-    collect_plan_for(&vex_dir, &cwd, dry_run, SystemTime::now())
+    let rules = config::load_settings()?.prune;
+    collect_plan_for(&vex_dir, &cwd, dry_run, SystemTime::now(), &rules)
 }
 
 fn collect_plan_for(
@@ -21,8 +24,12 @@ fn collect_plan_for(
     cwd: &Path,
     dry_run: bool,
     now: SystemTime,
+    rules: &PruneSettings,
 ) -> Result<PruneReport> {
     let retained = version_state::retained_versions(vex_dir, cwd)?;
+    let decisions =
+        toolchains::toolchain_decisions(vex_dir, &retained, rules, DateTime::<Utc>::from(now))?;
+
     let mut retained_toolchains = retained
         .iter()
         .map(|((tool, version), reason)| RetainedToolchain {
@@ -31,22 +38,61 @@ fn collect_plan_for(
             reason: reason.clone(),
         })
         .collect::<Vec<_>>();
+    retained_toolchains.extend(
+        decisions
+            .iter()
+            .filter(|decision| {
+                decision.keep
+                    && !retained.contains_key(&(decision.tool.clone(), decision.version.clone()))
+            })
+            .map(|decision| RetainedToolchain {
+                tool: decision.tool.clone(),
+                version: decision.version.clone(),
+                reason: decision.reason.clone(),
+            }),
+    );
     retained_toolchains.sort_by(|a, b| a.tool.cmp(&b.tool).then(a.version.cmp(&b.version)));
 
+    let kept_bytes: u64 = decisions
+        .iter()
+        .filter(|decision| decision.keep)
+        .map(|decision| decision.bytes)
+        .sum();
+
     let mut removable = Vec::new();
     removable.extend(cache::cache_candidates(vex_dir)?);
     removable.extend(locks::stale_lock_candidates(vex_dir, now)?);
-    removable.extend(toolchains::unused_toolchain_candidates(vex_dir, &retained)?);
+    removable.extend(
+        decisions
+            .into_iter()
+            .filter(|decision| !decision.keep)
+            .map(toolchains::ToolchainDecision::into_candidate),
+    );
 
     let total_bytes = removable.iter().map(|item| item.bytes).sum();
     let total_candidates = removable.len();
 
+    let mut note = "Only ~/.vex state is pruned. Current activations, global defaults, and versions pinned in the current working tree or in any registered project (see `vex projects list`) are retained.".to_string();
+    if let Some(budget) = rules
+        .max_toolchain_bytes
+        .filter(|budget| kept_bytes > *budget)
+    {
+        note.push_str(&format!(
+            " Retained toolchains use {}, over the {} budget; referenced versions are never removed to meet it.",
+            format_bytes(kept_bytes),
+            format_bytes(budget)
+        ));
+    }
+
     Ok(PruneReport {
         dry_run,
         removable,
         retained_toolchains,
         total_candidates,
         total_bytes,
-        note: "Only ~/.vex state is pruned. Current activations, global defaults, and versions pinned in the current working tree are retained. Other repositories are not scanned.".to_string(),
+        note,
     })
 }
+
+#[cfg(test)]
+mod tests;
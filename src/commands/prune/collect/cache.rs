@@ -17,6 +17,7 @@ pub(super) fn cache_candidates(vex_dir: &Path) -> Result<Vec<RemovalCandidate>>
             kind: "cache".to_string(),
             bytes: path_size(&path),
             path: path.display().to_string(),
+            reason: None,
         });
     }
 
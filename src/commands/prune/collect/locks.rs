@@ -29,6 +29,7 @@ pub(super) fn stale_lock_candidates(
             candidates.push(RemovalCandidate {
                 kind: "stale_lock".to_string(),
                 path: path.display().to_string(),
+                reason: None,
                 bytes: metadata.len(),
             });
         }
@@ -0,0 +1,173 @@
+use super::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::Duration;
+use tempfile::TempDir;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn install(vex: &Path, tool: &str, version: &str, bytes: usize) {
+    let dir = vex.join("toolchains").join(tool).join(version);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("payload"), vec![0u8; bytes]).unwrap();
+}
+
+fn record_usage(vex: &Path, entries: &[(&str, &str, SystemTime)]) {
+    let mut usage: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+    for (tool, version, time) in entries {
+        usage.entry(tool.to_string()).or_default().insert(
+            version.to_string(),
+            DateTime::<Utc>::from(*time).to_rfc3339(),
+        );
+    }
+    fs::write(
+        vex.join("toolchain-usage.json"),
+        serde_json::to_string(&usage).unwrap(),
+    )
+    .unwrap();
+}
+
+fn removed_toolchains(report: &PruneReport) -> Vec<String> {
+    let mut removed = report
+        .removable
+        .iter()
+        .filter(|item| item.kind == "toolchain")
+        .map(|item| {
+            let path = Path::new(&item.path);
+            format!(
+                "{}@{}",
+                path.parent()
+                    .unwrap()
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy(),
+                path.file_name().unwrap().to_string_lossy()
+            )
+        })
+        .collect::<Vec<_>>();
+    removed.sort();
+    removed
+}
+
+fn reason_for<'a>(report: &'a PruneReport, tool: &str, version: &str) -> &'a str {
+    report
+        .retained_toolchains
+        .iter()
+        .find(|item| item.tool == tool && item.version == version)
+        .map(|item| item.reason.as_str())
+        .or_else(|| {
+            report
+                .removable
+                .iter()
+                .find(|item| item.path.ends_with(&format!("{}/{}", tool, version)))
+                .and_then(|item| item.reason.as_deref())
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_without_rules_every_unreferenced_toolchain_is_removed() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    install(&vex, "node", "20.1.0", 1);
+    install(&vex, "node", "20.2.0", 1);
+    fs::create_dir_all(&vex).unwrap();
+    fs::write(vex.join("tool-versions"), "node 20.2.0\n").unwrap();
+
+    let report = collect_plan_for(
+        &vex,
+        temp.path(),
+        true,
+        SystemTime::now(),
+        &PruneSettings::default(),
+    )
+    .unwrap();
+
+    assert_eq!(removed_toolchains(&report), vec!["node@20.1.0"]);
+    assert!(reason_for(&report, "node", "20.1.0").contains("not referenced"));
+    assert_eq!(reason_for(&report, "node", "20.2.0"), "global default");
+}
+
+#[test]
+fn test_keep_latest_per_major_keeps_newest_versions_of_each_line() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    for version in ["18.19.0", "20.10.0", "20.11.0", "20.12.0"] {
+        install(&vex, "node", version, 1);
+    }
+    for version in ["3.11.8", "3.12.1", "3.12.2"] {
+        install(&vex, "python", version, 1);
+    }
+    let rules = PruneSettings {
+        keep_latest_per_major: Some(2),
+        ..PruneSettings::default()
+    };
+
+    let report = collect_plan_for(&vex, temp.path(), true, SystemTime::now(), &rules).unwrap();
+
+    assert_eq!(removed_toolchains(&report), vec!["node@20.10.0"]);
+    assert_eq!(
+        reason_for(&report, "node", "20.12.0"),
+        "newest 2 in node 20.x"
+    );
+    assert_eq!(
+        reason_for(&report, "node", "20.10.0"),
+        "not among the newest 2 in node 20.x"
+    );
+    assert_eq!(
+        reason_for(&report, "python", "3.12.1"),
+        "newest 2 in python 3.12.x"
+    );
+}
+
+#[test]
+fn test_max_unused_days_uses_recorded_activation_times() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    install(&vex, "go", "1.21.0", 1);
+    install(&vex, "go", "1.22.0", 1);
+    let now = SystemTime::now() + DAY * 200;
+    record_usage(&vex, &[("go", "1.22.0", now - DAY * 3)]);
+    let rules = PruneSettings {
+        max_unused_days: Some(90),
+        ..PruneSettings::default()
+    };
+
+    let report = collect_plan_for(&vex, temp.path(), true, now, &rules).unwrap();
+
+    assert_eq!(removed_toolchains(&report), vec!["go@1.21.0"]);
+    assert_eq!(
+        reason_for(&report, "go", "1.22.0"),
+        "used 3 day(s) ago (limit 90)"
+    );
+    assert!(reason_for(&report, "go", "1.21.0").starts_with("unused for 20"));
+}
+
+#[test]
+fn test_size_budget_evicts_least_recently_used_unreferenced_versions() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    install(&vex, "java", "17", 4096);
+    install(&vex, "java", "21", 4096);
+    install(&vex, "java", "22", 4096);
+    fs::write(vex.join("tool-versions"), "java 22\n").unwrap();
+    let now = SystemTime::now() + DAY * 10;
+    record_usage(
+        &vex,
+        &[("java", "17", now - DAY * 5), ("java", "21", now - DAY)],
+    );
+    let rules = PruneSettings {
+        max_toolchain_bytes: Some(9000),
+        ..PruneSettings::default()
+    };
+
+    let report = collect_plan_for(&vex, temp.path(), true, now, &rules).unwrap();
+
+    assert_eq!(removed_toolchains(&report), vec!["java@17"]);
+    assert!(reason_for(&report, "java", "17").contains("least recently used"));
+    assert_eq!(
+        reason_for(&report, "java", "21"),
+        "within the toolchain size budget"
+    );
+    assert!(!report.note.contains("over the"));
+}
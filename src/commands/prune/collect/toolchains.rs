@@ -1,20 +1,202 @@
 use crate::commands::prune::RemovalCandidate;
+use crate::config::PruneSettings;
 use crate::error::Result;
-use crate::fs_utils::path_size;
+use crate::fs_utils::{format_bytes, path_size};
+use crate::toolchain_usage;
+use crate::versioning::{release_line, version_sort_key};
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-pub(super) fn unused_toolchain_candidates(
+/// An installed toolchain and the reason it is kept or removed.
+#[derive(Debug)]
+pub(super) struct ToolchainDecision {
+    pub(super) tool: String,
+    pub(super) version: String,
+    pub(super) path: PathBuf,
+    pub(super) bytes: u64,
+    pub(super) keep: bool,
+    pub(super) reason: String,
+    retained: bool,
+    last_used: Option<DateTime<Utc>>,
+}
+
+impl ToolchainDecision {
+    pub(super) fn into_candidate(self) -> RemovalCandidate {
+        RemovalCandidate {
+            kind: "toolchain".to_string(),
+            bytes: self.bytes,
+            path: self.path.display().to_string(),
+            reason: Some(self.reason),
+        }
+    }
+}
+
+/// Decide for every installed toolchain whether prune keeps it.
+///
+/// Versions in `retained` (active, global, project pins) are always kept. Without retention
+/// rules every other version is removed. With rules, a version is kept when any of
+/// `keep_latest_per_major` or `max_unused_days` keeps it, and then the least recently used
+/// unreferenced versions are dropped until the `max_toolchain_bytes` budget is met.
+pub(super) fn toolchain_decisions(
     vex_dir: &Path,
     retained: &HashMap<(String, String), String>,
-) -> Result<Vec<RemovalCandidate>> {
+    rules: &PruneSettings,
+    now: DateTime<Utc>,
+) -> Result<Vec<ToolchainDecision>> {
+    let usage = toolchain_usage::load(vex_dir);
+    let mut decisions = installed_toolchains(vex_dir)?
+        .into_iter()
+        .map(|(tool, version, path)| {
+            let retained_reason = retained.get(&(tool.clone(), version.clone())).cloned();
+            ToolchainDecision {
+                bytes: path_size(&path),
+                last_used: toolchain_usage::last_used(&usage, &tool, &version, &path),
+                keep: retained_reason.is_some(),
+                retained: retained_reason.is_some(),
+                reason: retained_reason.unwrap_or_else(|| {
+                    "not referenced by an activation, global default, or project".to_string()
+                }),
+                tool,
+                version,
+                path,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !rules.has_retention_rules() {
+        return Ok(decisions);
+    }
+
+    let ranks = release_line_ranks(&decisions);
+    for (index, decision) in decisions.iter_mut().enumerate() {
+        if decision.retained {
+            continue;
+        }
+        apply_age_and_line_rules(decision, ranks[index], rules, now);
+    }
+
+    if let Some(budget) = rules.max_toolchain_bytes {
+        apply_size_budget(&mut decisions, budget);
+    }
+
+    Ok(decisions)
+}
+
+fn apply_age_and_line_rules(
+    decision: &mut ToolchainDecision,
+    rank: usize,
+    rules: &PruneSettings,
+    now: DateTime<Utc>,
+) {
+    let line = release_line(&decision.tool, &decision.version);
+    let idle_days = decision
+        .last_used
+        .map(|time| (now - time).num_days().max(0) as u64);
+
+    let mut keep_reasons = Vec::new();
+    let mut remove_reasons = Vec::new();
+
+    if let Some(keep) = rules.keep_latest_per_major {
+        if rank < keep {
+            keep_reasons.push(format!("newest {} in {} {}.x", keep, decision.tool, line));
+        } else {
+            remove_reasons.push(format!(
+                "not among the newest {} in {} {}.x",
+                keep, decision.tool, line
+            ));
+        }
+    }
+
+    if let Some(max_days) = rules.max_unused_days {
+        match idle_days {
+            Some(days) if days <= max_days => {
+                keep_reasons.push(format!("used {} day(s) ago (limit {})", days, max_days))
+            }
+            Some(days) => {
+                remove_reasons.push(format!("unused for {} day(s) (limit {})", days, max_days))
+            }
+            None => remove_reasons.push("never used".to_string()),
+        }
+    }
+
+    if keep_reasons.is_empty() && remove_reasons.is_empty() {
+        // Only a size budget is configured: keep everything until the budget says otherwise.
+        decision.keep = true;
+        decision.reason = "within the toolchain size budget".to_string();
+    } else if keep_reasons.is_empty() {
+        decision.keep = false;
+        decision.reason = remove_reasons.join("; ");
+    } else {
+        decision.keep = true;
+        decision.reason = keep_reasons.join("; ");
+    }
+}
+
+/// Position of each toolchain within its release line, newest first.
+fn release_line_ranks(decisions: &[ToolchainDecision]) -> Vec<usize> {
+    let mut lines: HashMap<(String, String), Vec<usize>> = HashMap::new();
+    for (index, decision) in decisions.iter().enumerate() {
+        lines
+            .entry((
+                decision.tool.clone(),
+                release_line(&decision.tool, &decision.version),
+            ))
+            .or_default()
+            .push(index);
+    }
+
+    let mut ranks = vec![0; decisions.len()];
+    for members in lines.values_mut() {
+        members
+            .sort_by_key(|index| std::cmp::Reverse(version_sort_key(&decisions[*index].version)));
+        for (rank, index) in members.iter().enumerate() {
+            ranks[*index] = rank;
+        }
+    }
+    ranks
+}
+
+fn apply_size_budget(decisions: &mut [ToolchainDecision], budget: u64) {
+    let mut total: u64 = decisions
+        .iter()
+        .filter(|decision| decision.keep)
+        .map(|decision| decision.bytes)
+        .sum();
+    if total <= budget {
+        return;
+    }
+
+    let mut evictable = decisions
+        .iter()
+        .enumerate()
+        .filter(|(_, decision)| decision.keep && !decision.retained)
+        .map(|(index, decision)| (decision.last_used, index))
+        .collect::<Vec<_>>();
+    evictable.sort();
+
+    for (_, index) in evictable {
+        if total <= budget {
+            break;
+        }
+        let decision = &mut decisions[index];
+        decision.keep = false;
+        decision.reason = format!(
+            "least recently used while toolchains exceed the {} budget",
+            format_bytes(budget)
+        );
+        total = total.saturating_sub(decision.bytes);
+    }
+}
+
+fn installed_toolchains(vex_dir: &Path) -> Result<Vec<(String, String, PathBuf)>> {
     let toolchains_dir = vex_dir.join("toolchains");
     if !toolchains_dir.exists() {
         return Ok(Vec::new());
     }
 
-    let mut candidates = Vec::new();
+    let mut installed = Vec::new();
     for tool_entry in fs::read_dir(&toolchains_dir)?.filter_map(|e| e.ok()) {
         if !tool_entry
             .file_type()
@@ -36,18 +218,10 @@ pub(super) fn unused_toolchain_candidates(
             }
 
             let version = version_entry.file_name().to_string_lossy().to_string();
-            if retained.contains_key(&(tool.clone(), version.clone())) {
-                continue;
-            }
-
-            let path = version_entry.path();
-            candidates.push(RemovalCandidate {
-                kind: "toolchain".to_string(),
-                bytes: path_size(&path),
-                path: path.display().to_string(),
-            });
+            installed.push((tool.clone(), version, version_entry.path()));
         }
     }
 
-    Ok(candidates)
+    installed.sort();
+    Ok(installed)
 }
@@ -18,6 +18,9 @@ pub(super) fn render_dry_run(report: &PruneReport) {
                 item.path.dimmed(),
                 format_bytes(item.bytes).dimmed()
             );
+            if let Some(reason) = &item.reason {
+                println!("      {}", reason.dimmed());
+            }
         }
         println!();
         println!(
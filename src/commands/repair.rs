@@ -1,3 +1,5 @@
+mod toolchain;
+
 use crate::cli::repair::{MigrateHomeArgs, RepairCommands};
 use crate::error::{Result, VexError};
 use crate::home_state::{self, AuditKind};
@@ -8,6 +10,7 @@ use std::path::Path;
 pub fn run(args: &crate::cli::repair::RepairArgs) -> Result<()> {
     match &args.command {
         RepairCommands::MigrateHome(migrate) => migrate_home(migrate),
+        RepairCommands::Toolchain(toolchain) => toolchain::run(toolchain),
     }
 }
 
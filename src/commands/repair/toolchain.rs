@@ -0,0 +1,193 @@
+//! `vex repair toolchain`
+//!
+//! Reinstalls toolchains that fail `vex verify`: from the cached archive when it still matches
+//! its recorded checksum, from the network otherwise or when the cached install fails. Rust
+//! targets and components are added back afterwards. The broken install is moved aside until
+//! the new one is complete, so a failed reinstall leaves the toolchain as it was.
+
+use crate::archive_cache::ArchiveCache;
+use crate::cli::repair::RepairToolchainArgs;
+use crate::commands::rust;
+use crate::commands::verify::{self, ArchiveStatus, FilesStatus, ToolchainCheck};
+use crate::error::{Result, VexError};
+use crate::installer;
+use crate::paths::vex_dir;
+use crate::tool_metadata::{self, ToolchainMetadata};
+use crate::tools;
+use owo_colors::OwoColorize;
+use std::fs;
+use std::path::Path;
+
+pub(super) fn run(args: &RepairToolchainArgs) -> Result<()> {
+    let vex = vex_dir()?;
+    let cache = ArchiveCache::new(&vex);
+    let mut repaired = 0usize;
+    let mut failed = Vec::new();
+
+    for (tool, version) in verify::select_toolchains(&vex, args.spec.as_deref())? {
+        let check = verify::check_toolchain(&vex, &cache, tool, version)?;
+        let label = format!("{}@{}", check.tool, check.version);
+        if !check.failed() && !args.force {
+            if check.files.status == FilesStatus::NoManifest {
+                println!(
+                    "{} {} has no install manifest; use {} to reinstall it anyway",
+                    "skip".yellow(),
+                    label,
+                    "--force".cyan()
+                );
+            }
+            continue;
+        }
+
+        println!("{} {} ({})", "repair".cyan(), label, reason(&check));
+        match reinstall(&vex, &cache, &check) {
+            Ok(()) => {
+                println!("{} {}", "repaired".green(), label);
+                repaired += 1;
+            }
+            Err(err) => {
+                eprintln!("{} {}: {}", "failed".red(), label, err);
+                failed.push(label);
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(VexError::Config(format!(
+            "Could not repair {}. The previous install was kept in place.",
+            failed.join(", ")
+        )));
+    }
+    if repaired == 0 {
+        println!("{}", "All checked toolchains passed verification.".green());
+    }
+    Ok(())
+}
+
+fn reason(check: &ToolchainCheck) -> String {
+    let mut reasons = Vec::new();
+    if check.files.status == FilesStatus::Changed {
+        let diff = &check.files.diff;
+        reasons.push(format!(
+            "{} modified, {} missing, {} extra files",
+            diff.modified.len(),
+            diff.missing.len(),
+            diff.extra.len()
+        ));
+    }
+    if check.archive.status == ArchiveStatus::Mismatch {
+        reasons.push("cached archive checksum mismatch".to_string());
+    }
+    if reasons.is_empty() {
+        reasons.push("forced".to_string());
+    }
+    reasons.join("; ")
+}
+
+fn reinstall(vex: &Path, cache: &ArchiveCache, check: &ToolchainCheck) -> Result<()> {
+    let tool = tools::get_tool(&check.tool)?;
+    let install_dir = vex
+        .join("toolchains")
+        .join(&check.tool)
+        .join(&check.version);
+    let previous = tool_metadata::read_metadata(&install_dir).ok().flatten();
+
+    let backup = vex
+        .join("cache")
+        .join("repair")
+        .join(format!("{}-{}", check.tool, check.version));
+    if backup.exists() {
+        fs::remove_dir_all(&backup)?;
+    }
+    if let Some(parent) = backup.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // A corrupted archive would only reproduce the corruption, so fetch a fresh one instead.
+    let from_cache = match check.archive.status {
+        ArchiveStatus::Verified | ArchiveStatus::NoChecksum => check.archive.path.is_some(),
+        ArchiveStatus::Mismatch => {
+            cache.remove_version(&check.tool, &check.version)?;
+            false
+        }
+        ArchiveStatus::NotCached => false,
+    };
+
+    let extensions = previous
+        .as_ref()
+        .map(|metadata| {
+            metadata
+                .extensions
+                .iter()
+                .map(|extension| (extension.kind.clone(), extension.name.clone()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    fs::rename(&install_dir, &backup)?;
+    let result = install_preferring_cache(&install_dir, from_cache, |offline| {
+        installer::install_with_mode(tool.as_ref(), &check.version, offline)
+    })
+    .and_then(|()| restore_provenance(&install_dir, &backup, previous))
+    .and_then(|()| rust::restore_extensions(&check.version, &install_dir, &extensions));
+    if let Err(err) = result {
+        if install_dir.exists() {
+            let _ = fs::remove_dir_all(&install_dir);
+        }
+        fs::rename(&backup, &install_dir)?;
+        return Err(err);
+    }
+
+    fs::remove_dir_all(&backup)?;
+    Ok(())
+}
+
+/// Install from the archive cache first when asked to, and from the network when that fails,
+/// for example because the cached install has no checksum to verify against.
+fn install_preferring_cache(
+    install_dir: &Path,
+    from_cache: bool,
+    install: impl Fn(bool) -> Result<()>,
+) -> Result<()> {
+    if from_cache {
+        let Err(err) = install(true) else {
+            return Ok(());
+        };
+        println!(
+            "  {} reinstalling from the cached archive failed ({}); downloading it again",
+            "note".yellow(),
+            err
+        );
+        if install_dir.exists() {
+            fs::remove_dir_all(install_dir)?;
+        }
+    }
+    install(false)
+}
+
+/// Offline installs record no provenance, so carry it over from the replaced install.
+///
+/// Rust targets and components are not part of the archive; they are dropped from the metadata
+/// here and added back by [`rust::restore_extensions`].
+fn restore_provenance(
+    install_dir: &Path,
+    backup: &Path,
+    previous: Option<ToolchainMetadata>,
+) -> Result<()> {
+    let Some(mut previous) = previous else {
+        return Ok(());
+    };
+    previous.extensions.clear();
+
+    if tool_metadata::read_metadata(install_dir)?.is_none() {
+        tool_metadata::write_metadata(install_dir, &previous)?;
+    }
+    let checksum = install_dir.join(".vex-checksum");
+    if !checksum.exists() && backup.join(".vex-checksum").exists() {
+        fs::copy(backup.join(".vex-checksum"), checksum)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,39 @@
+use super::*;
+use std::cell::RefCell;
+use tempfile::TempDir;
+
+#[test]
+fn test_failed_cached_install_retries_from_the_network() {
+    let temp = TempDir::new().unwrap();
+    let install_dir = temp.path().join("20.11.0");
+    let attempts = RefCell::new(Vec::new());
+
+    install_preferring_cache(&install_dir, true, |offline| {
+        attempts.borrow_mut().push(offline);
+        fs::create_dir_all(install_dir.join("bin"))?;
+        if offline {
+            return Err(VexError::Config("No checksum recorded".to_string()));
+        }
+        fs::write(install_dir.join("bin/node"), "")?;
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(*attempts.borrow(), vec![true, false]);
+    assert!(install_dir.join("bin/node").is_file());
+}
+
+#[test]
+fn test_uncached_install_goes_straight_to_the_network() {
+    let temp = TempDir::new().unwrap();
+    let attempts = RefCell::new(Vec::new());
+
+    let err = install_preferring_cache(temp.path(), false, |offline| {
+        attempts.borrow_mut().push(offline);
+        Err(VexError::Config("offline".to_string()))
+    })
+    .unwrap_err();
+
+    assert_eq!(*attempts.borrow(), vec![false]);
+    assert!(err.to_string().contains("offline"));
+}
@@ -3,11 +3,13 @@ use crate::cli::rust::{RustCommands, RustExtensionArgs, RustExtensionCommand};
 use crate::config;
 use crate::downloader::download_with_retry_in_current_context;
 use crate::error::{Result, VexError};
+use crate::output::{self, Event};
 use crate::requested_versions;
 use crate::resolver;
 use crate::tool_metadata::{
     self, ExtensionMetadata, OwnershipMetadata, ProvenanceMetadata, ToolchainMetadata,
 };
+use crate::toolchain_manifest;
 use crate::tools;
 use crate::tools::rust::dist::target_triple;
 use crate::tools::rust::install::{
@@ -17,6 +19,7 @@ use crate::tools::rust::install::{
 use crate::tools::rust::manifest::{self, ChannelManifest};
 use crate::version_state;
 use chrono::Utc;
+use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fs;
 use std::io::Read;
@@ -62,6 +65,47 @@ fn run_extension(kind: &str, args: &RustExtensionArgs) -> Result<()> {
     }
 }
 
+/// Add `(kind, name)` extensions back to a reinstalled Rust toolchain, as `vex rust ... add` does.
+pub(crate) fn restore_extensions(
+    version: &str,
+    install_dir: &Path,
+    extensions: &[(String, String)],
+) -> Result<()> {
+    if extensions.is_empty() {
+        return Ok(());
+    }
+    let arch = tools::Arch::detect()?;
+    let manifest = manifest::fetch_channel_manifest(version)?;
+    for (kind, name) in extensions {
+        install_extension(
+            kind,
+            name,
+            arch,
+            target_triple(arch),
+            version,
+            &manifest,
+            install_dir,
+        )?;
+    }
+    Ok(())
+}
+
+/// `data` of `vex rust target list` and `vex rust component list` in machine-readable output.
+#[derive(Serialize)]
+struct ExtensionReport<'a> {
+    kind: &'a str,
+    toolchain: String,
+    installed: Vec<InstalledExtension>,
+    available: &'a [String],
+}
+
+#[derive(Serialize)]
+struct InstalledExtension {
+    name: String,
+    /// Added by `vex rust ... add` rather than shipped with the toolchain.
+    managed: bool,
+}
+
 fn list_extensions(
     kind: &str,
     host_target: &str,
@@ -93,6 +137,19 @@ fn list_extensions(
         )
     };
 
+    output::report(&ExtensionReport {
+        kind,
+        toolchain: install_dir.display().to_string(),
+        installed: installed
+            .iter()
+            .map(|name| InstalledExtension {
+                name: name.clone(),
+                managed: managed.contains(name),
+            })
+            .collect(),
+        available: &available,
+    })?;
+
     println!("Active Rust toolchain: {}", install_dir.display());
     println!();
     println!("Installed {}s:", kind);
@@ -109,7 +166,7 @@ fn list_extensions(
     }
     println!();
     println!("Available official {}s:", kind);
-    for item in available {
+    for item in &available {
         println!("  {}", item);
     }
     Ok(())
@@ -126,6 +183,11 @@ fn install_extension(
 ) -> Result<()> {
     let component_dir = install_dir.join(component_dir_name(kind, name));
     if component_dir.exists() {
+        output::emit(Event::ExtensionSkipped {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            reason: "already_present".to_string(),
+        });
         println!("rust {} {} is already present", kind, name);
         return Ok(());
     }
@@ -175,6 +237,11 @@ fn install_extension(
         &artifact.checksum,
         owned_paths,
     )?;
+    toolchain_manifest::write_manifest(install_dir)?;
+    output::emit(Event::ExtensionInstalled {
+        kind: kind.to_string(),
+        name: name.to_string(),
+    });
     println!("Installed rust {} {}", kind, name);
     Ok(())
 }
@@ -186,6 +253,11 @@ fn remove_extension(kind: &str, name: &str, install_dir: &Path) -> Result<()> {
         .iter()
         .position(|extension| extension.kind == kind && extension.name == name)
     else {
+        output::emit(Event::ExtensionSkipped {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            reason: "not_managed".to_string(),
+        });
         println!("rust {} {} is not managed by vex", kind, name);
         return Ok(());
     };
@@ -196,6 +268,11 @@ fn remove_extension(kind: &str, name: &str, install_dir: &Path) -> Result<()> {
     }
 
     tool_metadata::write_metadata(install_dir, &metadata)?;
+    toolchain_manifest::write_manifest(install_dir)?;
+    output::emit(Event::ExtensionRemoved {
+        kind: kind.to_string(),
+        name: name.to_string(),
+    });
     println!("Removed rust {} {}", kind, name);
     Ok(())
 }
@@ -384,6 +461,7 @@ fn load_or_bootstrap_metadata(install_dir: &Path) -> Result<ToolchainMetadata> {
             mirror_url: None,
             checksum: None,
             installed_at: Utc::now().to_rfc3339(),
+            imported_from: None,
         },
         ownership: OwnershipMetadata {
             vex_owned: vec![install_dir.display().to_string()],
@@ -0,0 +1,168 @@
+//! `vex sbom`
+//!
+//! Describes installed toolchains as a CycloneDX or SPDX document, built from the provenance
+//! recorded in each `.vex-metadata.json` and the Python packages pinned in `requirements.lock`.
+
+mod cyclonedx;
+mod spdx;
+
+use crate::error::{Result, VexError};
+use crate::output::print_json;
+use crate::paths::vex_dir;
+use crate::requested_versions;
+use crate::resolver;
+use crate::tool_metadata::{self, ToolchainMetadata};
+use chrono::{SecondsFormat, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SbomFormat {
+    /// CycloneDX 1.5 JSON
+    #[default]
+    Cyclonedx,
+    /// SPDX 2.3 JSON
+    Spdx,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SbomScope {
+    /// Versions active in the current directory, including global defaults.
+    Current,
+    /// Versions pinned by the project's version files only.
+    Project,
+    /// Every installed toolchain.
+    All,
+}
+
+/// What a document describes.
+pub(super) struct Inventory {
+    pub(super) name: String,
+    pub(super) toolchains: Vec<ToolchainMetadata>,
+    pub(super) python_packages: Vec<PythonPackage>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) struct PythonPackage {
+    pub(super) name: String,
+    pub(super) version: String,
+}
+
+pub fn run(scope: SbomScope, format: SbomFormat) -> Result<()> {
+    let cwd = resolver::current_dir();
+    let inventory = collect(&cwd, &vex_dir()?, scope)?;
+    let created = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let document = match format {
+        SbomFormat::Cyclonedx => cyclonedx::document(&inventory, &created),
+        SbomFormat::Spdx => spdx::document(&inventory, &created),
+    };
+    print_json(&document)
+}
+
+fn collect(cwd: &Path, vex: &Path, scope: SbomScope) -> Result<Inventory> {
+    let versions = match scope {
+        SbomScope::All => tool_metadata::installed_toolchains(vex)?,
+        SbomScope::Current => installed_versions(vex, resolver::resolve_versions(cwd))?,
+        SbomScope::Project => {
+            let versions = resolver::resolve_project_versions(cwd);
+            if versions.is_empty() {
+                return Err(VexError::Config(
+                    "No version files found (.tool-versions, .node-version, etc.)".to_string(),
+                ));
+            }
+            installed_versions(vex, versions)?
+        }
+    };
+
+    let toolchains = versions
+        .iter()
+        .map(|(tool, version)| read_toolchain(vex, tool, version))
+        .collect::<Result<Vec<_>>>()?;
+    let (name, python_packages) = match scope {
+        SbomScope::All => ("vex-toolchains".to_string(), Vec::new()),
+        _ => {
+            let root = resolver::find_project_root(cwd).unwrap_or_else(|| cwd.to_path_buf());
+            let packages = fs::read_to_string(cwd.join("requirements.lock"))
+                .map(|content| parse_requirements(&content))
+                .unwrap_or_default();
+            let name = root
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| "vex-project".to_string());
+            (name, packages)
+        }
+    };
+
+    Ok(Inventory {
+        name,
+        toolchains,
+        python_packages,
+    })
+}
+
+/// Exact installed versions for requested ones; missing versions are reported and left out.
+fn installed_versions(
+    vex: &Path,
+    requested: HashMap<String, String>,
+) -> Result<Vec<(String, String)>> {
+    let mut requested = requested.into_iter().collect::<Vec<_>>();
+    requested.sort();
+
+    let mut installed = Vec::new();
+    for (tool, version) in requested {
+        match requested_versions::resolve_installed_version(vex, &tool, &version)? {
+            Some(exact) => installed.push((tool, exact)),
+            None => eprintln!("vex: {}@{} is not installed; leaving it out", tool, version),
+        }
+    }
+    Ok(installed)
+}
+
+/// Metadata for an installed toolchain, falling back to `.vex-checksum` for older installs.
+fn read_toolchain(vex: &Path, tool: &str, version: &str) -> Result<ToolchainMetadata> {
+    let install_dir = vex.join("toolchains").join(tool).join(version);
+    let mut metadata = tool_metadata::read_metadata(&install_dir)?.unwrap_or_default();
+    metadata.tool = tool.to_string();
+    metadata.version = version.to_string();
+    if metadata.provenance.checksum.is_none() {
+        metadata.provenance.checksum = fs::read_to_string(install_dir.join(".vex-checksum"))
+            .ok()
+            .map(|checksum| checksum.trim().to_string());
+    }
+    Ok(metadata)
+}
+
+/// `name==version` pins from `pip freeze` output; other requirement forms have no exact version.
+pub(super) fn parse_requirements(content: &str) -> Vec<PythonPackage> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter_map(|line| line.split_once("=="))
+        .map(|(name, version)| PythonPackage {
+            name: name.trim().to_string(),
+            version: version
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        })
+        .filter(|package| !package.name.is_empty() && !package.version.is_empty())
+        .collect()
+}
+
+pub(super) fn toolchain_purl(tool: &str, version: &str) -> String {
+    format!("pkg:generic/{}@{}", tool, version)
+}
+
+pub(super) fn python_purl(package: &PythonPackage) -> String {
+    format!(
+        "pkg:pypi/{}@{}",
+        package.name.to_lowercase().replace('_', "-"),
+        package.version
+    )
+}
+
+#[cfg(test)]
+mod tests;
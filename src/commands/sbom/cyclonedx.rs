@@ -0,0 +1,108 @@
+use super::{python_purl, toolchain_purl, Inventory};
+use crate::tool_metadata::{ExtensionMetadata, ToolchainMetadata};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+pub(super) fn document(inventory: &Inventory, created: &str) -> Value {
+    let mut components = inventory
+        .toolchains
+        .iter()
+        .map(toolchain_component)
+        .collect::<Vec<_>>();
+    components.extend(inventory.python_packages.iter().map(|package| {
+        let purl = python_purl(package);
+        json!({
+            "type": "library",
+            "bom-ref": purl,
+            "name": package.name,
+            "version": package.version,
+            "purl": purl,
+        })
+    }));
+
+    json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "serialNumber": format!("urn:uuid:{}", Uuid::new_v4()),
+        "version": 1,
+        "metadata": {
+            "timestamp": created,
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": "vex",
+                    "version": env!("CARGO_PKG_VERSION"),
+                }],
+            },
+            "component": {
+                "type": "application",
+                "bom-ref": inventory.name,
+                "name": inventory.name,
+            },
+        },
+        "components": components,
+    })
+}
+
+fn toolchain_component(metadata: &ToolchainMetadata) -> Value {
+    let purl = toolchain_purl(&metadata.tool, &metadata.version);
+    let provenance = &metadata.provenance;
+    let mut component = json!({
+        "type": "application",
+        "bom-ref": purl,
+        "name": metadata.tool,
+        "version": metadata.version,
+        "purl": purl,
+    });
+
+    if let Some(checksum) = &provenance.checksum {
+        component["hashes"] = json!([{ "alg": "SHA-256", "content": checksum }]);
+    }
+    let references = [&provenance.source_url, &provenance.mirror_url]
+        .into_iter()
+        .flatten()
+        .map(|url| json!({ "type": "distribution", "url": url }))
+        .collect::<Vec<_>>();
+    if !references.is_empty() {
+        component["externalReferences"] = json!(references);
+    }
+
+    let mut properties = Vec::new();
+    if !provenance.installed_at.is_empty() {
+        properties.push(json!({ "name": "vex:installed_at", "value": provenance.installed_at }));
+    }
+    if let Some(imported_from) = &provenance.imported_from {
+        properties.push(json!({ "name": "vex:imported_from", "value": imported_from }));
+    }
+    if !properties.is_empty() {
+        component["properties"] = json!(properties);
+    }
+
+    if !metadata.extensions.is_empty() {
+        component["components"] = json!(metadata
+            .extensions
+            .iter()
+            .map(|extension| extension_component(metadata, extension))
+            .collect::<Vec<_>>());
+    }
+    component
+}
+
+fn extension_component(toolchain: &ToolchainMetadata, extension: &ExtensionMetadata) -> Value {
+    let purl = toolchain_purl(&extension.name, &toolchain.version);
+    let mut component = json!({
+        "type": "library",
+        "bom-ref": format!("{}#{}", toolchain_purl(&toolchain.tool, &toolchain.version), extension.name),
+        "name": extension.name,
+        "version": toolchain.version,
+        "purl": purl,
+        "properties": [{ "name": "vex:extension_kind", "value": extension.kind }],
+    });
+    if let Some(checksum) = &extension.checksum {
+        component["hashes"] = json!([{ "alg": "SHA-256", "content": checksum }]);
+    }
+    if let Some(url) = &extension.source_url {
+        component["externalReferences"] = json!([{ "type": "distribution", "url": url }]);
+    }
+    component
+}
@@ -0,0 +1,117 @@
+use super::{python_purl, toolchain_purl, Inventory};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+pub(super) fn document(inventory: &Inventory, created: &str) -> Value {
+    let mut packages = Vec::new();
+    let mut relationships = Vec::new();
+
+    for metadata in &inventory.toolchains {
+        let id = spdx_id(&[&metadata.tool, &metadata.version]);
+        let provenance = &metadata.provenance;
+        packages.push(package(
+            &id,
+            &metadata.tool,
+            &metadata.version,
+            provenance.source_url.as_deref(),
+            provenance.checksum.as_deref(),
+            &toolchain_purl(&metadata.tool, &metadata.version),
+        ));
+        relationships.push(relationship("SPDXRef-DOCUMENT", "DESCRIBES", &id));
+
+        for extension in &metadata.extensions {
+            let extension_id = spdx_id(&[&metadata.tool, &metadata.version, &extension.name]);
+            let mut extension_package = package(
+                &extension_id,
+                &extension.name,
+                &metadata.version,
+                extension.source_url.as_deref(),
+                extension.checksum.as_deref(),
+                &toolchain_purl(&extension.name, &metadata.version),
+            );
+            extension_package["comment"] = json!(format!("{} {}", metadata.tool, extension.kind));
+            packages.push(extension_package);
+            relationships.push(relationship(&id, "CONTAINS", &extension_id));
+        }
+    }
+
+    for python_package in &inventory.python_packages {
+        let id = spdx_id(&["pypi", &python_package.name, &python_package.version]);
+        packages.push(package(
+            &id,
+            &python_package.name,
+            &python_package.version,
+            None,
+            None,
+            &python_purl(python_package),
+        ));
+        relationships.push(relationship("SPDXRef-DOCUMENT", "DESCRIBES", &id));
+    }
+
+    json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": inventory.name,
+        "documentNamespace": format!("https://spdx.org/spdxdocs/{}-{}", inventory.name, Uuid::new_v4()),
+        "creationInfo": {
+            "created": created,
+            "creators": [format!("Tool: vex-{}", env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": packages,
+        "relationships": relationships,
+    })
+}
+
+fn package(
+    id: &str,
+    name: &str,
+    version: &str,
+    download: Option<&str>,
+    checksum: Option<&str>,
+    purl: &str,
+) -> Value {
+    let mut package = json!({
+        "SPDXID": id,
+        "name": name,
+        "versionInfo": version,
+        "downloadLocation": download.unwrap_or("NOASSERTION"),
+        "filesAnalyzed": false,
+        "licenseConcluded": "NOASSERTION",
+        "licenseDeclared": "NOASSERTION",
+        "copyrightText": "NOASSERTION",
+        "externalRefs": [{
+            "referenceCategory": "PACKAGE-MANAGER",
+            "referenceType": "purl",
+            "referenceLocator": purl,
+        }],
+    });
+    if let Some(checksum) = checksum {
+        package["checksums"] = json!([{ "algorithm": "SHA256", "checksumValue": checksum }]);
+    }
+    package
+}
+
+fn relationship(from: &str, kind: &str, to: &str) -> Value {
+    json!({
+        "spdxElementId": from,
+        "relationshipType": kind,
+        "relatedSpdxElement": to,
+    })
+}
+
+/// `SPDXRef-` ids allow only letters, digits, `.` and `-`.
+pub(super) fn spdx_id(parts: &[&str]) -> String {
+    let id = parts
+        .join("-")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+    format!("SPDXRef-{}", id)
+}
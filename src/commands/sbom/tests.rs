@@ -0,0 +1,103 @@
+use super::*;
+use crate::tool_metadata::{ExtensionMetadata, ProvenanceMetadata};
+
+fn inventory() -> Inventory {
+    Inventory {
+        name: "demo".to_string(),
+        toolchains: vec![ToolchainMetadata {
+            tool: "rust".to_string(),
+            version: "1.93.0".to_string(),
+            provenance: ProvenanceMetadata {
+                source_url: Some("https://static.rust-lang.org/rust.tar.gz".to_string()),
+                mirror_url: None,
+                checksum: Some("abc123".to_string()),
+                installed_at: "2026-01-01T00:00:00Z".to_string(),
+                imported_from: None,
+            },
+            extensions: vec![ExtensionMetadata {
+                kind: "component".to_string(),
+                name: "rust-src".to_string(),
+                source_url: None,
+                checksum: Some("def456".to_string()),
+                installed_at: "2026-01-02T00:00:00Z".to_string(),
+                owned_paths: Vec::new(),
+            }],
+            ..Default::default()
+        }],
+        python_packages: vec![PythonPackage {
+            name: "Flask_Cors".to_string(),
+            version: "4.0.1".to_string(),
+        }],
+    }
+}
+
+#[test]
+fn test_parse_requirements_keeps_exact_pins_only() {
+    let packages = parse_requirements(
+        "# generated\nrequests==2.32.3\n-e git+https://example.com/pkg.git#egg=pkg\n\
+         colorama==0.4.6 ; sys_platform == \"win32\"\nlocalpkg @ file:///tmp/localpkg\n",
+    );
+    assert_eq!(
+        packages,
+        vec![
+            PythonPackage {
+                name: "requests".to_string(),
+                version: "2.32.3".to_string(),
+            },
+            PythonPackage {
+                name: "colorama".to_string(),
+                version: "0.4.6".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_cyclonedx_lists_toolchains_extensions_and_python_packages() {
+    let document = cyclonedx::document(&inventory(), "2026-10-18T00:00:00Z");
+    assert_eq!(document["bomFormat"], "CycloneDX");
+    assert_eq!(document["specVersion"], "1.5");
+    assert_eq!(document["metadata"]["component"]["name"], "demo");
+
+    let rust = &document["components"][0];
+    assert_eq!(rust["purl"], "pkg:generic/rust@1.93.0");
+    assert_eq!(rust["hashes"][0]["content"], "abc123");
+    assert_eq!(
+        rust["externalReferences"][0]["url"],
+        "https://static.rust-lang.org/rust.tar.gz"
+    );
+    assert_eq!(rust["components"][0]["name"], "rust-src");
+    assert_eq!(rust["components"][0]["hashes"][0]["content"], "def456");
+
+    assert_eq!(
+        document["components"][1]["purl"],
+        "pkg:pypi/flask-cors@4.0.1"
+    );
+}
+
+#[test]
+fn test_spdx_relates_packages_to_the_document() {
+    let document = spdx::document(&inventory(), "2026-10-18T00:00:00Z");
+    assert_eq!(document["spdxVersion"], "SPDX-2.3");
+
+    let packages = document["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 3);
+    assert_eq!(packages[0]["SPDXID"], "SPDXRef-rust-1.93.0");
+    assert_eq!(packages[0]["checksums"][0]["checksumValue"], "abc123");
+    assert_eq!(packages[2]["downloadLocation"], "NOASSERTION");
+
+    let relationships = document["relationships"].as_array().unwrap();
+    assert!(relationships.iter().any(|relationship| {
+        relationship["spdxElementId"] == "SPDXRef-rust-1.93.0"
+            && relationship["relationshipType"] == "CONTAINS"
+            && relationship["relatedSpdxElement"] == "SPDXRef-rust-1.93.0-rust-src"
+    }));
+}
+
+#[test]
+fn test_spdx_ids_replace_disallowed_characters() {
+    assert_eq!(
+        spdx::spdx_id(&["pypi", "zope_interface", "6.0+local"]),
+        "SPDXRef-pypi-zope-interface-6.0-local"
+    );
+}
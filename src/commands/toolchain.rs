@@ -1,6 +1,8 @@
 use crate::config;
 use crate::error::Result;
 use crate::paths::vex_dir;
+use crate::policy::Policy;
+use crate::registry;
 use crate::requested_versions;
 use crate::resolver;
 use crate::switcher;
@@ -13,9 +15,13 @@ mod source;
 
 pub use frozen::{install_from_version_files_with_frozen, sync_from_current_context_with_frozen};
 pub use lockfile_cmd::generate_lockfile;
-pub use source::{install_from_source, install_specs, sync_from_source};
+pub use source::{install_from_source, install_specs, sync_from_source, sync_workspace};
 
 pub fn auto_switch() -> Result<()> {
+    // The shell hook runs `vex use --auto` on every directory change, which is how most
+    // projects end up in the registry.
+    registry::record_current_project();
+
     if !config::auto_switch()? {
         return Ok(());
     }
@@ -36,6 +42,7 @@ pub fn auto_switch() -> Result<()> {
     }
 
     let vex = vex_dir()?;
+    let policy = Policy::load(&cwd)?;
 
     for (tool_name, version) in &versions {
         let tool = match tools::get_tool(tool_name) {
@@ -53,6 +60,18 @@ pub fn auto_switch() -> Result<()> {
             continue;
         };
 
+        // The shell hook must keep the prompt usable, so blocked versions are skipped
+        // with a message instead of failing the whole auto-switch.
+        if let Err(error) = policy.check(tool_name, &resolved) {
+            eprintln!(
+                "vex: {}@{} was not activated: {}",
+                tool_name,
+                resolved,
+                error.to_string().lines().next().unwrap_or_default()
+            );
+            continue;
+        }
+
         let current_link = vex.join("current").join(tool_name);
         if current_link.exists() {
             if let Ok(target) = fs::read_link(&current_link) {
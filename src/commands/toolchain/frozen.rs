@@ -3,11 +3,13 @@ mod lockfile_support;
 mod sync;
 
 use crate::error::Result;
+use crate::registry;
 
 pub(super) const NO_VERSION_FILES_MESSAGE: &str =
     "No version files found (.tool-versions, .node-version, etc.)";
 
 pub fn install_from_version_files_with_frozen(frozen: bool, offline: bool) -> Result<()> {
+    registry::record_current_project();
     if frozen {
         install::from_lockfile(offline)
     } else {
@@ -16,6 +18,7 @@ pub fn install_from_version_files_with_frozen(frozen: bool, offline: bool) -> Re
 }
 
 pub fn sync_from_current_context_with_frozen(frozen: bool, offline: bool) -> Result<()> {
+    registry::record_current_project();
     if frozen {
         sync::from_lockfile(offline)
     } else {
@@ -1,11 +1,11 @@
+use super::super::source::{install_pending, print_install_summary};
 use super::lockfile_support::{load_lockfile_for_frozen, validate_lockfile_matches_versions};
 use super::NO_VERSION_FILES_MESSAGE;
-use crate::error::Result;
-use crate::installer;
+use crate::error::{Result, VexError};
 use crate::paths::vex_dir;
+use crate::policy::Policy;
 use crate::requested_versions;
 use crate::resolver;
-use crate::switcher;
 use crate::tools;
 
 pub(super) fn from_version_files(offline: bool) -> Result<()> {
@@ -35,8 +35,15 @@ pub(super) fn from_lockfile(offline: bool) -> Result<()> {
     install_requested_versions(&requested, offline)
 }
 
+/// Skip what is already installed, then install the rest concurrently and switch to each.
+///
+/// Resolution and policy errors stop before anything is installed; install failures are listed
+/// in the summary without stopping the other tools.
 fn install_requested_versions(requested: &[(String, String)], offline: bool) -> Result<()> {
     let vex = vex_dir()?;
+    let policy = Policy::load(&resolver::current_dir())?;
+    let mut results = Vec::new();
+    let mut pending = Vec::new();
 
     for (tool_name, version) in requested {
         let tool = match tools::get_tool(tool_name) {
@@ -50,20 +57,41 @@ fn install_requested_versions(requested: &[(String, String)], offline: bool) ->
         if let Some(installed) =
             requested_versions::resolve_installed_version(&vex, tool_name, version)?
         {
+            policy.check(tool_name, &installed)?;
             println!("{}@{} already installed, skipping.", tool_name, installed);
+            results.push((tool_name.clone(), installed, Ok(false)));
             continue;
         }
 
         let resolved = requested_versions::resolve_for_install(tool.as_ref(), version)?;
+        policy.check(tool_name, &resolved)?;
         let version_dir = vex.join("toolchains").join(tool_name).join(&resolved);
         if version_dir.exists() {
             println!("{}@{} already installed, skipping.", tool_name, resolved);
+            results.push((tool_name.clone(), resolved, Ok(false)));
             continue;
         }
 
-        installer::install_with_mode(tool.as_ref(), &resolved, offline)?;
-        switcher::switch_version(tool.as_ref(), &resolved)?;
+        pending.push((results.len(), tool, resolved.clone()));
+        results.push((tool_name.clone(), resolved, Ok(true)));
     }
 
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    install_pending(&pending, &mut results, offline, true);
+    print_install_summary(&results);
+
+    let failed = results
+        .iter()
+        .filter(|(_, _, result)| result.is_err())
+        .count();
+    if failed > 0 {
+        return Err(VexError::Config(format!(
+            "{} installation(s) failed",
+            failed
+        )));
+    }
     Ok(())
 }
@@ -1,5 +1,6 @@
 use crate::error::{Result, VexError};
 use crate::lockfile;
+use crate::output::{self, Event};
 use crate::paths::vex_dir;
 use crate::requested_versions;
 use crate::resolver;
@@ -54,6 +55,14 @@ pub fn generate_lockfile() -> Result<()> {
     }
 
     let path = lockfile.save_to_dir(&cwd)?;
+    output::emit(Event::LockfileWritten {
+        path: path.display().to_string(),
+        tools: lockfile
+            .tools
+            .iter()
+            .map(|(tool, entry)| (tool.clone(), entry.version.clone()))
+            .collect(),
+    });
     println!("{} Lockfile generated: {}", "✓".green(), path.display());
     println!();
     println!("{}", "Locked versions:".cyan().bold());
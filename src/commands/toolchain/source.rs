@@ -3,7 +3,9 @@ mod summary;
 
 use crate::error::Result;
 
-pub use install::{install_from_source, install_specs, sync_from_source};
+pub(super) use install::install_pending;
+pub use install::{install_from_source, install_specs, sync_from_source, sync_workspace};
+pub(super) use summary::print_install_summary;
 
 pub(super) fn sync_versions(versions: &[(String, String)], offline: bool) -> Result<()> {
     install::sync_versions(versions, offline)
@@ -2,29 +2,39 @@ use super::summary::{print_install_summary, InstallResult};
 use crate::error::{Result, VexError};
 use crate::installer;
 use crate::paths::vex_dir;
+use crate::policy::Policy;
+use crate::project;
+use crate::registry;
 use crate::requested_versions;
 use crate::resolver;
 use crate::spec::parse_spec;
 use crate::switcher;
 use crate::team_config;
 use crate::tools::{self, Tool};
+use std::collections::BTreeSet;
 use std::fs;
 
 pub fn install_from_source(source: &str, offline: bool) -> Result<()> {
-    let loaded = team_config::load_versions_from_source(source, &resolver::current_dir(), offline)?;
+    let cwd = resolver::current_dir();
+    let loaded = team_config::load_versions_from_source(source, &cwd, offline)?;
     if loaded.versions.is_empty() {
         println!("No versions found in {}", loaded.description);
         return Ok(());
     }
 
-    let results = install_version_pairs(&loaded.versions, offline, false)?;
+    let mut policy = Policy::load(&cwd)?;
+    policy.push_layer(loaded.description.clone(), loaded.policy.clone())?;
+    let results = install_version_pairs(&loaded.versions, offline, false, &policy)?;
     print_install_summary(&results);
-    Ok(())
+    ensure_no_policy_blocks(results)
 }
 
 pub fn install_specs(specs: &[String], no_switch: bool, force: bool, offline: bool) -> Result<()> {
+    registry::record_current_project();
     let vex = vex_dir()?;
+    let policy = Policy::load(&resolver::current_dir())?;
     let mut results = Vec::new();
+    let mut pending = Vec::new();
 
     for spec in specs {
         let (tool_name, version) = parse_spec(spec)?;
@@ -47,7 +57,8 @@ pub fn install_specs(specs: &[String], no_switch: bool, force: bool, offline: bo
             if let Some(installed) =
                 requested_versions::resolve_installed_version(&vex, &tool_name, &version)?
             {
-                results.push((tool_name.clone(), installed, Ok(false)));
+                let result = policy.check(&tool_name, &installed).map(|_| false);
+                results.push((tool_name.clone(), installed, result));
                 continue;
             }
         }
@@ -60,8 +71,13 @@ pub fn install_specs(specs: &[String], no_switch: bool, force: bool, offline: bo
             }
         };
 
+        if let Err(error) = policy.check(&tool_name, &resolved) {
+            results.push((tool_name.clone(), resolved, Err(error)));
+            continue;
+        }
+
         let install_dir = vex.join("toolchains").join(&tool_name).join(&resolved);
-        if install_dir.exists() && !force {
+        if (install_dir.exists() && !force) || is_pending(&pending, &tool_name, &resolved) {
             results.push((tool_name.clone(), resolved.clone(), Ok(false)));
             continue;
         }
@@ -69,10 +85,11 @@ pub fn install_specs(specs: &[String], no_switch: bool, force: bool, offline: bo
             fs::remove_dir_all(&install_dir)?;
         }
 
-        let result = install_single(tool.as_ref(), &resolved, offline, !no_switch);
-        results.push((tool_name.clone(), resolved, result));
+        pending.push((results.len(), tool, resolved.clone()));
+        results.push((tool_name.clone(), resolved, Ok(true)));
     }
 
+    install_pending(&pending, &mut results, offline, !no_switch);
     print_install_summary(&results);
 
     let failed = results
@@ -90,31 +107,104 @@ pub fn install_specs(specs: &[String], no_switch: bool, force: bool, offline: bo
 }
 
 pub fn sync_from_source(source: &str, offline: bool) -> Result<()> {
-    let loaded = team_config::load_versions_from_source(source, &resolver::current_dir(), offline)?;
+    let cwd = resolver::current_dir();
+    let loaded = team_config::load_versions_from_source(source, &cwd, offline)?;
     if loaded.versions.is_empty() {
         println!("No versions found in {}", loaded.description);
         return Ok(());
     }
 
-    sync_versions(&loaded.versions, offline)
+    let mut policy = Policy::load(&cwd)?;
+    policy.push_layer(loaded.description.clone(), loaded.policy.clone())?;
+    sync_versions_with_policy(&loaded.versions, offline, &policy)
+}
+
+/// Install every version pinned by the workspace root or any of its members, without switching.
+/// Members are registered as projects so prune keeps their versions.
+pub fn sync_workspace(offline: bool) -> Result<()> {
+    let cwd = resolver::current_dir();
+    let root = project::find_workspace_root(&cwd)?.ok_or_else(|| {
+        VexError::Config(
+            "No .vex.toml with a [workspace] section found in the current directory or its parents."
+                .to_string(),
+        )
+    })?;
+    let members = project::workspace_members(&root)?;
+    let vex = vex_dir()?;
+
+    let mut versions = BTreeSet::new();
+    for dir in std::iter::once(&root.root).chain(members.iter()) {
+        versions.extend(resolver::resolve_project_versions(dir));
+        if let Err(err) = registry::record(&vex, dir) {
+            tracing::debug!(
+                "Could not register workspace member {}: {}",
+                dir.display(),
+                err
+            );
+        }
+    }
+
+    println!(
+        "Workspace {}: {} member(s), {} pinned version(s)",
+        root.root.display(),
+        members.len(),
+        versions.len()
+    );
+    if versions.is_empty() {
+        return Ok(());
+    }
+
+    let versions = versions.into_iter().collect::<Vec<_>>();
+    let policy = Policy::load(&root.root)?;
+    let results = install_version_pairs(&versions, offline, false, &policy)?;
+    print_install_summary(&results);
+    ensure_no_policy_blocks(results)
 }
 
 pub(in crate::commands::toolchain) fn sync_versions(
     versions: &[(String, String)],
     offline: bool,
 ) -> Result<()> {
-    let results = install_version_pairs(versions, offline, true)?;
+    let policy = Policy::load(&resolver::current_dir())?;
+    sync_versions_with_policy(versions, offline, &policy)
+}
+
+fn sync_versions_with_policy(
+    versions: &[(String, String)],
+    offline: bool,
+    policy: &Policy,
+) -> Result<()> {
+    let results = install_version_pairs(versions, offline, true, policy)?;
     print_install_summary(&results);
-    Ok(())
+    ensure_no_policy_blocks(results)
 }
 
+/// Fail with the first `[policy]` violation, keeping its structured error code; the summary
+/// printed before lists every blocked version.
+fn ensure_no_policy_blocks(results: Vec<InstallResult>) -> Result<()> {
+    match results
+        .into_iter()
+        .filter_map(|(_, _, result)| result.err())
+        .find(VexError::is_policy_violation)
+    {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// An install planned at `results[index]`: the tool and the resolved version to install.
+pub(in crate::commands::toolchain) type PendingInstall = (usize, Box<dyn Tool>, String);
+
+/// Resolve and policy-check every pair first, then install everything missing at once.
 fn install_version_pairs(
     versions: &[(String, String)],
     offline: bool,
     switch_after_install: bool,
+    policy: &Policy,
 ) -> Result<Vec<InstallResult>> {
     let vex = vex_dir()?;
     let mut results = Vec::new();
+    let mut pending = Vec::new();
 
     for (tool_name, version) in versions {
         let tool = match tools::get_tool(tool_name) {
@@ -128,7 +218,8 @@ fn install_version_pairs(
         if let Some(installed) =
             requested_versions::resolve_installed_version(&vex, tool_name, version)?
         {
-            results.push((tool_name.clone(), installed, Ok(false)));
+            let result = policy.check(tool_name, &installed).map(|_| false);
+            results.push((tool_name.clone(), installed, result));
             continue;
         }
 
@@ -140,28 +231,52 @@ fn install_version_pairs(
             }
         };
 
+        if let Err(error) = policy.check(tool_name, &resolved) {
+            results.push((tool_name.clone(), resolved, Err(error)));
+            continue;
+        }
+
         let install_dir = vex.join("toolchains").join(tool_name).join(&resolved);
-        if install_dir.exists() {
+        if install_dir.exists() || is_pending(&pending, tool_name, &resolved) {
             results.push((tool_name.clone(), resolved, Ok(false)));
             continue;
         }
 
-        let result = install_single(tool.as_ref(), &resolved, offline, switch_after_install);
-        results.push((tool_name.clone(), resolved, result));
+        pending.push((results.len(), tool, resolved.clone()));
+        results.push((tool_name.clone(), resolved, Ok(true)));
     }
 
+    install_pending(&pending, &mut results, offline, switch_after_install);
     Ok(results)
 }
 
-fn install_single(
-    tool: &dyn Tool,
-    version: &str,
+fn is_pending(pending: &[PendingInstall], tool_name: &str, version: &str) -> bool {
+    pending
+        .iter()
+        .any(|(_, tool, planned)| tool.name() == tool_name && planned == version)
+}
+
+/// Install every pending version concurrently and record each outcome in `results`.
+///
+/// Switches happen afterwards, in request order, and only for installs that succeeded.
+pub(in crate::commands::toolchain) fn install_pending(
+    pending: &[PendingInstall],
+    results: &mut [InstallResult],
     offline: bool,
     switch_after_install: bool,
-) -> Result<bool> {
-    installer::install_with_mode(tool, version, offline)?;
-    if switch_after_install {
-        let _ = switcher::switch_version(tool, version);
+) {
+    let installs = pending
+        .iter()
+        .map(|(_, tool, version)| (tool.as_ref(), version.as_str()))
+        .collect::<Vec<_>>();
+    let outcomes = installer::install_all(&installs, offline);
+
+    for ((index, tool, version), outcome) in pending.iter().zip(outcomes) {
+        results[*index].2 = outcome.map(|()| {
+            if switch_after_install {
+                let _ = switcher::switch_version(tool.as_ref(), version);
+            }
+            true
+        });
     }
-    Ok(true)
 }
@@ -2,9 +2,9 @@ use crate::advisories;
 use crate::error::Result;
 use owo_colors::OwoColorize;
 
-pub(super) type InstallResult = (String, String, Result<bool>);
+pub(in crate::commands::toolchain) type InstallResult = (String, String, Result<bool>);
 
-pub(super) fn print_install_summary(results: &[InstallResult]) {
+pub(in crate::commands::toolchain) fn print_install_summary(results: &[InstallResult]) {
     println!();
     println!("{}", "Sync Summary:".cyan().bold());
 
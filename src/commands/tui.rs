@@ -1,7 +1,9 @@
+mod app;
 mod render;
 mod state;
 #[cfg(test)]
 mod tests;
+mod worker;
 
 use crate::error::{Result, VexError};
 use crossterm::{
@@ -27,7 +29,7 @@ pub fn run() -> Result<()> {
 
     let state = state::collect_dashboard_state()?;
 
-    let res = render::run_tui(&mut terminal, state);
+    let res = render::run_tui(&mut terminal, app::App::new(state));
 
     disable_raw_mode()?;
     execute!(
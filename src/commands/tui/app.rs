@@ -0,0 +1,258 @@
+use super::state::{DashboardState, ToolState};
+use super::worker::{Job, RemoteRow, WorkerEvent};
+use crossterm::event::KeyCode;
+use std::collections::BTreeMap;
+
+/// Activity lines kept for the progress pane.
+const MAX_LOG_LINES: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Focus {
+    Installed,
+    Remote,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Mode {
+    Browse,
+    /// Waiting for `y` before running a destructive job.
+    Confirm(Job),
+}
+
+/// Everything the TUI shows; key presses and worker events are its only inputs.
+#[derive(Debug)]
+pub(super) struct App {
+    pub(super) state: DashboardState,
+    pub(super) tool_index: usize,
+    pub(super) focus: Focus,
+    pub(super) installed_cursor: usize,
+    pub(super) remote_cursor: usize,
+    /// Remote versions fetched so far, keyed by tool.
+    pub(super) remote: BTreeMap<String, Vec<RemoteRow>>,
+    pub(super) mode: Mode,
+    pub(super) running: Option<Job>,
+    pub(super) log: Vec<String>,
+    pub(super) status: Option<String>,
+    pub(super) tick: usize,
+    pub(super) quit: bool,
+}
+
+impl App {
+    pub(super) fn new(state: DashboardState) -> Self {
+        Self {
+            state,
+            tool_index: 0,
+            focus: Focus::Installed,
+            installed_cursor: 0,
+            remote_cursor: 0,
+            remote: BTreeMap::new(),
+            mode: Mode::Browse,
+            running: None,
+            log: Vec::new(),
+            status: None,
+            tick: 0,
+            quit: false,
+        }
+    }
+
+    pub(super) fn selected_tool(&self) -> &ToolState {
+        &self.state.tools[self.tool_index]
+    }
+
+    pub(super) fn remote_rows(&self) -> Option<&[RemoteRow]> {
+        self.remote
+            .get(&self.selected_tool().name)
+            .map(Vec::as_slice)
+    }
+
+    /// Apply a key press; returns the job to start on the worker thread, if any.
+    pub(super) fn handle_key(&mut self, code: KeyCode) -> Option<Job> {
+        if let Mode::Confirm(job) = &self.mode {
+            let job = job.clone();
+            self.mode = Mode::Browse;
+            if matches!(code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                return self.start(job);
+            }
+            self.status = Some("Cancelled.".to_string());
+            return None;
+        }
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                if self.running.is_some() {
+                    self.status =
+                        Some("Wait for the running action to finish before quitting.".to_string());
+                } else {
+                    self.quit = true;
+                }
+                None
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.select_tool(self.tool_index + self.state.tools.len() - 1);
+                None
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.select_tool(self.tool_index + 1);
+                None
+            }
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Installed if self.remote_rows().is_some() => Focus::Remote,
+                    _ => Focus::Installed,
+                };
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.move_cursor(false);
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.move_cursor(true);
+                None
+            }
+            KeyCode::Char('r') => self.start(Job::FetchRemote {
+                tool: self.selected_tool().name.clone(),
+            }),
+            KeyCode::Char('p') => self.start(Job::PrunePreview),
+            KeyCode::Char('i') => self.install_selected(),
+            KeyCode::Char('s') => self.switch_selected(),
+            KeyCode::Enter => match self.focus {
+                Focus::Installed => self.switch_selected(),
+                Focus::Remote => self.install_selected(),
+            },
+            KeyCode::Char('u') | KeyCode::Char('d') => {
+                if let Some(version) = self.selected_installed() {
+                    self.mode = Mode::Confirm(Job::Uninstall {
+                        tool: self.selected_tool().name.clone(),
+                        version,
+                    });
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Apply progress or a result reported by the worker thread.
+    pub(super) fn apply(&mut self, event: WorkerEvent) {
+        match event {
+            WorkerEvent::Output(line) => self.push_log(line),
+            WorkerEvent::Remote { tool, rows } => match rows {
+                Ok(rows) => {
+                    self.push_log(format!("Loaded {} {} versions", rows.len(), tool));
+                    self.remote.insert(tool.clone(), rows);
+                    if tool == self.selected_tool().name {
+                        self.focus = Focus::Remote;
+                        self.remote_cursor = 0;
+                    }
+                }
+                Err(message) => self.push_log(message),
+            },
+            WorkerEvent::Refreshed(state) => {
+                self.state = *state;
+                self.clamp_cursors();
+            }
+            WorkerEvent::Finished { success } => {
+                let Some(job) = self.running.take() else {
+                    return;
+                };
+                let outcome = if success { "finished" } else { "failed" };
+                self.status = Some(format!("{} {}", job.label(), outcome));
+                if success && job == Job::PrunePreview {
+                    self.mode = Mode::Confirm(Job::Prune);
+                }
+            }
+        }
+    }
+
+    pub(super) fn confirm_prompt(&self) -> Option<String> {
+        match &self.mode {
+            Mode::Browse => None,
+            Mode::Confirm(Job::Prune) => Some("Remove the items listed above? [y/N]".to_string()),
+            Mode::Confirm(job) => Some(format!("{}? [y/N]", job.label())),
+        }
+    }
+
+    fn start(&mut self, job: Job) -> Option<Job> {
+        if let Some(running) = &self.running {
+            self.status = Some(format!("Busy: {}", running.label()));
+            return None;
+        }
+        self.push_log(format!("› {}", job.label()));
+        self.status = None;
+        self.running = Some(job.clone());
+        Some(job)
+    }
+
+    fn install_selected(&mut self) -> Option<Job> {
+        if self.focus != Focus::Remote {
+            self.status = Some("Press r to browse remote versions, then i to install.".to_string());
+            return None;
+        }
+        let tool = self.selected_tool();
+        let version = self.remote_rows()?.get(self.remote_cursor)?.version.clone();
+        if tool.installed.contains(&version) {
+            self.status = Some(format!("{}@{} is already installed", tool.name, version));
+            return None;
+        }
+        let tool = tool.name.clone();
+        self.start(Job::Install { tool, version })
+    }
+
+    fn switch_selected(&mut self) -> Option<Job> {
+        let version = self.selected_installed()?;
+        let tool = self.selected_tool().name.clone();
+        self.start(Job::Switch { tool, version })
+    }
+
+    fn selected_installed(&self) -> Option<String> {
+        if self.focus != Focus::Installed {
+            return None;
+        }
+        self.selected_tool()
+            .installed
+            .get(self.installed_cursor)
+            .cloned()
+    }
+
+    fn select_tool(&mut self, index: usize) {
+        self.tool_index = index % self.state.tools.len();
+        self.installed_cursor = 0;
+        self.remote_cursor = 0;
+        if self.remote_rows().is_none() {
+            self.focus = Focus::Installed;
+        }
+    }
+
+    fn move_cursor(&mut self, down: bool) {
+        let (cursor, len) = match self.focus {
+            Focus::Installed => (
+                &mut self.installed_cursor,
+                self.state.tools[self.tool_index].installed.len(),
+            ),
+            Focus::Remote => (
+                &mut self.remote_cursor,
+                self.remote
+                    .get(&self.state.tools[self.tool_index].name)
+                    .map_or(0, Vec::len),
+            ),
+        };
+        if down {
+            *cursor = (*cursor + 1).min(len.saturating_sub(1));
+        } else {
+            *cursor = cursor.saturating_sub(1);
+        }
+    }
+
+    fn clamp_cursors(&mut self) {
+        let installed = self.selected_tool().installed.len();
+        self.installed_cursor = self.installed_cursor.min(installed.saturating_sub(1));
+    }
+
+    fn push_log(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > MAX_LOG_LINES {
+            self.log.drain(..self.log.len() - MAX_LOG_LINES);
+        }
+    }
+}
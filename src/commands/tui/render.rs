@@ -1,42 +1,73 @@
 mod layout;
 mod widgets;
 
-use super::state::DashboardState;
+use super::app::App;
+use super::worker::{self, WorkerEvent};
 use crate::error::Result;
-use crossterm::event::{self, Event, KeyCode};
+use crossterm::event::{self, Event, KeyEventKind};
 use ratatui::{backend::CrosstermBackend, Frame, Terminal};
 use std::io;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
 
 use layout::split_dashboard;
 use widgets::{
-    render_current_versions, render_disk_usage, render_footer, render_header,
-    render_managed_versions, render_missing_installs, render_warnings,
+    render_activity, render_current_versions, render_disk_usage, render_footer, render_header,
+    render_installed_versions, render_missing_installs, render_remote_versions, render_warnings,
 };
 
+/// How long to wait for a key before redrawing, so worker output and the spinner stay live.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 pub(super) fn run_tui(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
-    state: DashboardState,
+    mut app: App,
 ) -> Result<()> {
-    loop {
-        terminal.draw(|frame| render_dashboard(frame, &state))?;
+    let mut worker: Option<Receiver<WorkerEvent>> = None;
+
+    while !app.quit {
+        terminal.draw(|frame| render_app(frame, &app))?;
+        app.tick = app.tick.wrapping_add(1);
+
+        if let Some(events) = &worker {
+            loop {
+                match events.try_recv() {
+                    Ok(event) => app.apply(event),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        worker = None;
+                        break;
+                    }
+                }
+            }
+        }
 
+        if !event::poll(POLL_INTERVAL)? {
+            continue;
+        }
         if let Event::Key(key) = event::read()? {
-            match key.code {
-                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-                _ => {}
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if let Some(job) = app.handle_key(key.code) {
+                worker = Some(worker::spawn(job));
             }
         }
     }
+
+    Ok(())
 }
 
-fn render_dashboard(frame: &mut Frame, state: &DashboardState) {
+pub(super) fn render_app(frame: &mut Frame, app: &App) {
     let layout = split_dashboard(frame.area());
 
-    render_header(frame, layout.header);
-    render_current_versions(frame, layout.current_versions, &state.current_tools);
-    render_managed_versions(frame, layout.managed_versions, &state.managed_versions);
-    render_missing_installs(frame, layout.missing_installs, &state.missing_installs);
-    render_warnings(frame, layout.warnings, &state.warnings);
-    render_disk_usage(frame, layout.disk_usage, &state.disk_usage);
-    render_footer(frame, layout.footer);
+    render_header(frame, layout.header, app);
+    render_installed_versions(frame, layout.installed_versions, app);
+    render_remote_versions(frame, layout.remote_versions, app);
+    render_current_versions(frame, layout.current_versions, &app.state.current_tools);
+    render_missing_installs(frame, layout.missing_installs, &app.state.missing_installs);
+    render_warnings(frame, layout.warnings, &app.state.warnings);
+    render_disk_usage(frame, layout.disk_usage, &app.state.disk_usage);
+    render_activity(frame, layout.activity, app);
+    render_footer(frame, layout.footer, app);
 }
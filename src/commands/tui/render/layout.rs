@@ -2,11 +2,13 @@ use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 pub(super) struct DashboardLayout {
     pub(super) header: Rect,
+    pub(super) installed_versions: Rect,
+    pub(super) remote_versions: Rect,
     pub(super) current_versions: Rect,
-    pub(super) managed_versions: Rect,
     pub(super) missing_installs: Rect,
     pub(super) warnings: Rect,
     pub(super) disk_usage: Rect,
+    pub(super) activity: Rect,
     pub(super) footer: Rect,
 }
 
@@ -16,36 +18,39 @@ pub(super) fn split_dashboard(area: Rect) -> DashboardLayout {
         .constraints([
             Constraint::Length(3),
             Constraint::Min(10),
+            Constraint::Length(8),
             Constraint::Length(3),
         ])
         .split(area);
 
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+        ])
         .split(chunks[1]);
 
-    let left_chunks = Layout::default()
+    let overview_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Percentage(40),
+            Constraint::Min(3),
             Constraint::Percentage(30),
-            Constraint::Percentage(30),
+            Constraint::Length(4),
         ])
-        .split(main_chunks[0]);
-
-    let right_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(main_chunks[1]);
+        .split(main_chunks[2]);
 
     DashboardLayout {
         header: chunks[0],
-        current_versions: left_chunks[0],
-        managed_versions: left_chunks[1],
-        missing_installs: left_chunks[2],
-        warnings: right_chunks[0],
-        disk_usage: right_chunks[1],
-        footer: chunks[2],
+        installed_versions: main_chunks[0],
+        remote_versions: main_chunks[1],
+        current_versions: overview_chunks[0],
+        missing_installs: overview_chunks[1],
+        warnings: overview_chunks[2],
+        disk_usage: overview_chunks[3],
+        activity: chunks[2],
+        footer: chunks[3],
     }
 }
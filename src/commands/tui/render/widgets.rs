@@ -1,23 +1,115 @@
+use super::super::app::{App, Focus};
 use super::super::state::DiskUsage;
 use crate::commands::current::CurrentEntry;
+use crate::requested_versions::version_matches_request;
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
     Frame,
 };
-use std::collections::HashMap;
 
-pub(super) fn render_header(frame: &mut Frame, area: Rect) {
-    let header = Paragraph::new("vex TUI Dashboard")
-        .style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )
-        .block(Block::default().borders(Borders::ALL));
-    frame.render_widget(header, area);
+const SPINNER: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+pub(super) fn render_header(frame: &mut Frame, area: Rect, app: &App) {
+    let tabs = Tabs::new(
+        app.state
+            .tools
+            .iter()
+            .map(|tool| tool.name.as_str())
+            .collect::<Vec<_>>(),
+    )
+    .select(app.tool_index)
+    .highlight_style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .block(
+        Block::default()
+            .title(Span::styled(
+                "vex",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(tabs, area);
+}
+
+pub(super) fn render_installed_versions(frame: &mut Frame, area: Rect, app: &App) {
+    let tool = app.selected_tool();
+    let pinned = app.state.managed_versions.get(&tool.name);
+    let items = if tool.installed.is_empty() {
+        vec![ListItem::new(Span::styled(
+            format!("No {} versions installed", tool.name),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        tool.installed
+            .iter()
+            .map(|version| {
+                let mut spans = vec![Span::raw(version.as_str())];
+                if tool.current.as_ref() == Some(version) {
+                    spans.push(badge("current", Color::Green));
+                }
+                if pinned.is_some_and(|pin| version_matches_request(version, pin)) {
+                    spans.push(badge("pinned", Color::Cyan));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let title = format!("Installed {}", tool.name);
+    let selected = (app.focus == Focus::Installed && !tool.installed.is_empty())
+        .then_some(app.installed_cursor);
+    render_selectable_list(frame, area, &title, items, selected);
+}
+
+pub(super) fn render_remote_versions(frame: &mut Frame, area: Rect, app: &App) {
+    let tool = app.selected_tool();
+    let Some(rows) = app.remote_rows() else {
+        let hint = Paragraph::new("Press r to browse remote versions").block(
+            Block::default()
+                .title(format!("Remote {}", tool.name))
+                .borders(Borders::ALL),
+        );
+        frame.render_widget(hint, area);
+        return;
+    };
+
+    let items = rows
+        .iter()
+        .map(|row| {
+            let mut spans = vec![Span::raw(row.version.as_str())];
+            if let Some(lts) = &row.lts {
+                let label = if tool.name == "python" {
+                    lts.clone()
+                } else {
+                    format!("LTS {}", lts)
+                };
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(label, Style::default().fg(Color::Cyan)));
+            }
+            if tool.installed.contains(&row.version) {
+                spans.push(badge("installed", Color::Green));
+            }
+            if let Some(advisory) = &row.advisory {
+                spans.push(badge(advisory, Color::Yellow));
+            }
+            if row.vulnerabilities > 0 {
+                spans.push(badge(&format!("{} CVE", row.vulnerabilities), Color::Red));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let title = format!("Remote {}", tool.name);
+    let selected = (app.focus == Focus::Remote && !rows.is_empty()).then_some(app.remote_cursor);
+    render_selectable_list(frame, area, &title, items, selected);
 }
 
 pub(super) fn render_current_versions(frame: &mut Frame, area: Rect, tools: &[CurrentEntry]) {
@@ -42,32 +134,6 @@ pub(super) fn render_current_versions(frame: &mut Frame, area: Rect, tools: &[Cu
     render_list_block(frame, area, "Current Active Versions", items);
 }
 
-pub(super) fn render_managed_versions(
-    frame: &mut Frame,
-    area: Rect,
-    versions: &HashMap<String, String>,
-) {
-    let mut sorted_versions: Vec<(&String, &String)> = versions.iter().collect();
-    sorted_versions.sort_by(|a, b| a.0.cmp(b.0));
-
-    let items = if sorted_versions.is_empty() {
-        vec![ListItem::new("No managed versions")]
-    } else {
-        sorted_versions
-            .iter()
-            .map(|(tool, version)| {
-                ListItem::new(Line::from(vec![
-                    Span::styled(tool.as_str(), Style::default().fg(Color::Green)),
-                    Span::raw(" -> "),
-                    Span::styled(version.as_str(), Style::default().fg(Color::White)),
-                ]))
-            })
-            .collect()
-    };
-
-    render_list_block(frame, area, "Managed Versions", items);
-}
-
 pub(super) fn render_missing_installs(frame: &mut Frame, area: Rect, missing: &[String]) {
     let items = if missing.is_empty() {
         vec![ListItem::new(Span::styled(
@@ -120,11 +186,61 @@ pub(super) fn render_disk_usage(frame: &mut Frame, area: Rect, disk_usage: &Opti
     frame.render_widget(paragraph, area);
 }
 
-pub(super) fn render_footer(frame: &mut Frame, area: Rect) {
-    let footer = Paragraph::new("Press 'q' or ESC to exit")
+pub(super) fn render_activity(frame: &mut Frame, area: Rect, app: &App) {
+    let title = match &app.running {
+        Some(job) => format!(
+            "Activity {} {}",
+            SPINNER[app.tick % SPINNER.len()],
+            job.label()
+        ),
+        None => "Activity".to_string(),
+    };
+    let visible = usize::from(area.height.saturating_sub(2));
+    let lines = app.log[app.log.len().saturating_sub(visible)..]
+        .iter()
+        .map(|line| Line::raw(line.as_str()))
+        .collect::<Vec<_>>();
+
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().title(title).borders(Borders::ALL));
+    frame.render_widget(paragraph, area);
+}
+
+pub(super) fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
+    let footer = if let Some(prompt) = app.confirm_prompt() {
+        Paragraph::new(prompt).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if let Some(status) = &app.status {
+        Paragraph::new(status.as_str())
+    } else {
+        Paragraph::new(
+            "←/→ tool  ↑/↓ move  Tab pane  r remote  i install  s switch  u uninstall  p prune  q quit",
+        )
         .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::ALL));
-    frame.render_widget(footer, area);
+    };
+    frame.render_widget(footer.block(Block::default().borders(Borders::ALL)), area);
+}
+
+fn badge(label: &str, color: Color) -> Span<'static> {
+    Span::styled(format!(" [{}]", label), Style::default().fg(color))
+}
+
+fn render_selectable_list(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    items: Vec<ListItem>,
+    selected: Option<usize>,
+) {
+    let list = List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("› ");
+    let mut state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(list, area, &mut state);
 }
 
 fn render_list_block(frame: &mut Frame, area: Rect, title: &str, items: Vec<ListItem>) {
@@ -1,16 +1,22 @@
 use crate::advisories;
 use crate::commands::current::{collect_current, CurrentEntry};
+use crate::commands::versions;
 use crate::config;
 use crate::error::{Result, VexError};
 use crate::fs_utils::path_size;
 use crate::requested_versions;
 use crate::resolver;
+use crate::versioning::version_sort_key;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+/// Tools shown as tabs, in display order.
+pub(super) const TOOLS: &[&str] = &["node", "go", "java", "python", "rust"];
+
 #[derive(Debug)]
 pub(super) struct DashboardState {
+    pub(super) tools: Vec<ToolState>,
     pub(super) current_tools: Vec<CurrentEntry>,
     pub(super) warnings: Vec<String>,
     pub(super) disk_usage: Option<DiskUsage>,
@@ -18,6 +24,14 @@ pub(super) struct DashboardState {
     pub(super) missing_installs: Vec<String>,
 }
 
+/// Installed versions of one tool, newest first.
+#[derive(Debug, Clone)]
+pub(super) struct ToolState {
+    pub(super) name: String,
+    pub(super) current: Option<String>,
+    pub(super) installed: Vec<String>,
+}
+
 #[derive(Debug)]
 pub(super) struct DiskUsage {
     pub(super) vex_size_mb: u64,
@@ -54,11 +68,45 @@ pub(super) fn collect_dashboard_state() -> Result<DashboardState> {
                 warnings.push(format!("{}: {}", entry.tool, message));
             }
         }
+
+        let vulnerabilities = advisories::get_vulnerabilities(&entry.tool, &entry.version);
+        if let Some(most_severe) = vulnerabilities.first() {
+            warnings.push(format!(
+                "{}: {} known {}, most severe {}",
+                entry.tool,
+                vulnerabilities.len(),
+                if vulnerabilities.len() == 1 {
+                    "vulnerability"
+                } else {
+                    "vulnerabilities"
+                },
+                most_severe.label()
+            ));
+        }
     }
 
     let disk_usage = calculate_disk_usage(&vex_dir);
 
+    let tools = TOOLS
+        .iter()
+        .map(|tool| {
+            let report = versions::installed_versions(tool)?;
+            let mut installed = report
+                .versions
+                .into_iter()
+                .map(|entry| entry.version)
+                .collect::<Vec<_>>();
+            installed.sort_by_key(|version| std::cmp::Reverse(version_sort_key(version)));
+            Ok(ToolState {
+                name: tool.to_string(),
+                current: report.current_version,
+                installed,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
     Ok(DashboardState {
+        tools,
         current_tools,
         warnings,
         disk_usage,
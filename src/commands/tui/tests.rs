@@ -1,3 +1,11 @@
+use super::app::{App, Focus, Mode};
+use super::render::render_app;
+use super::state::{DashboardState, ToolState, TOOLS};
+use super::worker::{Job, RemoteRow, WorkerEvent};
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+use std::collections::HashMap;
+
 #[test]
 fn test_read_tool_versions_empty() {
     use std::path::PathBuf;
@@ -44,3 +52,180 @@ fn test_path_size_with_file() {
     let size = crate::fs_utils::path_size(temp_dir.path());
     assert!(size > 0);
 }
+
+fn sample_state() -> DashboardState {
+    let tools = TOOLS
+        .iter()
+        .map(|tool| ToolState {
+            name: tool.to_string(),
+            current: (*tool == "node").then(|| "20.11.0".to_string()),
+            installed: if *tool == "node" {
+                vec!["20.11.0".to_string(), "18.20.4".to_string()]
+            } else {
+                Vec::new()
+            },
+        })
+        .collect();
+    DashboardState {
+        tools,
+        current_tools: Vec::new(),
+        warnings: Vec::new(),
+        disk_usage: None,
+        managed_versions: HashMap::from([("node".to_string(), "20".to_string())]),
+        missing_installs: Vec::new(),
+    }
+}
+
+fn remote_row(version: &str, lts: Option<&str>, vulnerabilities: usize) -> RemoteRow {
+    RemoteRow {
+        version: version.to_string(),
+        lts: lts.map(str::to_string),
+        advisory: None,
+        vulnerabilities,
+    }
+}
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(140, 32)).unwrap();
+    terminal.draw(|frame| render_app(frame, app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_renders_installed_versions_with_badges() {
+    let app = App::new(sample_state());
+    let screen = screen(&app);
+    assert!(screen.contains("Installed node"));
+    assert!(screen.contains("20.11.0 [current] [pinned]"));
+    assert!(screen.contains("18.20.4"));
+    assert!(screen.contains("Press r to browse remote versions"));
+}
+
+#[test]
+fn test_remote_browsing_and_install() {
+    let mut app = App::new(sample_state());
+    assert_eq!(
+        app.handle_key(KeyCode::Char('r')),
+        Some(Job::FetchRemote {
+            tool: "node".to_string()
+        })
+    );
+    app.apply(WorkerEvent::Remote {
+        tool: "node".to_string(),
+        rows: Ok(vec![
+            remote_row("22.1.0", None, 0),
+            remote_row("20.11.0", Some("Iron"), 2),
+        ]),
+    });
+    app.apply(WorkerEvent::Finished { success: true });
+    assert_eq!(app.focus, Focus::Remote);
+
+    let screen = screen(&app);
+    assert!(screen.contains("Remote node"));
+    assert!(screen.contains("20.11.0 LTS Iron [installed] [2 CVE]"));
+
+    assert_eq!(
+        app.handle_key(KeyCode::Char('i')),
+        Some(Job::Install {
+            tool: "node".to_string(),
+            version: "22.1.0".to_string()
+        })
+    );
+    assert_eq!(app.handle_key(KeyCode::Char('p')), None);
+    assert!(app.status.as_deref().unwrap().starts_with("Busy"));
+
+    app.apply(WorkerEvent::Output(
+        "→ Downloading node 22.1.0...".to_string(),
+    ));
+    assert!(screen_contains(&app, "Install node@22.1.0"));
+    assert!(screen_contains(&app, "Downloading node 22.1.0"));
+    app.apply(WorkerEvent::Finished { success: true });
+    assert!(app.running.is_none());
+
+    app.handle_key(KeyCode::Down);
+    assert_eq!(app.handle_key(KeyCode::Char('i')), None);
+    assert!(app.status.as_deref().unwrap().contains("already installed"));
+}
+
+fn screen_contains(app: &App, text: &str) -> bool {
+    screen(app).contains(text)
+}
+
+#[test]
+fn test_uninstall_and_prune_require_confirmation() {
+    let mut app = App::new(sample_state());
+    app.handle_key(KeyCode::Down);
+    assert_eq!(app.handle_key(KeyCode::Char('u')), None);
+    assert!(screen_contains(&app, "Uninstall node@18.20.4? [y/N]"));
+    assert_eq!(app.handle_key(KeyCode::Char('n')), None);
+    assert_eq!(app.mode, Mode::Browse);
+
+    app.handle_key(KeyCode::Char('u'));
+    assert_eq!(
+        app.handle_key(KeyCode::Char('y')),
+        Some(Job::Uninstall {
+            tool: "node".to_string(),
+            version: "18.20.4".to_string()
+        })
+    );
+    let mut refreshed = sample_state();
+    refreshed.tools[0].installed.pop();
+    app.apply(WorkerEvent::Refreshed(Box::new(refreshed)));
+    app.apply(WorkerEvent::Finished { success: true });
+    assert_eq!(app.installed_cursor, 0);
+    assert_eq!(app.selected_tool().installed, ["20.11.0"]);
+
+    assert_eq!(app.handle_key(KeyCode::Char('p')), Some(Job::PrunePreview));
+    app.apply(WorkerEvent::Finished { success: true });
+    assert_eq!(app.mode, Mode::Confirm(Job::Prune));
+    assert_eq!(app.handle_key(KeyCode::Char('y')), Some(Job::Prune));
+}
+
+#[test]
+fn test_switch_tool_tabs_and_quit() {
+    let mut app = App::new(sample_state());
+    assert_eq!(
+        app.handle_key(KeyCode::Enter),
+        Some(Job::Switch {
+            tool: "node".to_string(),
+            version: "20.11.0".to_string()
+        })
+    );
+    app.handle_key(KeyCode::Char('q'));
+    assert!(!app.quit);
+    app.apply(WorkerEvent::Finished { success: false });
+    assert_eq!(app.status.as_deref(), Some("Switch to node@20.11.0 failed"));
+
+    app.handle_key(KeyCode::Left);
+    assert_eq!(app.selected_tool().name, "rust");
+    assert!(screen_contains(&app, "No rust versions installed"));
+    app.handle_key(KeyCode::Char('q'));
+    assert!(app.quit);
+}
+
+#[test]
+fn test_jobs_map_to_vex_commands() {
+    let install = Job::Install {
+        tool: "go".to_string(),
+        version: "1.23.5".to_string(),
+    };
+    assert_eq!(
+        install.command_args().unwrap(),
+        ["install", "go@1.23.5", "--no-switch"]
+    );
+    assert_eq!(
+        Job::PrunePreview.command_args().unwrap(),
+        ["prune", "--dry-run"]
+    );
+    assert!(Job::FetchRemote {
+        tool: "go".to_string()
+    }
+    .command_args()
+    .is_none());
+}
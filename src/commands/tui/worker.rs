@@ -0,0 +1,183 @@
+//! Background jobs for the TUI
+//!
+//! Installs, switches, uninstalls and prunes run the `vex` binary itself as a child process, so
+//! they behave exactly like the CLI and their output can be streamed into the progress pane
+//! without drawing over the alternate screen. Remote version lists are fetched in-process.
+
+use super::state::{self, DashboardState};
+use crate::advisories::{self, AdvisoryStatus};
+use crate::commands::versions::{self, RemoteFilter};
+use crate::output::strip_ansi;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+/// Remote versions listed per tool; advisory lookups for older releases are not worth the wait.
+const REMOTE_LIMIT: usize = 150;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Job {
+    FetchRemote { tool: String },
+    Install { tool: String, version: String },
+    Switch { tool: String, version: String },
+    Uninstall { tool: String, version: String },
+    PrunePreview,
+    Prune,
+}
+
+impl Job {
+    pub(super) fn label(&self) -> String {
+        match self {
+            Job::FetchRemote { tool } => format!("Fetch {} versions", tool),
+            Job::Install { tool, version } => format!("Install {}@{}", tool, version),
+            Job::Switch { tool, version } => format!("Switch to {}@{}", tool, version),
+            Job::Uninstall { tool, version } => format!("Uninstall {}@{}", tool, version),
+            Job::PrunePreview => "Prune dry run".to_string(),
+            Job::Prune => "Prune".to_string(),
+        }
+    }
+
+    /// Arguments for the `vex` child process, or `None` for jobs that run in-process.
+    pub(super) fn command_args(&self) -> Option<Vec<String>> {
+        let args = match self {
+            Job::FetchRemote { .. } => return None,
+            Job::Install { tool, version } => vec![
+                "install".to_string(),
+                format!("{}@{}", tool, version),
+                "--no-switch".to_string(),
+            ],
+            Job::Switch { tool, version } => {
+                vec!["use".to_string(), format!("{}@{}", tool, version)]
+            }
+            Job::Uninstall { tool, version } => {
+                vec!["uninstall".to_string(), format!("{}@{}", tool, version)]
+            }
+            Job::PrunePreview => vec!["prune".to_string(), "--dry-run".to_string()],
+            Job::Prune => vec!["prune".to_string()],
+        };
+        Some(args)
+    }
+}
+
+/// A remote version with the badges shown next to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RemoteRow {
+    pub(super) version: String,
+    /// LTS codename, or the support status for Python.
+    pub(super) lts: Option<String>,
+    pub(super) advisory: Option<String>,
+    pub(super) vulnerabilities: usize,
+}
+
+#[derive(Debug)]
+pub(super) enum WorkerEvent {
+    Output(String),
+    Remote {
+        tool: String,
+        rows: std::result::Result<Vec<RemoteRow>, String>,
+    },
+    Refreshed(Box<DashboardState>),
+    Finished {
+        success: bool,
+    },
+}
+
+/// Run `job` on a new thread; the receiver disconnects after `Finished`.
+pub(super) fn spawn(job: Job) -> Receiver<WorkerEvent> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let success = run(&job, &sender);
+        let _ = sender.send(WorkerEvent::Finished { success });
+    });
+    receiver
+}
+
+fn run(job: &Job, sender: &Sender<WorkerEvent>) -> bool {
+    let Some(args) = job.command_args() else {
+        let Job::FetchRemote { tool } = job else {
+            return false;
+        };
+        let rows = remote_rows(tool);
+        let success = rows.is_ok();
+        let _ = sender.send(WorkerEvent::Remote {
+            tool: tool.clone(),
+            rows,
+        });
+        return success;
+    };
+
+    let success = match run_vex(&args, sender) {
+        Ok(success) => success,
+        Err(err) => {
+            let _ = sender.send(WorkerEvent::Output(format!("Could not run vex: {}", err)));
+            false
+        }
+    };
+
+    match state::collect_dashboard_state() {
+        Ok(state) => {
+            let _ = sender.send(WorkerEvent::Refreshed(Box::new(state)));
+        }
+        Err(err) => {
+            let _ = sender.send(WorkerEvent::Output(format!("Could not refresh: {}", err)));
+        }
+    }
+    success
+}
+
+fn run_vex(args: &[String], sender: &Sender<WorkerEvent>) -> std::io::Result<bool> {
+    let mut child = Command::new(std::env::current_exe()?)
+        .args(args)
+        .env("VEX_NON_INTERACTIVE", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stderr = child.stderr.take().map(|stderr| {
+        let sender = sender.clone();
+        thread::spawn(move || forward_lines(stderr, &sender))
+    });
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, sender);
+    }
+    if let Some(handle) = stderr {
+        let _ = handle.join();
+    }
+    Ok(child.wait()?.success())
+}
+
+fn forward_lines(stream: impl Read, sender: &Sender<WorkerEvent>) {
+    for line in BufReader::new(stream).lines().map_while(|line| line.ok()) {
+        // Keep only what a terminal would show last on a line redrawn with `\r`.
+        let visible = strip_ansi(line.rsplit('\r').next().unwrap_or_default());
+        if !visible.trim().is_empty() {
+            let _ = sender.send(WorkerEvent::Output(visible));
+        }
+    }
+}
+
+fn remote_rows(tool: &str) -> std::result::Result<Vec<RemoteRow>, String> {
+    let report = versions::remote_versions(tool, RemoteFilter::All)
+        .map_err(|err| format!("Could not fetch {} versions: {}", tool, err))?;
+    advisories::refresh_vulnerabilities(tool);
+    Ok(report
+        .versions
+        .into_iter()
+        .take(REMOTE_LIMIT)
+        .map(|entry| {
+            let advisory = match advisories::get_advisory(tool, &entry.version).status {
+                AdvisoryStatus::Eol => Some("EOL".to_string()),
+                AdvisoryStatus::NearEol => Some("EOL soon".to_string()),
+                _ => None,
+            };
+            RemoteRow {
+                vulnerabilities: advisories::get_vulnerabilities(tool, &entry.version).len(),
+                version: entry.version,
+                lts: entry.label,
+                advisory,
+            }
+        })
+        .collect())
+}
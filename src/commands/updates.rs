@@ -1,10 +1,13 @@
 mod outdated;
 mod render;
+mod strategy;
 mod targets;
 mod upgrade;
 
+use crate::advisories::VulnerabilityMatch;
+use crate::config::UpgradeStrategy;
 use crate::error::{Result, VexError};
-use crate::output::{print_json, OutputMode};
+use crate::output::{self, print_json, OutputMode};
 use serde::Serialize;
 
 use render::{render_outdated_text, render_upgrade_text};
@@ -33,6 +36,8 @@ pub struct OutdatedEntry {
     pub advisory_message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub advisory_recommendation: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub vulnerabilities: Vec<VulnerabilityMatch>,
 }
 
 #[derive(Debug, Serialize)]
@@ -46,14 +51,21 @@ pub struct UpgradeEntry {
     pub tool: String,
     pub previous_version: String,
     pub target_version: String,
+    pub strategy: UpgradeStrategy,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_pin: Option<String>,
     pub status: String,
     pub source: ManagedSource,
     pub source_path: Option<String>,
+    pub needs_install: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_bytes: Option<u64>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct UpgradeReport {
     pub scope: String,
+    pub dry_run: bool,
     pub entries: Vec<UpgradeEntry>,
 }
 
@@ -68,11 +80,16 @@ pub fn outdated(tool: Option<&str>, output: OutputMode) -> Result<()> {
     }
 }
 
-pub fn upgrade(tool: Option<&str>, all: bool) -> Result<()> {
+pub fn upgrade(
+    tool: Option<&str>,
+    all: bool,
+    strategy: Option<UpgradeStrategy>,
+    dry_run: bool,
+) -> Result<()> {
     let report = if all {
-        upgrade_all()?
+        upgrade_all(strategy, dry_run)?
     } else if let Some(tool) = tool {
-        upgrade_one(tool)?
+        upgrade_one(tool, strategy, dry_run)?
     } else {
         return Err(VexError::Parse(
             "Please specify a tool (e.g., 'vex upgrade node') or use --all".to_string(),
@@ -80,5 +97,14 @@ pub fn upgrade(tool: Option<&str>, all: bool) -> Result<()> {
     };
 
     render_upgrade_text(&report);
+    output::report(&report)?;
+
+    let upgraded = report
+        .entries
+        .iter()
+        .any(|entry| entry.status == "upgraded");
+    if upgraded && crate::config::load_settings()?.prune.auto_after_upgrade {
+        crate::commands::prune::run(false)?;
+    }
     Ok(())
 }
@@ -7,6 +7,13 @@ pub(super) fn collect_outdated(tool: Option<&str>) -> Result<OutdatedReport> {
     let (scope, targets) = collect_targets(tool)?;
     let mut entries = Vec::new();
 
+    let mut refreshed = std::collections::BTreeSet::new();
+    for target in &targets {
+        if refreshed.insert(target.tool.clone()) {
+            advisories::refresh_vulnerabilities(&target.tool);
+        }
+    }
+
     for target in targets {
         let tool_impl = tools::get_tool(&target.tool)?;
         let latest_version = tools::resolve_fuzzy_version(tool_impl.as_ref(), "latest")?;
@@ -21,6 +28,7 @@ pub(super) fn collect_outdated(tool: Option<&str>) -> Result<OutdatedReport> {
             } else {
                 (None, None, None)
             };
+        let vulnerabilities = advisories::get_vulnerabilities(&target.tool, &target.version);
 
         entries.push(OutdatedEntry {
             tool: target.tool,
@@ -37,6 +45,7 @@ pub(super) fn collect_outdated(tool: Option<&str>) -> Result<OutdatedReport> {
             advisory_status,
             advisory_message,
             advisory_recommendation,
+            vulnerabilities,
         });
     }
 
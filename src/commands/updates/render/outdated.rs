@@ -13,6 +13,7 @@ pub(in crate::commands::updates) fn render_outdated_text(report: &OutdatedReport
     let mut table = ui::Table::new();
     let mut outdated_count = 0;
     let mut advisory_count = 0;
+    let mut vulnerable_count = 0;
 
     for entry in &report.entries {
         let status = if entry.status == "outdated" {
@@ -59,6 +60,20 @@ pub(in crate::commands::updates) fn render_outdated_text(report: &OutdatedReport
             ]);
         }
 
+        if !entry.vulnerabilities.is_empty() {
+            vulnerable_count += 1;
+        }
+        for vulnerability in &entry.vulnerabilities {
+            let label = match &vulnerability.summary {
+                Some(summary) => format!("{} - {}", vulnerability.label(), summary),
+                None => vulnerability.label(),
+            };
+            table = table.row(vec![
+                "".to_string(),
+                format!("{}: {}", "Vulnerability".red(), label.dimmed()),
+            ]);
+        }
+
         if let Some(path) = &entry.source_path {
             table = table.row(vec![
                 "".to_string(),
@@ -84,7 +99,7 @@ pub(in crate::commands::updates) fn render_outdated_text(report: &OutdatedReport
     table.render();
 
     println!();
-    if outdated_count == 0 && advisory_count == 0 {
+    if outdated_count == 0 && advisory_count == 0 && vulnerable_count == 0 {
         ui::success("All managed tools are up to date.");
     } else {
         if outdated_count > 0 {
@@ -99,5 +114,11 @@ pub(in crate::commands::updates) fn render_outdated_text(report: &OutdatedReport
                 advisory_count
             ));
         }
+        if vulnerable_count > 0 {
+            ui::warning(&format!(
+                "{} tool(s) have known vulnerabilities",
+                vulnerable_count
+            ));
+        }
     }
 }
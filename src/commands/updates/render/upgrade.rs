@@ -1,4 +1,6 @@
-use crate::commands::updates::UpgradeReport;
+use crate::commands::updates::{UpgradeEntry, UpgradeReport};
+use crate::config::UpgradeStrategy;
+use crate::fs_utils::format_bytes;
 use crate::ui;
 use owo_colors::OwoColorize;
 
@@ -8,28 +10,24 @@ pub(in crate::commands::updates) fn render_upgrade_text(report: &UpgradeReport)
         return;
     }
 
+    if report.dry_run {
+        render_upgrade_plan(report);
+        return;
+    }
+
     ui::header(&format!("Upgrade scope: {}", report.scope.cyan()));
 
     let mut summary = ui::Summary::new();
 
     for entry in &report.entries {
-        let message = format!(
-            "{}  {} → {}",
-            entry.tool.yellow(),
-            entry.previous_version.dimmed(),
-            entry.target_version.cyan()
-        );
+        let message = version_change(entry);
 
         match entry.status.as_str() {
             "already_latest" => summary = summary.info(message),
             "upgraded" => {
                 summary = summary.success(message);
-                if let Some(path) = &entry.source_path {
-                    summary = summary.info(format!(
-                        "  Updated: {} ({})",
-                        path.dimmed(),
-                        super::source_label(entry.source).dimmed()
-                    ));
+                if let Some(rewrite) = pin_rewrite(entry) {
+                    summary = summary.info(format!("  Updated: {}", rewrite));
                 }
             }
             _ => summary = summary.info(message),
@@ -38,3 +36,90 @@ pub(in crate::commands::updates) fn render_upgrade_text(report: &UpgradeReport)
 
     summary.render();
 }
+
+fn render_upgrade_plan(report: &UpgradeReport) {
+    ui::header(&format!(
+        "Upgrade plan (dry run), scope: {}",
+        report.scope.cyan()
+    ));
+
+    let mut summary = ui::Summary::new();
+    let mut total_download = 0;
+    let mut unknown_sizes = false;
+
+    for entry in &report.entries {
+        if entry.status != "planned" {
+            summary = summary.info(format!("{}  (already latest)", version_change(entry)));
+            continue;
+        }
+
+        summary = summary.success(version_change(entry));
+        if let Some(rewrite) = pin_rewrite(entry) {
+            summary = summary.info(format!("  Rewrites: {}", rewrite));
+        }
+
+        let download = match (entry.needs_install, entry.download_bytes) {
+            (false, _) => "already installed".to_string(),
+            (true, Some(bytes)) => {
+                total_download += bytes;
+                format_bytes(bytes)
+            }
+            (true, None) => {
+                unknown_sizes = true;
+                "size unknown".to_string()
+            }
+        };
+        summary = summary.info(format!("  Download: {}", download.dimmed()));
+    }
+
+    summary.render();
+
+    let planned = report
+        .entries
+        .iter()
+        .filter(|entry| entry.status == "planned")
+        .count();
+    if planned == 0 {
+        return;
+    }
+
+    println!();
+    println!(
+        "{} upgrade(s), {}{} to download.",
+        planned,
+        format_bytes(total_download),
+        if unknown_sizes { " or more" } else { "" }
+    );
+    ui::dimmed("Nothing is switched until every download succeeds; a failed switch or version file rewrite restores the previous state.");
+}
+
+fn version_change(entry: &UpgradeEntry) -> String {
+    format!(
+        "{}  {} → {}  {}",
+        entry.tool.yellow(),
+        entry.previous_version.dimmed(),
+        entry.target_version.cyan(),
+        format!("({})", strategy_label(entry.strategy)).dimmed()
+    )
+}
+
+fn pin_rewrite(entry: &UpgradeEntry) -> Option<String> {
+    let path = entry.source_path.as_ref()?;
+    let pin = entry.new_pin.as_ref()?;
+    Some(format!(
+        "{} ({}) → {} {}",
+        path.dimmed(),
+        super::source_label(entry.source).dimmed(),
+        entry.tool,
+        pin.cyan()
+    ))
+}
+
+fn strategy_label(strategy: UpgradeStrategy) -> &'static str {
+    match strategy {
+        UpgradeStrategy::Patch => "patch",
+        UpgradeStrategy::Minor => "minor",
+        UpgradeStrategy::Major => "major",
+        UpgradeStrategy::Lts => "lts",
+    }
+}
@@ -0,0 +1,86 @@
+//! Upgrade windows and pin rewriting for `vex upgrade`.
+
+use crate::config::UpgradeStrategy;
+use crate::versioning::{normalize_version, version_sort_key};
+
+/// Whether a pin is a plain version or version prefix (`20`, `3.12`, `v20.11.0`) rather than
+/// an alias such as `lts` or `lts-iron`.
+pub(super) fn is_numeric_pin(pin: &str) -> bool {
+    let version = normalize_version(pin);
+    !version.is_empty()
+        && version
+            .split('.')
+            .all(|segment| !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// The version request that finds the newest release `strategy` allows from `current`.
+///
+/// Go and Rust ship every release as `1.x`, so a "minor" move (`1.22` to `1.23`) is their
+/// feature release and `minor` stays on the `1.x` line like `patch` does.
+pub(super) fn upgrade_request(tool: &str, current: &str, strategy: UpgradeStrategy) -> String {
+    let segments = current.split('.').collect::<Vec<_>>();
+    let line = if has_fixed_major(tool) { 2 } else { 1 };
+    match strategy {
+        UpgradeStrategy::Major => "latest".to_string(),
+        UpgradeStrategy::Lts if has_lts_channel(tool) => "lts".to_string(),
+        UpgradeStrategy::Minor | UpgradeStrategy::Lts => {
+            segments[..segments.len().min(line)].join(".")
+        }
+        UpgradeStrategy::Patch => segments[..segments.len().min(2)].join("."),
+    }
+}
+
+/// Tools whose major version never changes, so their release line is `major.minor`.
+fn has_fixed_major(tool: &str) -> bool {
+    matches!(tool, "go" | "rust")
+}
+
+/// The release line a partial pin allows (`20` or `20.11` while `20.11.0` is in use), or `None`
+/// for exact pins and aliases.
+pub(super) fn pin_constraint(pin: &str, current: &str) -> Option<String> {
+    if !is_numeric_pin(pin) {
+        return None;
+    }
+    let pin = normalize_version(pin);
+    (pin.split('.').count() < current.split('.').count()).then_some(pin)
+}
+
+/// Node.js and Java publish LTS lines; other tools have no LTS channel to follow.
+pub(super) fn has_lts_channel(tool: &str) -> bool {
+    matches!(tool, "node" | "java")
+}
+
+/// The newer of two versions, so a strategy never moves a tool backwards.
+pub(super) fn newer_version(current: String, candidate: String) -> String {
+    if version_sort_key(&candidate) > version_sort_key(&current) {
+        candidate
+    } else {
+        current
+    }
+}
+
+/// The pin to write for `target`, keeping the original pin's precision and `v` prefix.
+///
+/// Returns `None` when the pin already covers `target` (`node 20` for `20.19.5`) or is an
+/// alias, in which case the version file is left untouched.
+pub(super) fn rewrite_pin(pin: &str, target: &str) -> Option<String> {
+    if !is_numeric_pin(pin) {
+        return None;
+    }
+
+    let precision = normalize_version(pin).split('.').count();
+    let rewritten = target
+        .split('.')
+        .take(precision)
+        .collect::<Vec<_>>()
+        .join(".");
+    if rewritten == normalize_version(pin) {
+        return None;
+    }
+
+    let prefix = if pin.starts_with('v') { "v" } else { "" };
+    Some(format!("{}{}", prefix, rewritten))
+}
+
+#[cfg(test)]
+mod tests;
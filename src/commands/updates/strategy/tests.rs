@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn test_numeric_pins_and_aliases() {
+    assert!(is_numeric_pin("20"));
+    assert!(is_numeric_pin("v20.11.0"));
+    assert!(is_numeric_pin("3.12"));
+    assert!(!is_numeric_pin("lts"));
+    assert!(!is_numeric_pin("lts-iron"));
+    assert!(!is_numeric_pin("20.x"));
+    assert!(!is_numeric_pin(""));
+}
+
+#[test]
+fn test_upgrade_request_per_strategy() {
+    assert_eq!(
+        upgrade_request("node", "20.11.0", UpgradeStrategy::Patch),
+        "20.11"
+    );
+    assert_eq!(
+        upgrade_request("node", "20.11.0", UpgradeStrategy::Minor),
+        "20"
+    );
+    assert_eq!(
+        upgrade_request("node", "20.11.0", UpgradeStrategy::Major),
+        "latest"
+    );
+    assert_eq!(
+        upgrade_request("node", "20.11.0", UpgradeStrategy::Lts),
+        "lts"
+    );
+    assert_eq!(upgrade_request("java", "17", UpgradeStrategy::Patch), "17");
+    assert_eq!(
+        upgrade_request("go", "1.23.5", UpgradeStrategy::Lts),
+        "1.23"
+    );
+    assert_eq!(
+        upgrade_request("rust", "1.80.1", UpgradeStrategy::Minor),
+        "1.80"
+    );
+}
+
+#[test]
+fn test_partial_pins_constrain_the_release_line() {
+    assert_eq!(pin_constraint("20", "20.11.0"), Some("20".to_string()));
+    assert_eq!(
+        pin_constraint("v20.11", "20.11.0"),
+        Some("20.11".to_string())
+    );
+    assert_eq!(pin_constraint("20.11.0", "20.11.0"), None);
+    assert_eq!(pin_constraint("lts-iron", "20.19.5"), None);
+}
+
+#[test]
+fn test_newer_version_never_downgrades() {
+    assert_eq!(
+        newer_version("20.11.0".to_string(), "20.19.5".to_string()),
+        "20.19.5"
+    );
+    assert_eq!(
+        newer_version("25.1.0".to_string(), "24.11.1".to_string()),
+        "25.1.0"
+    );
+}
+
+#[test]
+fn test_rewrite_pin_keeps_precision() {
+    assert_eq!(rewrite_pin("20", "20.19.5"), None);
+    assert_eq!(rewrite_pin("20", "24.11.1"), Some("24".to_string()));
+    assert_eq!(rewrite_pin("3.12", "3.12.9"), None);
+    assert_eq!(rewrite_pin("3.12", "3.13.2"), Some("3.13".to_string()));
+    assert_eq!(
+        rewrite_pin("20.11.0", "20.19.5"),
+        Some("20.19.5".to_string())
+    );
+    assert_eq!(
+        rewrite_pin("v20.11.0", "20.19.5"),
+        Some("v20.19.5".to_string())
+    );
+    assert_eq!(rewrite_pin("lts-iron", "20.19.5"), None);
+}
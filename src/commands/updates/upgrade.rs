@@ -1,99 +1,68 @@
+mod plan;
+mod transaction;
+
 use super::{
-    targets::{collect_targets, normalize_version, ManagedTarget},
-    ManagedSource, UpgradeEntry, UpgradeReport,
+    targets::{collect_targets, ManagedTarget},
+    UpgradeEntry, UpgradeReport,
 };
-use crate::config;
+use crate::config::{self, UpgradeStrategy};
 use crate::error::{Result, VexError};
-use crate::installer;
-use crate::switcher;
-use crate::tools;
-use crate::version_files;
 
-pub(super) fn upgrade_all() -> Result<UpgradeReport> {
+pub(super) fn upgrade_all(
+    strategy: Option<UpgradeStrategy>,
+    dry_run: bool,
+) -> Result<UpgradeReport> {
     let (scope, targets) = collect_targets(None)?;
-    if targets.is_empty() {
-        return Ok(UpgradeReport {
-            scope,
-            entries: Vec::new(),
-        });
-    }
-
-    let mut entries = Vec::new();
-    for target in targets {
-        entries.push(upgrade_target(&target)?);
-    }
-
-    entries.sort_by(|a, b| a.tool.cmp(&b.tool));
-    Ok(UpgradeReport { scope, entries })
+    run_upgrade(scope, targets, strategy, dry_run)
 }
 
-pub(super) fn upgrade_one(tool_name: &str) -> Result<UpgradeReport> {
+pub(super) fn upgrade_one(
+    tool_name: &str,
+    strategy: Option<UpgradeStrategy>,
+    dry_run: bool,
+) -> Result<UpgradeReport> {
     let (scope, targets) = collect_targets(Some(tool_name))?;
-    let target = targets
-        .into_iter()
-        .next()
-        .ok_or_else(|| VexError::VersionNotFound {
+    if targets.is_empty() {
+        return Err(VexError::VersionNotFound {
             tool: tool_name.to_string(),
             version: "managed version".to_string(),
             suggestions: String::new(),
-        })?;
-
-    Ok(UpgradeReport {
-        scope,
-        entries: vec![upgrade_target(&target)?],
-    })
-}
-
-fn upgrade_target(target: &ManagedTarget) -> Result<UpgradeEntry> {
-    let tool = tools::get_tool(&target.tool)?;
-    let latest = tools::resolve_fuzzy_version(tool.as_ref(), "latest")?;
-    let previous_version = normalize_version(&target.version);
-    let target_version = normalize_version(&latest);
-
-    let status = if previous_version == target_version {
-        "already_latest".to_string()
-    } else {
-        install_and_activate_target(tool.as_ref(), target, &target_version)?;
-        "upgraded".to_string()
-    };
+        });
+    }
 
-    Ok(UpgradeEntry {
-        tool: target.tool.clone(),
-        previous_version,
-        target_version,
-        status,
-        source: target.source,
-        source_path: target
-            .source_path
-            .as_ref()
-            .map(|path| path.display().to_string()),
-    })
+    run_upgrade(scope, targets, strategy, dry_run)
 }
 
-fn install_and_activate_target(
-    tool: &dyn tools::Tool,
-    target: &ManagedTarget,
-    target_version: &str,
-) -> Result<()> {
+/// Resolve every target first, then either report the plan or apply it as one transaction.
+fn run_upgrade(
+    scope: String,
+    targets: Vec<ManagedTarget>,
+    strategy: Option<UpgradeStrategy>,
+    dry_run: bool,
+) -> Result<UpgradeReport> {
     let vex_dir = config::vex_home().ok_or(VexError::HomeDirectoryNotFound)?;
-    let install_dir = vex_dir
-        .join("toolchains")
-        .join(&target.tool)
-        .join(target_version);
+    let plan = plan::plan_upgrades(&vex_dir, targets, strategy, dry_run)?;
 
-    if !install_dir.exists() {
-        installer::install(tool, target_version)?;
+    if !dry_run {
+        transaction::apply(&vex_dir, &plan)?;
     }
-    switcher::switch_version(tool, target_version)?;
 
-    if matches!(
-        target.source,
-        ManagedSource::Project | ManagedSource::Global
-    ) {
-        if let Some(source_path) = &target.source_path {
-            version_files::write_tool_version(source_path, &target.tool, target_version)?;
-        }
-    }
+    let mut entries = plan
+        .into_iter()
+        .map(|upgrade| {
+            let status = match (upgrade.is_upgrade(), dry_run) {
+                (false, _) => "already_latest",
+                (true, true) => "planned",
+                (true, false) => "upgraded",
+            };
+            upgrade.into_entry(status)
+        })
+        .collect::<Vec<UpgradeEntry>>();
+    entries.sort_by(|a, b| a.tool.cmp(&b.tool));
 
-    Ok(())
+    Ok(UpgradeReport {
+        scope,
+        dry_run,
+        entries,
+    })
 }
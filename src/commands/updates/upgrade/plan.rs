@@ -0,0 +1,175 @@
+use crate::commands::updates::strategy::{
+    is_numeric_pin, newer_version, pin_constraint, rewrite_pin, upgrade_request,
+};
+use crate::commands::updates::targets::{normalize_version, ManagedTarget};
+use crate::commands::updates::{ManagedSource, UpgradeEntry};
+use crate::config::{self, UpgradeStrategy};
+use crate::error::Result;
+use crate::http;
+use crate::requested_versions;
+use crate::resolver;
+use crate::tools::{self, Arch, Tool};
+use crate::version_state;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::debug;
+
+/// One managed target of an upgrade run, resolved before anything is installed or switched.
+#[derive(Debug)]
+pub(super) struct PlannedUpgrade {
+    pub(super) target: ManagedTarget,
+    pub(super) previous_version: String,
+    pub(super) target_version: String,
+    pub(super) strategy: UpgradeStrategy,
+    /// Replacement pin for the target's version file; `None` when the pin stays as written.
+    pub(super) new_pin: Option<String>,
+    pub(super) needs_install: bool,
+    pub(super) download_bytes: Option<u64>,
+}
+
+impl PlannedUpgrade {
+    pub(super) fn is_upgrade(&self) -> bool {
+        self.previous_version != self.target_version
+    }
+
+    /// The version file this upgrade rewrites and the pin written to it.
+    pub(super) fn pin_rewrite(&self) -> Option<(&Path, &str)> {
+        let path = self.target.source_path.as_deref()?;
+        let pin = self.new_pin.as_deref()?;
+        Some((path, pin))
+    }
+
+    pub(super) fn into_entry(self, status: &str) -> UpgradeEntry {
+        UpgradeEntry {
+            tool: self.target.tool,
+            previous_version: self.previous_version,
+            target_version: self.target_version,
+            strategy: self.strategy,
+            new_pin: self.new_pin,
+            status: status.to_string(),
+            source: self.target.source,
+            source_path: self
+                .target
+                .source_path
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            needs_install: self.needs_install,
+            download_bytes: self.download_bytes,
+        }
+    }
+}
+
+/// Resolve the version each target moves to under its upgrade strategy.
+///
+/// `strategy` overrides the per-tool defaults from `[upgrade]` in `config.toml`. Numeric pins
+/// are upgraded within the strategy's window and rewritten at their original precision; a
+/// partial pin such as `20` or `20.11` also caps the window to its line unless `major` was
+/// requested explicitly. Alias pins such as `lts-iron` resolve through the alias and are never
+/// rewritten. Download sizes
+/// are only looked up when `with_sizes` is set, since they cost one request per missing
+/// toolchain.
+pub(super) fn plan_upgrades(
+    vex_dir: &Path,
+    targets: Vec<ManagedTarget>,
+    strategy: Option<UpgradeStrategy>,
+    with_sizes: bool,
+) -> Result<Vec<PlannedUpgrade>> {
+    let settings = config::load_settings()?;
+    let active = version_state::read_current_versions(vex_dir)?;
+
+    let explicit_major = strategy == Some(UpgradeStrategy::Major);
+    let mut plan = Vec::with_capacity(targets.len());
+    for target in targets {
+        let tool = tools::get_tool(&target.tool)?;
+        let strategy = strategy.unwrap_or_else(|| settings.upgrade.strategy_for(&target.tool));
+        let previous_version = current_version(vex_dir, tool.as_ref(), &target, &active)?;
+
+        let request = if is_numeric_pin(&target.version) {
+            upgrade_request(&target.tool, &previous_version, strategy)
+        } else {
+            target.version.clone()
+        };
+        let mut resolved =
+            normalize_version(&tools::resolve_fuzzy_version(tool.as_ref(), &request)?);
+        let constraint = pin_constraint(&target.version, &previous_version)
+            .filter(|_| !explicit_major)
+            .filter(|line| !requested_versions::version_matches_request(&resolved, line));
+        if let Some(line) = constraint {
+            resolved = normalize_version(&tools::resolve_fuzzy_version(tool.as_ref(), &line)?);
+        }
+        let target_version = newer_version(previous_version.clone(), resolved);
+
+        let new_pin = match target.source {
+            ManagedSource::Project | ManagedSource::Global => {
+                rewrite_pin(&target.version, &target_version)
+            }
+            ManagedSource::Active | ManagedSource::Installed => None,
+        };
+        let needs_install = previous_version != target_version
+            && !vex_dir
+                .join("toolchains")
+                .join(&target.tool)
+                .join(&target_version)
+                .exists();
+        let download_bytes = if needs_install && with_sizes {
+            download_size(tool.as_ref(), &target_version)
+        } else {
+            None
+        };
+
+        plan.push(PlannedUpgrade {
+            target,
+            previous_version,
+            target_version,
+            strategy,
+            new_pin,
+            needs_install,
+            download_bytes,
+        });
+    }
+    Ok(plan)
+}
+
+/// The concrete version a target currently stands for: the newest installed version matching a
+/// numeric pin, the active version for an alias pin, or what the pin resolves to otherwise.
+fn current_version(
+    vex_dir: &Path,
+    tool: &dyn Tool,
+    target: &ManagedTarget,
+    active: &HashMap<String, String>,
+) -> Result<String> {
+    if !is_numeric_pin(&target.version) {
+        if let Some(version) = active.get(&target.tool) {
+            return Ok(version.clone());
+        }
+    } else if let Some(installed) =
+        requested_versions::resolve_installed_version(vex_dir, &target.tool, &target.version)?
+    {
+        return Ok(installed);
+    }
+
+    Ok(normalize_version(&tools::resolve_fuzzy_version(
+        tool,
+        &target.version,
+    )?))
+}
+
+fn download_size(tool: &dyn Tool, version: &str) -> Option<u64> {
+    let lookup = || -> Result<Option<u64>> {
+        let settings = config::load_effective_settings(&resolver::current_dir())?;
+        let upstream_url = tool.download_url(version, Arch::detect()?)?;
+        let url =
+            config::rewrite_download_url_with_settings(&settings, tool.name(), &upstream_url)?;
+        http::content_length_in_current_context(&url, concat!("vex/", env!("CARGO_PKG_VERSION")))
+    };
+
+    lookup().unwrap_or_else(|err| {
+        debug!(
+            "Could not determine download size for {}@{}: {}",
+            tool.name(),
+            version,
+            err
+        );
+        None
+    })
+}
@@ -0,0 +1,138 @@
+use super::plan::PlannedUpgrade;
+use crate::error::Result;
+use crate::installer;
+use crate::switcher;
+use crate::tools;
+use crate::version_files;
+use crate::version_state;
+use owo_colors::OwoColorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Apply an upgrade plan as a single unit.
+///
+/// Every missing toolchain is installed before anything is activated, so a failed download
+/// leaves the current setup untouched. Switching and version-file rewrites then run in order;
+/// if any of them fails, the activations and version files touched by the plan are restored
+/// to their state before the run.
+pub(super) fn apply(vex_dir: &Path, plan: &[PlannedUpgrade]) -> Result<()> {
+    let pending = plan
+        .iter()
+        .filter(|upgrade| upgrade.is_upgrade())
+        .collect::<Vec<_>>();
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    stage_installs(&pending)?;
+
+    let snapshot = Snapshot::capture(vex_dir, &pending)?;
+    for upgrade in &pending {
+        if let Err(err) = activate(upgrade) {
+            eprintln!(
+                "{} Upgrading {} to {} failed, restoring previous versions...",
+                "✗".red(),
+                upgrade.target.tool,
+                upgrade.target_version
+            );
+            snapshot.restore();
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+fn stage_installs(pending: &[&PlannedUpgrade]) -> Result<()> {
+    for upgrade in pending.iter().filter(|upgrade| upgrade.needs_install) {
+        let tool = tools::get_tool(&upgrade.target.tool)?;
+        if let Err(err) = installer::install(tool.as_ref(), &upgrade.target_version) {
+            eprintln!(
+                "{} Installing {}@{} failed; no versions were switched.",
+                "✗".red(),
+                upgrade.target.tool,
+                upgrade.target_version
+            );
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+fn activate(upgrade: &PlannedUpgrade) -> Result<()> {
+    let tool = tools::get_tool(&upgrade.target.tool)?;
+    switcher::switch_version(tool.as_ref(), &upgrade.target_version)?;
+    if let Some((path, pin)) = upgrade.pin_rewrite() {
+        version_files::write_tool_version(path, &upgrade.target.tool, pin)?;
+    }
+    Ok(())
+}
+
+/// Activations and version-file contents as they were before the plan was applied.
+#[derive(Debug)]
+struct Snapshot {
+    activations: Vec<(String, Option<String>)>,
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl Snapshot {
+    fn capture(vex_dir: &Path, pending: &[&PlannedUpgrade]) -> Result<Self> {
+        let current = version_state::read_current_versions(vex_dir)?;
+        let mut activations: Vec<(String, Option<String>)> = Vec::new();
+        let mut files: Vec<(PathBuf, Option<Vec<u8>>)> = Vec::new();
+
+        for upgrade in pending {
+            let tool = &upgrade.target.tool;
+            if !activations.iter().any(|(name, _)| name == tool) {
+                activations.push((tool.clone(), current.get(tool).cloned()));
+            }
+            if let Some((path, _)) = upgrade.pin_rewrite() {
+                if !files.iter().any(|(existing, _)| existing == path) {
+                    files.push((path.to_path_buf(), fs::read(path).ok()));
+                }
+            }
+        }
+
+        Ok(Self { activations, files })
+    }
+
+    fn restore(&self) {
+        let mut failures = self.restore_files();
+        for (tool_name, version) in &self.activations {
+            let restored = tools::get_tool(tool_name)
+                .and_then(|tool| switcher::restore_version(tool.as_ref(), version.as_deref()));
+            if let Err(err) = restored {
+                failures.push(format!("{} activation: {}", tool_name, err));
+            }
+        }
+
+        if failures.is_empty() {
+            eprintln!(
+                "{} Restored previous versions and version files",
+                "✓".green()
+            );
+        } else {
+            for failure in failures {
+                eprintln!("{} Could not restore {}", "⚠".yellow(), failure);
+            }
+        }
+    }
+
+    fn restore_files(&self) -> Vec<String> {
+        let mut failures = Vec::new();
+        for (path, content) in &self.files {
+            let restored = match content {
+                Some(bytes) => fs::write(path, bytes),
+                None if path.exists() => fs::remove_file(path),
+                None => Ok(()),
+            };
+            if let Err(err) = restored {
+                failures.push(format!("{}: {}", path.display(), err));
+            }
+        }
+        failures
+    }
+}
+
+#[cfg(test)]
+mod tests;
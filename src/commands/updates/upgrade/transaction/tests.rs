@@ -0,0 +1,89 @@
+use super::*;
+use crate::commands::updates::targets::ManagedTarget;
+use crate::commands::updates::ManagedSource;
+use crate::config::UpgradeStrategy;
+use std::os::unix::fs as unix_fs;
+use tempfile::TempDir;
+
+fn planned(tool: &str, from: &str, to: &str, source_path: Option<&Path>) -> PlannedUpgrade {
+    PlannedUpgrade {
+        target: ManagedTarget {
+            tool: tool.to_string(),
+            version: from.to_string(),
+            source: if source_path.is_some() {
+                ManagedSource::Project
+            } else {
+                ManagedSource::Active
+            },
+            source_path: source_path.map(Path::to_path_buf),
+        },
+        previous_version: from.to_string(),
+        target_version: to.to_string(),
+        strategy: UpgradeStrategy::Major,
+        new_pin: source_path.map(|_| to.to_string()),
+        needs_install: false,
+        download_bytes: None,
+    }
+}
+
+#[test]
+fn test_snapshot_records_each_tool_and_file_once() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    fs::create_dir_all(vex.join("toolchains/node/20.11.0")).unwrap();
+    fs::create_dir_all(vex.join("current")).unwrap();
+    unix_fs::symlink(
+        vex.join("toolchains/node/20.11.0"),
+        vex.join("current/node"),
+    )
+    .unwrap();
+    let pins = temp.path().join(".tool-versions");
+    fs::write(&pins, "node 20.11.0\ngo 1.22.0\n").unwrap();
+
+    let node = planned("node", "20.11.0", "22.1.0", Some(&pins));
+    let go = planned("go", "1.22.0", "1.23.5", Some(&pins));
+    let snapshot = Snapshot::capture(&vex, &[&node, &go]).unwrap();
+
+    assert_eq!(
+        snapshot.activations,
+        vec![
+            ("node".to_string(), Some("20.11.0".to_string())),
+            ("go".to_string(), None),
+        ]
+    );
+    assert_eq!(snapshot.files.len(), 1);
+    assert_eq!(snapshot.files[0].0, pins);
+}
+
+#[test]
+fn test_restore_files_puts_back_contents_and_removes_new_files() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let pins = temp.path().join(".tool-versions");
+    let created = temp.path().join(".node-version");
+    fs::write(&pins, "# team pins\nnode 20.11.0\n").unwrap();
+
+    let rewrite = planned("node", "20.11.0", "22.1.0", Some(&pins));
+    let create = planned("node", "20.11.0", "22.1.0", Some(&created));
+    let snapshot = Snapshot::capture(&vex, &[&rewrite, &create]).unwrap();
+
+    version_files::write_tool_version(&pins, "node", "22.1.0").unwrap();
+    version_files::write_tool_version(&created, "node", "22.1.0").unwrap();
+
+    assert!(snapshot.restore_files().is_empty());
+    assert_eq!(
+        fs::read_to_string(&pins).unwrap(),
+        "# team pins\nnode 20.11.0\n"
+    );
+    assert!(!created.exists());
+}
+
+#[test]
+fn test_apply_skips_targets_already_on_latest() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let current = planned("node", "22.1.0", "22.1.0", None);
+
+    apply(&vex, &[current]).unwrap();
+    assert!(!vex.join("current").exists());
+}
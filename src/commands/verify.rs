@@ -0,0 +1,289 @@
+//! `vex verify`
+//!
+//! Recomputes the SHA-256 of each toolchain's archive in `~/.vex/cache/archives` and compares it
+//! with the checksum recorded when the toolchain was installed, then checks the installed files
+//! against the `.vex-manifest.json` written at install time.
+
+use crate::archive_cache::ArchiveCache;
+use crate::checksum;
+use crate::error::{Result, VexError};
+use crate::output::{print_json, OutputMode};
+use crate::paths::vex_dir;
+use crate::requested_versions;
+use crate::spec::parse_spec;
+use crate::tool_metadata;
+use crate::toolchain_manifest::{self, CheckMode, IntegrityDiff};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Paths listed per kind of change before the rest are summarised; `--json` lists them all.
+const MAX_LISTED_PATHS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveStatus {
+    Verified,
+    Mismatch,
+    /// The archive is no longer in the cache, so there is nothing to hash.
+    NotCached,
+    /// The toolchain was installed without a published checksum.
+    NoChecksum,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchiveCheck {
+    pub status: ArchiveStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilesStatus {
+    Intact,
+    Changed,
+    /// Installed before vex wrote manifests, so there is nothing to compare against.
+    NoManifest,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilesCheck {
+    pub status: FilesStatus,
+    #[serde(flatten)]
+    pub diff: IntegrityDiff,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ToolchainCheck {
+    pub tool: String,
+    pub version: String,
+    pub archive: ArchiveCheck,
+    pub files: FilesCheck,
+}
+
+impl ToolchainCheck {
+    pub fn failed(&self) -> bool {
+        self.archive.status == ArchiveStatus::Mismatch || self.files.status == FilesStatus::Changed
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct VerifyReport {
+    pub toolchains: Vec<ToolchainCheck>,
+    pub failed: usize,
+}
+
+/// Verify every installed toolchain, or those matching `spec` (`tool` or `tool@version`).
+pub fn run(spec: Option<&str>, output: OutputMode) -> Result<()> {
+    let vex = vex_dir()?;
+    let toolchains = select_toolchains(&vex, spec)?;
+    let cache = ArchiveCache::new(&vex);
+    let checks = toolchains
+        .into_iter()
+        .map(|(tool, version)| check_toolchain(&vex, &cache, tool, version))
+        .collect::<Result<Vec<_>>>()?;
+    let report = VerifyReport {
+        failed: checks.iter().filter(|check| check.failed()).count(),
+        toolchains: checks,
+    };
+
+    match output {
+        OutputMode::Json => print_json(&report)?,
+        OutputMode::Text => render(&report),
+    }
+    if report.failed > 0 {
+        return Err(VexError::Config(format!(
+            "{} toolchain(s) failed verification. Reinstall them with 'vex repair toolchain'.",
+            report.failed
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn select_toolchains(vex: &Path, spec: Option<&str>) -> Result<Vec<(String, String)>> {
+    let installed = tool_metadata::installed_toolchains(vex)?;
+    let Some(spec) = spec else {
+        return Ok(installed);
+    };
+
+    let (tool, version) = parse_spec(spec)?;
+    if version.is_empty() {
+        let selected = installed
+            .into_iter()
+            .filter(|(installed_tool, _)| *installed_tool == tool)
+            .collect::<Vec<_>>();
+        if selected.is_empty() {
+            return Err(VexError::Config(format!(
+                "No {} versions are installed.",
+                tool
+            )));
+        }
+        return Ok(selected);
+    }
+
+    match requested_versions::resolve_installed_version(vex, &tool, &version)? {
+        Some(exact) => Ok(vec![(tool, exact)]),
+        None => Err(VexError::VersionNotFound {
+            tool,
+            version,
+            suggestions: String::new(),
+        }),
+    }
+}
+
+pub(crate) fn check_toolchain(
+    vex: &Path,
+    cache: &ArchiveCache,
+    tool: String,
+    version: String,
+) -> Result<ToolchainCheck> {
+    let archive = check_archive(vex, cache, &tool, &version)?;
+    let files = check_files(&vex.join("toolchains").join(&tool).join(&version))?;
+    Ok(ToolchainCheck {
+        tool,
+        version,
+        archive,
+        files,
+    })
+}
+
+fn check_files(install_dir: &Path) -> Result<FilesCheck> {
+    let Some(manifest) = toolchain_manifest::read_manifest(install_dir)? else {
+        return Ok(FilesCheck {
+            status: FilesStatus::NoManifest,
+            diff: IntegrityDiff::default(),
+        });
+    };
+    let diff = toolchain_manifest::compare(install_dir, &manifest, CheckMode::Full)?;
+    Ok(FilesCheck {
+        status: if diff.is_clean() {
+            FilesStatus::Intact
+        } else {
+            FilesStatus::Changed
+        },
+        diff,
+    })
+}
+
+fn check_archive(
+    vex: &Path,
+    cache: &ArchiveCache,
+    tool: &str,
+    version: &str,
+) -> Result<ArchiveCheck> {
+    let install_dir = vex.join("toolchains").join(tool).join(version);
+    let expected = match tool_metadata::read_metadata(&install_dir)? {
+        Some(metadata) if metadata.provenance.checksum.is_some() => metadata.provenance.checksum,
+        _ => fs::read_to_string(install_dir.join(".vex-checksum"))
+            .ok()
+            .map(|checksum| checksum.trim().to_string()),
+    };
+    let archive = cache.get_archive(tool, version, &format!("{}-{}.tar.gz", tool, version));
+
+    let (status, actual) = match (&expected, &archive) {
+        (None, _) => (ArchiveStatus::NoChecksum, None),
+        (Some(_), None) => (ArchiveStatus::NotCached, None),
+        (Some(expected), Some(archive)) => {
+            let actual = checksum::sha256_hex(archive)?;
+            let status = if actual.eq_ignore_ascii_case(expected) {
+                ArchiveStatus::Verified
+            } else {
+                ArchiveStatus::Mismatch
+            };
+            (status, Some(actual))
+        }
+    };
+
+    Ok(ArchiveCheck {
+        status,
+        path: archive.map(|path| path.display().to_string()),
+        expected,
+        actual,
+    })
+}
+
+fn render(report: &VerifyReport) {
+    if report.toolchains.is_empty() {
+        println!("{}", "No toolchains installed.".dimmed());
+        return;
+    }
+
+    for check in &report.toolchains {
+        let label = format!("{}@{}", check.tool, check.version);
+        match check.archive.status {
+            ArchiveStatus::Verified => println!(
+                "{} {} archive matches its recorded checksum",
+                "✓".green(),
+                label.cyan()
+            ),
+            ArchiveStatus::Mismatch => {
+                println!("{} {} archive checksum mismatch", "✗".red(), label.cyan());
+                if let (Some(expected), Some(actual)) =
+                    (&check.archive.expected, &check.archive.actual)
+                {
+                    println!("    {} {}", "expected".dimmed(), expected);
+                    println!("    {} {}", "actual  ".dimmed(), actual);
+                }
+            }
+            ArchiveStatus::NotCached => println!(
+                "{} {} archive is not in the cache; nothing to verify",
+                "-".dimmed(),
+                label.cyan()
+            ),
+            ArchiveStatus::NoChecksum => {
+                println!("{} {} has no recorded checksum", "-".dimmed(), label.cyan())
+            }
+        }
+        render_files(&label, &check.files);
+    }
+}
+
+fn render_files(label: &str, files: &FilesCheck) {
+    match files.status {
+        FilesStatus::Intact => println!(
+            "{} {} files match the install manifest",
+            "✓".green(),
+            label.cyan()
+        ),
+        FilesStatus::Changed => {
+            println!(
+                "{} {} files differ from the install manifest ({} modified, {} missing, {} extra)",
+                "✗".red(),
+                label.cyan(),
+                files.diff.modified.len(),
+                files.diff.missing.len(),
+                files.diff.extra.len()
+            );
+            for (kind, paths) in [
+                ("modified", &files.diff.modified),
+                ("missing ", &files.diff.missing),
+                ("extra   ", &files.diff.extra),
+            ] {
+                for path in paths.iter().take(MAX_LISTED_PATHS) {
+                    println!("    {} {}", kind.dimmed(), path);
+                }
+                if paths.len() > MAX_LISTED_PATHS {
+                    println!(
+                        "    {} ... and {} more",
+                        kind.dimmed(),
+                        paths.len() - MAX_LISTED_PATHS
+                    );
+                }
+            }
+        }
+        FilesStatus::NoManifest => println!(
+            "{} {} has no install manifest; reinstall it to record one",
+            "-".dimmed(),
+            label.cyan()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,94 @@
+use super::*;
+use tempfile::TempDir;
+
+fn install(vex: &Path, tool: &str, version: &str, checksum: Option<&str>) {
+    let install_dir = vex.join("toolchains").join(tool).join(version);
+    fs::create_dir_all(&install_dir).unwrap();
+    if let Some(checksum) = checksum {
+        fs::write(install_dir.join(".vex-checksum"), checksum).unwrap();
+    }
+}
+
+fn cache_archive(vex: &Path, tool: &str, version: &str, contents: &[u8]) {
+    let dir = vex.join("cache/archives").join(tool).join(version);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(format!("{}-{}.tar.gz", tool, version)), contents).unwrap();
+}
+
+#[test]
+fn test_check_archive_reports_each_status() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path();
+    let cache = ArchiveCache::new(vex);
+    let good = checksum::sha256_hex_bytes(b"archive");
+
+    install(vex, "node", "20.11.0", Some(&good));
+    cache_archive(vex, "node", "20.11.0", b"archive");
+    install(vex, "node", "22.1.0", Some(&good));
+    cache_archive(vex, "node", "22.1.0", b"tampered");
+    install(vex, "go", "1.24.0", Some(&good));
+    install(vex, "rust", "1.93.0", None);
+
+    let status = |tool, version| check_archive(vex, &cache, tool, version).unwrap().status;
+    assert_eq!(status("node", "20.11.0"), ArchiveStatus::Verified);
+    assert_eq!(status("node", "22.1.0"), ArchiveStatus::Mismatch);
+    assert_eq!(status("go", "1.24.0"), ArchiveStatus::NotCached);
+    assert_eq!(status("rust", "1.93.0"), ArchiveStatus::NoChecksum);
+
+    let mismatch = check_archive(vex, &cache, "node", "22.1.0").unwrap();
+    assert_eq!(mismatch.expected.as_deref(), Some(good.as_str()));
+    assert_eq!(
+        mismatch.actual,
+        Some(checksum::sha256_hex_bytes(b"tampered"))
+    );
+}
+
+#[test]
+fn test_select_toolchains_filters_by_tool_and_version() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path();
+    install(vex, "node", "20.11.0", None);
+    install(vex, "node", "22.1.0", None);
+    install(vex, "go", "1.24.0", None);
+    install(vex, "node", ".20.12.0.importing", None);
+
+    assert_eq!(select_toolchains(vex, None).unwrap().len(), 3);
+    assert_eq!(
+        select_toolchains(vex, Some("node")).unwrap(),
+        vec![
+            ("node".to_string(), "20.11.0".to_string()),
+            ("node".to_string(), "22.1.0".to_string()),
+        ]
+    );
+    assert_eq!(
+        select_toolchains(vex, Some("node@22")).unwrap(),
+        vec![("node".to_string(), "22.1.0".to_string())]
+    );
+    assert!(select_toolchains(vex, Some("python")).is_err());
+    assert!(select_toolchains(vex, Some("node@18")).is_err());
+}
+
+#[test]
+fn test_check_toolchain_compares_files_with_manifest() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path();
+    let cache = ArchiveCache::new(vex);
+    install(vex, "node", "20.11.0", None);
+    install(vex, "go", "1.24.0", None);
+    let install_dir = vex.join("toolchains/node/20.11.0");
+    fs::write(install_dir.join("README.md"), "node").unwrap();
+    toolchain_manifest::write_manifest(&install_dir).unwrap();
+
+    let check = |tool: &str, version: &str| {
+        check_toolchain(vex, &cache, tool.to_string(), version.to_string()).unwrap()
+    };
+    assert_eq!(check("node", "20.11.0").files.status, FilesStatus::Intact);
+    assert_eq!(check("go", "1.24.0").files.status, FilesStatus::NoManifest);
+    assert!(!check("go", "1.24.0").failed());
+
+    fs::write(install_dir.join("README.md"), "edited").unwrap();
+    let changed = check("node", "20.11.0");
+    assert_eq!(changed.files.status, FilesStatus::Changed);
+    assert_eq!(changed.files.diff.modified, vec!["README.md".to_string()]);
+    assert!(changed.failed());
+}
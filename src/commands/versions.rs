@@ -84,5 +84,15 @@ pub fn list_remote(
     }
 }
 
+/// Installed versions of a tool, for callers that render them themselves.
+pub fn installed_versions(tool_name: &str) -> Result<InstalledVersionsReport> {
+    collect_installed(tool_name)
+}
+
+/// Remote versions of a tool, preferring fresh cached data and never drawing a spinner.
+pub fn remote_versions(tool_name: &str, filter: RemoteFilter) -> Result<RemoteVersionsReport> {
+    collect_remote(tool_name, filter, true, false, false)
+}
+
 #[cfg(test)]
 mod tests;
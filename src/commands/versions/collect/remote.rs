@@ -93,5 +93,6 @@ fn fetch_versions_cached(tool: &dyn Tool, use_cache: bool, offline: bool) -> Res
 
     let versions = tool.list_remote()?;
     remote_cache.set_cached_versions(tool.name(), &versions);
+    crate::advisories::refresh_lifecycle(tool.name(), &versions);
     Ok(versions)
 }
@@ -0,0 +1,306 @@
+//! `vex which` and `vex where`
+//!
+//! `which` walks the `PATH` that `vex exec` would use, so the answer matches what actually runs
+//! in the current directory, and explains each hit: the vex-managed directory it comes from,
+//! the tool version, and the version file that selected it.
+
+use super::globals::{is_executable_file, MANAGED_BIN_DIRS};
+use crate::activation::{self, ActivationPlan};
+use crate::config;
+use crate::error::{Result, VexError};
+use crate::output::{print_json, OutputMode};
+use crate::paths::vex_dir;
+use crate::requested_versions;
+use crate::resolver;
+use crate::shell::detect_tool_manager;
+use crate::tools::{self, python};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One executable found on the activation `PATH`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Candidate {
+    pub path: String,
+    /// `toolchain`, `vex_bin`, `virtualenv`, `node_modules`, a `vex globals` kind such as
+    /// `npm_global`, or `external`.
+    pub kind: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Version file that selected `version`, or `~/.vex/current/<tool>` for the global default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_source: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WhichReport {
+    pub cwd: String,
+    pub command: String,
+    pub resolved: Candidate,
+    /// Later matches hidden by `resolved`; only filled with `--all`.
+    pub shadowed: Vec<Candidate>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WhereReport {
+    pub tool: String,
+    pub version: String,
+    pub path: String,
+}
+
+pub fn which(command: &str, all: bool, output: OutputMode) -> Result<()> {
+    let cwd = resolver::current_dir();
+    let plan = activation::build_activation_plan(&cwd)?;
+    let vex = vex_dir()?;
+    let toolchains_dir = config::toolchains_dir().ok_or(VexError::HomeDirectoryNotFound)?;
+    let path = activation::exec_path(&plan);
+
+    let mut candidates = find_candidates(command, &path)
+        .into_iter()
+        .map(|(dir, file)| classify(&cwd, &vex, &toolchains_dir, &plan, &dir, &file));
+    let resolved = candidates.next().ok_or_else(|| {
+        VexError::Config(format!(
+            "'{}' was not found on the PATH vex uses in {}.",
+            command,
+            cwd.display()
+        ))
+    })?;
+    let report = WhichReport {
+        cwd: cwd.display().to_string(),
+        command: command.to_string(),
+        resolved,
+        shadowed: if all {
+            candidates.collect()
+        } else {
+            Vec::new()
+        },
+    };
+
+    match output {
+        OutputMode::Json => print_json(&report),
+        OutputMode::Text => {
+            render_which(&report, all);
+            Ok(())
+        }
+    }
+}
+
+/// Print the toolchain directory of `spec`, or of the version active in this directory when
+/// `spec` has no version.
+pub fn where_spec(spec: &str, output: OutputMode) -> Result<()> {
+    let (tool_name, requested) = crate::spec::parse_spec(spec)?;
+    let tool = tools::get_tool(&tool_name)?;
+    let vex = vex_dir()?;
+    let version = if requested.is_empty() {
+        let plan = activation::build_activation_plan(&resolver::current_dir())?;
+        plan.versions.get(tool.name()).cloned().ok_or_else(|| {
+            VexError::Config(format!(
+                "No {} version is active here. Pass a version, for example 'vex where {}@20'.",
+                tool.name(),
+                tool.name()
+            ))
+        })?
+    } else {
+        requested_versions::resolve_installed_version(&vex, tool.name(), &requested)?.ok_or_else(
+            || VexError::VersionNotFound {
+                tool: tool.name().to_string(),
+                version: requested.clone(),
+                suggestions: format!(
+                    "\n\nInstall it first with 'vex install {}@{}'.",
+                    tool.name(),
+                    requested
+                ),
+            },
+        )?
+    };
+
+    let path = vex.join("toolchains").join(tool.name()).join(&version);
+    if !path.is_dir() {
+        return Err(VexError::VersionNotFound {
+            tool: tool.name().to_string(),
+            version,
+            suggestions: String::new(),
+        });
+    }
+    let report = WhereReport {
+        tool: tool.name().to_string(),
+        version,
+        path: path.display().to_string(),
+    };
+
+    match output {
+        OutputMode::Json => print_json(&report),
+        OutputMode::Text => {
+            println!("{}", report.path);
+            Ok(())
+        }
+    }
+}
+
+/// Every executable named `command` on `path`, in lookup order, as `(dir, file)`.
+fn find_candidates(command: &str, path: &str) -> Vec<(PathBuf, PathBuf)> {
+    let mut seen = Vec::new();
+    let mut candidates = Vec::new();
+    for entry in path.split(':').filter(|entry| !entry.is_empty()) {
+        let dir = PathBuf::from(entry);
+        if seen.contains(&dir) {
+            continue;
+        }
+        seen.push(dir.clone());
+        let file = dir.join(command);
+        if is_executable_file(&file) {
+            candidates.push((dir, file));
+        }
+    }
+    candidates
+}
+
+fn classify(
+    cwd: &Path,
+    vex: &Path,
+    toolchains_dir: &Path,
+    plan: &ActivationPlan,
+    dir: &Path,
+    file: &Path,
+) -> Candidate {
+    let mut candidate = Candidate {
+        path: file.display().to_string(),
+        kind: "external".to_string(),
+        description: match detect_tool_manager(&dir.to_string_lossy()) {
+            Some(manager) => format!("outside vex ({})", manager),
+            None => "outside vex".to_string(),
+        },
+        tool: None,
+        version: None,
+        version_source: None,
+    };
+    let active = |tool: &str| (Some(tool.to_string()), plan.versions.get(tool).cloned());
+
+    let (tool, version) = if let Some((tool, version)) = toolchain_of(toolchains_dir, dir) {
+        candidate.kind = "toolchain".to_string();
+        candidate.description = format!("{} {} toolchain", tool, version);
+        (Some(tool), Some(version))
+    } else if dir == vex.join("bin") {
+        candidate.kind = "vex_bin".to_string();
+        candidate.description = "global default link in ~/.vex/bin".to_string();
+        let target = fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+        let toolchains = fs::canonicalize(toolchains_dir).unwrap_or(toolchains_dir.to_path_buf());
+        match target
+            .parent()
+            .and_then(|parent| toolchain_of(&toolchains, parent))
+        {
+            Some((tool, version)) => {
+                candidate.version_source =
+                    Some(vex.join("current").join(&tool).display().to_string());
+                (Some(tool), Some(version))
+            }
+            None => (None, None),
+        }
+    } else if plan
+        .set_env
+        .get("VIRTUAL_ENV")
+        .is_some_and(|venv| dir == Path::new(venv).join("bin"))
+    {
+        candidate.kind = "virtualenv".to_string();
+        candidate.description = "project virtual environment".to_string();
+        active("python")
+    } else if dir.ends_with("node_modules/.bin") {
+        candidate.kind = "node_modules".to_string();
+        candidate.description = "project node_modules/.bin".to_string();
+        active("node")
+    } else if let Some((tool, kind, description, _)) = MANAGED_BIN_DIRS
+        .iter()
+        .find(|(_, _, _, managed)| dir == vex.join(managed))
+    {
+        candidate.kind = kind.to_string();
+        candidate.description = description.to_string();
+        active(tool)
+    } else if dir == python::user_bin_dir(vex) {
+        candidate.kind = "python_user_base".to_string();
+        candidate.description = "Python user base (pip --user)".to_string();
+        active("python")
+    } else if dir.starts_with(vex.join("python/base")) {
+        candidate.kind = "python_base".to_string();
+        candidate.description = "Python base environment (pip)".to_string();
+        active("python")
+    } else {
+        (None, None)
+    };
+
+    if candidate.version_source.is_none() {
+        if let (Some(tool), Some(version)) = (&tool, &version) {
+            candidate.version_source = version_source(cwd, vex, plan, tool, version);
+        }
+    }
+    candidate.tool = tool;
+    candidate.version = version;
+    candidate
+}
+
+/// `(tool, version)` when `dir` lies inside `toolchains_dir/<tool>/<version>`.
+fn toolchain_of(toolchains_dir: &Path, dir: &Path) -> Option<(String, String)> {
+    let mut components = dir.strip_prefix(toolchains_dir).ok()?.components();
+    let tool = components.next()?.as_os_str().to_string_lossy().to_string();
+    let version = components.next()?.as_os_str().to_string_lossy().to_string();
+    Some((tool, version))
+}
+
+/// The file that selected `tool@version` for `cwd`, if it is the version the plan activates.
+fn version_source(
+    cwd: &Path,
+    vex: &Path,
+    plan: &ActivationPlan,
+    tool: &str,
+    version: &str,
+) -> Option<String> {
+    if plan.versions.get(tool).map(String::as_str) != Some(version) {
+        return None;
+    }
+    let source = match resolver::version_requests(cwd, tool).into_iter().next() {
+        Some(request) => request.path,
+        None => vex.join("current").join(tool),
+    };
+    Some(source.display().to_string())
+}
+
+fn render_which(report: &WhichReport, all: bool) {
+    println!("{}", report.resolved.path);
+    render_details(&report.resolved);
+
+    if !all {
+        return;
+    }
+    println!();
+    if report.shadowed.is_empty() {
+        println!("{}", "No shadowed candidates.".dimmed());
+        return;
+    }
+    println!("{}", "Shadowed:".bold());
+    for candidate in &report.shadowed {
+        println!("  {}", candidate.path);
+        println!("    {}", describe(candidate).dimmed());
+    }
+}
+
+fn render_details(candidate: &Candidate) {
+    println!("  {} {}", "via".dimmed(), describe(candidate));
+    if let Some(source) = &candidate.version_source {
+        println!("  {} {}", "selected by".dimmed(), source.cyan());
+    }
+}
+
+fn describe(candidate: &Candidate) -> String {
+    match (&candidate.tool, &candidate.version, candidate.kind.as_str()) {
+        (Some(tool), Some(version), kind) if kind != "toolchain" => {
+            format!("{} ({}@{})", candidate.description, tool, version)
+        }
+        _ => candidate.description.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests;
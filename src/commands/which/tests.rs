@@ -0,0 +1,47 @@
+use super::*;
+use std::os::unix::fs::PermissionsExt;
+use tempfile::TempDir;
+
+fn executable(path: &Path) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, "#!/bin/sh\n").unwrap();
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[test]
+fn test_toolchain_of_reads_tool_and_version() {
+    let toolchains = Path::new("/home/me/.vex/toolchains");
+    assert_eq!(
+        toolchain_of(toolchains, &toolchains.join("node/20.11.0/bin")),
+        Some(("node".to_string(), "20.11.0".to_string()))
+    );
+    assert_eq!(toolchain_of(toolchains, &toolchains.join("node")), None);
+    assert_eq!(toolchain_of(toolchains, Path::new("/usr/bin")), None);
+}
+
+#[test]
+fn test_find_candidates_keeps_path_order_and_skips_non_executables() {
+    let temp = TempDir::new().unwrap();
+    let first = temp.path().join("first");
+    let second = temp.path().join("second");
+    executable(&first.join("node"));
+    executable(&second.join("node"));
+    fs::create_dir_all(temp.path().join("empty")).unwrap();
+    fs::write(temp.path().join("empty/node"), "not executable").unwrap();
+
+    let path = format!(
+        "{}:{}:{}:{}",
+        temp.path().join("empty").display(),
+        first.display(),
+        first.display(),
+        second.display()
+    );
+    let candidates = find_candidates("node", &path);
+    assert_eq!(
+        candidates,
+        vec![
+            (first.clone(), first.join("node")),
+            (second.clone(), second.join("node")),
+        ]
+    );
+}
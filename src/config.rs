@@ -24,7 +24,10 @@ use model::{
     BehaviorSettings, NetworkSettings, StrictSettings, BIN_DIR, CACHE_DIR, CURRENT_DIR,
     MAX_CACHE_TTL, MIN_CACHE_TTL, TOOLCHAINS_DIR, VEX_DIR_NAME,
 };
-pub use model::{Settings, CHECKSUM_BUFFER_SIZE, DOWNLOAD_BUFFER_SIZE, MIN_FREE_SPACE_BYTES};
+pub use model::{
+    PruneSettings, Settings, UpgradeStrategy, CHECKSUM_BUFFER_SIZE, DOWNLOAD_BUFFER_SIZE,
+    MIN_FREE_SPACE_BYTES,
+};
 #[cfg(test)]
 pub use model::{CONNECT_TIMEOUT, MAX_CONCURRENT_DOWNLOADS, READ_TIMEOUT, RETRY_BASE_DELAY};
 pub use paths::{
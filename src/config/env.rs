@@ -42,6 +42,9 @@ pub(super) fn apply_env_overrides(settings: &mut Settings) {
     if let Some(value) = env_bool("VEX_NON_INTERACTIVE") {
         settings.behavior.non_interactive = value;
     }
+    if let Some(value) = env_string("VEX_VULNERABILITY_FEED") {
+        settings.advisories.vulnerability_feed = Some(value);
+    }
 
     for (key, value) in std::env::vars() {
         if let Some(tool_name) = key.strip_prefix("VEX_MIRROR_") {
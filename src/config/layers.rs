@@ -31,6 +31,11 @@ pub(super) fn apply_file_config(settings: &mut Settings, file_config: FileConfig
         network,
         behavior,
         strict,
+        team_config,
+        policy,
+        advisories,
+        prune,
+        upgrade,
         mirrors,
     } = file_config;
 
@@ -53,8 +58,46 @@ pub(super) fn apply_file_config(settings: &mut Settings, file_config: FileConfig
         &mut settings.strict,
         strict.home_hygiene.map(|value| value.into_model()),
         strict.path_conflicts.map(|value| value.into_model()),
+        strict.team_config.map(|value| value.into_model()),
+        strict.policy.map(|value| value.into_model()),
     );
 
+    if let Some(trusted_keys) = team_config.trusted_keys {
+        settings.team_config.trusted_keys =
+            trusted_keys.into_iter().filter_map(non_empty).collect();
+    }
+
+    if let Some(policy) = policy {
+        settings.policy = policy;
+    }
+
+    if let Some(feed) = advisories.vulnerability_feed.and_then(non_empty) {
+        settings.advisories.vulnerability_feed = Some(feed);
+    }
+
+    if let Some(keep) = prune.keep_latest_per_major {
+        settings.prune.keep_latest_per_major = Some(keep.max(1));
+    }
+    if let Some(days) = prune.max_unused_days {
+        settings.prune.max_unused_days = Some(days);
+    }
+    if let Some(megabytes) = prune.max_toolchain_size_mb {
+        settings.prune.max_toolchain_bytes = Some(megabytes.saturating_mul(1024 * 1024));
+    }
+    if let Some(auto_after_upgrade) = prune.auto_after_upgrade {
+        settings.prune.auto_after_upgrade = auto_after_upgrade;
+    }
+
+    if let Some(strategy) = upgrade.strategy {
+        settings.upgrade.strategy = strategy.into_model();
+    }
+    for (tool, strategy) in upgrade.tools {
+        settings
+            .upgrade
+            .tools
+            .insert(tool.trim().to_ascii_lowercase(), strategy.into_model());
+    }
+
     apply_mirror_overrides(&mut settings.mirrors, mirrors);
 }
 
@@ -149,6 +192,8 @@ fn apply_strict_overrides(
     strict: &mut StrictSettings,
     home_hygiene: Option<StrictMode>,
     path_conflicts: Option<StrictMode>,
+    team_config: Option<StrictMode>,
+    policy: Option<StrictMode>,
 ) {
     if let Some(home_hygiene) = home_hygiene {
         strict.home_hygiene = home_hygiene;
@@ -156,6 +201,12 @@ fn apply_strict_overrides(
     if let Some(path_conflicts) = path_conflicts {
         strict.path_conflicts = path_conflicts;
     }
+    if let Some(team_config) = team_config {
+        strict.team_config = team_config;
+    }
+    if let Some(policy) = policy {
+        strict.policy = policy;
+    }
 }
 
 fn apply_mirror_overrides(mirrors: &mut HashMap<String, String>, entries: HashMap<String, String>) {
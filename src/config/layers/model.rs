@@ -1,4 +1,5 @@
-use crate::config::model::StrictMode;
+use crate::config::model::{StrictMode, UpgradeStrategy};
+use crate::policy::PolicyConfig;
 use crate::project;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -13,6 +14,15 @@ pub(in crate::config) struct FileConfig {
     #[serde(default)]
     pub(super) strict: StrictFileConfig,
     #[serde(default)]
+    pub(super) team_config: TeamConfigFileConfig,
+    pub(super) policy: Option<PolicyConfig>,
+    #[serde(default)]
+    pub(super) advisories: AdvisoriesFileConfig,
+    #[serde(default)]
+    pub(super) prune: PruneFileConfig,
+    #[serde(default)]
+    pub(super) upgrade: UpgradeFileConfig,
+    #[serde(default)]
     pub(super) mirrors: HashMap<String, String>,
 }
 
@@ -40,6 +50,53 @@ pub(super) struct BehaviorFileConfig {
 pub(super) struct StrictFileConfig {
     pub(super) home_hygiene: Option<StrictModeDef>,
     pub(super) path_conflicts: Option<StrictModeDef>,
+    pub(super) team_config: Option<StrictModeDef>,
+    pub(super) policy: Option<StrictModeDef>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub(super) struct TeamConfigFileConfig {
+    pub(super) trusted_keys: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub(super) struct AdvisoriesFileConfig {
+    pub(super) vulnerability_feed: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub(super) struct PruneFileConfig {
+    pub(super) keep_latest_per_major: Option<usize>,
+    pub(super) max_unused_days: Option<u64>,
+    pub(super) max_toolchain_size_mb: Option<u64>,
+    pub(super) auto_after_upgrade: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub(super) struct UpgradeFileConfig {
+    pub(super) strategy: Option<UpgradeStrategyDef>,
+    #[serde(default)]
+    pub(super) tools: HashMap<String, UpgradeStrategyDef>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum UpgradeStrategyDef {
+    Patch,
+    Minor,
+    Major,
+    Lts,
+}
+
+impl UpgradeStrategyDef {
+    pub(super) fn into_model(self) -> UpgradeStrategy {
+        match self {
+            Self::Patch => UpgradeStrategy::Patch,
+            Self::Minor => UpgradeStrategy::Minor,
+            Self::Major => UpgradeStrategy::Major,
+            Self::Lts => UpgradeStrategy::Lts,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
@@ -1,3 +1,4 @@
+use crate::policy::PolicyConfig;
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -82,6 +83,62 @@ pub enum StrictMode {
 pub struct StrictSettings {
     pub home_hygiene: StrictMode,
     pub path_conflicts: StrictMode,
+    pub team_config: StrictMode,
+    pub policy: StrictMode,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamConfigSettings {
+    pub trusted_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdvisorySettings {
+    pub vulnerability_feed: Option<String>,
+}
+
+/// Retention rules for `vex prune`. A version is kept when any configured rule keeps it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneSettings {
+    pub keep_latest_per_major: Option<usize>,
+    pub max_unused_days: Option<u64>,
+    pub max_toolchain_bytes: Option<u64>,
+    pub auto_after_upgrade: bool,
+}
+
+impl PruneSettings {
+    pub fn has_retention_rules(&self) -> bool {
+        self.keep_latest_per_major.is_some()
+            || self.max_unused_days.is_some()
+            || self.max_toolchain_bytes.is_some()
+    }
+}
+
+/// How far `vex upgrade` may move a tool from its current version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum UpgradeStrategy {
+    /// Newest release with the same major and minor version
+    Patch,
+    /// Newest release with the same major version
+    Minor,
+    /// Newest release
+    #[default]
+    Major,
+    /// Newest LTS release (Node.js and Java); same as `minor` for other tools
+    Lts,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpgradeSettings {
+    pub strategy: UpgradeStrategy,
+    pub tools: HashMap<String, UpgradeStrategy>,
+}
+
+impl UpgradeSettings {
+    pub fn strategy_for(&self, tool: &str) -> UpgradeStrategy {
+        self.tools.get(tool).copied().unwrap_or(self.strategy)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -90,6 +147,11 @@ pub struct Settings {
     pub network: NetworkSettings,
     pub behavior: BehaviorSettings,
     pub strict: StrictSettings,
+    pub team_config: TeamConfigSettings,
+    pub policy: PolicyConfig,
+    pub advisories: AdvisorySettings,
+    pub prune: PruneSettings,
+    pub upgrade: UpgradeSettings,
     pub mirrors: HashMap<String, String>,
 }
 
@@ -116,7 +178,14 @@ impl Default for Settings {
             strict: StrictSettings {
                 home_hygiene: StrictMode::Warn,
                 path_conflicts: StrictMode::Warn,
+                team_config: StrictMode::Warn,
+                policy: StrictMode::Warn,
             },
+            team_config: TeamConfigSettings::default(),
+            policy: PolicyConfig::default(),
+            advisories: AdvisorySettings::default(),
+            prune: PruneSettings::default(),
+            upgrade: UpgradeSettings::default(),
             mirrors: HashMap::new(),
         }
     }
@@ -95,6 +95,105 @@ node = "https://mirror.example.com/node"
     );
 }
 
+#[test]
+fn test_load_team_config_trust_settings_from_file() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+[strict]
+team_config = "enforce"
+
+[team_config]
+trusted_keys = ["ssh-ed25519 AAAAC3Nza platform@example.com", "  "]
+"#,
+    )
+    .unwrap();
+
+    let settings = load_settings_from_file(&path).unwrap();
+    assert_eq!(settings.strict.team_config, StrictMode::Enforce);
+    assert_eq!(
+        settings.team_config.trusted_keys,
+        vec!["ssh-ed25519 AAAAC3Nza platform@example.com".to_string()]
+    );
+}
+
+#[test]
+fn test_load_vulnerability_feed_from_file() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+[advisories]
+vulnerability_feed = "https://mirror.example.com/vex-vulnerabilities"
+"#,
+    )
+    .unwrap();
+
+    let settings = load_settings_from_file(&path).unwrap();
+    assert_eq!(
+        settings.advisories.vulnerability_feed.as_deref(),
+        Some("https://mirror.example.com/vex-vulnerabilities")
+    );
+}
+
+#[test]
+fn test_load_prune_retention_from_file() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+[prune]
+keep_latest_per_major = 2
+max_unused_days = 90
+max_toolchain_size_mb = 2048
+auto_after_upgrade = true
+"#,
+    )
+    .unwrap();
+
+    let settings = load_settings_from_file(&path).unwrap();
+    assert_eq!(settings.prune.keep_latest_per_major, Some(2));
+    assert_eq!(settings.prune.max_unused_days, Some(90));
+    assert_eq!(settings.prune.max_toolchain_bytes, Some(2048 * 1024 * 1024));
+    assert!(settings.prune.auto_after_upgrade);
+    assert!(settings.prune.has_retention_rules());
+    assert!(!Settings::default().prune.has_retention_rules());
+}
+
+#[test]
+fn test_load_upgrade_strategies_from_file() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+[upgrade]
+strategy = "minor"
+
+[upgrade.tools]
+node = "lts"
+Python = "patch"
+"#,
+    )
+    .unwrap();
+
+    let settings = load_settings_from_file(&path).unwrap();
+    assert_eq!(settings.upgrade.strategy_for("go"), UpgradeStrategy::Minor);
+    assert_eq!(settings.upgrade.strategy_for("node"), UpgradeStrategy::Lts);
+    assert_eq!(
+        settings.upgrade.strategy_for("python"),
+        UpgradeStrategy::Patch
+    );
+    assert_eq!(
+        Settings::default().upgrade.strategy_for("node"),
+        UpgradeStrategy::Major
+    );
+}
+
 #[test]
 fn test_invalid_config_returns_error() {
     let temp = TempDir::new().unwrap();
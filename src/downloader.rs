@@ -6,7 +6,8 @@
 //! # Features
 //!
 //! - **Atomic writes**: Downloads write to temporary files first, then atomically rename to avoid corruption
-//! - **Parallel downloads**: Support for downloading multiple files concurrently (max 3 concurrent)
+//! - **Parallel downloads**: Concurrent downloads, including those of parallel installs, share the
+//!   `max_concurrent_downloads` budget (3 by default)
 //! - **Automatic cleanup**: Failed downloads automatically clean up temporary files
 //! - **Retry logic**: Network errors are retried up to 3 times with exponential backoff
 
@@ -173,3 +173,31 @@ fn test_download_with_retry_ignores_invalid_project_config_for_global_use() {
 
     assert!(matches!(result, Err(VexError::Network(_))));
 }
+
+#[test]
+fn test_download_slots_bound_concurrent_downloads() {
+    use super::transfer::DownloadSlot;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let handles = (0..6)
+        .map(|_| {
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            std::thread::spawn(move || {
+                let _slot = DownloadSlot::acquire(2);
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                running.fetch_sub(1, Ordering::SeqCst);
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert!(peak.load(Ordering::SeqCst) <= 2);
+}
@@ -2,6 +2,7 @@ mod client;
 #[cfg(test)]
 mod parallel;
 mod retry;
+mod slots;
 
 use crate::config;
 use crate::error::Result;
@@ -9,6 +10,9 @@ use std::path::Path;
 #[cfg(test)]
 use std::path::PathBuf;
 
+#[cfg(test)]
+pub(super) use slots::DownloadSlot;
+
 #[cfg(test)]
 pub(super) fn create_http_client() -> Result<reqwest::blocking::Client> {
     client::create_http_client()
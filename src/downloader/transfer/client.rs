@@ -2,11 +2,17 @@ use crate::config;
 use crate::error::{Result, VexError};
 #[cfg(test)]
 use crate::http;
+use crate::output::{self, Event};
+use crate::ui;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::io::{IsTerminal, Read, Write};
 use std::path::Path;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info};
 
+/// Minimum gap between `download` events in `--output ndjson` streams.
+const DOWNLOAD_EVENT_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Create HTTP client with timeout configuration for direct current-context download tests.
 #[cfg(test)]
 pub(super) fn create_http_client() -> Result<reqwest::blocking::Client> {
@@ -29,8 +35,11 @@ pub(super) fn download_file_with_client(
     let total_size = response.content_length().unwrap_or(0);
     debug!("Download size: {} bytes", total_size);
 
-    let progress = if std::io::stdout().is_terminal() && !crate::logging::diagnostics_enabled() {
-        let progress = ProgressBar::new(total_size);
+    let progress = if std::io::stdout().is_terminal()
+        && !crate::logging::diagnostics_enabled()
+        && !output::is_quiet()
+    {
+        let progress = ui::attach_bar(ProgressBar::new(total_size));
         progress.set_style(
             ProgressStyle::default_bar()
                 .template("{msg}\n{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} {binary_bytes_per_sec} ({eta})")
@@ -51,6 +60,9 @@ pub(super) fn download_file_with_client(
     })?)?;
     let mut downloaded = 0u64;
     let mut buffer = vec![0u8; config::DOWNLOAD_BUFFER_SIZE];
+    let total_bytes = (total_size > 0).then_some(total_size);
+    let mut last_event = Instant::now();
+    emit_download(url, downloaded, total_bytes);
 
     let result = (|| -> Result<()> {
         loop {
@@ -62,7 +74,12 @@ pub(super) fn download_file_with_client(
             temp_file.write_all(&buffer[..bytes_read])?;
             downloaded += bytes_read as u64;
             progress.set_position(downloaded);
+            if last_event.elapsed() >= DOWNLOAD_EVENT_INTERVAL {
+                emit_download(url, downloaded, total_bytes);
+                last_event = Instant::now();
+            }
         }
+        emit_download(url, downloaded, total_bytes);
         Ok(())
     })();
 
@@ -77,3 +94,11 @@ pub(super) fn download_file_with_client(
     progress.finish_and_clear();
     Ok(())
 }
+
+fn emit_download(url: &str, downloaded_bytes: u64, total_bytes: Option<u64>) {
+    output::emit(Event::Download {
+        url: url.to_string(),
+        downloaded_bytes,
+        total_bytes,
+    });
+}
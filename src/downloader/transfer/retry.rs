@@ -1,7 +1,9 @@
 use super::client::download_file_with_client;
+use super::slots::DownloadSlot;
 use crate::config;
 use crate::error::{Result, VexError};
 use crate::http;
+use crate::output;
 use std::path::Path;
 use tracing::{error, info, warn};
 
@@ -16,7 +18,12 @@ pub(super) fn download_with_retry_with_settings(
     let client = http::client_for_settings(settings, concat!("vex/", env!("CARGO_PKG_VERSION")))?;
 
     loop {
-        match download_file_with_client(&client, url, dest) {
+        // Hold a slot per attempt only, so a retry backing off does not block other downloads.
+        let attempt = {
+            let _slot = DownloadSlot::acquire(settings.network.max_concurrent_downloads);
+            download_file_with_client(&client, url, dest)
+        };
+        match attempt {
             Ok(_) => {
                 info!("Download successful after {} attempts", attempts + 1);
                 return Ok(());
@@ -39,8 +46,10 @@ pub(super) fn download_with_retry_with_settings(
                         retries,
                         error_value
                     );
-                    eprintln!("Download failed: {}", error_value);
-                    eprintln!("Retrying... ({}/{} attempts)", attempts + 1, retries);
+                    if !output::is_quiet() {
+                        eprintln!("Download failed: {}", error_value);
+                        eprintln!("Retrying... ({}/{} attempts)", attempts + 1, retries);
+                    }
                     attempts += 1;
                     std::thread::sleep(settings.network.retry_base_delay);
                 } else {
@@ -0,0 +1,38 @@
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+static ACTIVE: Mutex<usize> = Mutex::new(0);
+static RELEASED: Condvar = Condvar::new();
+
+/// One of the process-wide download slots, held while a single download attempt runs.
+///
+/// Concurrent installs each download on their own thread; the slots keep the total number of
+/// transfers within `network.max_concurrent_downloads`. Released on drop.
+pub(in crate::downloader) struct DownloadSlot;
+
+impl DownloadSlot {
+    /// Block until fewer than `limit` downloads are running, then take a slot.
+    pub(in crate::downloader) fn acquire(limit: usize) -> Self {
+        let mut active = lock_active();
+        while *active >= limit.max(1) {
+            active = RELEASED
+                .wait(active)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        *active += 1;
+        Self
+    }
+}
+
+impl Drop for DownloadSlot {
+    fn drop(&mut self) {
+        let mut active = lock_active();
+        *active = active.saturating_sub(1);
+        RELEASED.notify_all();
+    }
+}
+
+fn lock_active() -> MutexGuard<'static, usize> {
+    ACTIVE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
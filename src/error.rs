@@ -4,6 +4,7 @@
 //! using [`thiserror`] to automatically derive `Display` and `Error`.
 //! Each variant includes user-friendly troubleshooting suggestions.
 
+use serde_json::json;
 use thiserror::Error;
 
 /// vex unified error type
@@ -11,6 +12,7 @@ use thiserror::Error;
 /// Covers all error scenarios including network, IO, checksum, version lookup, lock conflicts, etc.
 /// Each variant's `Display` output includes troubleshooting suggestions.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum VexError {
     /// Network request failed (connection timeout, DNS resolution failure, etc.)
     #[error("Network error: {0}\n\nTroubleshooting:\n  - Check your internet connection\n  - Verify firewall settings\n  - Try again in a few moments")]
@@ -89,6 +91,159 @@ pub enum VexError {
     /// Offline mode error - required data not available in cache
     #[error("Offline mode error: {0}\n\nIn offline mode, vex can only use cached data.\n\nTo fix this:\n  - Run the command without --offline to fetch fresh data\n  - Ensure you have previously fetched the data while online\n  - Check cache directory: ~/.vex/cache/")]
     OfflineModeError(String),
+
+    /// Team config signature is missing or does not match a trusted key
+    #[error("Untrusted team config: {location}\n\nReason: {reason}\n\nTo fix this:\n  - Ask the config owner to sign it with 'ssh-keygen -Y sign -n vex-team-config'\n  - Add the signer's public key to [team_config] trusted_keys in ~/.vex/config.toml\n  - Or set strict.team_config = \"warn\" to only warn about unsigned configs")]
+    UntrustedTeamConfig {
+        /// Team config source (path, URL, or Git repository)
+        location: String,
+        /// Why verification failed
+        reason: String,
+    },
+
+    /// Version matches a `deny` entry of a `[policy]` rule
+    #[error("Policy violation: {tool}@{version} is denied by rule '{rule}' in {origin}\n\nTo fix this:\n  - Pick a version that is not denied (see 'vex list-remote {tool}')\n  - Or ask the policy owner to update [policy.{tool}] deny")]
+    PolicyDenied {
+        /// Tool name
+        tool: String,
+        /// Blocked version
+        version: String,
+        /// Matching deny entry
+        rule: String,
+        /// Config file or team source that defined the rule
+        origin: String,
+    },
+
+    /// Version is not covered by the `allow` list of a `[policy]` rule
+    #[error("Policy violation: {tool}@{version} is not in the allowed versions ({allowed}) from {origin}\n\nTo fix this:\n  - Pick one of the allowed versions, e.g. 'vex install {tool}@<allowed>'\n  - Or ask the policy owner to update [policy.{tool}] allow")]
+    PolicyNotAllowed {
+        /// Tool name
+        tool: String,
+        /// Blocked version
+        version: String,
+        /// Allowed version prefixes, comma-separated
+        allowed: String,
+        /// Config file or team source that defined the rule
+        origin: String,
+    },
+
+    /// Version is older than the `min` of a `[policy]` rule
+    #[error("Policy violation: {tool}@{version} is below the minimum version {minimum} required by {origin}\n\nTo fix this:\n  - Install a newer version, e.g. 'vex install {tool}@{minimum}'\n  - Or ask the policy owner to update [policy.{tool}] min")]
+    PolicyBelowMinimum {
+        /// Tool name
+        tool: String,
+        /// Blocked version
+        version: String,
+        /// Minimum allowed version
+        minimum: String,
+        /// Config file or team source that defined the rule
+        origin: String,
+    },
+
+    /// Version is end-of-life and a `[policy]` rule sets `deny_eol`
+    #[error("Policy violation: {tool}@{version} is end-of-life and EOL versions are denied by {origin}\n\nTo fix this:\n  - Upgrade to a supported release (see 'vex outdated {tool}')\n  - Or ask the policy owner to update [policy] deny_eol")]
+    PolicyEndOfLife {
+        /// Tool name
+        tool: String,
+        /// Blocked version
+        version: String,
+        /// Config file or team source that defined the rule
+        origin: String,
+    },
+}
+
+impl VexError {
+    /// Stable machine-readable identifier for this error, reported as `error.code` by `--output json`.
+    ///
+    /// Codes are part of the output schema: never rename one, only add new codes for new variants.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Network(_) => "network",
+            Self::Io(_) => "io",
+            Self::DiskSpace { .. } => "disk_space",
+            Self::ChecksumMismatch { .. } => "checksum_mismatch",
+            Self::VersionNotFound { .. } => "version_not_found",
+            Self::ToolNotFound(_) => "tool_not_found",
+            Self::Parse(_) => "parse",
+            Self::Config(_) => "config",
+            Self::PythonEnv(_) => "python_env",
+            Self::Dialog(_) => "dialog",
+            Self::LockConflict { .. } => "lock_conflict",
+            Self::HomeDirectoryNotFound => "home_directory_not_found",
+            Self::UnsupportedArchitecture(_) => "unsupported_architecture",
+            Self::OfflineModeError(_) => "offline_mode",
+            Self::UntrustedTeamConfig { .. } => "untrusted_team_config",
+            Self::PolicyDenied { .. } => "policy_denied",
+            Self::PolicyNotAllowed { .. } => "policy_not_allowed",
+            Self::PolicyBelowMinimum { .. } => "policy_below_minimum",
+            Self::PolicyEndOfLife { .. } => "policy_end_of_life",
+        }
+    }
+
+    /// Structured fields of this error, named as in the variant, for machine-readable output.
+    pub fn details(&self) -> serde_json::Value {
+        match self {
+            Self::DiskSpace { need, available } => {
+                json!({ "need_gb": need, "available_gb": available })
+            }
+            Self::ChecksumMismatch { expected, actual } => {
+                json!({ "expected": expected, "actual": actual })
+            }
+            Self::VersionNotFound { tool, version, .. } | Self::LockConflict { tool, version } => {
+                json!({ "tool": tool, "version": version })
+            }
+            Self::ToolNotFound(tool) => json!({ "tool": tool }),
+            Self::UnsupportedArchitecture(arch) => json!({ "arch": arch }),
+            Self::UntrustedTeamConfig { location, reason } => {
+                json!({ "location": location, "reason": reason })
+            }
+            Self::PolicyDenied {
+                tool,
+                version,
+                rule,
+                origin,
+            } => json!({ "tool": tool, "version": version, "rule": rule, "origin": origin }),
+            Self::PolicyNotAllowed {
+                tool,
+                version,
+                allowed,
+                origin,
+            } => json!({ "tool": tool, "version": version, "allowed": allowed, "origin": origin }),
+            Self::PolicyBelowMinimum {
+                tool,
+                version,
+                minimum,
+                origin,
+            } => json!({ "tool": tool, "version": version, "minimum": minimum, "origin": origin }),
+            Self::PolicyEndOfLife {
+                tool,
+                version,
+                origin,
+            } => json!({ "tool": tool, "version": version, "origin": origin }),
+            _ => json!({}),
+        }
+    }
+
+    /// The first paragraph of the rendered error, without troubleshooting suggestions.
+    pub fn summary(&self) -> String {
+        let rendered = self.to_string();
+        rendered
+            .split("\n\n")
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// Whether this error was raised by a `[policy]` rule rather than an operational failure.
+    pub fn is_policy_violation(&self) -> bool {
+        matches!(
+            self,
+            Self::PolicyDenied { .. }
+                | Self::PolicyNotAllowed { .. }
+                | Self::PolicyBelowMinimum { .. }
+                | Self::PolicyEndOfLife { .. }
+        )
+    }
 }
 
 /// vex's Result type alias, equivalent to `std::result::Result<T, VexError>`
@@ -100,3 +100,112 @@ fn test_error_display_unsupported_architecture() {
         .contains("Unsupported architecture: sparc64"));
     assert!(err.to_string().contains("Apple Silicon"));
 }
+
+#[test]
+fn test_error_display_untrusted_team_config() {
+    let err = VexError::UntrustedTeamConfig {
+        location: "https://example.com/vex-config.toml".to_string(),
+        reason: "no signature found".to_string(),
+    };
+    assert!(err
+        .to_string()
+        .contains("Untrusted team config: https://example.com/vex-config.toml"));
+    assert!(err.to_string().contains("no signature found"));
+    assert!(err.to_string().contains("trusted_keys"));
+}
+
+#[test]
+fn test_error_display_policy_violations() {
+    let denied = VexError::PolicyDenied {
+        tool: "node".to_string(),
+        version: "21.7.3".to_string(),
+        rule: "21".to_string(),
+        origin: "~/.vex/config.toml".to_string(),
+    };
+    assert!(denied
+        .to_string()
+        .contains("node@21.7.3 is denied by rule '21' in ~/.vex/config.toml"));
+
+    let below = VexError::PolicyBelowMinimum {
+        tool: "node".to_string(),
+        version: "18.20.4".to_string(),
+        minimum: "20".to_string(),
+        origin: ".vex.toml".to_string(),
+    };
+    assert!(below.to_string().contains("below the minimum version 20"));
+    assert!(below.to_string().contains("vex install node@20"));
+
+    let eol = VexError::PolicyEndOfLife {
+        tool: "python".to_string(),
+        version: "3.7.17".to_string(),
+        origin: "team config".to_string(),
+    };
+    assert!(eol.to_string().contains("python@3.7.17 is end-of-life"));
+}
+
+#[test]
+fn test_error_codes_are_stable_and_unique() {
+    let errors = [
+        VexError::Io(std::io::Error::other("boom")),
+        VexError::DiskSpace {
+            need: 1,
+            available: 0,
+        },
+        VexError::VersionNotFound {
+            tool: "node".to_string(),
+            version: "99".to_string(),
+            suggestions: String::new(),
+        },
+        VexError::ToolNotFound("ruby".to_string()),
+        VexError::Parse("bad".to_string()),
+        VexError::Config("bad".to_string()),
+        VexError::HomeDirectoryNotFound,
+        VexError::PolicyEndOfLife {
+            tool: "node".to_string(),
+            version: "16.20.2".to_string(),
+            origin: "vex.toml".to_string(),
+        },
+    ];
+    let codes = errors.iter().map(VexError::code).collect::<Vec<_>>();
+
+    assert_eq!(
+        codes,
+        [
+            "io",
+            "disk_space",
+            "version_not_found",
+            "tool_not_found",
+            "parse",
+            "config",
+            "home_directory_not_found",
+            "policy_end_of_life",
+        ]
+    );
+}
+
+#[test]
+fn test_error_details_and_summary_drop_troubleshooting() {
+    let err = VexError::VersionNotFound {
+        tool: "node".to_string(),
+        version: "99.0.0".to_string(),
+        suggestions: "\n\nDid you mean:\n  - 20.11.0".to_string(),
+    };
+
+    assert_eq!(err.summary(), "Version not found: node@99.0.0");
+    assert_eq!(
+        err.details(),
+        serde_json::json!({ "tool": "node", "version": "99.0.0" })
+    );
+    assert_eq!(
+        VexError::DiskSpace {
+            need: 2,
+            available: 1
+        }
+        .details()["need_gb"],
+        2
+    );
+    assert_eq!(
+        VexError::Parse("bad".to_string()).details(),
+        serde_json::json!({})
+    );
+}
@@ -41,6 +41,32 @@ pub fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Match a single path component against a pattern where `*` matches any run of characters
+/// and `?` matches one character.
+pub fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +83,13 @@ mod tests {
 
         assert_eq!(path_size(dir.path()), 16);
     }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.rs", "main.rs"));
+        assert!(wildcard_match("m?in.*", "main.rs"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("*.rs", "main.rs.bak"));
+        assert!(!wildcard_match("app-?", "app-10"));
+    }
 }
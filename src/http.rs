@@ -2,6 +2,7 @@ use crate::config::{self, Settings};
 use crate::error::{Result, VexError};
 use reqwest::blocking::Client;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 pub fn client_for_settings(settings: &Settings, user_agent: &str) -> Result<Client> {
     build_client(settings, user_agent)
@@ -28,6 +29,22 @@ pub fn get_json_in_current_context<T: DeserializeOwned>(url: &str, user_agent: &
     response.json().map_err(VexError::Network)
 }
 
+pub fn post_json_in_current_context<B: Serialize, T: DeserializeOwned>(
+    url: &str,
+    body: &B,
+    user_agent: &str,
+) -> Result<T> {
+    let response = client_for_current_context(user_agent)?
+        .post(url)
+        .json(body)
+        .send()
+        .map_err(VexError::Network)?
+        .error_for_status()
+        .map_err(VexError::Network)?;
+
+    response.json().map_err(VexError::Network)
+}
+
 pub fn get_text_in_current_context(url: &str, user_agent: &str) -> Result<String> {
     let response = client_for_current_context(user_agent)?
         .get(url)
@@ -39,6 +56,23 @@ pub fn get_text_in_current_context(url: &str, user_agent: &str) -> Result<String
     response.text().map_err(VexError::Network)
 }
 
+/// Size advertised by the server for `url`, from a `HEAD` request. `None` when the server does not
+/// report one.
+pub fn content_length_in_current_context(url: &str, user_agent: &str) -> Result<Option<u64>> {
+    let response = client_for_current_context(user_agent)?
+        .head(url)
+        .send()
+        .map_err(VexError::Network)?
+        .error_for_status()
+        .map_err(VexError::Network)?;
+
+    Ok(response
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok()))
+}
+
 fn build_client(settings: &Settings, user_agent: &str) -> Result<Client> {
     let mut builder = Client::builder()
         .connect_timeout(settings.network.connect_timeout)
@@ -5,6 +5,8 @@
 //!
 //! # Features
 //!
+//! - **Parallel installs**: Several toolchains install at once, each on its own thread with its own
+//!   `InstallLock`; downloads share the `max_concurrent_downloads` budget
 //! - **Parallel extraction**: Files are extracted in parallel using rayon (directories created sequentially)
 //! - **Path safety**: All archive paths are validated to prevent path traversal attacks
 //! - **Atomic operations**: Installation uses temporary directories and atomic moves
@@ -13,6 +15,7 @@
 mod extract;
 mod offline;
 mod online;
+mod parallel;
 mod support;
 #[cfg(test)]
 mod tests;
@@ -29,6 +32,14 @@ pub fn install_with_mode(tool: &dyn Tool, version: &str, offline: bool) -> Resul
     }
 }
 
+/// Install several tool versions concurrently; results are in the order of `installs`.
+///
+/// One failure does not stop the other installs. Extraction and post-install steps of one tool
+/// overlap with the downloads of the others.
+pub fn install_all(installs: &[(&dyn Tool, &str)], offline: bool) -> Vec<Result<()>> {
+    parallel::install_all(installs, offline)
+}
+
 /// Install specified tool version
 pub fn install(tool: &dyn Tool, version: &str) -> Result<()> {
     online::install(tool, version)
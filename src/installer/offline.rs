@@ -4,7 +4,9 @@ use crate::archive_cache::ArchiveCache;
 use crate::config;
 use crate::error::{Result, VexError};
 use crate::lock::InstallLock;
+use crate::output::{self, Event};
 use crate::paths::vex_dir;
+use crate::toolchain_manifest;
 use crate::tools::{Arch, Tool};
 use crate::ui;
 use flate2::read::GzDecoder;
@@ -24,6 +26,10 @@ pub(super) fn install_offline(tool: &dyn Tool, version: &str) -> Result<()> {
         .join(version);
     if final_dir.exists() {
         info!("Version already installed: {}@{}", tool.name(), version);
+        output::emit(Event::AlreadyInstalled {
+            tool: tool.name().to_string(),
+            version: version.to_string(),
+        });
         ui::success(&format!(
             "{} is already installed.",
             format!("{}@{}", tool.name(), version).yellow()
@@ -48,6 +54,7 @@ pub(super) fn install_offline(tool: &dyn Tool, version: &str) -> Result<()> {
         })?;
 
     let ctx = ui::UiContext::new();
+    let label = format!("{}@{}", tool.name(), version);
     let progress = ui::Progress::new(
         &ctx,
         &format!(
@@ -71,7 +78,7 @@ pub(super) fn install_offline(tool: &dyn Tool, version: &str) -> Result<()> {
     let mut guard = CleanupGuard::new();
     guard.add(extract_dir.clone());
 
-    progress.set_message("Extracting archive");
+    progress.set_message(&format!("Extracting {}", label));
     fs::create_dir_all(&extract_dir)?;
 
     let tar_gz = fs::File::open(&cached_archive)?;
@@ -79,7 +86,7 @@ pub(super) fn install_offline(tool: &dyn Tool, version: &str) -> Result<()> {
     let mut archive = Archive::new(tar);
     extract_archive(&mut archive, &extract_dir)?;
 
-    progress.set_message("Finalizing installation");
+    progress.set_message(&format!("Finalizing {}", label));
     let extracted_root = find_extracted_root(&extract_dir)?;
 
     let toolchains_dir = vex.join("toolchains").join(tool.name());
@@ -88,10 +95,16 @@ pub(super) fn install_offline(tool: &dyn Tool, version: &str) -> Result<()> {
     fs::rename(&extracted_root, &final_dir)?;
 
     tool.post_install(&final_dir, arch)?;
+    let _ = toolchain_manifest::write_manifest(&final_dir);
 
     guard.disarm();
     let _ = fs::remove_dir_all(&extract_dir);
 
+    output::emit(Event::Installed {
+        tool: tool.name().to_string(),
+        version: version.to_string(),
+        path: final_dir.display().to_string(),
+    });
     progress.finish_with_success(&format!(
         "Installed {} {} (offline) to {}",
         tool.name().yellow(),
@@ -5,9 +5,11 @@ use crate::config;
 use crate::downloader::{download_with_retry_in_current_context, verify_checksum};
 use crate::error::{Result, VexError};
 use crate::lock::InstallLock;
+use crate::output::{self, Event};
 use crate::paths::vex_dir;
 use crate::resolver;
 use crate::tool_metadata;
+use crate::toolchain_manifest;
 use crate::tools::{Arch, Tool};
 use crate::ui;
 use flate2::read::GzDecoder;
@@ -28,14 +30,20 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
         .join(version);
     if final_dir.exists() {
         info!("Version already installed: {}@{}", tool.name(), version);
+        output::emit(Event::AlreadyInstalled {
+            tool: tool.name().to_string(),
+            version: version.to_string(),
+        });
         ui::success(&format!(
             "{} is already installed.",
             format!("{}@{}", tool.name(), version).yellow()
         ));
-        println!(
-            "Use {} to switch to it.",
-            format!("'vex use {}@{}'", tool.name(), version).cyan()
-        );
+        if !output::is_quiet() {
+            println!(
+                "Use {} to switch to it.",
+                format!("'vex use {}@{}'", tool.name(), version).cyan()
+            );
+        }
         return Ok(());
     }
 
@@ -43,6 +51,7 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
     check_disk_space(&vex, config::MIN_FREE_SPACE_BYTES)?;
 
     let ctx = ui::UiContext::new();
+    let label = format!("{}@{}", tool.name(), version);
     ui::info(&format!(
         "Installing {} {}",
         tool.name().yellow(),
@@ -70,7 +79,7 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
         settings.network.download_retries,
     )?;
 
-    let progress = ui::Progress::new(&ctx, "Verifying checksum");
+    let progress = ui::Progress::new(&ctx, &format!("Verifying {} checksum", label));
 
     let verified_checksum = match tool.get_checksum(version, arch) {
         Ok(Some(expected)) => {
@@ -89,7 +98,7 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
     let archive_cache = ArchiveCache::new(&vex);
     let _ = archive_cache.store_archive(tool.name(), version, &archive_name, &archive_path);
 
-    progress.set_message("Extracting archive");
+    progress.set_message(&format!("Extracting {}", label));
     fs::create_dir_all(&extract_dir)?;
 
     let tar_gz = fs::File::open(&archive_path)?;
@@ -97,7 +106,7 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
     let mut archive = Archive::new(tar);
     extract_archive(&mut archive, &extract_dir)?;
 
-    progress.set_message("Finalizing installation");
+    progress.set_message(&format!("Finalizing {}", label));
     let extracted_dir = find_extracted_root(&extract_dir)?;
 
     let toolchains_dir = vex.join("toolchains").join(tool.name());
@@ -106,6 +115,7 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
     fs::rename(&extracted_dir, &final_dir)?;
 
     tool.post_install(&final_dir, arch)?;
+    let _ = toolchain_manifest::write_manifest(&final_dir);
 
     if let Some(ref checksum) = verified_checksum {
         let checksum_file = final_dir.join(".vex-checksum");
@@ -131,6 +141,11 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
     let _ = fs::remove_file(&archive_path);
     let _ = fs::remove_dir_all(&extract_dir);
 
+    output::emit(Event::Installed {
+        tool: tool.name().to_string(),
+        version: version.to_string(),
+        path: final_dir.display().to_string(),
+    });
     progress.finish_with_success(&format!(
         "Installed {} {} to {}",
         tool.name().yellow(),
@@ -140,7 +155,7 @@ pub(super) fn install(tool: &dyn Tool, version: &str) -> Result<()> {
 
     if tool.name() == "node" {
         if let Ok(major_version) = version.split('.').next().unwrap_or("0").parse::<u32>() {
-            if major_version >= 25 {
+            if major_version >= 25 && !output::is_quiet() {
                 println!();
                 ui::info(&format!(
                     "Node.js 25+ no longer includes Corepack. To use pnpm or yarn, run: {}",
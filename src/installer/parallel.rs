@@ -0,0 +1,35 @@
+use super::install_with_mode;
+use crate::error::{Result, VexError};
+use crate::tools::Tool;
+use crate::ui;
+
+pub(super) fn install_all(installs: &[(&dyn Tool, &str)], offline: bool) -> Vec<Result<()>> {
+    if installs.len() <= 1 {
+        return installs
+            .iter()
+            .map(|(tool, version)| install_with_mode(*tool, version, offline))
+            .collect();
+    }
+
+    let _display = ui::MultiProgressScope::start(&ui::UiContext::new());
+    std::thread::scope(|scope| {
+        let handles = installs
+            .iter()
+            .map(|(tool, version)| scope.spawn(move || install_with_mode(*tool, version, offline)))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .zip(installs)
+            .map(|(handle, (tool, version))| {
+                handle.join().unwrap_or_else(|_| {
+                    Err(VexError::Config(format!(
+                        "Installing {}@{} panicked",
+                        tool.name(),
+                        version
+                    )))
+                })
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,98 @@
+//! vex - macOS binary version manager
+//!
+//! Manages official binary distributions of Node.js, Go, Java, Rust, and other languages.
+//! Implements fast version switching via symlinks + PATH prepending.
+//!
+//! Besides the `vex` binary, this crate exposes a small library API for editors and
+//! bootstrappers that want to resolve, activate, and install toolchains without parsing CLI
+//! output. Only the items re-exported here are covered by semver; everything else is an
+//! implementation detail of the binary.
+//!
+//! Library calls never print. Progress, warnings, and changes are passed to an [`Event`]
+//! callback instead. Output is silenced per thread, so the library functions do their work on
+//! the calling thread.
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! let context = vex::resolve_context(Path::new("."));
+//! for request in &context.requests {
+//!     println!("{}@{} from {}", request.tool, request.version, request.source.display());
+//! }
+//!
+//! let version = vex::install("node", "20", &vex::InstallOptions::new().switch(true), |event| {
+//!     if let vex::Event::Progress { message } = event {
+//!         eprintln!("{}", message);
+//!     }
+//! })?;
+//!
+//! let activation = vex::plan_activation(Path::new("."))?;
+//! println!("node {} with PATH={}", version, activation.path);
+//! # Ok::<(), vex::VexError>(())
+//! ```
+
+mod activation;
+mod advisories;
+mod alias;
+mod api;
+mod app;
+mod archive_cache;
+mod cache;
+mod checksum;
+mod cli;
+mod commands;
+mod config;
+mod downloader;
+mod error;
+mod fs_utils;
+mod home_state;
+mod http;
+mod installer;
+mod lock;
+mod lockfile;
+mod logging;
+mod output;
+mod paths;
+mod policy;
+mod project;
+mod registry;
+mod requested_versions;
+mod resolver;
+mod shell;
+mod spec;
+mod switcher;
+mod team_config;
+mod templates;
+mod tool_metadata;
+mod toolchain_manifest;
+mod toolchain_usage;
+mod tools;
+mod ui;
+mod updater;
+mod version_files;
+mod version_state;
+mod versioning;
+
+pub use api::{
+    install, list_installed, list_remote, plan_activation, resolve_context, switch, Activation,
+    Context, InstallOptions, InstalledVersion, RemoteVersion, ToolRequest, SUPPORTED_TOOLS,
+};
+pub use error::{Result, VexError};
+pub use output::Event;
+
+/// Entry point of the `vex` binary; not part of the library API.
+#[doc(hidden)]
+pub fn run_cli() {
+    logging::init();
+
+    let result = app::run();
+    output::finish(&result);
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+#[path = "lib_tests.rs"]
+mod tests;
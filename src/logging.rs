@@ -33,7 +33,7 @@ pub fn diagnostics_enabled() -> bool {
 /// - unset - logging disabled (default)
 ///
 /// # Example
-/// ```no_run
+/// ```ignore
 /// vex::logging::init();
 /// ```
 pub fn init() {
@@ -1,5 +1,32 @@
+//! Machine-readable output
+//!
+//! Commands print human-readable text by default. With the global `--output json` flag every
+//! command instead writes one versioned envelope to stdout, and with `--output ndjson` it streams
+//! [`Event`] lines followed by a final `result` or `error` line. In both machine formats the
+//! human text is moved to stderr, so stdout only ever carries JSON.
+//!
+//! The per-command `--json` flags predate this module and keep printing their bare reports.
+//!
+//! Library calls use [`capture`] instead: it silences text output on the calling thread and
+//! hands every [`Event`] to a callback.
+
+mod event;
+#[cfg(test)]
+mod tests;
+
+pub use event::Event;
+
 use crate::error::{Result, VexError};
 use serde::Serialize;
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Write;
+use std::os::fd::FromRawFd;
+use std::sync::{Mutex, OnceLock};
+
+/// Version of the envelope and report schemas; bumped only for incompatible changes.
+pub const SCHEMA_VERSION: u32 = 1;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputMode {
@@ -9,7 +36,7 @@ pub enum OutputMode {
 
 impl OutputMode {
     pub fn from_json_flag(json: bool) -> Self {
-        if json {
+        if json || is_machine() {
             Self::Json
         } else {
             Self::Text
@@ -17,9 +44,240 @@ impl OutputMode {
     }
 }
 
+/// Value of the global `--output` flag.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text (default)
+    #[default]
+    Text,
+    /// One JSON envelope on stdout when the command finishes
+    Json,
+    /// Newline-delimited JSON events, ending with a `result` or `error` line
+    Ndjson,
+}
+
+struct MachineOutput {
+    format: OutputFormat,
+    schema: String,
+    /// The original stdout; fd 1 now points at stderr.
+    stdout: Mutex<File>,
+    changes: Mutex<Vec<Value>>,
+    report: Mutex<Option<Value>>,
+    finished: Mutex<bool>,
+}
+
+static MACHINE: OnceLock<MachineOutput> = OnceLock::new();
+
+type Reporter = Box<dyn FnMut(&Event)>;
+
+thread_local! {
+    /// Callback of the library call running on this thread, if any.
+    static REPORTER: RefCell<Option<Reporter>> = const { RefCell::new(None) };
+}
+
+/// Switch to `format` for the rest of the process; `schema` names the command, e.g. `vex.install`.
+pub fn init(format: OutputFormat, schema: String) -> Result<()> {
+    if format == OutputFormat::Text {
+        return Ok(());
+    }
+
+    // Keep a private handle on stdout for JSON and point fd 1 at stderr, so every `println!`
+    // and child process output lands on stderr without touching each call site.
+    // SAFETY: dup/dup2 only manipulate this process's descriptor table; the duplicated fd is
+    // owned exclusively by the `File` created below.
+    let stdout = unsafe {
+        let saved = libc::dup(libc::STDOUT_FILENO);
+        if saved < 0 || libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+            return Err(VexError::Io(std::io::Error::last_os_error()));
+        }
+        File::from_raw_fd(saved)
+    };
+
+    let _ = MACHINE.set(MachineOutput {
+        format,
+        schema,
+        stdout: Mutex::new(stdout),
+        changes: Mutex::new(Vec::new()),
+        report: Mutex::new(None),
+        finished: Mutex::new(false),
+    });
+    Ok(())
+}
+
+/// Whether `--output json` or `--output ndjson` is active.
+pub fn is_machine() -> bool {
+    MACHINE.get().is_some()
+}
+
 pub fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    if is_machine() {
+        return report(value);
+    }
     let json = serde_json::to_string_pretty(value)
         .map_err(|e| VexError::Parse(format!("Failed to serialize JSON output: {}", e)))?;
     println!("{}", json);
     Ok(())
 }
+
+/// Record `value` as the command's `data` in machine output; no-op for text output.
+///
+/// The first report wins, so a follow-up step such as the prune after `vex upgrade` does not
+/// replace the report of the command that was asked for.
+pub fn report<T: Serialize>(value: &T) -> Result<()> {
+    let Some(machine) = MACHINE.get() else {
+        return Ok(());
+    };
+    let value = serde_json::to_value(value)
+        .map_err(|e| VexError::Parse(format!("Failed to serialize JSON output: {}", e)))?;
+    let mut report = lock(&machine.report);
+    if report.is_none() {
+        *report = Some(value);
+    }
+    Ok(())
+}
+
+/// Run `f` with text output silenced on this thread and every event passed to `reporter`.
+///
+/// The reporter is thread-local: threads that `f` spawns, such as the workers of a parallel
+/// install, still print and their events never reach `reporter`.
+pub fn capture<T>(reporter: impl FnMut(&Event) + 'static, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Reporter>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            REPORTER.with(|reporter| *reporter.borrow_mut() = previous);
+        }
+    }
+
+    let previous = REPORTER.with(|current| current.borrow_mut().replace(Box::new(reporter)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Whether text output is silenced because a library call is running on this thread.
+pub fn is_quiet() -> bool {
+    REPORTER.with(|reporter| {
+        reporter
+            .try_borrow()
+            .map_or(true, |reporter| reporter.is_some())
+    })
+}
+
+/// Record a change or progress event; streamed immediately with `--output ndjson`.
+pub fn emit(event: Event) {
+    REPORTER.with(|reporter| {
+        // A callback that triggers another event while running does not see it again.
+        if let Ok(mut reporter) = reporter.try_borrow_mut() {
+            if let Some(reporter) = reporter.as_mut() {
+                reporter(&event);
+            }
+        }
+    });
+
+    let Some(machine) = MACHINE.get() else {
+        return;
+    };
+    let Ok(value) = serde_json::to_value(&event) else {
+        return;
+    };
+    if machine.format == OutputFormat::Ndjson {
+        write_line(machine, &value.to_string());
+    }
+    if event.is_change() {
+        lock(&machine.changes).push(value);
+    }
+}
+
+/// Write the final envelope for `result`; only the first call prints anything.
+pub fn finish(result: &Result<()>) {
+    let Some(machine) = MACHINE.get() else {
+        return;
+    };
+    {
+        let mut finished = lock(&machine.finished);
+        if *finished {
+            return;
+        }
+        *finished = true;
+    }
+
+    let outcome = match result {
+        Ok(()) => Ok(lock(&machine.report).take().unwrap_or_else(|| {
+            let changes = std::mem::take(&mut *lock(&machine.changes));
+            json!({ "changes": changes })
+        })),
+        Err(err) => Err(err),
+    };
+    let line = render_envelope(machine.format, &machine.schema, outcome);
+    write_line(machine, &line);
+}
+
+/// Render the final line: `{"schema", "schema_version", "ok", "data" | "error"}`.
+fn render_envelope(
+    format: OutputFormat,
+    schema: &str,
+    outcome: std::result::Result<Value, &VexError>,
+) -> String {
+    let mut envelope = serde_json::Map::new();
+    if format == OutputFormat::Ndjson {
+        let event = if outcome.is_ok() { "result" } else { "error" };
+        envelope.insert("event".to_string(), event.into());
+    }
+    envelope.insert("schema".to_string(), schema.into());
+    envelope.insert("schema_version".to_string(), SCHEMA_VERSION.into());
+    envelope.insert("ok".to_string(), outcome.is_ok().into());
+    match outcome {
+        Ok(data) => {
+            envelope.insert("data".to_string(), data);
+        }
+        Err(err) => {
+            envelope.insert(
+                "error".to_string(),
+                json!({
+                    "code": err.code(),
+                    "message": err.summary(),
+                    "details": err.details(),
+                }),
+            );
+        }
+    }
+
+    let envelope = Value::Object(envelope);
+    match format {
+        OutputFormat::Ndjson => envelope.to_string(),
+        _ => serde_json::to_string_pretty(&envelope).unwrap_or_else(|_| envelope.to_string()),
+    }
+}
+
+fn write_line(machine: &MachineOutput, line: &str) {
+    let mut stdout = lock(&machine.stdout);
+    let _ = writeln!(stdout, "{}", line);
+    let _ = stdout.flush();
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Remove ANSI escape sequences such as colors from rendered text.
+pub fn strip_ansi(line: &str) -> String {
+    let mut visible = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            visible.push(ch);
+            continue;
+        }
+        if chars.next() == Some('[') {
+            for code in chars.by_ref() {
+                if ('@'..='~').contains(&code) {
+                    break;
+                }
+            }
+        }
+    }
+    visible
+}
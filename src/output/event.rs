@@ -0,0 +1,79 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Something a command did, tagged by `event` in machine-readable output.
+///
+/// Changes are collected into `data.changes` of the final envelope for commands without a
+/// dedicated report; progress events only appear in the `--output ndjson` stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Event {
+    Progress {
+        message: String,
+    },
+    /// Something the user should know about that does not stop the command.
+    Warning {
+        message: String,
+    },
+    Download {
+        url: String,
+        downloaded_bytes: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total_bytes: Option<u64>,
+    },
+    Installed {
+        tool: String,
+        version: String,
+        path: String,
+    },
+    AlreadyInstalled {
+        tool: String,
+        version: String,
+    },
+    Switched {
+        tool: String,
+        version: String,
+    },
+    Uninstalled {
+        tool: String,
+        version: String,
+    },
+    VersionFileWritten {
+        path: String,
+        tool: String,
+        version: String,
+    },
+    LockfileWritten {
+        path: String,
+        tools: BTreeMap<String, String>,
+    },
+    Removed {
+        kind: String,
+        path: String,
+        bytes: u64,
+    },
+    ExtensionInstalled {
+        kind: String,
+        name: String,
+    },
+    ExtensionRemoved {
+        kind: String,
+        name: String,
+    },
+    ExtensionSkipped {
+        kind: String,
+        name: String,
+        reason: String,
+    },
+}
+
+impl Event {
+    /// Whether this event records a change rather than progress.
+    pub fn is_change(&self) -> bool {
+        !matches!(
+            self,
+            Self::Progress { .. } | Self::Warning { .. } | Self::Download { .. }
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+#[test]
+fn test_json_envelope_wraps_data_with_schema() {
+    let rendered = render_envelope(
+        OutputFormat::Json,
+        "vex.install",
+        Ok(json!({ "changes": [] })),
+    );
+    let value: Value = serde_json::from_str(&rendered).unwrap();
+
+    assert_eq!(
+        value,
+        json!({
+            "schema": "vex.install",
+            "schema_version": SCHEMA_VERSION,
+            "ok": true,
+            "data": { "changes": [] },
+        })
+    );
+}
+
+#[test]
+fn test_ndjson_error_envelope_is_one_line_with_code() {
+    let err = VexError::VersionNotFound {
+        tool: "node".to_string(),
+        version: "99".to_string(),
+        suggestions: String::new(),
+    };
+    let rendered = render_envelope(OutputFormat::Ndjson, "vex.use", Err(&err));
+    let value: Value = serde_json::from_str(&rendered).unwrap();
+
+    assert!(!rendered.contains('\n'));
+    assert_eq!(value["event"], "error");
+    assert_eq!(value["ok"], false);
+    assert_eq!(value["error"]["code"], "version_not_found");
+    assert_eq!(value["error"]["message"], "Version not found: node@99");
+    assert_eq!(value["error"]["details"]["tool"], "node");
+}
+
+#[test]
+fn test_events_are_tagged_and_split_into_changes_and_progress() {
+    let installed = Event::Installed {
+        tool: "go".to_string(),
+        version: "1.23.0".to_string(),
+        path: "/tmp/go".to_string(),
+    };
+    let progress = Event::Progress {
+        message: "Extracting archive".to_string(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&installed).unwrap()["event"],
+        "installed"
+    );
+    assert!(installed.is_change());
+    assert!(!progress.is_change());
+}
+
+#[test]
+fn test_strip_ansi_removes_color_codes() {
+    assert_eq!(
+        strip_ansi("\u{1b}[32m✓\u{1b}[39m Installed \u{1b}[1mgo\u{1b}[0m"),
+        "✓ Installed go"
+    );
+}
+
+#[test]
+fn test_capture_silences_text_and_forwards_events() {
+    let seen = std::rc::Rc::new(RefCell::new(Vec::new()));
+    let sink = seen.clone();
+
+    assert!(!is_quiet());
+    let quiet = capture(
+        move |event| sink.borrow_mut().push(event.clone()),
+        || {
+            emit(Event::Progress {
+                message: "Extracting archive".to_string(),
+            });
+            is_quiet()
+        },
+    );
+
+    assert!(quiet);
+    assert!(!is_quiet());
+    assert_eq!(
+        seen.borrow().as_slice(),
+        [Event::Progress {
+            message: "Extracting archive".to_string()
+        }]
+    );
+}
@@ -0,0 +1,288 @@
+//! Version policy enforcement
+//!
+//! `[policy]` sections in `~/.vex/config.toml`, `.vex.toml`, and team configs restrict which
+//! toolchain versions may be installed or activated. Every layer is checked independently, so a
+//! project can tighten a user policy but never relax it. `strict.policy` decides whether a
+//! violation only warns or blocks the command.
+
+#[cfg(test)]
+mod tests;
+
+use crate::advisories::{self, AdvisoryStatus};
+use crate::config::{self, StrictMode};
+use crate::error::{Result, VexError};
+use crate::output::{self, Event};
+use crate::project;
+use crate::requested_versions::version_matches_request;
+use crate::tools;
+use crate::versioning::version_sort_key;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// A `[policy]` table as written in a config file.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+pub struct PolicyConfig {
+    /// Deny end-of-life versions of every tool unless a tool rule says otherwise.
+    #[serde(default)]
+    pub deny_eol: bool,
+    /// Per-tool rules from `[policy.<tool>]` tables.
+    #[serde(flatten)]
+    pub tools: BTreeMap<String, ToolPolicy>,
+}
+
+/// Rules for one tool. Version entries match by prefix, so `"21"` covers `21.0.4`.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ToolPolicy {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+    pub min: Option<String>,
+    pub deny_eol: Option<bool>,
+}
+
+impl PolicyConfig {
+    pub fn is_empty(&self) -> bool {
+        !self.deny_eol && self.tools.is_empty()
+    }
+
+    fn validate(&self, origin: &str) -> Result<()> {
+        for tool in self.tools.keys() {
+            if tools::get_tool(tool).is_err() {
+                return Err(VexError::Config(format!(
+                    "Unknown tool '{}' in [policy] of {}. Supported tools: node, go, java, rust, python.",
+                    tool, origin
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyRule {
+    Denied(String),
+    NotAllowed(Vec<String>),
+    BelowMinimum(String),
+    EndOfLife,
+}
+
+/// A single rule that blocks a tool version, with the layer that defined it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyViolation {
+    pub tool: String,
+    pub version: String,
+    pub rule: PolicyRule,
+    pub origin: String,
+}
+
+impl PolicyViolation {
+    pub fn summary(&self) -> String {
+        let reason = match &self.rule {
+            PolicyRule::Denied(rule) => format!("denied by rule '{}'", rule),
+            PolicyRule::NotAllowed(allowed) => format!("not in allowed {}", allowed.join(", ")),
+            PolicyRule::BelowMinimum(minimum) => format!("below minimum {}", minimum),
+            PolicyRule::EndOfLife => "end-of-life".to_string(),
+        };
+        format!(
+            "{}@{}: {} ({})",
+            self.tool, self.version, reason, self.origin
+        )
+    }
+
+    pub fn into_error(self) -> VexError {
+        let Self {
+            tool,
+            version,
+            rule,
+            origin,
+        } = self;
+        match rule {
+            PolicyRule::Denied(rule) => VexError::PolicyDenied {
+                tool,
+                version,
+                rule,
+                origin,
+            },
+            PolicyRule::NotAllowed(allowed) => VexError::PolicyNotAllowed {
+                tool,
+                version,
+                allowed: allowed.join(", "),
+                origin,
+            },
+            PolicyRule::BelowMinimum(minimum) => VexError::PolicyBelowMinimum {
+                tool,
+                version,
+                minimum,
+                origin,
+            },
+            PolicyRule::EndOfLife => VexError::PolicyEndOfLife {
+                tool,
+                version,
+                origin,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PolicyLayer {
+    origin: String,
+    config: PolicyConfig,
+}
+
+/// The effective policy for a directory: the user layer plus the nearest project layer.
+#[derive(Debug, Clone)]
+pub struct Policy {
+    mode: StrictMode,
+    layers: Vec<PolicyLayer>,
+}
+
+impl Policy {
+    pub fn new(mode: StrictMode) -> Self {
+        Self {
+            mode,
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn load(start_dir: &Path) -> Result<Self> {
+        let settings = config::load_settings()?;
+        let mut policy = Self::new(settings.strict.policy);
+
+        let user_origin = config::config_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "~/.vex/config.toml".to_string());
+        policy.push_layer(user_origin, settings.policy)?;
+
+        if let Some(project) = project::load_nearest_project_config(start_dir)? {
+            policy.push_layer(project.path.display().to_string(), project.config.policy)?;
+        }
+
+        Ok(policy)
+    }
+
+    pub fn mode(&self) -> StrictMode {
+        self.mode
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Add a layer such as a team config's `[policy]`. Empty configs are ignored.
+    pub fn push_layer(&mut self, origin: String, config: PolicyConfig) -> Result<()> {
+        if config.is_empty() {
+            return Ok(());
+        }
+        config.validate(&origin)?;
+        self.layers.push(PolicyLayer { origin, config });
+        Ok(())
+    }
+
+    pub fn evaluate(&self, tool: &str, version: &str) -> Vec<PolicyViolation> {
+        self.layers
+            .iter()
+            .filter_map(|layer| evaluate_layer(layer, tool, version))
+            .collect()
+    }
+
+    /// Warn about or reject a version depending on `strict.policy`.
+    pub fn check(&self, tool: &str, version: &str) -> Result<()> {
+        let mut violations = self.evaluate(tool, version);
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        match self.mode {
+            StrictMode::Enforce => Err(violations.remove(0).into_error()),
+            StrictMode::Warn => {
+                for violation in violations {
+                    let message = format!("policy: {}", violation.summary());
+                    if !output::is_quiet() {
+                        eprintln!("vex: warning: {}", message);
+                    }
+                    output::emit(Event::Warning { message });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Check every installed toolchain under `~/.vex/toolchains`.
+    pub fn installed_violations(&self, vex_dir: &Path) -> Result<Vec<PolicyViolation>> {
+        let toolchains_dir = vex_dir.join("toolchains");
+        if self.is_empty() || !toolchains_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut violations = Vec::new();
+        for tool_entry in fs::read_dir(&toolchains_dir)?.filter_map(|entry| entry.ok()) {
+            if !tool_entry.path().is_dir() {
+                continue;
+            }
+            let tool = tool_entry.file_name().to_string_lossy().to_string();
+            for version_entry in fs::read_dir(tool_entry.path())?.filter_map(|entry| entry.ok()) {
+                if !version_entry.path().is_dir() {
+                    continue;
+                }
+                let version = version_entry.file_name().to_string_lossy().to_string();
+                violations.extend(self.evaluate(&tool, &version));
+            }
+        }
+
+        violations.sort_by(|left, right| {
+            left.tool.cmp(&right.tool).then_with(|| {
+                version_sort_key(&left.version).cmp(&version_sort_key(&right.version))
+            })
+        });
+        Ok(violations)
+    }
+}
+
+fn evaluate_layer(layer: &PolicyLayer, tool: &str, version: &str) -> Option<PolicyViolation> {
+    let rules = layer.config.tools.get(tool);
+    let violation = |rule| PolicyViolation {
+        tool: tool.to_string(),
+        version: version.to_string(),
+        rule,
+        origin: layer.origin.clone(),
+    };
+
+    if let Some(rules) = rules {
+        if let Some(rule) = rules
+            .deny
+            .iter()
+            .find(|rule| version_matches_request(version, rule))
+        {
+            return Some(violation(PolicyRule::Denied(rule.clone())));
+        }
+
+        if !rules.allow.is_empty()
+            && !rules
+                .allow
+                .iter()
+                .any(|rule| version_matches_request(version, rule))
+        {
+            return Some(violation(PolicyRule::NotAllowed(rules.allow.clone())));
+        }
+
+        if let Some(minimum) = &rules.min {
+            if version_sort_key(version) < version_sort_key(minimum) {
+                return Some(violation(PolicyRule::BelowMinimum(minimum.clone())));
+            }
+        }
+    }
+
+    let deny_eol = rules
+        .and_then(|rules| rules.deny_eol)
+        .unwrap_or(layer.config.deny_eol);
+    if deny_eol && advisories::get_advisory(tool, version).status == AdvisoryStatus::Eol {
+        return Some(violation(PolicyRule::EndOfLife));
+    }
+
+    None
+}
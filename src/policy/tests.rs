@@ -0,0 +1,150 @@
+use super::*;
+use tempfile::TempDir;
+
+fn policy_from_toml(mode: StrictMode, origin: &str, content: &str) -> Policy {
+    let config: PolicyConfig = toml::from_str(content).unwrap();
+    let mut policy = Policy::new(mode);
+    policy.push_layer(origin.to_string(), config).unwrap();
+    policy
+}
+
+#[test]
+fn test_policy_config_parses_global_and_tool_rules() {
+    let config: PolicyConfig = toml::from_str(
+        r#"
+deny_eol = true
+
+[node]
+min = "20"
+deny = ["21", "23"]
+
+[java]
+allow = ["17", "21"]
+deny_eol = false
+"#,
+    )
+    .unwrap();
+
+    assert!(config.deny_eol);
+    assert_eq!(config.tools["node"].min.as_deref(), Some("20"));
+    assert_eq!(config.tools["node"].deny, vec!["21", "23"]);
+    assert_eq!(config.tools["java"].allow, vec!["17", "21"]);
+    assert_eq!(config.tools["java"].deny_eol, Some(false));
+}
+
+#[test]
+fn test_policy_config_rejects_unknown_rule_fields() {
+    let err = toml::from_str::<PolicyConfig>("[node]\nmaximum = \"22\"\n").unwrap_err();
+    assert!(err.to_string().contains("maximum"));
+}
+
+#[test]
+fn test_push_layer_rejects_unknown_tools() {
+    let config: PolicyConfig = toml::from_str("[nodejs]\nmin = \"20\"\n").unwrap();
+    let err = Policy::new(StrictMode::Enforce)
+        .push_layer("config.toml".to_string(), config)
+        .unwrap_err();
+    assert!(err.to_string().contains("Unknown tool 'nodejs'"));
+}
+
+#[test]
+fn test_minimum_version_rule() {
+    let policy = policy_from_toml(StrictMode::Enforce, "config.toml", "[node]\nmin = \"20\"\n");
+
+    assert!(policy.evaluate("node", "20.0.0").is_empty());
+    assert!(policy.evaluate("node", "22.11.0").is_empty());
+    assert_eq!(
+        policy.evaluate("node", "18.20.4")[0].rule,
+        PolicyRule::BelowMinimum("20".to_string())
+    );
+    assert!(policy.evaluate("go", "1.20.0").is_empty());
+}
+
+#[test]
+fn test_deny_and_allow_rules_match_version_prefixes() {
+    let policy = policy_from_toml(
+        StrictMode::Enforce,
+        "config.toml",
+        "[java]\nallow = [\"17\", \"21\"]\n\n[node]\ndeny = [\"21\"]\n",
+    );
+
+    assert!(policy.evaluate("java", "21.0.4").is_empty());
+    assert!(policy.evaluate("java", "17.0.12").is_empty());
+    assert_eq!(
+        policy.evaluate("java", "11.0.24")[0].rule,
+        PolicyRule::NotAllowed(vec!["17".to_string(), "21".to_string()])
+    );
+    assert_eq!(
+        policy.evaluate("node", "21.7.3")[0].rule,
+        PolicyRule::Denied("21".to_string())
+    );
+    assert!(policy.evaluate("node", "210.0.0").is_empty());
+}
+
+#[test]
+fn test_deny_eol_uses_lifecycle_advisories() {
+    let policy = policy_from_toml(
+        StrictMode::Enforce,
+        "config.toml",
+        "[python]\ndeny_eol = true\n",
+    );
+
+    assert_eq!(
+        policy.evaluate("python", "3.7.17")[0].rule,
+        PolicyRule::EndOfLife
+    );
+    assert!(policy.evaluate("python", "3.12.8").is_empty());
+    assert!(policy.evaluate("java", "16.0.2").is_empty());
+}
+
+#[test]
+fn test_every_layer_is_checked_and_reports_its_origin() {
+    let mut policy = policy_from_toml(StrictMode::Enforce, "user", "[node]\nmin = \"18\"\n");
+    policy
+        .push_layer(
+            "team".to_string(),
+            toml::from_str("[node]\nmin = \"20\"\n").unwrap(),
+        )
+        .unwrap();
+
+    let violations = policy.evaluate("node", "18.20.4");
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].origin, "team");
+}
+
+#[test]
+fn test_check_warns_or_enforces_by_mode() {
+    let rules = "[node]\nmin = \"20\"\n";
+
+    let warn = policy_from_toml(StrictMode::Warn, "config.toml", rules);
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let sink = seen.clone();
+    let checked = output::capture(
+        move |event| sink.borrow_mut().push(event.clone()),
+        || warn.check("node", "18.20.4"),
+    );
+    assert!(checked.is_ok());
+    assert!(matches!(
+        seen.borrow().as_slice(),
+        [Event::Warning { message }] if message.starts_with("policy: ")
+    ));
+
+    let enforce = policy_from_toml(StrictMode::Enforce, "config.toml", rules);
+    let err = enforce.check("node", "18.20.4").unwrap_err();
+    assert!(err.is_policy_violation());
+    assert!(matches!(err, VexError::PolicyBelowMinimum { .. }));
+}
+
+#[test]
+fn test_installed_violations_scans_toolchains() {
+    let temp = TempDir::new().unwrap();
+    for (tool, version) in [("node", "18.20.4"), ("node", "22.11.0"), ("go", "1.23.0")] {
+        fs::create_dir_all(temp.path().join("toolchains").join(tool).join(version)).unwrap();
+    }
+    let policy = policy_from_toml(StrictMode::Warn, "config.toml", "[node]\nmin = \"20\"\n");
+
+    let violations = policy.installed_violations(temp.path()).unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].version, "18.20.4");
+    assert!(violations[0].summary().contains("below minimum 20"));
+}
@@ -1,11 +1,14 @@
 mod discovery;
+mod workspace;
 
 use crate::error::{Result, VexError};
+use crate::policy::PolicyConfig;
 pub use discovery::{find_nearest_node_modules_bin, find_nearest_project_file, find_nearest_venv};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+pub use workspace::{find_workspace_root, workspace_members};
 
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct ProjectBehaviorConfig {
@@ -34,10 +37,117 @@ pub struct ProjectConfig {
     pub network: ProjectNetworkConfig,
     #[serde(default)]
     pub mirrors: HashMap<String, String>,
+    /// Versions pinned for the project; `.tool-versions` entries take precedence over these.
+    #[serde(default)]
+    pub tools: HashMap<String, String>,
     #[serde(default)]
     pub env: HashMap<String, String>,
     #[serde(default)]
-    pub commands: HashMap<String, String>,
+    pub commands: HashMap<String, TaskDef>,
+    #[serde(default)]
+    pub policy: PolicyConfig,
+    pub workspace: Option<WorkspaceConfig>,
+}
+
+/// `[workspace]`: the root `.vex.toml` of a monorepo lists its member packages.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceConfig {
+    /// Member directories relative to the workspace root; `*` matches within one component.
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+/// A `[commands]` entry: a plain shell command or a structured task table.
+#[derive(Debug, Clone)]
+pub enum TaskDef {
+    Command(String),
+    Task(Box<TaskConfig>),
+}
+
+impl<'de> Deserialize<'de> for TaskDef {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct TaskDefVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for TaskDefVisitor {
+            type Value = TaskDef;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a command string or a task table")
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<TaskDef, E> {
+                Ok(TaskDef::Command(value.to_string()))
+            }
+
+            fn visit_map<A>(self, map: A) -> std::result::Result<TaskDef, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                // Deserialize the table directly so unknown or mistyped fields are reported.
+                TaskConfig::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+                    .map(|task| TaskDef::Task(Box::new(task)))
+            }
+        }
+
+        deserializer.deserialize_any(TaskDefVisitor)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TaskConfig {
+    /// Shell command; optional for tasks that only group their dependencies.
+    pub run: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Versions that replace the resolved ones while this task runs.
+    #[serde(default)]
+    pub tools: HashMap<String, String>,
+    /// Working directory relative to the project root.
+    pub cwd: Option<String>,
+    /// Glob patterns; with `outputs`, the task is skipped when no source is newer than them.
+    #[serde(default)]
+    pub sources: Vec<String>,
+    #[serde(default)]
+    pub outputs: Vec<String>,
+}
+
+impl TaskDef {
+    pub fn command(&self) -> Option<&str> {
+        match self {
+            TaskDef::Command(command) => Some(command),
+            TaskDef::Task(task) => task.run.as_deref(),
+        }
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            TaskDef::Command(_) => None,
+            TaskDef::Task(task) => task.description.as_deref(),
+        }
+    }
+
+    pub fn depends_on(&self) -> &[String] {
+        match self {
+            TaskDef::Command(_) => &[],
+            TaskDef::Task(task) => &task.depends_on,
+        }
+    }
+
+    /// Tool versions this task overrides, if any.
+    pub fn tools(&self) -> Option<&HashMap<String, String>> {
+        match self {
+            TaskDef::Task(task) if !task.tools.is_empty() => Some(&task.tools),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -45,26 +155,37 @@ pub struct LoadedProjectConfig {
     pub root: PathBuf,
     pub path: PathBuf,
     pub config: ProjectConfig,
+    /// Root of the workspace this project is a member of; its env and commands are inherited.
+    pub workspace_root: Option<PathBuf>,
 }
 
+/// Load the nearest `.vex.toml`, merged with its workspace root's `[env]` and `[commands]` when
+/// the directory belongs to a workspace member.
 pub fn load_nearest_project_config(start_dir: &Path) -> Result<Option<LoadedProjectConfig>> {
     let Some(path) = find_nearest_project_file(start_dir) else {
         return Ok(None);
     };
 
-    let content = fs::read_to_string(&path)?;
+    let loaded = workspace::inherit_workspace(load_project_file(&path, start_dir)?, start_dir)?;
+    validate_task_dependencies(&loaded)?;
+    Ok(Some(loaded))
+}
+
+pub(crate) fn load_project_file(path: &Path, start_dir: &Path) -> Result<LoadedProjectConfig> {
+    let content = fs::read_to_string(path)?;
     let config: ProjectConfig = toml::from_str(&content)
         .map_err(|err| VexError::Config(format!("Failed to parse {}: {}", path.display(), err)))?;
-    validate_project_config(&path, &config)?;
+    validate_project_config(path, &config)?;
 
-    Ok(Some(LoadedProjectConfig {
+    Ok(LoadedProjectConfig {
         root: path
             .parent()
             .map(Path::to_path_buf)
             .unwrap_or_else(|| start_dir.to_path_buf()),
-        path,
+        path: path.to_path_buf(),
         config,
-    }))
+        workspace_root: None,
+    })
 }
 
 fn validate_project_config(path: &Path, config: &ProjectConfig) -> Result<()> {
@@ -78,6 +199,77 @@ fn validate_project_config(path: &Path, config: &ProjectConfig) -> Result<()> {
         }
     }
 
+    validate_tools(&config.tools, "[tools]", path)?;
+
+    for (name, task) in &config.commands {
+        let TaskDef::Task(task) = task else {
+            continue;
+        };
+        validate_tools(&task.tools, &format!("task '{}'", name), path)?;
+        if task.run.is_none() && task.depends_on.is_empty() {
+            return Err(VexError::Config(format!(
+                "Task '{}' in {} needs a 'run' command or 'depends_on' tasks.",
+                name,
+                path.display()
+            )));
+        }
+        if let Some(key) = task.env.keys().find(|key| !is_valid_env_key(key)) {
+            return Err(VexError::Config(format!(
+                "Invalid environment variable name '{}' in task '{}' in {}. Names must match [A-Za-z_][A-Za-z0-9_]*.",
+                key.escape_debug(),
+                name,
+                path.display()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_tools(tools: &HashMap<String, String>, section: &str, path: &Path) -> Result<()> {
+    let mut names = tools.keys().collect::<Vec<_>>();
+    names.sort();
+    for tool in names {
+        if crate::tools::get_tool(tool).is_err() {
+            return Err(VexError::Config(format!(
+                "Unknown tool '{}' in {} of {}. Supported tools: node, go, java, python, rust.",
+                tool,
+                section,
+                path.display()
+            )));
+        }
+        let version = &tools[tool];
+        if version.trim().is_empty() || version.contains(char::is_whitespace) {
+            return Err(VexError::Config(format!(
+                "Invalid version '{}' for {} in {} of {}.",
+                version.escape_debug(),
+                tool,
+                section,
+                path.display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Dependencies are checked once workspace commands are merged in, since a member task may
+/// depend on a task defined by the workspace root.
+fn validate_task_dependencies(loaded: &LoadedProjectConfig) -> Result<()> {
+    let commands = &loaded.config.commands;
+    for (name, task) in commands {
+        if let Some(missing) = task
+            .depends_on()
+            .iter()
+            .find(|dependency| !commands.contains_key(*dependency))
+        {
+            return Err(VexError::Config(format!(
+                "Task '{}' in {} depends on unknown task '{}'.",
+                name,
+                loaded.path.display(),
+                missing
+            )));
+        }
+    }
     Ok(())
 }
 
@@ -32,7 +32,11 @@ test = "cargo test"
         Some("debug")
     );
     assert_eq!(
-        loaded.config.commands.get("test").map(String::as_str),
+        loaded
+            .config
+            .commands
+            .get("test")
+            .and_then(TaskDef::command),
         Some("cargo test")
     );
 }
@@ -129,3 +133,209 @@ fn test_find_nearest_node_modules_bin() {
     let bin = find_nearest_node_modules_bin(&nested).expect("node_modules/.bin should be found");
     assert_eq!(bin, project.join("node_modules/.bin"));
 }
+
+#[test]
+fn test_load_structured_tasks_alongside_plain_commands() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join(".vex.toml"),
+        r#"
+[commands]
+lint = "cargo clippy"
+
+[commands.build]
+run = "cargo build"
+description = "Compile the workspace"
+depends_on = ["lint"]
+env = { RUSTFLAGS = "-D warnings" }
+cwd = "crates/app"
+sources = ["src/**/*.rs"]
+outputs = ["target/debug/app"]
+"#,
+    )
+    .unwrap();
+
+    let loaded = load_nearest_project_config(temp.path()).unwrap().unwrap();
+    let build = &loaded.config.commands["build"];
+    assert_eq!(build.command(), Some("cargo build"));
+    assert_eq!(build.description(), Some("Compile the workspace"));
+    assert_eq!(build.depends_on(), ["lint".to_string()]);
+    let TaskDef::Task(task) = build else {
+        panic!("build should be a structured task");
+    };
+    assert_eq!(task.cwd.as_deref(), Some("crates/app"));
+    assert_eq!(task.env["RUSTFLAGS"], "-D warnings");
+    assert_eq!(
+        loaded.config.commands["lint"].command(),
+        Some("cargo clippy")
+    );
+}
+
+#[test]
+fn test_structured_tasks_are_validated() {
+    let temp = TempDir::new().unwrap();
+    for (config, expected) in [
+        (
+            "[commands.build]\nrun = \"make\"\ndepends_on = [\"gen\"]\n",
+            "unknown task 'gen'",
+        ),
+        (
+            "[commands.all]\ndescription = \"nothing\"\n",
+            "needs a 'run'",
+        ),
+        (
+            "[commands.build]\nrun = \"make\"\nenv = { \"A-B\" = \"1\" }\n",
+            "Invalid environment variable name",
+        ),
+        (
+            "[commands.build]\nrun = \"make\"\nretries = 2\n",
+            "unknown field",
+        ),
+    ] {
+        fs::write(temp.path().join(".vex.toml"), config).unwrap();
+        let error = load_nearest_project_config(temp.path())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains(expected), "unexpected error: {error}");
+    }
+}
+
+fn write_workspace(root: &Path) {
+    fs::create_dir_all(root.join("packages/web/src")).unwrap();
+    fs::create_dir_all(root.join("packages/api")).unwrap();
+    fs::create_dir_all(root.join("tools/lint")).unwrap();
+    fs::write(
+        root.join(".vex.toml"),
+        r#"
+[workspace]
+members = ["packages/*"]
+
+[env]
+CI = "1"
+LOG_LEVEL = "info"
+
+[commands]
+test = "npm test"
+lint = "eslint ."
+"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("packages/web/.vex.toml"),
+        r#"
+[env]
+LOG_LEVEL = "debug"
+
+[commands]
+test = "vitest run"
+
+[commands.check]
+depends_on = ["lint", "test"]
+"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_workspace_members_expand_patterns() {
+    let temp = TempDir::new().unwrap();
+    write_workspace(temp.path());
+
+    let root = find_workspace_root(&temp.path().join("packages/web/src"))
+        .unwrap()
+        .expect("workspace root");
+    assert_eq!(root.root, temp.path());
+    assert_eq!(
+        workspace_members(&root).unwrap(),
+        vec![
+            temp.path().join("packages/api"),
+            temp.path().join("packages/web")
+        ]
+    );
+
+    fs::write(
+        temp.path().join(".vex.toml"),
+        "[workspace]\nmembers = [\"../outside\"]\n",
+    )
+    .unwrap();
+    let root = find_workspace_root(temp.path()).unwrap().unwrap();
+    let err = workspace_members(&root).unwrap_err().to_string();
+    assert!(err.contains("without '..'"), "unexpected error: {err}");
+}
+
+#[test]
+fn test_workspace_member_inherits_root_env_and_commands() {
+    let temp = TempDir::new().unwrap();
+    write_workspace(temp.path());
+
+    let web = load_nearest_project_config(&temp.path().join("packages/web/src"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(web.root, temp.path().join("packages/web"));
+    assert_eq!(web.workspace_root.as_deref(), Some(temp.path()));
+    assert_eq!(web.config.env["CI"], "1");
+    assert_eq!(web.config.env["LOG_LEVEL"], "debug");
+    assert_eq!(web.config.commands["test"].command(), Some("vitest run"));
+    assert_eq!(web.config.commands["lint"].command(), Some("eslint ."));
+    assert_eq!(
+        web.config.commands["check"].depends_on(),
+        ["lint".to_string(), "test".to_string()]
+    );
+
+    let api = load_nearest_project_config(&temp.path().join("packages/api"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(api.root, temp.path().join("packages/api"));
+    assert_eq!(api.path, temp.path().join(".vex.toml"));
+    assert!(api.config.workspace.is_none());
+    assert_eq!(api.config.commands["test"].command(), Some("npm test"));
+
+    let outside = load_nearest_project_config(&temp.path().join("tools/lint"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(outside.root, temp.path());
+    assert!(outside.workspace_root.is_none());
+}
+
+#[test]
+fn test_project_and_task_tools_are_validated() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join(".vex.toml");
+    fs::write(
+        &path,
+        r#"
+[tools]
+node = "20"
+
+[commands.test-legacy]
+run = "npm test"
+tools = { node = "18" }
+"#,
+    )
+    .unwrap();
+    let loaded = load_nearest_project_config(temp.path()).unwrap().unwrap();
+    assert_eq!(loaded.config.tools["node"], "20");
+    assert_eq!(
+        loaded.config.commands["test-legacy"].tools().unwrap()["node"],
+        "18"
+    );
+
+    fs::write(
+        &path,
+        "[commands.old]\nrun = \"x\"\ntools = { ruby = \"3\" }\n",
+    )
+    .unwrap();
+    let err = load_nearest_project_config(temp.path())
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("Unknown tool 'ruby' in task 'old'"),
+        "unexpected error: {err}"
+    );
+
+    fs::write(&path, "[tools]\nnode = \"\"\n").unwrap();
+    let err = load_nearest_project_config(temp.path())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Invalid version"), "unexpected error: {err}");
+}
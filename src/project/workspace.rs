@@ -0,0 +1,135 @@
+use super::{load_project_file, LoadedProjectConfig};
+use crate::error::{Result, VexError};
+use crate::fs_utils::wildcard_match;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// The nearest ancestor `.vex.toml` (including `start_dir`) that declares `[workspace]`.
+pub fn find_workspace_root(start_dir: &Path) -> Result<Option<LoadedProjectConfig>> {
+    let Some(path) = super::find_nearest_project_file(start_dir) else {
+        return Ok(None);
+    };
+    let loaded = load_project_file(&path, start_dir)?;
+    if loaded.config.workspace.is_some() {
+        return Ok(Some(loaded));
+    }
+    find_workspace_root_above(&loaded.root)
+}
+
+fn find_workspace_root_above(project_root: &Path) -> Result<Option<LoadedProjectConfig>> {
+    match project_root.parent() {
+        Some(parent) => find_workspace_root(parent),
+        None => Ok(None),
+    }
+}
+
+/// Member directories of a workspace root, sorted. Patterns may use `*` and `?` within a
+/// path component; only existing directories match.
+pub fn workspace_members(root: &LoadedProjectConfig) -> Result<Vec<PathBuf>> {
+    let Some(workspace) = &root.config.workspace else {
+        return Ok(Vec::new());
+    };
+
+    let mut members = Vec::new();
+    for pattern in &workspace.members {
+        let components = Path::new(pattern).components().collect::<Vec<_>>();
+        if components.is_empty()
+            || !components
+                .iter()
+                .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(VexError::Config(format!(
+                "Workspace member '{}' in {} must be a path relative to the workspace root without '..'.",
+                pattern,
+                root.path.display()
+            )));
+        }
+        let components = components
+            .iter()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        collect_members(&root.root, &components, &mut members);
+    }
+
+    members.retain(|member| member != &root.root);
+    members.sort();
+    members.dedup();
+    Ok(members)
+}
+
+fn collect_members(base: &Path, components: &[String], members: &mut Vec<PathBuf>) {
+    let Some((first, rest)) = components.split_first() else {
+        if base.is_dir() {
+            members.push(base.to_path_buf());
+        }
+        return;
+    };
+
+    if !first.contains(['*', '?']) {
+        collect_members(&base.join(first), rest, members);
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(base) else {
+        return;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.starts_with('.')
+            && entry.file_type().is_ok_and(|kind| kind.is_dir())
+            && wildcard_match(first, &name)
+        {
+            collect_members(&entry.path(), rest, members);
+        }
+    }
+}
+
+/// Attach a loaded `.vex.toml` to its workspace.
+///
+/// A member with its own `.vex.toml` inherits the root's `[env]` and `[commands]`, with its own
+/// entries taking precedence. A directory inside a member without a `.vex.toml` gets the root
+/// config, rooted at the member directory so tasks run there.
+pub(super) fn inherit_workspace(
+    loaded: LoadedProjectConfig,
+    start_dir: &Path,
+) -> Result<LoadedProjectConfig> {
+    if loaded.config.workspace.is_some() {
+        let members = workspace_members(&loaded)?;
+        let Some(member) = members
+            .into_iter()
+            .filter(|member| start_dir.starts_with(member))
+            .max_by_key(|member| member.components().count())
+        else {
+            return Ok(loaded);
+        };
+
+        let mut config = loaded.config.clone();
+        config.workspace = None;
+        return Ok(LoadedProjectConfig {
+            root: member,
+            path: loaded.path,
+            config,
+            workspace_root: Some(loaded.root),
+        });
+    }
+
+    let Some(root) = find_workspace_root_above(&loaded.root)? else {
+        return Ok(loaded);
+    };
+    if !workspace_members(&root)?.contains(&loaded.root) {
+        return Ok(loaded);
+    }
+
+    let mut member = loaded;
+    for (key, value) in root.config.env {
+        member.config.env.entry(key).or_insert(value);
+    }
+    for (name, task) in root.config.commands {
+        member.config.commands.entry(name).or_insert(task);
+    }
+    member.workspace_root = Some(root.root);
+    Ok(member)
+}
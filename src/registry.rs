@@ -1 +1,194 @@
+//! Project registry
+//!
+//! Records the project roots vex has worked in (`~/.vex/projects.json`) so pruning and
+//! `vex doctor` can account for versions pinned in checkouts other than the current one.
 
+use crate::error::{Result, VexError};
+use crate::output::{self, Event};
+use crate::paths::vex_dir;
+use crate::resolver;
+use chrono::Utc;
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const REGISTRY_FILE: &str = "projects.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProjectEntry {
+    pub root: PathBuf,
+    pub last_seen: String,
+}
+
+impl ProjectEntry {
+    /// A project is stale once its root is gone or no longer pins any versions.
+    pub fn is_stale(&self) -> bool {
+        resolver::find_project_root(&self.root).as_deref() != Some(self.root.as_path())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProjectRegistry {
+    #[serde(default)]
+    projects: Vec<ProjectEntry>,
+}
+
+pub fn registry_path(vex_dir: &Path) -> PathBuf {
+    vex_dir.join(REGISTRY_FILE)
+}
+
+/// Registered projects, oldest registration first. A missing or unreadable registry is empty.
+pub fn load(vex_dir: &Path) -> Vec<ProjectEntry> {
+    read_registry(vex_dir).projects
+}
+
+/// Register the project that contains `start_dir`, if any.
+///
+/// The registry is only rewritten when the project is new or was last seen on an earlier day,
+/// so the shell hook does not touch the file on every prompt.
+pub fn record(vex_dir: &Path, start_dir: &Path) -> Result<Option<PathBuf>> {
+    let Some(root) = resolver::find_project_root(start_dir) else {
+        return Ok(None);
+    };
+    let root = fs::canonicalize(&root).unwrap_or(root);
+    let now = Utc::now().to_rfc3339();
+    let today = &now[..10];
+
+    let seen_today = |registry: &ProjectRegistry| {
+        registry
+            .projects
+            .iter()
+            .any(|entry| entry.root == root && entry.last_seen.starts_with(today))
+    };
+    if seen_today(&read_registry(vex_dir)) {
+        return Ok(Some(root));
+    }
+
+    let _lock = lock_registry(vex_dir)?;
+    let mut registry = read_registry_for_update(vex_dir)?;
+    if seen_today(&registry) {
+        return Ok(Some(root));
+    }
+    match registry
+        .projects
+        .iter_mut()
+        .find(|entry| entry.root == root)
+    {
+        Some(entry) => entry.last_seen = now,
+        None => registry.projects.push(ProjectEntry {
+            root: root.clone(),
+            last_seen: now,
+        }),
+    }
+
+    write_registry(vex_dir, &registry)?;
+    Ok(Some(root))
+}
+
+/// Best-effort registration of the current directory's project. Failures never interrupt the
+/// command that triggered it.
+pub fn record_current_project() {
+    let result = vex_dir().and_then(|vex| record(&vex, &resolver::current_dir()));
+    if let Err(err) = result {
+        tracing::debug!("Could not update the project registry: {}", err);
+    }
+}
+
+/// Remove a project from the registry. Returns `false` when it was not registered.
+pub fn forget(vex_dir: &Path, root: &Path) -> Result<bool> {
+    let root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    let _lock = lock_registry(vex_dir)?;
+    let mut registry = read_registry_for_update(vex_dir)?;
+    let before = registry.projects.len();
+    registry.projects.retain(|entry| entry.root != root);
+    if registry.projects.len() == before {
+        return Ok(false);
+    }
+
+    write_registry(vex_dir, &registry)?;
+    Ok(true)
+}
+
+/// Drop every stale entry and return what was removed.
+pub fn forget_stale(vex_dir: &Path) -> Result<Vec<ProjectEntry>> {
+    let _lock = lock_registry(vex_dir)?;
+    let mut registry = read_registry_for_update(vex_dir)?;
+    let (stale, live): (Vec<_>, Vec<_>) = registry
+        .projects
+        .into_iter()
+        .partition(ProjectEntry::is_stale);
+    registry.projects = live;
+
+    if !stale.is_empty() {
+        write_registry(vex_dir, &registry)?;
+    }
+    Ok(stale)
+}
+
+/// Hold an exclusive lock for a read-modify-write of the registry, so shell hooks running in
+/// several terminals at once do not drop each other's entries. Released when the file drops.
+fn lock_registry(vex_dir: &Path) -> Result<File> {
+    fs::create_dir_all(vex_dir)?;
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(vex_dir.join(format!("{}.lock", REGISTRY_FILE)))?;
+    file.lock_exclusive()?;
+    Ok(file)
+}
+
+fn read_registry(vex_dir: &Path) -> ProjectRegistry {
+    fs::read_to_string(registry_path(vex_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// The registry for a locked update. A registry that does not parse is moved aside to
+/// `projects.json.corrupt` with a warning, so the rewrite never silently drops its entries.
+fn read_registry_for_update(vex_dir: &Path) -> Result<ProjectRegistry> {
+    let path = registry_path(vex_dir);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(ProjectRegistry::default()),
+        Err(err) => return Err(err.into()),
+    };
+    let err = match serde_json::from_str(&content) {
+        Ok(registry) => return Ok(registry),
+        Err(err) => err,
+    };
+
+    let backup = vex_dir.join(format!("{}.corrupt", REGISTRY_FILE));
+    fs::rename(&path, &backup)?;
+    let message = format!(
+        "{} could not be parsed ({}); moved it to {} and started a new project registry",
+        path.display(),
+        err,
+        backup.display()
+    );
+    if !output::is_quiet() {
+        eprintln!("vex: warning: {}", message);
+    }
+    output::emit(Event::Warning { message });
+    Ok(ProjectRegistry::default())
+}
+
+fn write_registry(vex_dir: &Path, registry: &ProjectRegistry) -> Result<()> {
+    fs::create_dir_all(vex_dir)?;
+    let json = serde_json::to_string_pretty(registry)
+        .map_err(|err| VexError::Parse(format!("Invalid project registry: {}", err)))?;
+
+    // Readers do not take the lock; write to a temporary file and rename it into place so they
+    // never see a partial registry.
+    let path = registry_path(vex_dir);
+    let tmp = vex_dir.join(format!("{}.{}.tmp", REGISTRY_FILE, std::process::id()));
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,126 @@
+use super::*;
+use crate::version_state;
+use tempfile::TempDir;
+
+fn project_with_pins(parent: &Path, name: &str, pins: &str) -> PathBuf {
+    let root = parent.join(name);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join(".tool-versions"), pins).unwrap();
+    fs::canonicalize(root).unwrap()
+}
+
+#[test]
+fn test_record_registers_nearest_project_root_once() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let root = project_with_pins(temp.path(), "app", "node 20.11.0\n");
+
+    let recorded = record(&vex, &root.join("src")).unwrap();
+    assert_eq!(recorded.as_deref(), Some(root.as_path()));
+    record(&vex, &root).unwrap();
+
+    let projects = load(&vex);
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].root, root);
+}
+
+#[test]
+fn test_concurrent_records_keep_every_project() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let roots = (0..8)
+        .map(|index| project_with_pins(temp.path(), &format!("app-{}", index), "node 20\n"))
+        .collect::<Vec<_>>();
+
+    std::thread::scope(|scope| {
+        for root in &roots {
+            let vex = &vex;
+            scope.spawn(move || record(vex, root).unwrap());
+        }
+    });
+
+    let mut registered = load(&vex)
+        .into_iter()
+        .map(|entry| entry.root)
+        .collect::<Vec<_>>();
+    registered.sort();
+    assert_eq!(registered, roots);
+}
+
+#[test]
+fn test_record_ignores_directories_without_version_files() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let plain = temp.path().join("plain");
+    fs::create_dir_all(&plain).unwrap();
+
+    assert_eq!(record(&vex, &plain).unwrap(), None);
+    assert!(!registry_path(&vex).exists());
+}
+
+#[test]
+fn test_forget_and_forget_stale() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let kept = project_with_pins(temp.path(), "kept", "go 1.22.0\n");
+    let removed = project_with_pins(temp.path(), "removed", "go 1.21.0\n");
+    let forgotten = project_with_pins(temp.path(), "forgotten", "go 1.20.0\n");
+    for root in [&kept, &removed, &forgotten] {
+        record(&vex, root).unwrap();
+    }
+
+    assert!(forget(&vex, &forgotten).unwrap());
+    assert!(!forget(&vex, &forgotten).unwrap());
+
+    fs::remove_dir_all(&removed).unwrap();
+    let stale = forget_stale(&vex).unwrap();
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].root, removed);
+
+    let roots = load(&vex)
+        .into_iter()
+        .map(|entry| entry.root)
+        .collect::<Vec<_>>();
+    assert_eq!(roots, vec![kept]);
+}
+
+#[test]
+fn test_retained_versions_include_registered_projects() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    let cwd = temp.path().join("elsewhere");
+    fs::create_dir_all(&cwd).unwrap();
+    fs::create_dir_all(vex.join("toolchains/node/20.11.0")).unwrap();
+    let other = project_with_pins(temp.path(), "other", "node 20\n");
+    let gone = project_with_pins(temp.path(), "gone", "node 18.19.0\n");
+    record(&vex, &other).unwrap();
+    record(&vex, &gone).unwrap();
+    fs::remove_file(gone.join(".tool-versions")).unwrap();
+
+    let retained = version_state::retained_versions(&vex, &cwd).unwrap();
+
+    assert_eq!(
+        retained
+            .get(&("node".to_string(), "20.11.0".to_string()))
+            .map(String::as_str),
+        Some(format!("project {}", other.display()).as_str())
+    );
+    assert!(!retained.contains_key(&("node".to_string(), "18.19.0".to_string())));
+}
+
+#[test]
+fn test_record_moves_a_corrupt_registry_aside() {
+    let temp = TempDir::new().unwrap();
+    let vex = temp.path().join(".vex");
+    fs::create_dir_all(&vex).unwrap();
+    fs::write(registry_path(&vex), "{\"projects\": [").unwrap();
+    let root = project_with_pins(temp.path(), "app", "node 20\n");
+
+    record(&vex, &root).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(vex.join("projects.json.corrupt")).unwrap(),
+        "{\"projects\": ["
+    );
+    assert_eq!(load(&vex).len(), 1);
+}
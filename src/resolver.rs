@@ -1,17 +1,21 @@
 //! Version file resolution module
 //!
 //! Traverses upward from project directory to find version files (`.tool-versions`, `.node-version`, etc.).
-//! `.tool-versions` has higher priority than language-specific files.
+//! `.tool-versions` has higher priority than the `[tools]` table of `.vex.toml`, which in turn
+//! has higher priority than language-specific files.
 
 mod discovery;
 
 #[cfg(test)]
 pub use discovery::resolve_version;
 pub use discovery::{
-    current_dir, find_project_source, read_tool_versions_file, resolve_local_tool_versions_only,
-    resolve_project_versions, resolve_versions,
+    current_dir, find_project_root, find_project_source, read_tool_versions_file,
+    resolve_local_tool_versions_only, resolve_project_versions, resolve_versions, version_requests,
 };
 
+/// Project config file whose `[tools]` table pins versions
+pub(super) const PROJECT_CONFIG_FILE: &str = ".vex.toml";
+
 /// Language-specific version file mappings
 pub(super) const TOOL_VERSION_FILES: &[(&str, &str)] = &[
     (".node-version", "node"),
@@ -6,10 +6,18 @@ mod project;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+/// A version requested for one tool by one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRequest {
+    pub path: PathBuf,
+    pub version: String,
+}
+
 /// Traverse upward from start directory to find version mappings for all tools
 ///
-/// `.tool-versions` has higher priority than language-specific files (`.node-version`, etc.).
-/// First found version takes precedence (child directory over parent directory).
+/// `.tool-versions` has higher priority than `.vex.toml` `[tools]`, which has higher priority
+/// than language-specific files (`.node-version`, etc.). Within each kind, the first found version
+/// takes precedence (child directory over parent directory).
 ///
 /// # Arguments
 /// - `start_dir` - Directory to start searching from
@@ -40,6 +48,23 @@ pub fn read_tool_versions_file(path: &Path) -> HashMap<String, String> {
     files::read_tool_versions_file(path)
 }
 
+/// Return the nearest ancestor directory (including `start_dir`) that pins versions.
+pub fn find_project_root(start_dir: &Path) -> Option<PathBuf> {
+    project::find_project_root(start_dir)
+}
+
+/// Every file that requests a version of `tool_name`, most significant first: project files in
+/// [`resolve_versions`] precedence order, then the global `~/.vex/tool-versions`.
+pub fn version_requests(start_dir: &Path, tool_name: &str) -> Vec<VersionRequest> {
+    let mut requests = project::project_version_requests(start_dir, tool_name);
+    if let Some(path) = global::vex_global_tool_versions() {
+        if let Some(version) = read_tool_versions_file(&path).remove(tool_name) {
+            requests.push(VersionRequest { path, version });
+        }
+    }
+    requests
+}
+
 pub fn find_project_source(start_dir: &Path, tool_name: &str) -> Option<PathBuf> {
     project::find_project_source(start_dir, tool_name)
 }
@@ -1,8 +1,7 @@
 use crate::resolver::parse_tool_versions;
-use crate::resolver::TOOL_VERSION_FILES;
 use std::collections::HashMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 pub(super) fn read_tool_versions_file(path: &Path) -> HashMap<String, String> {
     let Ok(content) = fs::read_to_string(path) else {
@@ -12,12 +11,27 @@ pub(super) fn read_tool_versions_file(path: &Path) -> HashMap<String, String> {
     parse_tool_versions(&content).into_iter().collect()
 }
 
-pub(super) fn find_tool_specific_version_file(dir: &Path, tool_name: &str) -> Option<PathBuf> {
-    TOOL_VERSION_FILES
+/// Versions pinned by the `[tools]` table of a `.vex.toml` file.
+///
+/// Unreadable or invalid files pin nothing here; loading the project config reports the error.
+pub(super) fn read_project_tools(path: &Path) -> HashMap<String, String> {
+    let Some(table) = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+    else {
+        return HashMap::new();
+    };
+    let Some(tools) = table.get("tools").and_then(toml::Value::as_table) else {
+        return HashMap::new();
+    };
+
+    tools
         .iter()
-        .filter(|(_, tool)| *tool == tool_name)
-        .map(|(file, _)| dir.join(file))
-        .find(|path| path.is_file())
+        .filter_map(|(tool, version)| {
+            let version = version.as_str()?.trim();
+            (!version.is_empty()).then(|| (tool.clone(), version.to_string()))
+        })
+        .collect()
 }
 
 pub(super) fn read_language_version_file(path: &Path) -> Option<String> {
@@ -1,29 +1,32 @@
-use super::files::{
-    find_tool_specific_version_file, read_language_version_file, read_tool_versions_file,
-};
+use super::files::{read_language_version_file, read_project_tools, read_tool_versions_file};
 #[cfg(test)]
 use super::global::vex_global_tool_versions;
+use super::VersionRequest;
 use crate::resolver::parse_tool_versions;
-use crate::resolver::TOOL_VERSION_FILES;
+use crate::resolver::{PROJECT_CONFIG_FILE, TOOL_VERSION_FILES};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Versions pinned by the project tree around `start_dir`.
+///
+/// Every `.tool-versions` from `start_dir` upward wins over every `.vex.toml` `[tools]` table,
+/// which in turn wins over language-specific files. Within each kind, nearer directories win.
 pub(super) fn resolve_project_versions(start_dir: &Path) -> HashMap<String, String> {
     let mut versions = collect_tool_versions_from_ancestors(start_dir);
-    let mut dir = start_dir.to_path_buf();
+    for dir in start_dir.ancestors() {
+        for (tool, version) in read_project_tools(&dir.join(PROJECT_CONFIG_FILE)) {
+            versions.entry(tool).or_insert(version);
+        }
+    }
 
-    loop {
+    for dir in start_dir.ancestors() {
         for (file, tool) in TOOL_VERSION_FILES {
             let path = dir.join(file);
             if let Some(version) = read_language_version_file(&path) {
                 versions.entry(tool.to_string()).or_insert(version);
             }
         }
-
-        if !dir.pop() {
-            break;
-        }
     }
 
     versions
@@ -51,53 +54,63 @@ pub(super) fn collect_tool_versions_from_ancestors(start_dir: &Path) -> HashMap<
     versions
 }
 
-pub(super) fn find_project_source(start_dir: &Path, tool_name: &str) -> Option<PathBuf> {
-    let mut dir = start_dir.to_path_buf();
+pub(super) fn find_project_root(start_dir: &Path) -> Option<PathBuf> {
+    start_dir
+        .ancestors()
+        .find(|dir| {
+            dir.join(".tool-versions").is_file()
+                || TOOL_VERSION_FILES
+                    .iter()
+                    .any(|(file, _)| dir.join(file).is_file())
+                || !read_project_tools(&dir.join(PROJECT_CONFIG_FILE)).is_empty()
+        })
+        .map(Path::to_path_buf)
+}
 
-    loop {
-        let tool_versions = dir.join(".tool-versions");
-        if tool_versions.is_file()
-            && read_tool_versions_file(&tool_versions).contains_key(tool_name)
-        {
-            return Some(tool_versions);
+/// Every project file that requests `tool_name`, in the precedence order of
+/// [`resolve_project_versions`]; the first entry is the one in effect.
+pub(super) fn project_version_requests(start_dir: &Path, tool_name: &str) -> Vec<VersionRequest> {
+    let mut requests = Vec::new();
+    for dir in start_dir.ancestors() {
+        let path = dir.join(".tool-versions");
+        if let Some(version) = read_tool_versions_file(&path).remove(tool_name) {
+            requests.push(VersionRequest { path, version });
         }
-
-        if let Some(path) = find_tool_specific_version_file(&dir, tool_name) {
-            return Some(path);
+    }
+    for dir in start_dir.ancestors() {
+        let path = dir.join(PROJECT_CONFIG_FILE);
+        if let Some(version) = read_project_tools(&path).remove(tool_name) {
+            requests.push(VersionRequest { path, version });
         }
-
-        if !dir.pop() {
-            break;
+    }
+    for dir in start_dir.ancestors() {
+        for (file, _) in TOOL_VERSION_FILES
+            .iter()
+            .filter(|(_, tool)| *tool == tool_name)
+        {
+            let path = dir.join(file);
+            if let Some(version) = read_language_version_file(&path) {
+                requests.push(VersionRequest { path, version });
+            }
         }
     }
+    requests
+}
 
-    None
+pub(super) fn find_project_source(start_dir: &Path, tool_name: &str) -> Option<PathBuf> {
+    project_version_requests(start_dir, tool_name)
+        .into_iter()
+        .next()
+        .map(|request| request.path)
 }
 
 #[cfg(test)]
 pub(super) fn resolve_version(tool_name: &str, start_dir: &Path) -> Option<String> {
-    let mut dir = start_dir.to_path_buf();
-
-    loop {
-        let tool_versions = dir.join(".tool-versions");
-        if tool_versions.is_file() {
-            if let Some(version) = read_tool_versions_file(&tool_versions)
-                .into_iter()
-                .find_map(|(tool, version)| (tool == tool_name).then_some(version))
-            {
-                return Some(version);
-            }
-        }
-
-        if let Some(path) = find_tool_specific_version_file(&dir, tool_name) {
-            if let Some(version) = read_language_version_file(&path) {
-                return Some(version);
-            }
-        }
-
-        if !dir.pop() {
-            break;
-        }
+    if let Some(request) = project_version_requests(start_dir, tool_name)
+        .into_iter()
+        .next()
+    {
+        return Some(request.version);
     }
 
     if let Some(global_path) = vex_global_tool_versions() {
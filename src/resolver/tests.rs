@@ -260,3 +260,35 @@ fn test_parse_tool_versions_multiple_spaces() {
     assert_eq!(result[0], ("node".into(), "20.11.0".into()));
     assert_eq!(result[1], ("go".into(), "1.23.5".into()));
 }
+
+#[test]
+fn test_vex_toml_tools_rank_between_tool_versions_and_language_files() {
+    let root = std::env::temp_dir().join("vex_test_vex_toml_tools");
+    let nested = root.join("nested");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&nested).unwrap();
+
+    fs::write(root.join(".tool-versions"), "node 20.0.0\n").unwrap();
+    fs::write(
+        root.join(".vex.toml"),
+        "[tools]\nnode = \"18\"\ngo = \"1.24\"\npython = \"3.12\"\n",
+    )
+    .unwrap();
+    fs::write(nested.join(".python-version"), "3.11.9\n").unwrap();
+
+    let versions = resolve_project_versions(&nested);
+    assert_eq!(versions.get("node"), Some(&"20.0.0".into()));
+    assert_eq!(versions.get("go"), Some(&"1.24".into()));
+    assert_eq!(versions.get("python"), Some(&"3.12".into()));
+
+    let requests = version_requests(&nested, "node");
+    assert_eq!(requests[0].path, root.join(".tool-versions"));
+    assert_eq!(requests[1].path, root.join(".vex.toml"));
+    assert_eq!(requests[1].version, "18");
+    assert_eq!(
+        find_project_source(&nested, "python"),
+        Some(root.join(".vex.toml"))
+    );
+
+    let _ = fs::remove_dir_all(&root);
+}
@@ -8,7 +8,7 @@ use crate::activation::ActivationPlan;
 mod detection;
 mod hooks;
 
-pub use detection::{detect_shell, get_shell_config_path, is_vex_configured};
+pub use detection::{detect_shell, detect_tool_manager, get_shell_config_path, is_vex_configured};
 use hooks::{
     generate_bash_exports, generate_bash_hook, generate_fish_exports, generate_fish_hook,
     generate_nushell_exports, generate_nushell_hook, generate_zsh_exports, generate_zsh_hook,
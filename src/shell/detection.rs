@@ -76,3 +76,26 @@ pub fn is_vex_configured(config_path: &std::path::Path) -> std::io::Result<bool>
     let content = std::fs::read_to_string(config_path)?;
     Ok(content.contains("vex env") || content.contains("# vex shell integration"))
 }
+
+/// Name the version manager that owns a `PATH` entry, such as `nvm` or `pyenv shims`.
+pub fn detect_tool_manager(entry: &str) -> Option<&'static str> {
+    if entry.contains("/.pyenv/shims") {
+        Some("pyenv shims")
+    } else if entry.contains("/.pyenv/bin") {
+        Some("pyenv")
+    } else if entry.contains("/.nvm/") {
+        Some("nvm")
+    } else if entry.contains("/.fnm/") {
+        Some("fnm")
+    } else if entry.contains("/.volta/bin") {
+        Some("volta")
+    } else if entry.contains("/.asdf/") {
+        Some("asdf")
+    } else if entry.contains("/.cargo/bin") && !entry.contains("/.vex/cargo/bin") {
+        Some("rustup cargo env")
+    } else if entry.contains("/.vex/cargo/bin") {
+        Some("cargo env")
+    } else {
+        None
+    }
+}
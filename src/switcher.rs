@@ -8,7 +8,9 @@ mod links;
 mod rollback;
 
 use crate::error::{Result, VexError};
+use crate::output::{self, Event};
 use crate::paths::vex_dir;
+use crate::toolchain_usage;
 use crate::tools::Tool;
 use owo_colors::OwoColorize;
 use std::fs;
@@ -47,12 +49,14 @@ fn switch_version_in(tool: &dyn Tool, version: &str, base_dir: &Path) -> Result<
     let old_version = current_version(base_dir, tool.name());
     debug!("Current version: {:?}", old_version);
 
-    println!(
-        "{} {} to version {}...",
-        "Switching".cyan(),
-        tool.name().yellow(),
-        version.yellow()
-    );
+    if !output::is_quiet() {
+        println!(
+            "{} {} to version {}...",
+            "Switching".cyan(),
+            tool.name().yellow(),
+            version.yellow()
+        );
+    }
 
     match links::perform_switch(tool, base_dir, &toolchain_dir) {
         Ok(_) => {
@@ -61,7 +65,16 @@ fn switch_version_in(tool: &dyn Tool, version: &str, base_dir: &Path) -> Result<
                 attempt_rollback(tool, base_dir, old_version.as_deref());
                 return Err(err);
             }
-            println!("{} Switched to {}@{}", "✓".green(), tool.name(), version);
+            if let Err(err) = toolchain_usage::record(base_dir, [(tool.name(), version)]) {
+                debug!("Could not record toolchain usage: {}", err);
+            }
+            output::emit(Event::Switched {
+                tool: tool.name().to_string(),
+                version: version.to_string(),
+            });
+            if !output::is_quiet() {
+                println!("{} Switched to {}@{}", "✓".green(), tool.name(), version);
+            }
             Ok(())
         }
         Err(err) => {
@@ -72,6 +85,36 @@ fn switch_version_in(tool: &dyn Tool, version: &str, base_dir: &Path) -> Result<
     }
 }
 
+/// Restore a tool's activation to `previous_version`, or deactivate it when it had none.
+///
+/// Used to roll back multi-tool operations such as `vex upgrade --all`, so unlike
+/// [`switch_version`] it does not print progress or record toolchain usage.
+pub fn restore_version(tool: &dyn Tool, previous_version: Option<&str>) -> Result<()> {
+    restore_version_in(tool, previous_version, &vex_dir()?)
+}
+
+fn restore_version_in(
+    tool: &dyn Tool,
+    previous_version: Option<&str>,
+    base_dir: &Path,
+) -> Result<()> {
+    let Some(version) = previous_version else {
+        return links::remove_links(tool, base_dir);
+    };
+
+    let toolchain_dir = base_dir.join("toolchains").join(tool.name()).join(version);
+    if !toolchain_dir.exists() {
+        return Err(VexError::VersionNotFound {
+            tool: tool.name().to_string(),
+            version: version.to_string(),
+            suggestions: String::new(),
+        });
+    }
+
+    links::perform_switch(tool, base_dir, &toolchain_dir)?;
+    tool.post_switch(base_dir, &toolchain_dir, version)
+}
+
 fn relink_current_tool_in(tool: &dyn Tool, base_dir: &Path) -> Result<()> {
     let current_link = base_dir.join("current").join(tool.name());
     if !current_link.exists() {
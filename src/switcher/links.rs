@@ -20,6 +20,16 @@ pub(super) fn rebuild_bin_links(
     update_bin_links(tool, base_dir, toolchain_dir)
 }
 
+/// Remove the `current` link and every bin link that points into the tool's toolchains.
+pub(super) fn remove_links(tool: &dyn Tool, base_dir: &Path) -> Result<()> {
+    let current_link = base_dir.join("current").join(tool.name());
+    if fs::symlink_metadata(&current_link).is_ok() {
+        fs::remove_file(&current_link)?;
+    }
+    cleanup_stale_bin_links(tool, &base_dir.join("bin"), &HashSet::new());
+    Ok(())
+}
+
 fn update_current_symlink(tool: &dyn Tool, base_dir: &Path, toolchain_dir: &Path) -> Result<()> {
     let current_dir = base_dir.join("current");
     fs::create_dir_all(&current_dir)?;
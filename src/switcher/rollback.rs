@@ -1,4 +1,5 @@
 use super::links;
+use crate::output::{self, Event};
 use crate::tools::Tool;
 use owo_colors::OwoColorize;
 use std::fs;
@@ -22,11 +23,21 @@ pub(super) fn attempt_rollback(tool: &dyn Tool, base_dir: &Path, previous_versio
         return;
     };
 
-    eprintln!(
-        "{} Version switch failed, rolling back to {}...",
-        "⚠".yellow(),
-        previous_version
-    );
+    let quiet = output::is_quiet();
+    if !quiet {
+        eprintln!(
+            "{} Version switch failed, rolling back to {}...",
+            "⚠".yellow(),
+            previous_version
+        );
+    }
+    output::emit(Event::Warning {
+        message: format!(
+            "Version switch failed, rolling back to {}@{}",
+            tool.name(),
+            previous_version
+        ),
+    });
 
     let previous_toolchain_dir = base_dir
         .join("toolchains")
@@ -40,16 +51,26 @@ pub(super) fn attempt_rollback(tool: &dyn Tool, base_dir: &Path, previous_versio
 
     match links::perform_switch(tool, base_dir, &previous_toolchain_dir) {
         Ok(_) => {
-            eprintln!(
-                "{} Rolled back to {}@{}",
-                "✓".green(),
-                tool.name(),
-                previous_version
-            );
+            if !quiet {
+                eprintln!(
+                    "{} Rolled back to {}@{}",
+                    "✓".green(),
+                    tool.name(),
+                    previous_version
+                );
+            }
+            output::emit(Event::Progress {
+                message: format!("Rolled back to {}@{}", tool.name(), previous_version),
+            });
         }
         Err(rollback_err) => {
             warn!("Rollback also failed: {}", rollback_err);
-            eprintln!("{} Rollback failed: {}", "✗".red(), rollback_err);
+            if !quiet {
+                eprintln!("{} Rollback failed: {}", "✗".red(), rollback_err);
+            }
+            output::emit(Event::Warning {
+                message: format!("Rollback failed: {}", rollback_err),
+            });
         }
     }
 }
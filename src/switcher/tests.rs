@@ -610,3 +610,29 @@ fn test_relink_current_tool_requires_active_version() {
     assert!(err.contains("No active node version found"));
     let _ = fs::remove_dir_all(&base);
 }
+
+#[test]
+fn test_restore_version_switches_back_or_removes_links() {
+    let base = make_temp_dir("restore_version");
+    for version in ["20.11.0", "22.1.0"] {
+        let tc = base.join(format!("toolchains/node/{}/bin", version));
+        fs::create_dir_all(&tc).unwrap();
+        for name in &["node", "npm", "npx"] {
+            fs::write(tc.join(name), "fake").unwrap();
+        }
+    }
+
+    switch_version_in(&NodeTool, "22.1.0", &base).unwrap();
+    restore_version_in(&NodeTool, Some("20.11.0"), &base).unwrap();
+    let target = fs::read_link(base.join("current/node")).unwrap();
+    assert!(target.ends_with("toolchains/node/20.11.0"));
+    let node_target = fs::read_link(base.join("bin/node")).unwrap();
+    assert!(node_target.ends_with("toolchains/node/20.11.0/bin/node"));
+
+    restore_version_in(&NodeTool, None, &base).unwrap();
+    assert!(fs::symlink_metadata(base.join("current/node")).is_err());
+    assert!(fs::symlink_metadata(base.join("bin/node")).is_err());
+    assert!(fs::symlink_metadata(base.join("bin/npm")).is_err());
+
+    let _ = fs::remove_dir_all(&base);
+}
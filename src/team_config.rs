@@ -1,15 +1,21 @@
 mod parse;
 mod source;
+mod verify;
 
+use crate::config;
 use crate::error::{Result, VexError};
+use crate::policy::PolicyConfig;
 use crate::resolver;
-use parse::parse_team_config;
+use parse::{parse_team_config, parse_team_policy, parse_team_templates};
 use source::{
     classify_source, load_https_team_config, load_team_config_file, load_team_config_from_git_repo,
     load_version_file, SourceKind,
 };
 use std::collections::BTreeMap;
 use std::path::Path;
+use verify::TrustPolicy;
+
+pub(crate) use source::{checkout_template, parse_git_template_source, GitCheckout, GitSource};
 
 pub(super) const TEAM_CONFIG_FILE: &str = "vex-config.toml";
 
@@ -17,17 +23,31 @@ pub(super) const TEAM_CONFIG_FILE: &str = "vex-config.toml";
 pub struct LoadedVersions {
     pub description: String,
     pub versions: Vec<(String, String)>,
+    /// `[policy]` declared by a team config; empty for plain version files.
+    pub policy: PolicyConfig,
+    /// `[templates]` name → source mappings declared by a team config.
+    pub templates: BTreeMap<String, String>,
 }
 
 pub fn load_versions_from_source(
     source: &str,
     start_dir: &Path,
     offline: bool,
+) -> Result<LoadedVersions> {
+    let policy = TrustPolicy::from_settings(&config::load_settings()?);
+    load_versions_from_source_with_trust(source, start_dir, offline, &policy)
+}
+
+fn load_versions_from_source_with_trust(
+    source: &str,
+    start_dir: &Path,
+    offline: bool,
+    policy: &TrustPolicy,
 ) -> Result<LoadedVersions> {
     let source_kind = classify_source(source, start_dir)?;
     match source_kind {
         SourceKind::VersionFile(path) => load_version_file(&path),
-        SourceKind::TeamConfigFile(path) => load_team_config_file(&path, start_dir),
+        SourceKind::TeamConfigFile(path) => load_team_config_file(&path, start_dir, policy),
         SourceKind::HttpsTeamConfig(url) => {
             if offline {
                 return Err(VexError::OfflineModeError(
@@ -35,17 +55,11 @@ pub fn load_versions_from_source(
                         .to_string(),
                 ));
             }
-            load_https_team_config(&url, start_dir)
+            load_https_team_config(&url, start_dir, policy)
         }
-        SourceKind::GitRepo { source, is_local } => {
-            if offline && !is_local {
-                return Err(VexError::OfflineModeError(
-                    "Remote Git team config requires network access. Re-run without --offline."
-                        .to_string(),
-                ));
-            }
-            let content = load_team_config_from_git_repo(&source, is_local)?;
-            load_team_config(&content, source, start_dir)
+        SourceKind::GitRepo(git) => {
+            let content = load_team_config_from_git_repo(&git, offline, policy)?;
+            load_team_config(&content, git.description(), start_dir)
         }
     }
 }
@@ -56,6 +70,8 @@ fn load_team_config(
     start_dir: &Path,
 ) -> Result<LoadedVersions> {
     let baseline = parse_team_config(content)?;
+    let policy = parse_team_policy(content)?;
+    let templates = parse_team_templates(content)?;
     let overrides = resolver::resolve_local_tool_versions_only(start_dir);
 
     let mut merged: BTreeMap<String, String> = baseline.into_iter().collect();
@@ -66,6 +82,8 @@ fn load_team_config(
     Ok(LoadedVersions {
         description,
         versions: merged.into_iter().collect(),
+        policy,
+        templates,
     })
 }
 
@@ -1,5 +1,6 @@
 use crate::error::{Result, VexError};
-use std::collections::BTreeSet;
+use crate::policy::PolicyConfig;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub(super) fn validate_remote_team_config_response(
     url: &str,
@@ -57,7 +58,9 @@ pub(super) fn parse_team_config(content: &str) -> Result<Vec<(String, String)>>
         VexError::Config("Team config must be a TOML table with a [tools] section.".to_string())
     })?;
 
-    let allowed: BTreeSet<_> = ["version", "tools"].into_iter().collect();
+    let allowed: BTreeSet<_> = ["version", "tools", "policy", "templates"]
+        .into_iter()
+        .collect();
     let unexpected: Vec<_> = table
         .keys()
         .filter(|key| !allowed.contains(key.as_str()))
@@ -112,3 +115,45 @@ pub(super) fn parse_team_config(content: &str) -> Result<Vec<(String, String)>>
     versions.sort_by(|a, b| a.0.cmp(&b.0));
     Ok(versions)
 }
+
+/// Team configs may restrict versions with the same `[policy]` table as `~/.vex/config.toml`.
+pub(super) fn parse_team_policy(content: &str) -> Result<PolicyConfig> {
+    let value: toml::Value = toml::from_str(content)
+        .map_err(|err| VexError::Config(format!("Failed to parse team config: {}", err)))?;
+    let Some(policy) = value.get("policy") else {
+        return Ok(PolicyConfig::default());
+    };
+
+    policy
+        .clone()
+        .try_into()
+        .map_err(|err| VexError::Config(format!("Invalid [policy] in team config: {}", err)))
+}
+
+/// Team configs may name project templates for `vex init --template <name> --from <source>`.
+pub(super) fn parse_team_templates(content: &str) -> Result<BTreeMap<String, String>> {
+    let value: toml::Value = toml::from_str(content)
+        .map_err(|err| VexError::Config(format!("Failed to parse team config: {}", err)))?;
+    let Some(templates) = value.get("templates") else {
+        return Ok(BTreeMap::new());
+    };
+    let templates = templates.as_table().ok_or_else(|| {
+        VexError::Config("Team config [templates] must be a table of name = source.".to_string())
+    })?;
+
+    let mut parsed = BTreeMap::new();
+    for (name, value) in templates {
+        let source = value
+            .as_str()
+            .map(str::trim)
+            .filter(|source| !source.is_empty())
+            .ok_or_else(|| {
+                VexError::Config(format!(
+                    "Team config template '{}' must be a non-empty source string.",
+                    name
+                ))
+            })?;
+        parsed.insert(name.clone(), source.to_string());
+    }
+    Ok(parsed)
+}
@@ -1,46 +1,90 @@
 mod git;
 mod remote;
 
+pub(crate) use git::{checkout_template, GitCheckout};
+
 use super::parse::validate_remote_team_config_response;
+use super::verify::{self, TrustPolicy};
 use super::{load_team_config, LoadedVersions, TEAM_CONFIG_FILE};
 use crate::error::{Result, VexError};
+use crate::policy::PolicyConfig;
 use crate::resolver;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 #[derive(Debug, Clone)]
 pub(super) enum SourceKind {
     VersionFile(PathBuf),
     TeamConfigFile(PathBuf),
     HttpsTeamConfig(String),
-    GitRepo { source: String, is_local: bool },
+    GitRepo(GitSource),
+}
+
+/// A Git team config or template source, optionally narrowed with `#<ref>:<path>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GitSource {
+    pub(crate) repo: String,
+    pub(crate) is_local: bool,
+    pub(crate) reference: Option<String>,
+    /// Team config file, or template directory (empty for the repository root).
+    pub(crate) config_path: String,
+}
+
+impl GitSource {
+    fn new(repo: String, is_local: bool) -> Self {
+        Self {
+            repo,
+            is_local,
+            reference: None,
+            config_path: TEAM_CONFIG_FILE.to_string(),
+        }
+    }
+
+    pub(super) fn description(&self) -> String {
+        if self.reference.is_none() && self.config_path == TEAM_CONFIG_FILE {
+            return self.repo.clone();
+        }
+        format!(
+            "{}#{}:{}",
+            self.repo,
+            self.reference.as_deref().unwrap_or_default(),
+            self.config_path
+        )
+    }
 }
 
 pub(super) fn classify_source(source: &str, start_dir: &Path) -> Result<SourceKind> {
+    if !resolve_source_path(source, start_dir).exists() {
+        if let Some((repo, selector)) = source.split_once('#') {
+            return classify_git_selector_source(source, repo, selector, start_dir);
+        }
+    }
+
     if source.starts_with("https://") {
         if source.ends_with(".git") {
-            return Ok(SourceKind::GitRepo {
-                source: source.to_string(),
-                is_local: false,
-            });
+            return Ok(SourceKind::GitRepo(GitSource::new(
+                source.to_string(),
+                false,
+            )));
         }
         return Ok(SourceKind::HttpsTeamConfig(source.to_string()));
     }
 
     if source.starts_with("git@") || source.starts_with("ssh://") {
-        return Ok(SourceKind::GitRepo {
-            source: source.to_string(),
-            is_local: false,
-        });
+        return Ok(SourceKind::GitRepo(GitSource::new(
+            source.to_string(),
+            false,
+        )));
     }
 
     let path = resolve_source_path(source, start_dir);
     if path.is_dir() {
         if path.join(".git").exists() {
-            return Ok(SourceKind::GitRepo {
-                source: path.display().to_string(),
-                is_local: true,
-            });
+            return Ok(SourceKind::GitRepo(GitSource::new(
+                path.display().to_string(),
+                true,
+            )));
         }
         return Err(VexError::Config(format!(
             "Directory source '{}' is not a Git repository.",
@@ -60,15 +104,99 @@ pub(super) fn classify_source(source: &str, start_dir: &Path) -> Result<SourceKi
     }
 
     if source.ends_with(".git") {
-        return Ok(SourceKind::GitRepo {
-            source: source.to_string(),
-            is_local: false,
-        });
+        return Ok(SourceKind::GitRepo(GitSource::new(
+            source.to_string(),
+            false,
+        )));
     }
 
     Ok(SourceKind::VersionFile(path))
 }
 
+fn classify_git_selector_source(
+    source: &str,
+    repo: &str,
+    selector: &str,
+    start_dir: &Path,
+) -> Result<SourceKind> {
+    let SourceKind::GitRepo(mut git) = classify_source(repo, start_dir)? else {
+        return Err(VexError::Config(format!(
+            "Source '{}' uses a '#<ref>:<path>' selector, but only Git repository sources support selectors.",
+            source
+        )));
+    };
+
+    let (reference, path) = selector.split_once(':').unwrap_or((selector, ""));
+    git.reference = parse_git_reference(source, reference)?;
+    git.config_path = parse_git_config_path(source, path)?;
+    Ok(SourceKind::GitRepo(git))
+}
+
+/// Parse a template source `<repo>[#<ref>[:<dir>]]`; `None` when `source` is not a Git URL.
+///
+/// `config_path` holds the template directory, empty for the repository root. Local
+/// repositories are cloned into a temporary directory; remote ones are cached like team configs.
+pub(crate) fn parse_git_template_source(source: &str) -> Result<Option<GitSource>> {
+    let (repo, selector) = source.split_once('#').unwrap_or((source, ""));
+    let is_git_url = repo.trim_end_matches('/').ends_with(".git");
+    let is_remote = repo.starts_with("git@")
+        || repo.starts_with("ssh://")
+        || (repo.starts_with("https://") && is_git_url);
+    let is_local = !is_remote && (repo.starts_with("file://") || is_git_url);
+    if !is_remote && !is_local {
+        return Ok(None);
+    }
+
+    let (reference, dir) = selector.split_once(':').unwrap_or((selector, ""));
+    let mut git = GitSource::new(repo.to_string(), is_local);
+    git.reference = parse_git_reference(source, reference)?;
+    git.config_path = parse_repo_path(source, dir)?.to_string();
+    Ok(Some(git))
+}
+
+fn parse_git_reference(source: &str, reference: &str) -> Result<Option<String>> {
+    let reference = reference.trim();
+    if reference.is_empty() {
+        return Ok(None);
+    }
+    if reference.starts_with('-') || reference.chars().any(char::is_whitespace) {
+        return Err(VexError::Config(format!(
+            "Invalid Git ref '{}' in source '{}'.",
+            reference, source
+        )));
+    }
+    Ok(Some(reference.to_string()))
+}
+
+/// Paths are repository-relative; a path that does not name a `.toml` file is treated as a
+/// directory containing `vex-config.toml`.
+fn parse_git_config_path(source: &str, path: &str) -> Result<String> {
+    let path = parse_repo_path(source, path)?;
+    if path.is_empty() {
+        return Ok(TEAM_CONFIG_FILE.to_string());
+    }
+    if path.ends_with(".toml") {
+        Ok(path.to_string())
+    } else {
+        Ok(format!("{}/{}", path, TEAM_CONFIG_FILE))
+    }
+}
+
+/// A selector path inside the repository, empty for its root.
+fn parse_repo_path<'a>(source: &str, path: &'a str) -> Result<&'a str> {
+    let path = path.trim().trim_matches('/');
+    if !Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(VexError::Config(format!(
+            "Invalid path '{}' in source '{}'. Use a path relative to the repository root.",
+            path, source
+        )));
+    }
+    Ok(path)
+}
+
 pub(super) fn load_version_file(path: &Path) -> Result<LoadedVersions> {
     if !path.exists() {
         return Err(VexError::Config(format!(
@@ -81,10 +209,16 @@ pub(super) fn load_version_file(path: &Path) -> Result<LoadedVersions> {
     Ok(LoadedVersions {
         description: path.display().to_string(),
         versions: resolver::parse_tool_versions(&content),
+        policy: PolicyConfig::default(),
+        templates: BTreeMap::new(),
     })
 }
 
-pub(super) fn load_team_config_file(path: &Path, start_dir: &Path) -> Result<LoadedVersions> {
+pub(super) fn load_team_config_file(
+    path: &Path,
+    start_dir: &Path,
+    policy: &TrustPolicy,
+) -> Result<LoadedVersions> {
     if !path.exists() {
         return Err(VexError::Config(format!(
             "Team config file not found: {}",
@@ -93,15 +227,33 @@ pub(super) fn load_team_config_file(path: &Path, start_dir: &Path) -> Result<Loa
     }
 
     let content = fs::read_to_string(path)?;
+    if policy.is_active() {
+        let signature_path = verify::signature_path(path);
+        let check = if signature_path.is_file() {
+            let signature = fs::read_to_string(&signature_path)?;
+            verify::verify_detached_signature(content.as_bytes(), &signature, &policy.trusted_keys)?
+        } else {
+            verify::SignatureCheck::Unsigned
+        };
+        policy.enforce(&path.display().to_string(), check)?;
+    }
     load_team_config(&content, path.display().to_string(), start_dir)
 }
 
-pub(super) fn load_https_team_config(url: &str, start_dir: &Path) -> Result<LoadedVersions> {
-    remote::load_https_team_config(url, start_dir)
+pub(super) fn load_https_team_config(
+    url: &str,
+    start_dir: &Path,
+    policy: &TrustPolicy,
+) -> Result<LoadedVersions> {
+    remote::load_https_team_config(url, start_dir, policy)
 }
 
-pub(super) fn load_team_config_from_git_repo(source: &str, is_local: bool) -> Result<String> {
-    git::load_team_config_from_git_repo(source, is_local)
+pub(super) fn load_team_config_from_git_repo(
+    source: &GitSource,
+    offline: bool,
+    policy: &TrustPolicy,
+) -> Result<String> {
+    git::load_team_config_from_git_repo(source, offline, policy)
 }
 
 pub(super) fn is_team_config_path(path: &Path) -> bool {
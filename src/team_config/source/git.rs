@@ -1,6 +1,11 @@
-use super::TEAM_CONFIG_FILE;
+use super::verify::{self, TrustPolicy};
+use super::GitSource;
+use crate::checksum;
+use crate::config;
 use crate::error::{Result, VexError};
+use crate::output::{self, Event};
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -8,11 +13,243 @@ use tempfile::TempDir;
 
 const GIT_CLONE_TIMEOUT: Duration = Duration::from_secs(60);
 
-pub(super) fn load_team_config_from_git_repo(source: &str, is_local: bool) -> Result<String> {
-    let temp = TempDir::new()?;
-    let clone_dir = temp.path().join("repo");
+/// Remote clones are reused from `~/.vex/cache/team-config/<key>`, keyed by repository, ref, and
+/// path, so sources that check out different paths never share a sparse working tree.
+const GIT_CACHE_DIR: &str = "team-config";
+
+/// Template repositories get their own cache, since their sparse checkout covers a directory.
+const TEMPLATE_CACHE_DIR: &str = "template-git";
+
+/// A sparse checkout of a Git source; temporary checkouts are removed on drop.
+#[derive(Debug)]
+pub(crate) struct GitCheckout {
+    path: PathBuf,
+    _temp: Option<TempDir>,
+}
+
+impl GitCheckout {
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+pub(super) fn load_team_config_from_git_repo(
+    source: &GitSource,
+    offline: bool,
+    policy: &TrustPolicy,
+) -> Result<String> {
+    let checkout = checkout(source, GIT_CACHE_DIR, offline)?;
+    read_checkout(source, checkout.path(), policy)
+}
+
+/// Check out a template directory through the same cache, sparse clone, timeouts, and signature
+/// policy as team configs, since templates write files that later run through `vex run`.
+pub(crate) fn checkout_template(source: &GitSource) -> Result<GitCheckout> {
+    let policy = TrustPolicy::from_settings(&config::load_settings()?);
+    checkout_template_with_trust(source, &policy)
+}
+
+fn checkout_template_with_trust(source: &GitSource, policy: &TrustPolicy) -> Result<GitCheckout> {
+    let checkout = checkout(source, TEMPLATE_CACHE_DIR, false)?;
+    verify_checkout(source, checkout.path(), policy)?;
+    Ok(checkout)
+}
+
+/// Local repositories are cloned into a temporary directory; remote ones are cached.
+fn checkout(source: &GitSource, cache_dir: &str, offline: bool) -> Result<GitCheckout> {
+    if source.is_local {
+        let temp = TempDir::new()?;
+        let path = temp.path().join("repo");
+        clone_sparse(source, &path)?;
+        return Ok(GitCheckout {
+            path,
+            _temp: Some(temp),
+        });
+    }
+
+    let cache_root = config::cache_dir()
+        .ok_or(VexError::HomeDirectoryNotFound)?
+        .join(cache_dir);
+    Ok(GitCheckout {
+        path: cached_checkout(source, &cache_root, offline)?,
+        _temp: None,
+    })
+}
+
+#[cfg(test)]
+fn load_team_config_from_cache(
+    source: &GitSource,
+    cache_root: &Path,
+    offline: bool,
+    policy: &TrustPolicy,
+) -> Result<String> {
+    let checkout = cached_checkout(source, cache_root, offline)?;
+    read_checkout(source, &checkout, policy)
+}
+
+fn cached_checkout(source: &GitSource, cache_root: &Path, offline: bool) -> Result<PathBuf> {
+    let checkout = cache_root.join(cache_key(source));
+    if checkout.join(".git").is_dir() {
+        if !offline && !is_pinned_tag(source, &checkout) {
+            if let Err(err) = refresh_checkout(source, &checkout) {
+                if has_head(&checkout) {
+                    // A failed fetch is usually the network; keep the tree `--offline` relies on.
+                    let message = format!(
+                        "could not refresh Git repository '{}', using the cached checkout: {}",
+                        source.repo, err
+                    );
+                    if !output::is_quiet() {
+                        eprintln!("vex: warning: {}", message);
+                    }
+                    output::emit(Event::Warning { message });
+                } else {
+                    // A broken cached clone should not wedge future syncs; start over from scratch.
+                    fs::remove_dir_all(&checkout)?;
+                }
+            }
+        }
+    } else if offline {
+        return Err(VexError::OfflineModeError(format!(
+            "Remote Git repository '{}' has not been cached yet and requires network access. Re-run without --offline.",
+            source.repo
+        )));
+    }
+
+    if !checkout.join(".git").is_dir() {
+        fs::create_dir_all(cache_root)?;
+        let staging = TempDir::new_in(cache_root)?;
+        let staged_checkout = staging.path().join("repo");
+        clone_sparse(source, &staged_checkout)?;
+        if fs::rename(&staged_checkout, &checkout).is_err() && !checkout.join(".git").is_dir() {
+            return Err(VexError::Config(format!(
+                "Failed to cache Git repository '{}' at {}.",
+                source.repo,
+                checkout.display()
+            )));
+        }
+    }
+
+    Ok(checkout)
+}
+
+fn cache_key(source: &GitSource) -> String {
+    let identity = format!(
+        "{}#{}:{}",
+        source.repo,
+        source.reference.as_deref().unwrap_or_default(),
+        source.config_path
+    );
+    checksum::sha256_hex_bytes(identity.as_bytes())[..16].to_string()
+}
+
+fn clone_sparse(source: &GitSource, checkout: &Path) -> Result<()> {
+    let mut command = git_command(source, None);
+    command.args([
+        "clone",
+        "--depth",
+        "1",
+        "--filter=blob:none",
+        "--sparse",
+        "--quiet",
+    ]);
+    if let Some(reference) = &source.reference {
+        command.args(["--branch", reference]);
+    }
+    command.arg(&source.repo).arg(checkout);
+    run_git(command, source, "clone")?;
+    checkout_config_path(source, checkout)
+}
+
+fn refresh_checkout(source: &GitSource, checkout: &Path) -> Result<()> {
+    let mut fetch = git_command(source, Some(checkout));
+    fetch
+        .args(["fetch", "--depth", "1", "--filter=blob:none", "--quiet"])
+        .arg("origin")
+        .arg(source.reference.as_deref().unwrap_or("HEAD"));
+    run_git(fetch, source, "fetch")?;
+
+    let mut reset = git_command(source, Some(checkout));
+    reset.args(["checkout", "--detach", "--quiet", "FETCH_HEAD"]);
+    run_git(reset, source, "update")?;
+    checkout_config_path(source, checkout)
+}
+
+fn has_head(checkout: &Path) -> bool {
+    Command::new("git")
+        .current_dir(checkout)
+        .args(["rev-parse", "--verify", "--quiet", "HEAD"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Tags are treated as immutable releases, so a cached tag checkout is reused without fetching.
+fn is_pinned_tag(source: &GitSource, checkout: &Path) -> bool {
+    let Some(reference) = &source.reference else {
+        return false;
+    };
+    Command::new("git")
+        .current_dir(checkout)
+        .args(["show-ref", "--verify", "--quiet"])
+        .arg(format!("refs/tags/{}", reference))
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Check out only `config_path`, a file or directory; an empty path checks out everything.
+fn checkout_config_path(source: &GitSource, checkout: &Path) -> Result<()> {
+    let pattern = if source.config_path.is_empty() {
+        "/*".to_string()
+    } else if checkout.join(&source.config_path).exists() {
+        return Ok(());
+    } else {
+        format!("/{}", source.config_path)
+    };
+    let mut sparse = git_command(source, Some(checkout));
+    sparse
+        .args(["sparse-checkout", "set", "--no-cone"])
+        .arg(pattern);
+    run_git(sparse, source, "check out")
+}
+
+fn read_checkout(source: &GitSource, checkout: &Path, policy: &TrustPolicy) -> Result<String> {
+    let config_path = checkout.join(&source.config_path);
+    if !config_path.is_file() {
+        return Err(VexError::Config(format!(
+            "Git repository '{}' does not contain {}{}.",
+            source.repo,
+            source.config_path,
+            source
+                .reference
+                .as_deref()
+                .map(|reference| format!(" at ref '{}'", reference))
+                .unwrap_or_default()
+        )));
+    }
+
+    verify_checkout(source, checkout, policy)?;
+    fs::read_to_string(config_path).map_err(VexError::from)
+}
+
+fn verify_checkout(source: &GitSource, checkout: &Path, policy: &TrustPolicy) -> Result<()> {
+    if policy.is_active() {
+        let check = verify::verify_git_checkout(checkout, &policy.trusted_keys)?;
+        policy.enforce(&source.description(), check)?;
+    }
+    Ok(())
+}
+
+fn git_command(source: &GitSource, checkout: Option<&Path>) -> Command {
     let mut command = Command::new("git");
-    if !is_local {
+    if let Some(checkout) = checkout {
+        command.current_dir(checkout);
+    }
+    if !source.is_local {
         command
             .env("GIT_TERMINAL_PROMPT", "0")
             .env(
@@ -21,12 +258,11 @@ pub(super) fn load_team_config_from_git_repo(source: &str, is_local: bool) -> Re
             )
             .args(["-c", "http.lowSpeedLimit=1", "-c", "http.lowSpeedTime=30"]);
     }
+    command.stdout(Stdio::null()).stderr(Stdio::piped());
     command
-        .args(["clone", "--depth", "1", "--quiet", source])
-        .arg(&clone_dir)
-        .stdout(Stdio::null())
-        .stderr(Stdio::piped());
+}
 
+fn run_git(mut command: Command, source: &GitSource, action: &str) -> Result<()> {
     let mut child = command.spawn()?;
     let deadline = Instant::now() + GIT_CLONE_TIMEOUT;
     loop {
@@ -38,8 +274,9 @@ pub(super) fn load_team_config_from_git_repo(source: &str, is_local: bool) -> Re
             let output = child.wait_with_output()?;
             let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(VexError::Config(format!(
-                "Timed out while cloning team config repository '{}' after {} seconds. {}",
-                source,
+                "Timed out during git {} of repository '{}' after {} seconds. {}",
+                action,
+                source.repo,
                 GIT_CLONE_TIMEOUT.as_secs(),
                 stderr.trim()
             )));
@@ -51,19 +288,14 @@ pub(super) fn load_team_config_from_git_repo(source: &str, is_local: bool) -> Re
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(VexError::Config(format!(
-            "Failed to clone team config repository '{}': {}",
-            source,
+            "Failed to {} Git repository '{}': {}",
+            action,
+            source.repo,
             stderr.trim()
         )));
     }
-
-    let config_path = clone_dir.join(TEAM_CONFIG_FILE);
-    if !config_path.exists() {
-        return Err(VexError::Config(format!(
-            "Git repository '{}' does not contain {} at its root.",
-            source, TEAM_CONFIG_FILE
-        )));
-    }
-
-    fs::read_to_string(config_path).map_err(VexError::from)
+    Ok(())
 }
+
+#[cfg(test)]
+mod tests;
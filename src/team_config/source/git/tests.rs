@@ -0,0 +1,176 @@
+use super::*;
+use crate::config::StrictMode;
+use std::path::PathBuf;
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(repo)
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+fn init_platform_repo(root: &Path) -> PathBuf {
+    let repo = root.join("platform-configs");
+    fs::create_dir_all(repo.join("teams/payments")).unwrap();
+    git(&repo, &["init", "--quiet", "--initial-branch", "main"]);
+    git(&repo, &["config", "user.email", "codex@example.com"]);
+    git(&repo, &["config", "user.name", "Codex"]);
+    git(&repo, &["config", "uploadpack.allowFilter", "true"]);
+    fs::write(
+        repo.join("teams/payments/vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"20\"\n",
+    )
+    .unwrap();
+    fs::write(repo.join("README.md"), "platform configs\n").unwrap();
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "Add payments config", "--quiet"]);
+    repo
+}
+
+fn remote_source(repo: &Path, reference: Option<&str>) -> GitSource {
+    GitSource {
+        repo: format!("file://{}", repo.display()),
+        is_local: false,
+        reference: reference.map(ToString::to_string),
+        config_path: "teams/payments/vex-config.toml".to_string(),
+    }
+}
+
+fn no_trust() -> TrustPolicy {
+    TrustPolicy {
+        mode: StrictMode::Warn,
+        trusted_keys: Vec::new(),
+    }
+}
+
+#[test]
+fn test_tag_checkout_is_cached_and_reused_without_fetching() {
+    let temp = TempDir::new().unwrap();
+    let repo = init_platform_repo(temp.path());
+    git(&repo, &["tag", "-a", "v3", "-m", "Release v3"]);
+    let cache_root = temp.path().join("cache");
+    let source = remote_source(&repo, Some("v3"));
+
+    let content = load_team_config_from_cache(&source, &cache_root, false, &no_trust()).unwrap();
+    assert!(content.contains("node = \"20\""));
+
+    let checkout = cache_root.join(cache_key(&source));
+    assert!(checkout.join("teams/payments/vex-config.toml").is_file());
+    assert!(!checkout.join("README.md").exists());
+
+    fs::remove_dir_all(&repo).unwrap();
+    let content = load_team_config_from_cache(&source, &cache_root, false, &no_trust()).unwrap();
+    assert!(content.contains("node = \"20\""));
+}
+
+#[test]
+fn test_branch_checkout_is_refreshed_from_cache() {
+    let temp = TempDir::new().unwrap();
+    let repo = init_platform_repo(temp.path());
+    let cache_root = temp.path().join("cache");
+    let source = remote_source(&repo, Some("main"));
+
+    load_team_config_from_cache(&source, &cache_root, false, &no_trust()).unwrap();
+
+    fs::write(
+        repo.join("teams/payments/vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"22\"\n",
+    )
+    .unwrap();
+    git(&repo, &["commit", "-am", "Bump node", "--quiet"]);
+
+    let offline = load_team_config_from_cache(&source, &cache_root, true, &no_trust()).unwrap();
+    assert!(offline.contains("node = \"20\""));
+
+    let refreshed = load_team_config_from_cache(&source, &cache_root, false, &no_trust()).unwrap();
+    assert!(refreshed.contains("node = \"22\""));
+}
+
+#[test]
+fn test_failed_refresh_keeps_cached_checkout() {
+    let temp = TempDir::new().unwrap();
+    let repo = init_platform_repo(temp.path());
+    let cache_root = temp.path().join("cache");
+    let source = remote_source(&repo, Some("main"));
+    load_team_config_from_cache(&source, &cache_root, false, &no_trust()).unwrap();
+
+    // The remote disappearing looks the same as a network outage to `git fetch`.
+    fs::rename(&repo, temp.path().join("moved")).unwrap();
+    let content = load_team_config_from_cache(&source, &cache_root, false, &no_trust()).unwrap();
+    assert!(content.contains("node = \"20\""));
+    assert!(cache_root.join(cache_key(&source)).join(".git").is_dir());
+
+    let offline = load_team_config_from_cache(&source, &cache_root, true, &no_trust()).unwrap();
+    assert!(offline.contains("node = \"20\""));
+}
+
+#[test]
+fn test_offline_without_cached_checkout_is_rejected() {
+    let temp = TempDir::new().unwrap();
+    let repo = init_platform_repo(temp.path());
+    let source = remote_source(&repo, None);
+
+    let err = load_team_config_from_cache(&source, &temp.path().join("cache"), true, &no_trust())
+        .unwrap_err();
+    assert!(matches!(err, VexError::OfflineModeError(_)));
+}
+
+#[test]
+fn test_missing_config_path_reports_ref() {
+    let temp = TempDir::new().unwrap();
+    let repo = init_platform_repo(temp.path());
+    let mut source = remote_source(&repo, Some("main"));
+    source.config_path = "teams/search/vex-config.toml".to_string();
+
+    let err = load_team_config_from_cache(&source, &temp.path().join("cache"), false, &no_trust())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("teams/search/vex-config.toml at ref 'main'"));
+}
+
+#[test]
+fn test_cache_key_depends_on_repo_ref_and_path() {
+    let temp = TempDir::new().unwrap();
+    let main = remote_source(temp.path(), Some("main"));
+    let tag = remote_source(temp.path(), Some("v3"));
+    let mut other_path = main.clone();
+    other_path.config_path = "teams/search/vex-config.toml".to_string();
+
+    assert_ne!(cache_key(&main), cache_key(&tag));
+    assert_ne!(cache_key(&main), cache_key(&other_path));
+}
+
+#[test]
+fn test_unsigned_template_checkout_is_rejected_when_enforced() {
+    let temp = TempDir::new().unwrap();
+    let repo = init_platform_repo(temp.path());
+    let key = temp.path().join("signer");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-f"])
+        .arg(&key)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let source = GitSource {
+        repo: repo.display().to_string(),
+        is_local: true,
+        reference: None,
+        config_path: "teams".to_string(),
+    };
+    let policy = TrustPolicy {
+        mode: StrictMode::Enforce,
+        trusted_keys: vec![fs::read_to_string(key.with_extension("pub")).unwrap()],
+    };
+
+    let err = checkout_template_with_trust(&source, &policy).unwrap_err();
+    assert!(
+        matches!(err, VexError::UntrustedTeamConfig { .. }),
+        "{}",
+        err
+    );
+    assert!(checkout_template_with_trust(&source, &no_trust()).is_ok());
+}
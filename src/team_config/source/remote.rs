@@ -1,11 +1,19 @@
+use super::verify::{self, SignatureCheck, TrustPolicy};
 use super::{load_team_config, validate_remote_team_config_response, LoadedVersions};
 use crate::error::{Result, VexError};
 use crate::http;
+use reqwest::blocking::Client;
 use reqwest::header::CONTENT_TYPE;
+use reqwest::StatusCode;
 use std::path::Path;
 
-pub(super) fn load_https_team_config(url: &str, start_dir: &Path) -> Result<LoadedVersions> {
-    let response = http::client_for_current_context(concat!("vex/", env!("CARGO_PKG_VERSION")))?
+pub(super) fn load_https_team_config(
+    url: &str,
+    start_dir: &Path,
+    policy: &TrustPolicy,
+) -> Result<LoadedVersions> {
+    let client = http::client_for_current_context(concat!("vex/", env!("CARGO_PKG_VERSION")))?;
+    let response = client
         .get(url)
         .send()
         .map_err(VexError::Network)?
@@ -20,5 +28,29 @@ pub(super) fn load_https_team_config(url: &str, start_dir: &Path) -> Result<Load
     let content = response.text().map_err(VexError::Network)?;
 
     validate_remote_team_config_response(url, content_type.as_deref(), &content)?;
+    if policy.is_active() {
+        let check = match fetch_signature(&client, url)? {
+            Some(signature) => verify::verify_detached_signature(
+                content.as_bytes(),
+                &signature,
+                &policy.trusted_keys,
+            )?,
+            None => SignatureCheck::Unsigned,
+        };
+        policy.enforce(url, check)?;
+    }
     load_team_config(&content, url.to_string(), start_dir)
 }
+
+fn fetch_signature(client: &Client, url: &str) -> Result<Option<String>> {
+    let signature_url = format!("{}{}", url, verify::SIGNATURE_SUFFIX);
+    let response = client
+        .get(&signature_url)
+        .send()
+        .map_err(VexError::Network)?;
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let response = response.error_for_status().map_err(VexError::Network)?;
+    response.text().map(Some).map_err(VexError::Network)
+}
@@ -1,8 +1,11 @@
 use super::parse::{parse_team_config, validate_remote_team_config_response};
 use super::source::{classify_source, SourceKind};
+use super::verify::TrustPolicy;
 use super::*;
+use crate::config::StrictMode;
 use std::collections::BTreeMap;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use tempfile::TempDir;
 
@@ -149,6 +152,51 @@ fn test_load_versions_from_local_git_repo() {
     );
 }
 
+#[test]
+fn test_load_versions_from_local_git_repo_with_ref_and_path() {
+    let temp = TempDir::new().unwrap();
+    let repo = temp.path().join("platform-configs");
+    fs::create_dir_all(repo.join("teams/payments")).unwrap();
+
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .current_dir(&repo)
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.email", "codex@example.com"]);
+    git(&["config", "user.name", "Codex"]);
+    fs::write(
+        repo.join("teams/payments/vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"20\"\n",
+    )
+    .unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-m", "Payments v3", "--quiet"]);
+    git(&["tag", "v3"]);
+    fs::write(
+        repo.join("teams/payments/vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"22\"\n",
+    )
+    .unwrap();
+    git(&["commit", "-am", "Payments next", "--quiet"]);
+
+    let source = format!("{}#v3:teams/payments", repo.display());
+    let loaded = load_versions_from_source(&source, temp.path(), false).unwrap();
+    assert_eq!(
+        loaded.versions,
+        vec![("node".to_string(), "20".to_string())]
+    );
+    assert!(loaded
+        .description
+        .ends_with("#v3:teams/payments/vex-config.toml"));
+}
+
 #[test]
 fn test_validate_remote_team_config_rejects_html_content_type() {
     let err = validate_remote_team_config_response(
@@ -172,3 +220,346 @@ fn test_validate_remote_team_config_rejects_unexpected_content_type() {
     .to_string();
     assert!(err.contains("unsupported content type"));
 }
+
+fn generate_signing_key(dir: &Path, name: &str) -> (PathBuf, String) {
+    let key = dir.join(name);
+    let status = Command::new("ssh-keygen")
+        .args([
+            "-q",
+            "-t",
+            "ed25519",
+            "-N",
+            "",
+            "-C",
+            "platform@example.com",
+            "-f",
+        ])
+        .arg(&key)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let public_key = fs::read_to_string(key.with_extension("pub"))
+        .unwrap()
+        .trim()
+        .to_string();
+    (key, public_key)
+}
+
+fn sign_file(key: &Path, file: &Path) {
+    let status = Command::new("ssh-keygen")
+        .args(["-Y", "sign", "-n", "vex-team-config", "-f"])
+        .arg(key)
+        .arg(file)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+fn enforce_policy(trusted_keys: Vec<String>) -> TrustPolicy {
+    TrustPolicy {
+        mode: StrictMode::Enforce,
+        trusted_keys,
+    }
+}
+
+#[test]
+fn test_signed_team_config_file_is_verified() {
+    let temp = TempDir::new().unwrap();
+    let (key, public_key) = generate_signing_key(temp.path(), "signer");
+    let config = temp.path().join("vex-config.toml");
+    fs::write(&config, "version = 1\n\n[tools]\nnode = \"20\"\n").unwrap();
+    sign_file(&key, &config);
+
+    let policy = enforce_policy(vec![public_key]);
+    let loaded =
+        load_versions_from_source_with_trust("vex-config.toml", temp.path(), false, &policy)
+            .unwrap();
+    assert_eq!(
+        loaded.versions,
+        vec![("node".to_string(), "20".to_string())]
+    );
+}
+
+#[test]
+fn test_tampered_team_config_file_is_rejected_when_enforced() {
+    let temp = TempDir::new().unwrap();
+    let (key, public_key) = generate_signing_key(temp.path(), "signer");
+    let config = temp.path().join("vex-config.toml");
+    fs::write(&config, "version = 1\n\n[tools]\nnode = \"20\"\n").unwrap();
+    sign_file(&key, &config);
+    fs::write(&config, "version = 1\n\n[tools]\nnode = \"18\"\n").unwrap();
+
+    let policy = enforce_policy(vec![public_key]);
+    let err = load_versions_from_source_with_trust("vex-config.toml", temp.path(), false, &policy)
+        .unwrap_err();
+    assert!(matches!(err, VexError::UntrustedTeamConfig { .. }));
+    assert!(err.to_string().contains("does not match any trusted key"));
+}
+
+#[test]
+fn test_unsigned_team_config_file_is_rejected_when_enforced() {
+    let temp = TempDir::new().unwrap();
+    let (_, public_key) = generate_signing_key(temp.path(), "signer");
+    fs::write(
+        temp.path().join("vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"20\"\n",
+    )
+    .unwrap();
+
+    let policy = enforce_policy(vec![public_key]);
+    let err = load_versions_from_source_with_trust("vex-config.toml", temp.path(), false, &policy)
+        .unwrap_err();
+    assert!(err.to_string().contains("no signature was found"));
+}
+
+#[test]
+fn test_unsigned_team_config_file_only_warns_by_default() {
+    let temp = TempDir::new().unwrap();
+    let (_, public_key) = generate_signing_key(temp.path(), "signer");
+    fs::write(
+        temp.path().join("vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"20\"\n",
+    )
+    .unwrap();
+
+    let policy = TrustPolicy {
+        mode: StrictMode::Warn,
+        trusted_keys: vec![public_key],
+    };
+    let loaded =
+        load_versions_from_source_with_trust("vex-config.toml", temp.path(), false, &policy)
+            .unwrap();
+    assert_eq!(
+        loaded.versions,
+        vec![("node".to_string(), "20".to_string())]
+    );
+}
+
+#[test]
+fn test_signed_git_commit_is_verified_and_unsigned_commit_is_rejected() {
+    let temp = TempDir::new().unwrap();
+    let (key, public_key) = generate_signing_key(temp.path(), "signer");
+    let repo = temp.path().join("team-config-repo");
+    fs::create_dir_all(&repo).unwrap();
+
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .current_dir(&repo)
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.email", "codex@example.com"]);
+    git(&["config", "user.name", "Codex"]);
+    git(&["config", "gpg.format", "ssh"]);
+    git(&["config", "user.signingkey", key.to_str().unwrap()]);
+    fs::write(
+        repo.join("vex-config.toml"),
+        "version = 1\n\n[tools]\nrust = \"stable\"\n",
+    )
+    .unwrap();
+    git(&["add", "vex-config.toml"]);
+    git(&["commit", "-S", "-m", "Add team config", "--quiet"]);
+
+    let policy = enforce_policy(vec![public_key]);
+    let loaded =
+        load_versions_from_source_with_trust(repo.to_str().unwrap(), temp.path(), false, &policy)
+            .unwrap();
+    assert_eq!(
+        loaded.versions,
+        vec![("rust".to_string(), "stable".to_string())]
+    );
+
+    fs::write(
+        repo.join("vex-config.toml"),
+        "version = 1\n\n[tools]\nrust = \"1.70\"\n",
+    )
+    .unwrap();
+    git(&[
+        "commit",
+        "-am",
+        "Unsigned change",
+        "--quiet",
+        "--no-gpg-sign",
+    ]);
+
+    let err =
+        load_versions_from_source_with_trust(repo.to_str().unwrap(), temp.path(), false, &policy)
+            .unwrap_err();
+    assert!(matches!(err, VexError::UntrustedTeamConfig { .. }));
+
+    git(&["tag", "-s", "v2", "-m", "Release v2"]);
+    let check = verify::verify_git_checkout(&repo, &policy.trusted_keys).unwrap();
+    assert_eq!(
+        check,
+        verify::SignatureCheck::Verified("tag v2 by platform@example.com".to_string())
+    );
+}
+
+#[test]
+fn test_gpg_signed_commit_and_tag_are_rejected_without_trusted_ssh_key() {
+    let temp = TempDir::new().unwrap();
+    let (_, public_key) = generate_signing_key(temp.path(), "signer");
+    let gnupg_home = temp.path().join("gnupg");
+    fs::create_dir_all(&gnupg_home).unwrap();
+    let gpg = temp.path().join("gpg");
+    fs::write(
+        &gpg,
+        format!(
+            "#!/bin/sh\nGNUPGHOME='{}' exec gpg \"$@\"\n",
+            gnupg_home.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&gpg, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+    let status = Command::new(&gpg)
+        .args(["--batch", "--passphrase", "", "--quick-gen-key"])
+        .args(["vex-trusted-1", "default", "default", "never"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let repo = temp.path().join("team-config-repo");
+    fs::create_dir_all(&repo).unwrap();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .current_dir(&repo)
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "--quiet"]);
+    git(&["config", "user.email", "codex@example.com"]);
+    git(&["config", "user.name", "Codex"]);
+    git(&["config", "gpg.program", gpg.to_str().unwrap()]);
+    git(&["config", "user.signingkey", "vex-trusted-1"]);
+    fs::write(
+        repo.join("vex-config.toml"),
+        "version = 1\n\n[tools]\nrust = \"stable\"\n",
+    )
+    .unwrap();
+    git(&["add", "vex-config.toml"]);
+    git(&["commit", "-S", "-m", "Add team config", "--quiet"]);
+    git(&["tag", "-s", "v1", "-m", "v1"]);
+
+    let check = verify::verify_git_checkout(&repo, &[public_key]).unwrap();
+    assert_eq!(
+        check,
+        verify::SignatureCheck::Invalid(
+            "commit or tag signature does not match any trusted key".to_string()
+        )
+    );
+}
+
+#[test]
+fn test_classify_git_source_with_ref_and_path_selector() {
+    let temp = TempDir::new().unwrap();
+    let source = classify_source(
+        "git@github.com:company/platform-configs.git#v3:teams/payments/vex-config.toml",
+        temp.path(),
+    )
+    .unwrap();
+
+    let SourceKind::GitRepo(git) = source else {
+        panic!("expected Git source");
+    };
+    assert_eq!(git.repo, "git@github.com:company/platform-configs.git");
+    assert!(!git.is_local);
+    assert_eq!(git.reference.as_deref(), Some("v3"));
+    assert_eq!(git.config_path, "teams/payments/vex-config.toml");
+}
+
+#[test]
+fn test_classify_git_source_selector_defaults() {
+    let temp = TempDir::new().unwrap();
+
+    let SourceKind::GitRepo(ref_only) =
+        classify_source("https://example.com/configs.git#main", temp.path()).unwrap()
+    else {
+        panic!("expected Git source");
+    };
+    assert_eq!(ref_only.reference.as_deref(), Some("main"));
+    assert_eq!(ref_only.config_path, "vex-config.toml");
+
+    let SourceKind::GitRepo(dir_only) = classify_source(
+        "https://example.com/configs.git#:teams/search/",
+        temp.path(),
+    )
+    .unwrap() else {
+        panic!("expected Git source");
+    };
+    assert_eq!(dir_only.reference, None);
+    assert_eq!(dir_only.config_path, "teams/search/vex-config.toml");
+}
+
+#[test]
+fn test_classify_git_source_rejects_invalid_selectors() {
+    let temp = TempDir::new().unwrap();
+
+    let err = classify_source(
+        "https://example.com/configs.git#v3:../secrets.toml",
+        temp.path(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("relative to the repository root"));
+
+    let err = classify_source(
+        "https://example.com/configs.git#--upload-pack=evil",
+        temp.path(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("Invalid Git ref"));
+
+    let err = classify_source("tool-versions#v3", temp.path()).unwrap_err();
+    assert!(err.to_string().contains("only Git repository sources"));
+}
+
+#[test]
+fn test_team_config_policy_is_loaded() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"22\"\n\n[policy.node]\nmin = \"20\"\n",
+    )
+    .unwrap();
+
+    let loaded = load_versions_from_source("vex-config.toml", temp.path(), false).unwrap();
+    assert_eq!(loaded.policy.tools["node"].min.as_deref(), Some("20"));
+}
+
+#[test]
+fn test_team_config_templates_are_loaded() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"22\"\n\n[templates]\nservice = \"git@example.com:acme/templates.git#v2:service\"\n",
+    )
+    .unwrap();
+
+    let loaded = load_versions_from_source("vex-config.toml", temp.path(), false).unwrap();
+    assert_eq!(
+        loaded.templates.get("service").map(String::as_str),
+        Some("git@example.com:acme/templates.git#v2:service")
+    );
+
+    fs::write(
+        temp.path().join("vex-config.toml"),
+        "version = 1\n\n[tools]\nnode = \"22\"\n\n[templates]\nservice = \"\"\n",
+    )
+    .unwrap();
+    let err = load_versions_from_source("vex-config.toml", temp.path(), false).unwrap_err();
+    assert!(err.to_string().contains("non-empty source string"));
+}
@@ -0,0 +1,284 @@
+use crate::config::{Settings, StrictMode};
+use crate::error::{Result, VexError};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tempfile::TempDir;
+
+/// SSH signature namespace team config owners must sign with (`ssh-keygen -Y sign -n`).
+pub(super) const SIGNATURE_NAMESPACE: &str = "vex-team-config";
+
+/// Suffix of the detached signature published next to a team config file or URL.
+pub(super) const SIGNATURE_SUFFIX: &str = ".sig";
+
+const PRINCIPAL_PREFIX: &str = "vex-trusted-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum SignatureCheck {
+    Verified(String),
+    Unsigned,
+    Invalid(String),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct TrustPolicy {
+    pub(super) mode: StrictMode,
+    pub(super) trusted_keys: Vec<String>,
+}
+
+impl TrustPolicy {
+    pub(super) fn from_settings(settings: &Settings) -> Self {
+        Self {
+            mode: settings.strict.team_config,
+            trusted_keys: settings.team_config.trusted_keys.clone(),
+        }
+    }
+
+    /// Verification only runs once keys are trusted or enforcement is requested,
+    /// so existing unsigned setups keep working silently.
+    pub(super) fn is_active(&self) -> bool {
+        !self.trusted_keys.is_empty() || self.mode == StrictMode::Enforce
+    }
+
+    pub(super) fn enforce(&self, location: &str, check: SignatureCheck) -> Result<()> {
+        let reason = match check {
+            SignatureCheck::Verified(_) => return Ok(()),
+            SignatureCheck::Unsigned => "no signature was found".to_string(),
+            SignatureCheck::Invalid(reason) => reason,
+        };
+
+        match self.mode {
+            StrictMode::Enforce => Err(VexError::UntrustedTeamConfig {
+                location: location.to_string(),
+                reason,
+            }),
+            StrictMode::Warn => {
+                eprintln!(
+                    "vex: warning: team config '{}' is not verified: {}",
+                    location, reason
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+pub(super) fn signature_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(SIGNATURE_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Verify an SSH signature (`ssh-keygen -Y sign -n vex-team-config`) over `content`.
+pub(super) fn verify_detached_signature(
+    content: &[u8],
+    signature: &str,
+    trusted_keys: &[String],
+) -> Result<SignatureCheck> {
+    if trusted_keys.is_empty() {
+        return Ok(SignatureCheck::Invalid(
+            "no trusted keys are configured in [team_config] trusted_keys".to_string(),
+        ));
+    }
+
+    let temp = TempDir::new()?;
+    let allowed_signers = write_allowed_signers(temp.path(), trusted_keys)?;
+    let signature_file = temp.path().join("team-config.sig");
+    fs::write(&signature_file, signature)?;
+
+    for index in 0..trusted_keys.len() {
+        let principal = principal_for(index);
+        let mut child = match Command::new("ssh-keygen")
+            .args([
+                "-Y",
+                "verify",
+                "-n",
+                SIGNATURE_NAMESPACE,
+                "-I",
+                &principal,
+                "-f",
+            ])
+            .arg(&allowed_signers)
+            .arg("-s")
+            .arg(&signature_file)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                return Ok(SignatureCheck::Invalid(format!(
+                    "ssh-keygen is required to verify signatures: {}",
+                    err
+                )))
+            }
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(content)?;
+        }
+        if child.wait()?.success() {
+            return Ok(SignatureCheck::Verified(signer_label(trusted_keys, index)));
+        }
+    }
+
+    Ok(SignatureCheck::Invalid(
+        "signature does not match any trusted key".to_string(),
+    ))
+}
+
+/// Verify that the checked-out commit, or a tag pointing at it, carries a trusted SSH signature.
+///
+/// Git reports any signature its GPG or X.509 keyring trusts as good, so a signature only counts
+/// when it was made by one of the `vex-trusted-N` principals with that principal's SSH key.
+pub(super) fn verify_git_checkout(repo: &Path, trusted_keys: &[String]) -> Result<SignatureCheck> {
+    if trusted_keys.is_empty() {
+        return Ok(SignatureCheck::Invalid(
+            "no trusted keys are configured in [team_config] trusted_keys".to_string(),
+        ));
+    }
+
+    let temp = TempDir::new()?;
+    let allowed_signers = write_allowed_signers(temp.path(), trusted_keys)?;
+    let fingerprints = key_fingerprints(temp.path(), trusted_keys)?;
+    let signers_arg = format!("gpg.ssh.allowedSignersFile={}", allowed_signers.display());
+
+    let output = Command::new("git")
+        .current_dir(repo)
+        .args(["-c", "gpg.format=ssh", "-c", &signers_arg])
+        .args(["log", "-1", "--format=%G?%n%GS%n%GF", "HEAD"])
+        .stderr(Stdio::null())
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let commit_status = lines.next().unwrap_or("N").trim().to_string();
+    let commit_signer = lines.next().unwrap_or_default().trim();
+    let commit_fingerprint = lines.next().unwrap_or_default().trim();
+
+    if commit_status == "G" {
+        if let Some(index) = trusted_signer(&fingerprints, commit_signer, commit_fingerprint) {
+            return Ok(SignatureCheck::Verified(signer_label(trusted_keys, index)));
+        }
+    }
+
+    let tags = Command::new("git")
+        .current_dir(repo)
+        .args(["tag", "--points-at", "HEAD"])
+        .stderr(Stdio::null())
+        .output()?;
+    let mut tag_signed = false;
+    for tag in String::from_utf8_lossy(&tags.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+    {
+        let verify = Command::new("git")
+            .current_dir(repo)
+            .args(["-c", "gpg.format=ssh", "-c", &signers_arg])
+            .args(["verify-tag", "--raw", tag])
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()?;
+        let stderr = String::from_utf8_lossy(&verify.stderr);
+        if verify.status.success() {
+            let signer = stderr.lines().find_map(ssh_good_signature);
+            if let Some(index) =
+                signer.and_then(|(principal, key)| trusted_signer(&fingerprints, principal, key))
+            {
+                return Ok(SignatureCheck::Verified(format!(
+                    "tag {} by {}",
+                    tag,
+                    signer_label(trusted_keys, index)
+                )));
+            }
+        }
+        if !stderr.contains("no signature found") {
+            tag_signed = true;
+        }
+    }
+
+    if commit_status == "N" && !tag_signed {
+        Ok(SignatureCheck::Unsigned)
+    } else {
+        Ok(SignatureCheck::Invalid(
+            "commit or tag signature does not match any trusted key".to_string(),
+        ))
+    }
+}
+
+/// `Good "git" signature for vex-trusted-1 with ED25519 key SHA256:...` from `ssh-keygen -Y verify`.
+fn ssh_good_signature(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim().strip_prefix("Good \"git\" signature for ")?;
+    let (principal, key) = rest.split_once(" with ")?;
+    Some((principal, key.split_whitespace().last()?))
+}
+
+/// The index of the trusted key whose principal and SSH fingerprint both match the signature.
+fn trusted_signer(
+    fingerprints: &[Option<String>],
+    principal: &str,
+    fingerprint: &str,
+) -> Option<usize> {
+    let index = principal
+        .strip_prefix(PRINCIPAL_PREFIX)?
+        .parse::<usize>()
+        .ok()?
+        .checked_sub(1)?;
+    fingerprints
+        .get(index)?
+        .as_deref()
+        .filter(|expected| *expected == fingerprint)
+        .map(|_| index)
+}
+
+fn key_fingerprints(dir: &Path, trusted_keys: &[String]) -> Result<Vec<Option<String>>> {
+    let key_file = dir.join("trusted_key.pub");
+    trusted_keys
+        .iter()
+        .map(|key| {
+            fs::write(&key_file, key.trim())?;
+            let output = Command::new("ssh-keygen")
+                .arg("-l")
+                .arg("-f")
+                .arg(&key_file)
+                .stderr(Stdio::null())
+                .output()?;
+            Ok(output
+                .status
+                .success()
+                .then(|| {
+                    String::from_utf8_lossy(&output.stdout)
+                        .split_whitespace()
+                        .nth(1)
+                        .map(ToString::to_string)
+                })
+                .flatten())
+        })
+        .collect()
+}
+
+fn write_allowed_signers(dir: &Path, trusted_keys: &[String]) -> Result<PathBuf> {
+    let path = dir.join("allowed_signers");
+    let content = trusted_keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| format!("{} {}\n", principal_for(index), key.trim()))
+        .collect::<String>();
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+fn principal_for(index: usize) -> String {
+    format!("{}{}", PRINCIPAL_PREFIX, index + 1)
+}
+
+/// Prefer the key comment (e.g. `platform@company`) when describing who signed a config.
+fn signer_label(trusted_keys: &[String], index: usize) -> String {
+    trusted_keys
+        .get(index)
+        .and_then(|key| key.split_whitespace().nth(2))
+        .map(ToString::to_string)
+        .unwrap_or_else(|| principal_for(index))
+}
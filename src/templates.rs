@@ -1,9 +1,12 @@
 mod catalog;
+mod custom;
 mod write;
 
 use crate::error::Result;
 use catalog::{render_template_plan, TEMPLATE_INFOS};
+use custom::{locate_template, render_custom_template, TemplateLocation};
 use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use write::plan::build_write_plan;
 use write::rollback::apply_write_plan;
@@ -24,15 +27,39 @@ pub struct TemplateInfo {
 pub(super) enum MergeStrategy {
     ToolVersions,
     GitIgnore,
+    /// Add missing `[commands]` and `[env]` entries.
+    VexToml,
+    /// Add missing `scripts` and `devDependencies`.
+    PackageJson,
+    /// Add missing `[dependencies]`, `[dev-dependencies]`, and `[build-dependencies]`.
+    CargoToml,
+}
+
+/// Inputs for user-defined and remote templates.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateInputs {
+    /// Values for manifest variables, from `--var name=value`.
+    pub variables: BTreeMap<String, String>,
+    /// Team config source whose `[templates]` table names extra templates.
+    pub from: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub(super) struct TemplateFile {
-    pub(super) path: &'static str,
+    pub(super) path: String,
     pub(super) contents: String,
     pub(super) merge_strategy: Option<MergeStrategy>,
 }
 
+/// A key an add-only merge kept at the project's value because the template wants another.
+#[derive(Debug, Clone)]
+pub(super) struct MergeConflict {
+    pub(super) path: String,
+    pub(super) key: String,
+    pub(super) existing: String,
+    pub(super) template: String,
+}
+
 #[derive(Debug, Clone)]
 pub(super) enum PlannedWriteKind {
     Create,
@@ -50,11 +77,49 @@ pub fn list_templates() -> &'static [TemplateInfo] {
     TEMPLATE_INFOS
 }
 
-pub fn print_templates() {
+/// Built-in and `~/.vex/templates` template ids, for shell completion.
+pub fn template_ids() -> Vec<String> {
+    let mut ids = list_templates()
+        .iter()
+        .map(|template| template.id.to_string())
+        .collect::<Vec<_>>();
+    ids.extend(
+        custom::user_templates()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, _)| id),
+    );
+    ids
+}
+
+pub fn print_templates(cwd: &Path, from: Option<&str>) -> Result<()> {
     println!("{}", "Available templates:".cyan().bold());
     for template in list_templates() {
         println!("  {} {}", template.id.cyan(), template.description);
     }
+
+    let user_templates = custom::user_templates()?;
+    if !user_templates.is_empty() {
+        println!();
+        println!("{}", "User templates (~/.vex/templates):".cyan().bold());
+        for (id, description) in user_templates {
+            println!("  {} {}", id.cyan(), description);
+        }
+    }
+
+    if let Some(from) = from {
+        let team_templates = custom::team_templates(from, cwd)?;
+        println!();
+        println!("{}", format!("Team templates ({}):", from).cyan().bold());
+        if team_templates.is_empty() {
+            println!("  {}", "none declared".dimmed());
+        }
+        for (id, source) in team_templates {
+            println!("  {} {}", id.cyan(), source.dimmed());
+        }
+    }
+
+    Ok(())
 }
 
 pub fn init_template(
@@ -62,9 +127,14 @@ pub fn init_template(
     template_name: &str,
     dry_run: bool,
     conflict_mode: ConflictMode,
+    inputs: &TemplateInputs,
 ) -> Result<()> {
-    let plan = render_template_plan(cwd, template_name)?;
-    let preview = build_write_plan(cwd, &plan, conflict_mode)?;
+    let plan = match locate_template(template_name, inputs.from.as_deref(), cwd)? {
+        TemplateLocation::Builtin => render_template_plan(cwd, template_name)?,
+        location => render_custom_template(&location, cwd, &inputs.variables)?,
+    };
+    let write_plan = build_write_plan(cwd, &plan, conflict_mode)?;
+    let preview = write_plan.writes;
 
     if preview.is_empty() {
         println!(
@@ -73,6 +143,7 @@ pub fn init_template(
             template_name.cyan(),
             cwd.display().to_string().dimmed()
         );
+        print_merge_conflicts(&write_plan.merge_conflicts);
         return Ok(());
     }
 
@@ -97,6 +168,7 @@ pub fn init_template(
             }
         }
     }
+    print_merge_conflicts(&write_plan.merge_conflicts);
 
     if dry_run {
         println!();
@@ -115,5 +187,26 @@ pub fn init_template(
     Ok(())
 }
 
+fn print_merge_conflicts(conflicts: &[MergeConflict]) {
+    if conflicts.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{}",
+        "Existing values kept where the template differs:".yellow()
+    );
+    for conflict in conflicts {
+        println!(
+            "  {} {} = {} {}",
+            "keep".yellow(),
+            format!("{} {}", conflict.path, conflict.key).cyan(),
+            conflict.existing,
+            format!("(template: {})", conflict.template).dimmed()
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests;
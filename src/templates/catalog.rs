@@ -47,7 +47,7 @@ pub(super) fn render_template_plan(cwd: &Path, template_name: &str) -> Result<Ve
     }
 }
 
-fn inferred_project_name(cwd: &Path) -> String {
+pub(super) fn inferred_project_name(cwd: &Path) -> String {
     let fallback = "vex-app".to_string();
     let Some(name) = cwd.file_name().and_then(|value| value.to_str()) else {
         return fallback;
@@ -69,13 +69,19 @@ fn inferred_project_name(cwd: &Path) -> String {
     }
 }
 
+pub(super) fn is_builtin(template_name: &str) -> bool {
+    TEMPLATE_INFOS
+        .iter()
+        .any(|template| template.id == template_name)
+}
+
 pub(super) fn template_file(
-    path: &'static str,
+    path: &str,
     contents: impl Into<String>,
     merge_strategy: Option<MergeStrategy>,
 ) -> TemplateFile {
     TemplateFile {
-        path,
+        path: path.to_string(),
         contents: contents.into(),
         merge_strategy,
     }
@@ -17,7 +17,7 @@ build = "go build ./..."
 test = "go test ./..."
 run = "go run ./cmd/app"
 "#,
-            None,
+            Some(MergeStrategy::VexToml),
         ),
         template_file(".gitignore", "bin/\n", Some(MergeStrategy::GitIgnore)),
         template_file(
@@ -16,7 +16,7 @@ build = "mkdir -p out && javac -d out src/Main.java"
 run = "mkdir -p out && javac -d out src/Main.java && java -cp out Main"
 test = "mkdir -p out && javac -d out src/Main.java tests/MainSmoke.java && java -cp out MainSmoke"
 "#,
-            None,
+            Some(MergeStrategy::VexToml),
         ),
         template_file(".gitignore", "out/\n", Some(MergeStrategy::GitIgnore)),
         template_file(
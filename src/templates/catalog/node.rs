@@ -17,7 +17,7 @@ build = "npm run build"
 test = "npm run test"
 run = "npm run start"
 "#,
-            None,
+            Some(MergeStrategy::VexToml),
         ),
         template_file(
             ".gitignore",
@@ -45,7 +45,7 @@ run = "npm run start"
 "#,
                 project_name
             ),
-            None,
+            Some(MergeStrategy::PackageJson),
         ),
         template_file(
             "tsconfig.json",
@@ -18,7 +18,7 @@ sync = "vex python sync"
 run = "python src/main.py"
 test = "python -m unittest discover -s tests"
 "#,
-            None,
+            Some(MergeStrategy::VexToml),
         ),
         template_file(
             ".gitignore",
@@ -17,7 +17,7 @@ build = "cargo build"
 test = "cargo test"
 run = "cargo run"
 "#,
-            None,
+            Some(MergeStrategy::VexToml),
         ),
         template_file(".gitignore", "target/\n", Some(MergeStrategy::GitIgnore)),
         template_file(
@@ -32,7 +32,7 @@ edition = "2021"
 "#,
                 project_name
             ),
-            None,
+            Some(MergeStrategy::CargoToml),
         ),
         template_file(
             "src/main.rs",
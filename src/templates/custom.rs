@@ -0,0 +1,65 @@
+//! User-defined project templates
+//!
+//! A template is a directory with a `template.toml` manifest and the files it declares. It can
+//! live in `~/.vex/templates/<id>/`, in a local directory, in a Git repository, or be named by a
+//! team config's `[templates]` table. Rendered files go through the same write plan as the
+//! built-in templates.
+
+mod manifest;
+mod source;
+mod variables;
+
+use super::catalog::template_file;
+use super::TemplateFile;
+use crate::error::{Result, VexError};
+use manifest::TemplateManifest;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+pub(super) use source::{locate_template, team_templates, user_templates, TemplateLocation};
+
+pub(super) const MANIFEST_FILE: &str = "template.toml";
+
+/// Render the files of a manifest template with variables from `provided`, prompts, or defaults.
+pub(super) fn render_custom_template(
+    location: &TemplateLocation,
+    cwd: &Path,
+    provided: &BTreeMap<String, String>,
+) -> Result<Vec<TemplateFile>> {
+    let root = location.root().ok_or_else(|| {
+        VexError::Config("Built-in templates do not have a manifest.".to_string())
+    })?;
+    let manifest = TemplateManifest::load(root)?;
+    let values = variables::resolve(&manifest, cwd, provided, variables::can_prompt()?)?;
+
+    manifest
+        .files
+        .iter()
+        .map(|file| {
+            let path = variables::render(&file.path, &values);
+            manifest::ensure_relative(&path, "file path")?;
+            let source = file
+                .source
+                .clone()
+                .unwrap_or_else(|| format!("files/{}", file.path));
+            let source_path = manifest::contained_path(root, &source)?;
+            let contents = fs::read_to_string(&source_path).map_err(|err| {
+                VexError::Config(format!(
+                    "Template file '{}' could not be read from {}: {}",
+                    file.path,
+                    source_path.display(),
+                    err
+                ))
+            })?;
+            Ok(template_file(
+                &path,
+                variables::render(&contents, &values),
+                file.merge.map(Into::into),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
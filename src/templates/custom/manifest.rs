@@ -0,0 +1,158 @@
+use super::MANIFEST_FILE;
+use crate::error::{Result, VexError};
+use crate::templates::MergeStrategy;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// `template.toml`: what a template writes and which variables it asks for.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct TemplateManifest {
+    #[serde(default)]
+    pub(super) description: String,
+    #[serde(default)]
+    pub(super) variables: Vec<VariableDef>,
+    #[serde(default)]
+    pub(super) files: Vec<FileDef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct VariableDef {
+    pub(super) name: String,
+    pub(super) prompt: Option<String>,
+    pub(super) default: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct FileDef {
+    /// Destination relative to the project root; may use `{{variables}}`.
+    pub(super) path: String,
+    /// File inside the template directory, `files/<path>` by default.
+    pub(super) source: Option<String>,
+    pub(super) merge: Option<MergeDef>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum MergeDef {
+    ToolVersions,
+    Gitignore,
+    VexToml,
+    PackageJson,
+    CargoToml,
+}
+
+impl From<MergeDef> for MergeStrategy {
+    fn from(value: MergeDef) -> Self {
+        match value {
+            MergeDef::ToolVersions => MergeStrategy::ToolVersions,
+            MergeDef::Gitignore => MergeStrategy::GitIgnore,
+            MergeDef::VexToml => MergeStrategy::VexToml,
+            MergeDef::PackageJson => MergeStrategy::PackageJson,
+            MergeDef::CargoToml => MergeStrategy::CargoToml,
+        }
+    }
+}
+
+impl TemplateManifest {
+    pub(super) fn load(root: &Path) -> Result<Self> {
+        let path = contained_path(root, MANIFEST_FILE)?;
+        let content = fs::read_to_string(&path).map_err(|err| {
+            VexError::Config(format!(
+                "Template manifest {} could not be read: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        Self::parse(&content)
+            .map_err(|err| VexError::Config(format!("Invalid {}: {}", path.display(), err)))
+    }
+
+    pub(super) fn parse(content: &str) -> Result<Self> {
+        let manifest: Self =
+            toml::from_str(content).map_err(|err| VexError::Parse(err.to_string()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.files.is_empty() {
+            return Err(VexError::Parse(
+                "a template must declare at least one [[files]] entry".to_string(),
+            ));
+        }
+
+        let mut names = BTreeSet::new();
+        for variable in &self.variables {
+            if variable.name.is_empty()
+                || !variable
+                    .name
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+            {
+                return Err(VexError::Parse(format!(
+                    "variable name '{}' may only contain letters, digits, and '_'",
+                    variable.name
+                )));
+            }
+            if variable.name == "project_name" {
+                return Err(VexError::Parse(
+                    "'project_name' is built in and cannot be redeclared".to_string(),
+                ));
+            }
+            if !names.insert(variable.name.as_str()) {
+                return Err(VexError::Parse(format!(
+                    "variable '{}' is declared more than once",
+                    variable.name
+                )));
+            }
+        }
+
+        for file in &self.files {
+            ensure_relative(&file.path, "file path")?;
+            if let Some(source) = &file.source {
+                ensure_relative(source, "file source")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Template paths must stay inside the project (or template) directory.
+pub(super) fn ensure_relative(path: &str, what: &str) -> Result<()> {
+    let is_safe = !path.trim().is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if is_safe {
+        Ok(())
+    } else {
+        Err(VexError::Parse(format!(
+            "{} '{}' must be relative and must not contain '..'",
+            what, path
+        )))
+    }
+}
+
+/// `root/relative`, rejecting symlinks that resolve outside the template directory, so a
+/// downloaded template cannot read files such as `~/.ssh/id_ed25519` into the project.
+pub(super) fn contained_path(root: &Path, relative: &str) -> Result<PathBuf> {
+    let path = root.join(relative);
+    let (Ok(root), Ok(resolved)) = (root.canonicalize(), path.canonicalize()) else {
+        // Missing files are reported by the read that follows.
+        return Ok(path);
+    };
+    if resolved.starts_with(&root) {
+        Ok(path)
+    } else {
+        Err(VexError::Config(format!(
+            "Template file {} is a symlink to {}, outside the template directory.",
+            path.display(),
+            resolved.display()
+        )))
+    }
+}
@@ -0,0 +1,148 @@
+use super::manifest::{ensure_relative, TemplateManifest};
+use super::MANIFEST_FILE;
+use crate::error::{Result, VexError};
+use crate::paths::vex_dir;
+use crate::team_config::{self, GitCheckout, GitSource};
+use crate::templates::catalog::is_builtin;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where a template named on the command line was found.
+#[derive(Debug)]
+pub(in crate::templates) enum TemplateLocation {
+    Builtin,
+    Directory(PathBuf),
+    /// A sparse clone that lives as long as the location; `root` is inside `checkout`.
+    Git {
+        _checkout: GitCheckout,
+        root: PathBuf,
+    },
+}
+
+impl TemplateLocation {
+    pub(in crate::templates) fn root(&self) -> Option<&Path> {
+        match self {
+            TemplateLocation::Builtin => None,
+            TemplateLocation::Directory(root) => Some(root),
+            TemplateLocation::Git { root, .. } => Some(root),
+        }
+    }
+}
+
+/// Find a template by name.
+///
+/// Git sources (`<repo>[#<ref>[:<dir>]]`) are cloned, `--from` team config names are looked up
+/// next, then template directories given as a path, then `~/.vex/templates/<name>/`, and finally
+/// the built-in catalog.
+pub(in crate::templates) fn locate_template(
+    name: &str,
+    from: Option<&str>,
+    cwd: &Path,
+) -> Result<TemplateLocation> {
+    if let Some(location) = locate_source(name, cwd)? {
+        return Ok(location);
+    }
+
+    if let Some(from) = from {
+        if let Some(source) = team_templates(from, cwd)?.get(name) {
+            return locate_source(source, cwd)?.ok_or_else(|| {
+                VexError::Config(format!(
+                    "Team template '{}' points to '{}', which is neither a Git repository nor a directory with {}.",
+                    name, source, MANIFEST_FILE
+                ))
+            });
+        }
+    }
+
+    if let Some(root) = user_template_dir(name).filter(|root| root.join(MANIFEST_FILE).is_file()) {
+        return Ok(TemplateLocation::Directory(root));
+    }
+
+    if is_builtin(name) {
+        return Ok(TemplateLocation::Builtin);
+    }
+
+    Err(VexError::Config(format!(
+        "Unknown template '{}'. Run 'vex init --list-templates' to see the built-in and user templates.",
+        name
+    )))
+}
+
+/// `[templates]` declared by a team config source.
+pub(in crate::templates) fn team_templates(
+    from: &str,
+    cwd: &Path,
+) -> Result<BTreeMap<String, String>> {
+    Ok(team_config::load_versions_from_source(from, cwd, false)?.templates)
+}
+
+/// Templates installed under `~/.vex/templates`, with their manifest descriptions.
+pub(in crate::templates) fn user_templates() -> Result<Vec<(String, String)>> {
+    let root = vex_dir()?.join("templates");
+    let Ok(entries) = fs::read_dir(&root) else {
+        return Ok(Vec::new());
+    };
+
+    let mut templates = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join(MANIFEST_FILE).is_file())
+        .map(|entry| {
+            let description = match TemplateManifest::load(&entry.path()) {
+                Ok(manifest) => manifest.description,
+                Err(err) => format!("(invalid manifest: {})", err),
+            };
+            (entry.file_name().to_string_lossy().to_string(), description)
+        })
+        .collect::<Vec<_>>();
+    templates.sort();
+    Ok(templates)
+}
+
+fn user_template_dir(name: &str) -> Option<PathBuf> {
+    if ensure_relative(name, "template name").is_err() || name.contains('/') {
+        return None;
+    }
+    vex_dir().ok().map(|vex| vex.join("templates").join(name))
+}
+
+/// Resolve a Git source or a template directory path; `None` when `source` is neither.
+fn locate_source(source: &str, cwd: &Path) -> Result<Option<TemplateLocation>> {
+    if let Some(git) = team_config::parse_git_template_source(source)? {
+        return clone_template(&git).map(Some);
+    }
+
+    if source.contains('/') || source.starts_with('.') {
+        let root = cwd.join(source);
+        if root.join(MANIFEST_FILE).is_file() {
+            return Ok(Some(TemplateLocation::Directory(root)));
+        }
+    }
+    Ok(None)
+}
+
+/// Check out a Git template source and find its manifest.
+fn clone_template(source: &GitSource) -> Result<TemplateLocation> {
+    let checkout = team_config::checkout_template(source)?;
+    let root = checkout.path().join(&source.config_path);
+    if !root.join(MANIFEST_FILE).is_file() {
+        return Err(VexError::Config(format!(
+            "Template repository '{}' has no {} in {}.",
+            source.repo,
+            MANIFEST_FILE,
+            if source.config_path.is_empty() {
+                "its root"
+            } else {
+                &source.config_path
+            }
+        )));
+    }
+
+    Ok(TemplateLocation::Git {
+        _checkout: checkout,
+        root,
+    })
+}
+
+#[cfg(test)]
+mod tests;
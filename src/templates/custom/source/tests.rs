@@ -0,0 +1,114 @@
+use super::*;
+use tempfile::TempDir;
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .current_dir(repo)
+        .args(args)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn test_git_template_source_parsing() {
+    let source =
+        team_config::parse_git_template_source("git@github.com:acme/templates.git#v2:services/api")
+            .unwrap()
+            .unwrap();
+    assert_eq!(source.repo, "git@github.com:acme/templates.git");
+    assert!(!source.is_local);
+    assert_eq!(source.reference.as_deref(), Some("v2"));
+    assert_eq!(source.config_path, "services/api");
+
+    let source = team_config::parse_git_template_source("https://example.com/acme/template.git")
+        .unwrap()
+        .unwrap();
+    assert_eq!(source.reference, None);
+    assert_eq!(source.config_path, "");
+    assert!(
+        team_config::parse_git_template_source("file:///srv/templates.git")
+            .unwrap()
+            .unwrap()
+            .is_local
+    );
+
+    assert!(team_config::parse_git_template_source("rust-cli")
+        .unwrap()
+        .is_none());
+    assert!(team_config::parse_git_template_source("./templates/api")
+        .unwrap()
+        .is_none());
+
+    let err =
+        team_config::parse_git_template_source("git@github.com:acme/t.git#main:../x").unwrap_err();
+    assert!(err.to_string().contains("relative to the repository root"));
+    let err = team_config::parse_git_template_source("git@github.com:acme/t.git#--upload-pack=x")
+        .unwrap_err();
+    assert!(err.to_string().contains("Invalid Git ref"));
+}
+
+#[test]
+fn test_locate_git_template_checks_out_selected_directory() {
+    let temp = TempDir::new().unwrap();
+    let repo = temp.path().join("templates");
+    fs::create_dir_all(repo.join("services/api/files")).unwrap();
+    git(&repo, &["init", "--quiet", "--initial-branch", "main"]);
+    git(&repo, &["config", "user.email", "codex@example.com"]);
+    git(&repo, &["config", "user.name", "Codex"]);
+    fs::write(
+        repo.join("services/api").join(MANIFEST_FILE),
+        "[[files]]\npath = \"README.md\"\n",
+    )
+    .unwrap();
+    fs::write(repo.join("services/api/files/README.md"), "api\n").unwrap();
+    fs::write(repo.join("other.txt"), "unrelated\n").unwrap();
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "Add api template", "--quiet"]);
+
+    let source = format!("file://{}#main:services/api", repo.display());
+    let location = locate_template(&source, None, temp.path()).unwrap();
+    let root = location.root().unwrap().to_path_buf();
+    assert!(root.join("files/README.md").is_file());
+    assert!(!root
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("other.txt")
+        .exists());
+
+    let missing = format!("file://{}#main:services/web", repo.display());
+    let err = locate_template(&missing, None, temp.path()).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("has no template.toml in services/web"));
+
+    drop(location);
+    assert!(!root.exists());
+}
+
+#[test]
+fn test_locate_template_prefers_paths_then_builtins() {
+    let temp = TempDir::new().unwrap();
+    let template = temp.path().join("templates/api");
+    fs::create_dir_all(&template).unwrap();
+    fs::write(
+        template.join(MANIFEST_FILE),
+        "[[files]]\npath = \"README.md\"\n",
+    )
+    .unwrap();
+
+    assert!(matches!(
+        locate_template("./templates/api", None, temp.path()).unwrap(),
+        TemplateLocation::Directory(root) if root == temp.path().join("./templates/api")
+    ));
+    assert!(matches!(
+        locate_template("rust-cli", None, temp.path()).unwrap(),
+        TemplateLocation::Builtin
+    ));
+    let err = locate_template("./templates/missing", None, temp.path()).unwrap_err();
+    assert!(err.to_string().contains("Unknown template"));
+}
@@ -0,0 +1,174 @@
+use super::manifest::TemplateManifest;
+use super::*;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"
+description = "Internal service"
+
+[[variables]]
+name = "service_name"
+prompt = "Service name"
+default = "{{ project_name }}-svc"
+
+[[variables]]
+name = "owner"
+
+[[files]]
+path = "README.md"
+
+[[files]]
+path = "deploy/{{service_name}}.yaml"
+source = "files/deploy.yaml"
+
+[[files]]
+path = ".gitignore"
+merge = "gitignore"
+"#;
+
+fn write_template(root: &Path) {
+    fs::create_dir_all(root.join("files")).unwrap();
+    fs::write(root.join(MANIFEST_FILE), MANIFEST).unwrap();
+    fs::write(
+        root.join("files/README.md"),
+        "# {{service_name}}\n\nOwned by {{ owner }}.\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("files/deploy.yaml"),
+        "name: {{service_name}}\ntoken: ${{ secrets.DEPLOY_TOKEN }}\n",
+    )
+    .unwrap();
+    fs::write(root.join("files/.gitignore"), "target/\n").unwrap();
+}
+
+fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_manifest_rejects_unsafe_paths_and_bad_variables() {
+    let err = TemplateManifest::parse("[[files]]\npath = \"../outside\"\n").unwrap_err();
+    assert!(err.to_string().contains("must not contain '..'"));
+
+    let err = TemplateManifest::parse("[[files]]\npath = \"/etc/passwd\"\n").unwrap_err();
+    assert!(err.to_string().contains("must be relative"));
+
+    let err = TemplateManifest::parse(
+        "[[variables]]\nname = \"project_name\"\n\n[[files]]\npath = \"a\"\n",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("built in"));
+
+    let err = TemplateManifest::parse("description = \"empty\"\n").unwrap_err();
+    assert!(err.to_string().contains("at least one [[files]]"));
+
+    let err = TemplateManifest::parse("[[files]]\npath = \"a\"\nmode = \"0755\"\n").unwrap_err();
+    assert!(err.to_string().contains("unknown field"));
+}
+
+#[test]
+fn test_variables_use_provided_values_then_defaults() {
+    let temp = TempDir::new().unwrap();
+    let cwd = temp.path().join("Billing_API");
+    let manifest = TemplateManifest::parse(MANIFEST).unwrap();
+
+    let resolved =
+        variables::resolve(&manifest, &cwd, &values(&[("owner", "payments")]), false).unwrap();
+    assert_eq!(resolved["project_name"], "billing-api");
+    assert_eq!(resolved["service_name"], "billing-api-svc");
+    assert_eq!(resolved["owner"], "payments");
+
+    let err = variables::resolve(&manifest, &cwd, &BTreeMap::new(), false).unwrap_err();
+    assert!(err.to_string().contains("--var owner=<value>"));
+
+    let err = variables::resolve(
+        &manifest,
+        &cwd,
+        &values(&[("owner", "payments"), ("region", "eu")]),
+        false,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("region"));
+}
+
+#[test]
+fn test_render_only_replaces_known_variables() {
+    let rendered = variables::render(
+        "{{name}} {{ name }} ${{ secrets.TOKEN }} {{unknown}} {{ unterminated",
+        &values(&[("name", "api")]),
+    );
+    assert_eq!(
+        rendered,
+        "api api ${{ secrets.TOKEN }} {{unknown}} {{ unterminated"
+    );
+}
+
+#[test]
+fn test_render_custom_template_from_directory() {
+    let temp = TempDir::new().unwrap();
+    let template_root = temp.path().join("service-template");
+    write_template(&template_root);
+    let cwd = temp.path().join("billing");
+
+    let files = render_custom_template(
+        &TemplateLocation::Directory(template_root),
+        &cwd,
+        &values(&[("owner", "payments"), ("service_name", "billing")]),
+    )
+    .unwrap();
+
+    let paths = files
+        .iter()
+        .map(|file| file.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec!["README.md", "deploy/billing.yaml", ".gitignore"]
+    );
+    assert_eq!(files[0].contents, "# billing\n\nOwned by payments.\n");
+    assert_eq!(
+        files[1].contents,
+        "name: billing\ntoken: ${{ secrets.DEPLOY_TOKEN }}\n"
+    );
+    assert!(matches!(
+        files[2].merge_strategy,
+        Some(crate::templates::MergeStrategy::GitIgnore)
+    ));
+}
+
+#[test]
+fn test_render_custom_template_rejects_rendered_paths_outside_project() {
+    let temp = TempDir::new().unwrap();
+    let template_root = temp.path().join("service-template");
+    write_template(&template_root);
+
+    let err = render_custom_template(
+        &TemplateLocation::Directory(template_root),
+        temp.path(),
+        &values(&[("owner", "payments"), ("service_name", "../../escape")]),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("must not contain '..'"));
+}
+
+#[test]
+fn test_render_custom_template_rejects_symlinks_outside_template() {
+    let temp = TempDir::new().unwrap();
+    let template_root = temp.path().join("service-template");
+    write_template(&template_root);
+    let secret = temp.path().join("secret.txt");
+    fs::write(&secret, "token").unwrap();
+    fs::remove_file(template_root.join("files/README.md")).unwrap();
+    std::os::unix::fs::symlink(&secret, template_root.join("files/README.md")).unwrap();
+
+    let err = render_custom_template(
+        &TemplateLocation::Directory(template_root),
+        temp.path(),
+        &values(&[("owner", "payments"), ("service_name", "billing")]),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("outside the template directory"));
+}
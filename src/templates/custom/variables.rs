@@ -0,0 +1,126 @@
+use super::manifest::TemplateManifest;
+use crate::config;
+use crate::error::{Result, VexError};
+use crate::templates::catalog::inferred_project_name;
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+
+/// Collect a value for every declared variable, plus the built-in `project_name`.
+///
+/// Each variable comes from `--var` first, then an interactive prompt, then its default.
+/// Defaults may refer to `project_name` and to variables declared before them.
+pub(super) fn resolve(
+    manifest: &TemplateManifest,
+    cwd: &Path,
+    provided: &BTreeMap<String, String>,
+    interactive: bool,
+) -> Result<BTreeMap<String, String>> {
+    let undeclared = provided
+        .keys()
+        .filter(|name| {
+            name.as_str() != "project_name"
+                && !manifest
+                    .variables
+                    .iter()
+                    .any(|variable| &variable.name == *name)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if !undeclared.is_empty() {
+        return Err(VexError::Config(format!(
+            "The template does not declare variable(s): {}. Declared: {}.",
+            undeclared.join(", "),
+            declared_names(manifest)
+        )));
+    }
+
+    let mut values = BTreeMap::new();
+    values.insert(
+        "project_name".to_string(),
+        provided
+            .get("project_name")
+            .cloned()
+            .unwrap_or_else(|| inferred_project_name(cwd)),
+    );
+
+    for variable in &manifest.variables {
+        let default = variable
+            .default
+            .as_deref()
+            .map(|default| render(default, &values));
+        let value = match provided.get(&variable.name) {
+            Some(value) => value.clone(),
+            None if interactive => prompt(
+                variable.prompt.as_deref().unwrap_or(&variable.name),
+                default.as_deref(),
+            )?
+            .ok_or_else(|| missing_value(&variable.name))?,
+            None => default.ok_or_else(|| missing_value(&variable.name))?,
+        };
+        values.insert(variable.name.clone(), value);
+    }
+
+    Ok(values)
+}
+
+/// Replace `{{ name }}` placeholders for known variables; any other `{{ ... }}` text (for
+/// example GitHub Actions `${{ secrets.TOKEN }}`) is left untouched.
+pub(super) fn render(text: &str, values: &BTreeMap<String, String>) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+        rendered.push_str(&rest[..start]);
+        match values.get(name) {
+            Some(value) => rendered.push_str(value),
+            None => rendered.push_str(&rest[start..start + len + 4]),
+        }
+        rest = &rest[start + len + 4..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+fn prompt(label: &str, default: Option<&str>) -> Result<Option<String>> {
+    match default {
+        Some(default) => print!("{} [{}]: ", label, default),
+        None => print!("{}: ", label),
+    }
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+    if input.is_empty() {
+        Ok(default.map(str::to_string))
+    } else {
+        Ok(Some(input.to_string()))
+    }
+}
+
+fn missing_value(name: &str) -> VexError {
+    VexError::Config(format!(
+        "Template variable '{}' has no value. Pass it with --var {}=<value>.",
+        name, name
+    ))
+}
+
+fn declared_names(manifest: &TemplateManifest) -> String {
+    let mut names = vec!["project_name".to_string()];
+    names.extend(
+        manifest
+            .variables
+            .iter()
+            .map(|variable| variable.name.clone()),
+    );
+    names.join(", ")
+}
+
+/// Prompt for missing variables only when a person can answer.
+pub(super) fn can_prompt() -> Result<bool> {
+    Ok(io::stdin().is_terminal() && !config::non_interactive()?)
+}
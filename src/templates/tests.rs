@@ -22,7 +22,13 @@ fn test_strict_mode_rejects_existing_files() {
     let temp = TempDir::new().unwrap();
     fs::write(temp.path().join(".tool-versions"), "node 20\n").unwrap();
 
-    let result = init_template(temp.path(), "node-typescript", false, ConflictMode::Strict);
+    let result = init_template(
+        temp.path(),
+        "node-typescript",
+        false,
+        ConflictMode::Strict,
+        &TemplateInputs::default(),
+    );
     assert!(result.is_err());
     let err = result.unwrap_err().to_string();
     assert!(err.contains(".tool-versions"));
@@ -35,7 +41,14 @@ fn test_add_only_merges_tool_versions_and_gitignore() {
     fs::write(temp.path().join(".tool-versions"), "rust stable\n").unwrap();
     fs::write(temp.path().join(".gitignore"), "target/\n").unwrap();
 
-    init_template(temp.path(), "python-venv", false, ConflictMode::AddOnly).unwrap();
+    init_template(
+        temp.path(),
+        "python-venv",
+        false,
+        ConflictMode::AddOnly,
+        &TemplateInputs::default(),
+    )
+    .unwrap();
 
     let tool_versions = fs::read_to_string(temp.path().join(".tool-versions")).unwrap();
     assert!(tool_versions.contains("rust stable"));
@@ -49,7 +62,7 @@ fn test_add_only_merges_tool_versions_and_gitignore() {
 }
 
 #[test]
-fn test_add_only_rejects_existing_vex_toml() {
+fn test_add_only_merges_existing_vex_toml_and_keeps_conflicting_commands() {
     let temp = TempDir::new().unwrap();
     fs::write(temp.path().join(".tool-versions"), "").unwrap();
     fs::write(temp.path().join(".gitignore"), "").unwrap();
@@ -59,15 +72,59 @@ fn test_add_only_rejects_existing_vex_toml() {
     )
     .unwrap();
 
-    let result = init_template(temp.path(), "rust-cli", false, ConflictMode::AddOnly);
-    assert!(result.is_err());
-    assert!(!temp.path().join("Cargo.toml").exists());
+    init_template(
+        temp.path(),
+        "rust-cli",
+        false,
+        ConflictMode::AddOnly,
+        &TemplateInputs::default(),
+    )
+    .unwrap();
+
+    let vex_toml = fs::read_to_string(temp.path().join(".vex.toml")).unwrap();
+    assert!(vex_toml.starts_with("[commands]\nrun = \"true\"\n"));
+    assert!(vex_toml.contains("build = \"cargo build\""));
+    assert!(!vex_toml.contains("cargo run"));
+    assert!(temp.path().join("Cargo.toml").exists());
+}
+
+#[test]
+fn test_add_only_reports_merge_conflicts_in_plan() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("package.json"),
+        "{\n  \"name\": \"web\",\n  \"scripts\": {\n    \"build\": \"vite build\"\n  }\n}\n",
+    )
+    .unwrap();
+
+    let files = render_template_plan(temp.path(), "node-typescript").unwrap();
+    let plan = build_write_plan(temp.path(), &files, ConflictMode::AddOnly).unwrap();
+
+    assert_eq!(plan.merge_conflicts.len(), 1);
+    let conflict = &plan.merge_conflicts[0];
+    assert_eq!(conflict.path, "package.json");
+    assert_eq!(conflict.key, "scripts.build");
+    assert_eq!(conflict.existing, "\"vite build\"");
+    assert_eq!(conflict.template, "\"tsc -p tsconfig.json\"");
+    assert!(plan
+        .writes
+        .iter()
+        .any(|write| write.path.ends_with("package.json")
+            && matches!(write.kind, PlannedWriteKind::Merge)
+            && write.contents.contains("\"typescript\"")));
 }
 
 #[test]
 fn test_dry_run_does_not_write_files() {
     let temp = TempDir::new().unwrap();
-    init_template(temp.path(), "go-service", true, ConflictMode::Strict).unwrap();
+    init_template(
+        temp.path(),
+        "go-service",
+        true,
+        ConflictMode::Strict,
+        &TemplateInputs::default(),
+    )
+    .unwrap();
     assert!(!temp.path().join("go.mod").exists());
     assert!(!temp.path().join(".tool-versions").exists());
 }
@@ -77,7 +134,13 @@ fn test_strict_mode_rolls_back_files_when_write_fails() {
     let temp = TempDir::new().unwrap();
     fs::write(temp.path().join("src"), "blocking file").unwrap();
 
-    let result = init_template(temp.path(), "rust-cli", false, ConflictMode::Strict);
+    let result = init_template(
+        temp.path(),
+        "rust-cli",
+        false,
+        ConflictMode::Strict,
+        &TemplateInputs::default(),
+    );
     assert!(result.is_err());
     assert!(!temp.path().join(".tool-versions").exists());
     assert!(!temp.path().join(".vex.toml").exists());
@@ -95,7 +158,13 @@ fn test_add_only_rolls_back_merged_files_when_write_fails() {
     fs::write(temp.path().join(".gitignore"), "target/\n").unwrap();
     fs::write(temp.path().join("src"), "blocking file").unwrap();
 
-    let result = init_template(temp.path(), "python-venv", false, ConflictMode::AddOnly);
+    let result = init_template(
+        temp.path(),
+        "python-venv",
+        false,
+        ConflictMode::AddOnly,
+        &TemplateInputs::default(),
+    );
     assert!(result.is_err());
     assert_eq!(
         fs::read_to_string(temp.path().join(".tool-versions")).unwrap(),
@@ -118,7 +187,13 @@ fn test_rollback_removes_created_empty_directories() {
     let temp = TempDir::new().unwrap();
     fs::write(temp.path().join("tests"), "blocking file").unwrap();
 
-    let result = init_template(temp.path(), "rust-cli", false, ConflictMode::Strict);
+    let result = init_template(
+        temp.path(),
+        "rust-cli",
+        false,
+        ConflictMode::Strict,
+        &TemplateInputs::default(),
+    );
     assert!(result.is_err());
     assert!(!temp.path().join("src").exists());
     assert_eq!(
@@ -1,9 +1,31 @@
+mod package_json;
+mod toml_tables;
+
 use crate::error::{Result, VexError};
 use crate::resolver;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 
+pub(super) use package_json::merge_package_json_file;
+pub(super) use toml_tables::{merge_cargo_toml_file, merge_vex_toml_file};
+
+/// Result of a structural merge: the new file contents, if anything was added, and the keys
+/// whose existing values differ from the template's.
+#[derive(Debug, Default)]
+pub(super) struct MergeOutcome {
+    pub(super) contents: Option<String>,
+    pub(super) conflicts: Vec<KeyConflict>,
+}
+
+/// A key present in both the project file and the template with different values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct KeyConflict {
+    pub(super) key: String,
+    pub(super) existing: String,
+    pub(super) template: String,
+}
+
 pub(super) fn merge_tool_versions_file(
     path: &Path,
     template_contents: &str,
@@ -76,3 +98,6 @@ pub(super) fn merge_gitignore_file(path: &Path, template_contents: &str) -> Resu
     }
     Ok(Some(merged))
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,160 @@
+use super::{KeyConflict, MergeOutcome};
+use crate::error::{Result, VexError};
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::value::RawValue;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+/// Objects of `package.json` that a template may add entries to.
+const PACKAGE_JSON_OBJECTS: &[&str] = &["scripts", "devDependencies"];
+
+/// A JSON object in file order whose values keep their original text.
+type RawObject = IndexMap<String, Box<RawValue>>;
+
+/// A top-level `package.json` value: kept verbatim, or an object vex added entries to.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Entry {
+    Raw(Box<RawValue>),
+    Merged(RawObject),
+}
+
+/// Add the template's missing `scripts` and `devDependencies` to an existing `package.json`.
+///
+/// Key order is kept and the file is rewritten with its own indentation, the way npm does.
+/// Values vex does not touch are copied as written.
+pub(in crate::templates::write) fn merge_package_json_file(
+    path: &Path,
+    template_contents: &str,
+) -> Result<MergeOutcome> {
+    let existing_contents = fs::read_to_string(path)?;
+    let existing = parse(&existing_contents, path)?;
+    let template = parse(template_contents, path)?;
+
+    let mut merged = existing
+        .into_iter()
+        .map(|(key, value)| (key, Entry::Raw(value)))
+        .collect::<IndexMap<_, _>>();
+    let mut changed = false;
+    let mut conflicts = Vec::new();
+    for object_name in PACKAGE_JSON_OBJECTS {
+        let Some(template_object) = template.get(*object_name).and_then(|raw| as_object(raw))
+        else {
+            continue;
+        };
+
+        let mut existing_object = match merged.get(*object_name) {
+            None => RawObject::new(),
+            Some(Entry::Raw(raw)) => match as_object(raw) {
+                Some(object) => object,
+                None => {
+                    conflicts.push(KeyConflict {
+                        key: object_name.to_string(),
+                        existing: compact(raw),
+                        template: compact(&template[*object_name]),
+                    });
+                    continue;
+                }
+            },
+            Some(Entry::Merged(_)) => continue,
+        };
+
+        let mut object_changed = false;
+        for (key, value) in template_object {
+            match existing_object.get(&key) {
+                Some(current) if compact(current) != compact(&value) => {
+                    conflicts.push(KeyConflict {
+                        key: format!("{}.{}", object_name, key),
+                        existing: compact(current),
+                        template: compact(&value),
+                    })
+                }
+                Some(_) => {}
+                None => {
+                    existing_object.insert(key, value);
+                    object_changed = true;
+                }
+            }
+        }
+        if object_changed {
+            merged.insert(object_name.to_string(), Entry::Merged(existing_object));
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return Ok(MergeOutcome {
+            contents: None,
+            conflicts,
+        });
+    }
+
+    let indent = detect_indent(&existing_contents);
+    let mut buffer = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(
+        &mut buffer,
+        serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes()),
+    );
+    merged
+        .serialize(&mut serializer)
+        .map_err(|err| VexError::Parse(format!("Failed to write {}: {}", path.display(), err)))?;
+    let mut merged = String::from_utf8_lossy(&buffer).to_string();
+    merged.push('\n');
+
+    Ok(MergeOutcome {
+        contents: Some(merged),
+        conflicts,
+    })
+}
+
+fn parse(contents: &str, path: &Path) -> Result<RawObject> {
+    match serde_json::from_str::<Value>(contents) {
+        Ok(Value::Object(_)) => serde_json::from_str(contents).map_err(|err| {
+            VexError::Config(format!(
+                "Cannot merge {} because it is not valid JSON: {}",
+                path.display(),
+                err
+            ))
+        }),
+        Ok(_) => Err(VexError::Config(format!(
+            "Cannot merge {} because it is not a JSON object.",
+            path.display()
+        ))),
+        Err(err) => Err(VexError::Config(format!(
+            "Cannot merge {} because it is not valid JSON: {}",
+            path.display(),
+            err
+        ))),
+    }
+}
+
+fn as_object(raw: &RawValue) -> Option<RawObject> {
+    raw.get()
+        .trim_start()
+        .starts_with('{')
+        .then(|| serde_json::from_str(raw.get()).ok())
+        .flatten()
+}
+
+/// A value on one line, for comparisons and conflict reports.
+fn compact(raw: &RawValue) -> String {
+    serde_json::from_str::<Value>(raw.get())
+        .map(|value| value.to_string())
+        .unwrap_or_else(|_| raw.get().to_string())
+}
+
+/// The indentation of the first indented line, two spaces by default.
+fn detect_indent(contents: &str) -> String {
+    contents
+        .lines()
+        .skip(1)
+        .map(|line| {
+            line.chars()
+                .take_while(|ch| *ch == ' ' || *ch == '\t')
+                .collect::<String>()
+        })
+        .find(|indent| !indent.is_empty())
+        .unwrap_or_else(|| "  ".to_string())
+}
@@ -0,0 +1,142 @@
+use super::*;
+use tempfile::TempDir;
+
+fn write(temp: &TempDir, name: &str, contents: &str) -> std::path::PathBuf {
+    let path = temp.path().join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_vex_toml_merge_adds_missing_commands_and_keeps_comments() {
+    let temp = TempDir::new().unwrap();
+    let path = write(
+        &temp,
+        ".vex.toml",
+        "# project tasks\n[commands]\nrun = \"make run\" # local\n\n[network]\nconnect_timeout_secs = 5\n",
+    );
+
+    let outcome = merge_vex_toml_file(
+        &path,
+        "[commands]\nrun = \"cargo run\"\ntest = \"cargo test\"\n\n[env]\nRUST_LOG = \"info\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.contents.as_deref(),
+        Some(
+            "# project tasks\n[commands]\nrun = \"make run\" # local\ntest = \"cargo test\"\n\n[network]\nconnect_timeout_secs = 5\n\n[env]\nRUST_LOG = \"info\"\n"
+        )
+    );
+    assert_eq!(
+        outcome.conflicts,
+        vec![KeyConflict {
+            key: "commands.run".to_string(),
+            existing: "\"make run\"".to_string(),
+            template: "\"cargo run\"".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_vex_toml_merge_reports_conflicts_without_changes() {
+    let temp = TempDir::new().unwrap();
+    let path = write(&temp, ".vex.toml", "[commands]\ntest = \"just test\"\n");
+
+    let outcome = merge_vex_toml_file(&path, "[commands]\ntest = \"cargo test\"\n").unwrap();
+    assert!(outcome.contents.is_none());
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert_eq!(outcome.conflicts[0].key, "commands.test");
+}
+
+#[test]
+fn test_cargo_toml_merge_adds_dependencies_inline() {
+    let temp = TempDir::new().unwrap();
+    let path = write(
+        &temp,
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\n\n[dependencies.tokio]\nversion = \"1\"\n",
+    );
+
+    let outcome = merge_cargo_toml_file(
+        &path,
+        "[dependencies]\nserde = \"1.0.200\"\nclap = { version = \"4\", features = [\"derive\"] }\ntokio = { version = \"1\" }\n\n[dev-dependencies]\ntempfile = \"3\"\n",
+    )
+    .unwrap();
+
+    let merged = outcome.contents.unwrap();
+    let parsed = merged.parse::<toml::Table>().unwrap();
+    assert_eq!(parsed["dependencies"]["serde"].as_str(), Some("1"));
+    assert_eq!(
+        parsed["dependencies"]["clap"]["features"][0].as_str(),
+        Some("derive")
+    );
+    assert_eq!(parsed["dev-dependencies"]["tempfile"].as_str(), Some("3"));
+    assert!(merged.contains("[dependencies]\nserde = \"1\"\nclap = "));
+    assert_eq!(
+        outcome
+            .conflicts
+            .iter()
+            .map(|conflict| conflict.key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["dependencies.serde"]
+    );
+}
+
+#[test]
+fn test_package_json_merge_keeps_order_and_indent() {
+    let temp = TempDir::new().unwrap();
+    let path = write(
+        &temp,
+        "package.json",
+        "{\n    \"name\": \"web\",\n    \"scripts\": {\n        \"build\": \"vite build\"\n    },\n    \"dependencies\": {\n        \"react\": \"^19.0.0\"\n    }\n}\n",
+    );
+
+    let outcome = merge_package_json_file(
+        &path,
+        r#"{"name": "template", "scripts": {"build": "tsc", "test": "node --test"}, "devDependencies": {"typescript": "^5.8.0"}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.contents.as_deref(),
+        Some(
+            "{\n    \"name\": \"web\",\n    \"scripts\": {\n        \"build\": \"vite build\",\n        \"test\": \"node --test\"\n    },\n    \"dependencies\": {\n        \"react\": \"^19.0.0\"\n    },\n    \"devDependencies\": {\n        \"typescript\": \"^5.8.0\"\n    }\n}\n"
+        )
+    );
+    assert_eq!(
+        outcome.conflicts,
+        vec![KeyConflict {
+            key: "scripts.build".to_string(),
+            existing: "\"vite build\"".to_string(),
+            template: "\"tsc\"".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_package_json_merge_copies_untouched_values_as_written() {
+    let temp = TempDir::new().unwrap();
+    let contents = "{\n  \"name\": \"web\",\n  \"exports\": {\n    \"./server\": \"./server.js\",\n    \".\": \"./index.js\"\n  },\n  \"files\": [\"dist\", \"bin\"]\n}\n";
+    let path = write(&temp, "package.json", contents);
+
+    let outcome =
+        merge_package_json_file(&path, r#"{"scripts": {"test": "node --test"}}"#).unwrap();
+
+    assert_eq!(
+        outcome.contents.as_deref(),
+        Some("{\n  \"name\": \"web\",\n  \"exports\": {\n    \"./server\": \"./server.js\",\n    \".\": \"./index.js\"\n  },\n  \"files\": [\"dist\", \"bin\"],\n  \"scripts\": {\n    \"test\": \"node --test\"\n  }\n}\n")
+    );
+}
+
+#[test]
+fn test_structural_merges_reject_unparseable_files() {
+    let temp = TempDir::new().unwrap();
+    let path = write(&temp, "package.json", "{ not json");
+    let err = merge_package_json_file(&path, "{}").unwrap_err();
+    assert!(err.to_string().contains("not valid JSON"));
+
+    let path = write(&temp, "Cargo.toml", "[package\n");
+    let err = merge_cargo_toml_file(&path, "[dependencies]\n").unwrap_err();
+    assert!(err.to_string().contains("not valid TOML"));
+}
@@ -0,0 +1,171 @@
+use super::{KeyConflict, MergeOutcome};
+use crate::error::{Result, VexError};
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Tables of `.vex.toml` that a template may add entries to.
+const VEX_TOML_TABLES: &[&str] = &["tools", "commands", "env"];
+
+/// Tables of `Cargo.toml` that a template may add dependencies to.
+const CARGO_TOML_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+pub(in crate::templates::write) fn merge_vex_toml_file(
+    path: &Path,
+    template_contents: &str,
+) -> Result<MergeOutcome> {
+    merge_toml_tables(path, template_contents, VEX_TOML_TABLES)
+}
+
+pub(in crate::templates::write) fn merge_cargo_toml_file(
+    path: &Path,
+    template_contents: &str,
+) -> Result<MergeOutcome> {
+    merge_toml_tables(path, template_contents, CARGO_TOML_TABLES)
+}
+
+/// Add the template's missing entries of `tables` to an existing TOML file.
+///
+/// New lines are inserted at the end of each table's section, so comments and layout of the
+/// existing file are kept. Entries whose values differ are left alone and reported.
+fn merge_toml_tables(
+    path: &Path,
+    template_contents: &str,
+    tables: &[&str],
+) -> Result<MergeOutcome> {
+    let existing_contents = fs::read_to_string(path)?;
+    let existing = parse(&existing_contents, path)?;
+    let template = parse(template_contents, path)?;
+
+    let mut merged = existing_contents.clone();
+    let mut conflicts = Vec::new();
+    for table_name in tables {
+        let Some(template_table) = template.get(*table_name).and_then(Value::as_table) else {
+            continue;
+        };
+
+        let existing_table = match existing.get(*table_name) {
+            None => None,
+            Some(Value::Table(table)) => Some(table),
+            Some(other) => {
+                conflicts.push(KeyConflict {
+                    key: table_name.to_string(),
+                    existing: other.to_string(),
+                    template: Value::Table(template_table.clone()).to_string(),
+                });
+                continue;
+            }
+        };
+
+        let mut additions = Vec::new();
+        for (key, value) in template_table {
+            match existing_table.and_then(|table| table.get(key)) {
+                Some(current) if current != value => conflicts.push(KeyConflict {
+                    key: format!("{}.{}", table_name, key),
+                    existing: current.to_string(),
+                    template: value.to_string(),
+                }),
+                Some(_) => {}
+                None => additions.push(format!("{} = {}", format_key(key), value)),
+            }
+        }
+
+        if !additions.is_empty() {
+            merged = insert_into_section(&merged, table_name, &additions);
+        }
+    }
+
+    if merged == existing_contents {
+        return Ok(MergeOutcome {
+            contents: None,
+            conflicts,
+        });
+    }
+
+    parse(&merged, path).map_err(|err| {
+        VexError::Config(format!(
+            "Merging the template into {} would produce invalid TOML ({}). No files were written.",
+            path.display(),
+            err
+        ))
+    })?;
+    Ok(MergeOutcome {
+        contents: Some(merged),
+        conflicts,
+    })
+}
+
+fn parse(contents: &str, path: &Path) -> Result<Table> {
+    contents.parse::<Table>().map_err(|err| {
+        VexError::Config(format!(
+            "Cannot merge {} because it is not valid TOML: {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+fn format_key(key: &str) -> String {
+    let is_bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if is_bare {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Insert `lines` after the last entry of the `[table]` section, or append a new section.
+fn insert_into_section(contents: &str, table: &str, lines: &[String]) -> String {
+    let source_lines = contents.lines().collect::<Vec<_>>();
+    let header = source_lines.iter().position(|line| is_header(line, table));
+
+    let Some(header) = header else {
+        let mut merged = contents.to_string();
+        if !merged.is_empty() && !merged.ends_with('\n') {
+            merged.push('\n');
+        }
+        if !merged.trim().is_empty() {
+            merged.push('\n');
+        }
+        merged.push_str(&format!("[{}]\n", table));
+        for line in lines {
+            merged.push_str(line);
+            merged.push('\n');
+        }
+        return merged;
+    };
+
+    let section_end = source_lines[header + 1..]
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .map(|offset| header + 1 + offset)
+        .unwrap_or(source_lines.len());
+    let mut insert_at = section_end;
+    while insert_at > header + 1 && source_lines[insert_at - 1].trim().is_empty() {
+        insert_at -= 1;
+    }
+
+    let mut merged = source_lines[..insert_at]
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    merged.extend(lines.iter().cloned());
+    merged.extend(
+        source_lines[insert_at..]
+            .iter()
+            .map(|line| line.to_string()),
+    );
+    let mut merged = merged.join("\n");
+    merged.push('\n');
+    merged
+}
+
+fn is_header(line: &str, table: &str) -> bool {
+    let line = line.split('#').next().unwrap_or_default().trim();
+    line.strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .is_some_and(|name| !name.starts_with('[') && name.trim() == table)
+}
@@ -1,18 +1,31 @@
-use super::merge::{merge_gitignore_file, merge_tool_versions_file};
+use super::merge::{
+    merge_cargo_toml_file, merge_gitignore_file, merge_package_json_file, merge_tool_versions_file,
+    merge_vex_toml_file, MergeOutcome,
+};
 use crate::error::{Result, VexError};
-use crate::templates::{ConflictMode, MergeStrategy, PlannedWrite, PlannedWriteKind, TemplateFile};
+use crate::templates::{
+    ConflictMode, MergeConflict, MergeStrategy, PlannedWrite, PlannedWriteKind, TemplateFile,
+};
 use std::path::Path;
 
+/// Files to write, plus keys that add-only merges left at their existing values.
+#[derive(Debug, Default)]
+pub(in crate::templates) struct WritePlan {
+    pub(in crate::templates) writes: Vec<PlannedWrite>,
+    pub(in crate::templates) merge_conflicts: Vec<MergeConflict>,
+}
+
 pub(in crate::templates) fn build_write_plan(
     cwd: &Path,
     files: &[TemplateFile],
     conflict_mode: ConflictMode,
-) -> Result<Vec<PlannedWrite>> {
+) -> Result<WritePlan> {
     let mut writes = Vec::new();
+    let mut merge_conflicts = Vec::new();
     let mut conflicts = Vec::new();
 
     for file in files {
-        let path = cwd.join(file.path);
+        let path = cwd.join(&file.path);
         if !path.exists() {
             writes.push(PlannedWrite {
                 path,
@@ -30,10 +43,27 @@ pub(in crate::templates) fn build_write_plan(
                     continue;
                 };
                 let merged = match strategy {
-                    MergeStrategy::ToolVersions => merge_tool_versions_file(&path, &file.contents)?,
-                    MergeStrategy::GitIgnore => merge_gitignore_file(&path, &file.contents)?,
+                    MergeStrategy::ToolVersions => MergeOutcome {
+                        contents: merge_tool_versions_file(&path, &file.contents)?,
+                        conflicts: Vec::new(),
+                    },
+                    MergeStrategy::GitIgnore => MergeOutcome {
+                        contents: merge_gitignore_file(&path, &file.contents)?,
+                        conflicts: Vec::new(),
+                    },
+                    MergeStrategy::VexToml => merge_vex_toml_file(&path, &file.contents)?,
+                    MergeStrategy::PackageJson => merge_package_json_file(&path, &file.contents)?,
+                    MergeStrategy::CargoToml => merge_cargo_toml_file(&path, &file.contents)?,
                 };
-                if let Some(contents) = merged {
+                merge_conflicts.extend(merged.conflicts.into_iter().map(|conflict| {
+                    MergeConflict {
+                        path: file.path.clone(),
+                        key: conflict.key,
+                        existing: conflict.existing,
+                        template: conflict.template,
+                    }
+                }));
+                if let Some(contents) = merged.contents {
                     writes.push(PlannedWrite {
                         path,
                         contents,
@@ -45,7 +75,10 @@ pub(in crate::templates) fn build_write_plan(
     }
 
     if conflicts.is_empty() {
-        return Ok(writes);
+        return Ok(WritePlan {
+            writes,
+            merge_conflicts,
+        });
     }
 
     let mut message =
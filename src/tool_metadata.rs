@@ -1,5 +1,6 @@
 use crate::error::Result;
 use crate::tools::Tool;
+use crate::versioning::version_sort_key;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -26,6 +27,8 @@ pub struct ProvenanceMetadata {
     pub mirror_url: Option<String>,
     pub checksum: Option<String>,
     pub installed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imported_from: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -65,6 +68,38 @@ pub fn read_metadata(install_dir: &Path) -> Result<Option<ToolchainMetadata>> {
     Ok(Some(metadata))
 }
 
+/// Every `(tool, version)` under `~/.vex/toolchains`, sorted by tool then version.
+///
+/// Hidden entries are skipped; they are staging directories such as `.<version>.importing`
+/// left behind by an interrupted install or import.
+pub fn installed_toolchains(vex_dir: &Path) -> Result<Vec<(String, String)>> {
+    let toolchains_dir = vex_dir.join("toolchains");
+    if !toolchains_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let visible_dirs = |dir: &Path| -> Result<Vec<String>> {
+        Ok(fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| !name.starts_with('.'))
+            .collect())
+    };
+    let mut installed = Vec::new();
+    for tool in visible_dirs(&toolchains_dir)? {
+        for version in visible_dirs(&toolchains_dir.join(&tool))? {
+            installed.push((tool.clone(), version));
+        }
+    }
+    installed.sort_by(|left, right| {
+        left.0
+            .cmp(&right.0)
+            .then_with(|| version_sort_key(&left.1).cmp(&version_sort_key(&right.1)))
+    });
+    Ok(installed)
+}
+
 pub fn write_metadata(install_dir: &Path, metadata: &ToolchainMetadata) -> Result<()> {
     let path = metadata_path(install_dir);
     let json = serde_json::to_string_pretty(metadata)
@@ -91,6 +126,7 @@ pub fn write_base_metadata(
             mirror_url: mirror_url.map(ToString::to_string),
             checksum: checksum.map(ToString::to_string),
             installed_at: Utc::now().to_rfc3339(),
+            imported_from: None,
         },
         ownership: OwnershipMetadata {
             vex_owned: vec![install_dir.display().to_string()],
@@ -0,0 +1,242 @@
+//! Per-file content manifest for installed toolchains
+//!
+//! Written to `.vex-manifest.json` next to `.vex-metadata.json` at install time, recording the
+//! size, SHA-256, and executable bit of every file plus the target of every symlink. `vex verify`
+//! compares a toolchain against it to find modified, missing, and extra files.
+
+use crate::checksum;
+use crate::error::{Result, VexError};
+use crate::tool_metadata::METADATA_FILE_NAME;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+#[cfg(test)]
+mod tests;
+
+pub const MANIFEST_FILE_NAME: &str = ".vex-manifest.json";
+const MANIFEST_VERSION: u32 = 1;
+
+/// Bookkeeping files vex writes into the toolchain itself.
+const VEX_FILES: &[&str] = &[MANIFEST_FILE_NAME, METADATA_FILE_NAME, ".vex-checksum"];
+/// Files the toolchain's own tools create at runtime; they never indicate tampering.
+const RUNTIME_NAMES: &[&str] = &["__pycache__", ".DS_Store"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolchainManifest {
+    pub version: u32,
+    /// Entries by path relative to the toolchain root, using `/` separators.
+    pub files: BTreeMap<String, ManifestEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ManifestEntry {
+    File {
+        size: u64,
+        sha256: String,
+        #[serde(default)]
+        executable: bool,
+    },
+    Symlink {
+        target: String,
+    },
+}
+
+/// How closely [`compare`] checks files that are still present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckMode {
+    /// Re-hash every file.
+    Full,
+    /// Compare sizes, permissions, and symlink targets only.
+    Quick,
+}
+
+/// Differences between a toolchain directory and its manifest, as sorted relative paths.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IntegrityDiff {
+    pub modified: Vec<String>,
+    pub missing: Vec<String>,
+    pub extra: Vec<String>,
+}
+
+impl IntegrityDiff {
+    pub fn is_clean(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// What is on disk, before any hashing.
+enum Scanned {
+    File {
+        path: PathBuf,
+        size: u64,
+        executable: bool,
+    },
+    Symlink {
+        target: String,
+    },
+}
+
+pub fn manifest_path(install_dir: &Path) -> PathBuf {
+    install_dir.join(MANIFEST_FILE_NAME)
+}
+
+pub fn read_manifest(install_dir: &Path) -> Result<Option<ToolchainManifest>> {
+    let path = manifest_path(install_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path)?;
+    let manifest = serde_json::from_str(&content)
+        .map_err(|err| VexError::Parse(format!("Invalid toolchain manifest: {}", err)))?;
+    Ok(Some(manifest))
+}
+
+/// Record the current contents of `install_dir` as its manifest.
+pub fn write_manifest(install_dir: &Path) -> Result<()> {
+    let manifest = build(install_dir)?;
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|err| VexError::Parse(format!("Invalid toolchain manifest: {}", err)))?;
+    fs::write(manifest_path(install_dir), json)?;
+    Ok(())
+}
+
+pub fn build(install_dir: &Path) -> Result<ToolchainManifest> {
+    let files = scan(install_dir)?
+        .into_par_iter()
+        .map(|(relative, scanned)| {
+            let entry = match scanned {
+                Scanned::File {
+                    path,
+                    size,
+                    executable,
+                } => ManifestEntry::File {
+                    size,
+                    sha256: checksum::sha256_hex(&path)?,
+                    executable,
+                },
+                Scanned::Symlink { target } => ManifestEntry::Symlink { target },
+            };
+            Ok((relative, entry))
+        })
+        .collect::<Result<BTreeMap<_, _>>>()?;
+
+    Ok(ToolchainManifest {
+        version: MANIFEST_VERSION,
+        files,
+    })
+}
+
+/// Compare `install_dir` with `manifest`.
+pub fn compare(
+    install_dir: &Path,
+    manifest: &ToolchainManifest,
+    mode: CheckMode,
+) -> Result<IntegrityDiff> {
+    let mut on_disk = scan(install_dir)?;
+    let mut diff = IntegrityDiff::default();
+    let mut to_hash = Vec::new();
+
+    for (relative, expected) in &manifest.files {
+        let Some(actual) = on_disk.remove(relative) else {
+            diff.missing.push(relative.clone());
+            continue;
+        };
+        match (expected, actual) {
+            (
+                ManifestEntry::File {
+                    size,
+                    sha256,
+                    executable,
+                },
+                Scanned::File {
+                    path,
+                    size: actual_size,
+                    executable: actual_executable,
+                },
+            ) if *size == actual_size && *executable == actual_executable => {
+                if mode == CheckMode::Full {
+                    to_hash.push((relative, path, sha256));
+                }
+            }
+            (ManifestEntry::Symlink { target }, Scanned::Symlink { target: actual })
+                if *target == actual => {}
+            _ => diff.modified.push(relative.clone()),
+        }
+    }
+
+    let changed = to_hash
+        .into_par_iter()
+        .map(|(relative, path, expected)| {
+            let actual = checksum::sha256_hex(&path)?;
+            Ok((!actual.eq_ignore_ascii_case(expected)).then(|| relative.clone()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    diff.modified.extend(changed.into_iter().flatten());
+    diff.modified.sort();
+    diff.extra = on_disk.into_keys().collect();
+
+    Ok(diff)
+}
+
+/// Every file and symlink under `install_dir`, minus vex's own files and runtime caches.
+fn scan(install_dir: &Path) -> Result<BTreeMap<String, Scanned>> {
+    let mut entries = BTreeMap::new();
+    let mut pending = vec![install_dir.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if RUNTIME_NAMES.contains(&name.as_str())
+                || (dir == install_dir && VEX_FILES.contains(&name.as_str()))
+            {
+                continue;
+            }
+
+            let metadata = fs::symlink_metadata(&path)?;
+            let relative = relative_path(install_dir, &path);
+            if metadata.file_type().is_symlink() {
+                let target = fs::read_link(&path)?.to_string_lossy().to_string();
+                entries.insert(relative, Scanned::Symlink { target });
+            } else if metadata.is_dir() {
+                pending.push(path);
+            } else {
+                entries.insert(
+                    relative,
+                    Scanned::File {
+                        path,
+                        size: metadata.len(),
+                        executable: is_executable(&metadata),
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(unix)]
+fn is_executable(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &fs::Metadata) -> bool {
+    false
+}
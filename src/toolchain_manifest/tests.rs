@@ -0,0 +1,89 @@
+use super::*;
+use tempfile::TempDir;
+
+fn toolchain() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    fs::create_dir_all(root.join("bin")).unwrap();
+    fs::create_dir_all(root.join("lib/node_modules/npm")).unwrap();
+    fs::write(root.join("bin/node"), "#!/bin/sh\necho node\n").unwrap();
+    fs::write(root.join("lib/node_modules/npm/package.json"), "{}").unwrap();
+    fs::write(root.join(METADATA_FILE_NAME), "{}").unwrap();
+    fs::write(root.join(".vex-checksum"), "abc").unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(root.join("bin/node"), fs::Permissions::from_mode(0o755)).unwrap();
+        std::os::unix::fs::symlink("../lib/node_modules/npm", root.join("bin/npm")).unwrap();
+    }
+    temp
+}
+
+#[test]
+fn test_build_records_files_and_skips_vex_bookkeeping() {
+    let temp = toolchain();
+    write_manifest(temp.path()).unwrap();
+    let manifest = read_manifest(temp.path()).unwrap().unwrap();
+
+    assert_eq!(manifest.version, MANIFEST_VERSION);
+    assert!(!manifest.files.contains_key(METADATA_FILE_NAME));
+    assert!(!manifest.files.contains_key(".vex-checksum"));
+    assert!(!manifest.files.contains_key(MANIFEST_FILE_NAME));
+    assert_eq!(
+        manifest.files["lib/node_modules/npm/package.json"],
+        ManifestEntry::File {
+            size: 2,
+            sha256: checksum::sha256_hex_bytes(b"{}"),
+            executable: false,
+        }
+    );
+    #[cfg(unix)]
+    {
+        assert!(matches!(
+            manifest.files["bin/node"],
+            ManifestEntry::File {
+                executable: true,
+                ..
+            }
+        ));
+        assert_eq!(
+            manifest.files["bin/npm"],
+            ManifestEntry::Symlink {
+                target: "../lib/node_modules/npm".to_string()
+            }
+        );
+    }
+}
+
+#[test]
+fn test_compare_reports_modified_missing_and_extra_files() {
+    let temp = toolchain();
+    let root = temp.path();
+    let manifest = build(root).unwrap();
+    assert!(compare(root, &manifest, CheckMode::Full)
+        .unwrap()
+        .is_clean());
+
+    // Same size, different content: only a full check can tell.
+    fs::write(root.join("bin/node"), "#!/bin/sh\necho evil\n").unwrap();
+    fs::remove_file(root.join("lib/node_modules/npm/package.json")).unwrap();
+    fs::create_dir_all(root.join("lib/node_modules/left-pad")).unwrap();
+    fs::write(root.join("lib/node_modules/left-pad/index.js"), "").unwrap();
+    fs::create_dir_all(root.join("lib/__pycache__")).unwrap();
+    fs::write(root.join("lib/__pycache__/cached.pyc"), "").unwrap();
+    fs::write(root.join(METADATA_FILE_NAME), "{\"changed\": true}").unwrap();
+
+    let quick = compare(root, &manifest, CheckMode::Quick).unwrap();
+    assert!(quick.modified.is_empty());
+
+    let full = compare(root, &manifest, CheckMode::Full).unwrap();
+    assert_eq!(full.modified, vec!["bin/node".to_string()]);
+    assert_eq!(
+        full.missing,
+        vec!["lib/node_modules/npm/package.json".to_string()]
+    );
+    assert_eq!(
+        full.extra,
+        vec!["lib/node_modules/left-pad/index.js".to_string()]
+    );
+}
@@ -0,0 +1,150 @@
+//! Per-toolchain "last used" timestamps
+//!
+//! Recorded in `~/.vex/toolchain-usage.json` whenever a toolchain is switched to, activated by the
+//! shell hook, or used by `vex exec`/`vex run`, so prune retention rules can tell stale versions
+//! from ones still in use.
+
+use crate::error::{Result, VexError};
+use crate::paths::vex_dir;
+use crate::tool_metadata;
+use chrono::{DateTime, Utc};
+use fs2::FileExt;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+pub const USAGE_FILE: &str = "toolchain-usage.json";
+
+type UsageMap = BTreeMap<String, BTreeMap<String, String>>;
+
+pub fn usage_path(vex_dir: &Path) -> PathBuf {
+    vex_dir.join(USAGE_FILE)
+}
+
+/// Record that the given toolchains were used now.
+///
+/// Timestamps have day resolution for writes: the file is only rewritten when a toolchain was
+/// not yet recorded today, so prompt-time activation stays cheap. Updates hold a lock so
+/// concurrent shells do not drop each other's timestamps.
+pub fn record<'a, I>(vex_dir: &Path, versions: I) -> Result<()>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let now = Utc::now().to_rfc3339();
+    let today = &now[..10];
+    let versions = versions.into_iter().collect::<Vec<_>>();
+
+    let recorded_today = |usage: &UsageMap| {
+        versions.iter().all(|(tool, version)| {
+            usage
+                .get(*tool)
+                .and_then(|versions| versions.get(*version))
+                .is_some_and(|timestamp| timestamp.starts_with(today))
+        })
+    };
+    if recorded_today(&read_usage(vex_dir)) {
+        return Ok(());
+    }
+
+    let _lock = lock_usage(vex_dir)?;
+    let mut usage = read_usage(vex_dir);
+    if recorded_today(&usage) {
+        return Ok(());
+    }
+    for (tool, version) in &versions {
+        let entry = usage
+            .entry(tool.to_string())
+            .or_default()
+            .entry(version.to_string())
+            .or_default();
+        if !entry.starts_with(today) {
+            *entry = now.clone();
+        }
+    }
+    write_usage(vex_dir, &usage)
+}
+
+/// Best-effort variant of [`record`] for command paths that must not fail on bookkeeping.
+pub fn record_quietly<'a, I>(versions: I)
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    if let Err(err) = vex_dir().and_then(|vex| record(&vex, versions)) {
+        tracing::debug!("Could not record toolchain usage: {}", err);
+    }
+}
+
+/// Recorded last-use times keyed by `(tool, version)`.
+pub fn load(vex_dir: &Path) -> HashMap<(String, String), DateTime<Utc>> {
+    read_usage(vex_dir)
+        .into_iter()
+        .flat_map(|(tool, versions)| {
+            versions
+                .into_iter()
+                .filter_map(move |(version, timestamp)| {
+                    parse_timestamp(&timestamp).map(|time| ((tool.clone(), version), time))
+                })
+        })
+        .collect()
+}
+
+/// When a toolchain was last used, falling back to its install time and then to the install
+/// directory's modification time for versions that were never activated.
+pub fn last_used(
+    usage: &HashMap<(String, String), DateTime<Utc>>,
+    tool: &str,
+    version: &str,
+    install_dir: &Path,
+) -> Option<DateTime<Utc>> {
+    let installed_at = tool_metadata::read_metadata(install_dir)
+        .ok()
+        .flatten()
+        .and_then(|metadata| parse_timestamp(&metadata.provenance.installed_at))
+        .or_else(|| {
+            fs::metadata(install_dir)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .map(DateTime::<Utc>::from)
+        });
+    let recorded = usage.get(&(tool.to_string(), version.to_string())).copied();
+
+    recorded.max(installed_at)
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn lock_usage(vex_dir: &Path) -> Result<File> {
+    fs::create_dir_all(vex_dir)?;
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(vex_dir.join(format!("{}.lock", USAGE_FILE)))?;
+    file.lock_exclusive()?;
+    Ok(file)
+}
+
+fn read_usage(vex_dir: &Path) -> UsageMap {
+    fs::read_to_string(usage_path(vex_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_usage(vex_dir: &Path, usage: &UsageMap) -> Result<()> {
+    fs::create_dir_all(vex_dir)?;
+    let json = serde_json::to_string_pretty(usage)
+        .map_err(|err| VexError::Parse(format!("Invalid toolchain usage data: {}", err)))?;
+    // Readers do not take the lock, so they must never see a partially written file.
+    let tmp = vex_dir.join(format!("{}.{}.tmp", USAGE_FILE, std::process::id()));
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, usage_path(vex_dir))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_record_skips_versions_already_recorded_today() {
+    let temp = TempDir::new().unwrap();
+    record(temp.path(), [("node", "20.11.0")]).unwrap();
+    let first = fs::read_to_string(usage_path(temp.path())).unwrap();
+
+    record(temp.path(), [("node", "20.11.0")]).unwrap();
+    assert_eq!(fs::read_to_string(usage_path(temp.path())).unwrap(), first);
+}
+
+#[test]
+fn test_concurrent_records_keep_every_version() {
+    let temp = TempDir::new().unwrap();
+    let versions = (0..8)
+        .map(|minor| format!("20.{}.0", minor))
+        .collect::<Vec<_>>();
+
+    std::thread::scope(|scope| {
+        for version in &versions {
+            let vex = temp.path();
+            scope.spawn(move || record(vex, [("node", version.as_str())]).unwrap());
+        }
+    });
+
+    let usage = load(temp.path());
+    for version in &versions {
+        assert!(
+            usage.contains_key(&("node".to_string(), version.clone())),
+            "{} was lost",
+            version
+        );
+    }
+}
@@ -122,6 +122,9 @@ pub trait Tool: Send + Sync {
     }
 }
 
+/// Tool names accepted by `get_tool` and by every command.
+pub const SUPPORTED_TOOLS: &[&str] = &["node", "go", "java", "python", "rust"];
+
 /// Get tool implementation by name, supports node, go, java, rust
 pub fn get_tool(name: &str) -> Result<Box<dyn Tool>> {
     match name {
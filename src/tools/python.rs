@@ -39,6 +39,11 @@ pub fn user_bin_dir(vex_dir: &std::path::Path) -> std::path::PathBuf {
     user_base_dir(vex_dir).join("bin")
 }
 
+/// Branch statuses (`feature`, `bugfix`, `security`, `end-of-life`) from the Python devguide.
+pub(crate) fn fetch_lifecycle_statuses() -> Result<BTreeMap<String, String>> {
+    fetch_python_lifecycle_statuses()
+}
+
 /// Python tool (python-build-standalone prebuilt CPython)
 pub struct PythonTool;
 
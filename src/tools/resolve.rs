@@ -3,6 +3,7 @@ mod suggest;
 
 use super::{Tool, Version};
 use crate::error::Result;
+use crate::output;
 use crate::paths::vex_dir;
 use owo_colors::OwoColorize;
 
@@ -25,10 +26,12 @@ pub(super) fn resolve_fuzzy_version_cached(
     let dot_count = normalized.chars().filter(|c| *c == '.').count();
 
     if tool.name() == "java" || dot_count >= 2 {
-        println!(
-            "{}...",
-            format!("Validating {}@{}", tool.name(), partial).cyan()
-        );
+        if !output::is_quiet() {
+            println!(
+                "{}...",
+                format!("Validating {}@{}", tool.name(), partial).cyan()
+            );
+        }
         let versions = fetch_versions_with_cache(tool, use_cache)?;
         let exists = versions
             .iter()
@@ -57,10 +60,12 @@ pub(super) fn resolve_fuzzy_version_cached(
         return Ok(resolved);
     }
 
-    println!(
-        "{}...",
-        format!("Resolving {}@{}", tool.name(), partial).cyan()
-    );
+    if !output::is_quiet() {
+        println!(
+            "{}...",
+            format!("Resolving {}@{}", tool.name(), partial).cyan()
+        );
+    }
     let versions = fetch_versions_with_cache(tool, use_cache)?;
     let prefix = format!("{}.", normalized);
 
@@ -16,5 +16,6 @@ pub(super) fn fetch_versions_with_cache(tool: &dyn Tool, use_cache: bool) -> Res
 
     let versions = tool.list_remote()?;
     remote_cache.set_cached_versions(tool.name(), &versions);
+    crate::advisories::refresh_lifecycle(tool.name(), &versions);
     Ok(versions)
 }
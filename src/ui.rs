@@ -20,6 +20,6 @@ mod table;
 mod tests;
 
 pub use primitives::{dimmed, error, header, info, success, warning, UiContext};
-pub use progress::Progress;
+pub use progress::{attach_bar, MultiProgressScope, Progress};
 pub use summary::Summary;
 pub use table::Table;
@@ -1,3 +1,5 @@
+use super::progress::print_line;
+use crate::output;
 use owo_colors::OwoColorize;
 use std::io::{self, IsTerminal};
 
@@ -12,7 +14,9 @@ impl UiContext {
     /// Create a new UI context
     pub fn new() -> Self {
         Self {
-            interactive: io::stdout().is_terminal() && !crate::logging::diagnostics_enabled(),
+            interactive: io::stdout().is_terminal()
+                && !crate::logging::diagnostics_enabled()
+                && !output::is_quiet(),
         }
     }
 
@@ -30,27 +34,45 @@ impl Default for UiContext {
 }
 
 pub fn header(text: &str) {
-    println!();
-    println!("{}", text.bold());
-    println!();
+    if output::is_quiet() {
+        return;
+    }
+    print_line("");
+    print_line(text.bold());
+    print_line("");
 }
 
 pub fn success(text: &str) {
-    println!("{} {}", "✓".green(), text);
+    if output::is_quiet() {
+        return;
+    }
+    print_line(format!("{} {}", "✓".green(), text));
 }
 
 pub fn warning(text: &str) {
-    println!("{} {}", "⚠".yellow(), text);
+    if output::is_quiet() {
+        return;
+    }
+    print_line(format!("{} {}", "⚠".yellow(), text));
 }
 
 pub fn error(text: &str) {
-    println!("{} {}", "✗".red(), text);
+    if output::is_quiet() {
+        return;
+    }
+    print_line(format!("{} {}", "✗".red(), text));
 }
 
 pub fn info(text: &str) {
-    println!("{} {}", "→".cyan(), text);
+    if output::is_quiet() {
+        return;
+    }
+    print_line(format!("{} {}", "→".cyan(), text));
 }
 
 pub fn dimmed(text: &str) {
-    println!("{}", text.dimmed());
+    if output::is_quiet() {
+        return;
+    }
+    print_line(text.dimmed());
 }
@@ -1,8 +1,65 @@
 use super::primitives::{success, UiContext};
-use indicatif::{ProgressBar as IndicatifProgressBar, ProgressStyle};
+use crate::output::{self, Event};
+use indicatif::{MultiProgress, ProgressBar as IndicatifProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
+use std::fmt::Display;
+use std::sync::Mutex;
 use std::time::Duration;
 
+/// Shared display for bars created while a [`MultiProgressScope`] is alive.
+static MULTI: Mutex<Option<MultiProgress>> = Mutex::new(None);
+
+/// Stacks every spinner and download bar created while it is alive into one display, so
+/// concurrent installs each keep their own line instead of overwriting each other.
+pub struct MultiProgressScope {
+    active: bool,
+}
+
+impl MultiProgressScope {
+    /// Start a shared display; does nothing for non-interactive output.
+    pub fn start(ctx: &UiContext) -> Self {
+        if !ctx.interactive {
+            return Self { active: false };
+        }
+        *lock_multi() = Some(MultiProgress::new());
+        Self { active: true }
+    }
+}
+
+impl Drop for MultiProgressScope {
+    fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
+        if let Some(multi) = lock_multi().take() {
+            let _ = multi.clear();
+        }
+    }
+}
+
+/// Add `bar` to the active [`MultiProgressScope`], if any.
+pub fn attach_bar(bar: IndicatifProgressBar) -> IndicatifProgressBar {
+    match lock_multi().as_ref() {
+        Some(multi) => multi.add(bar),
+        None => bar,
+    }
+}
+
+/// Print a line to stdout above any bars of the active [`MultiProgressScope`].
+pub(super) fn print_line(line: impl Display) {
+    let multi = lock_multi().clone();
+    match multi {
+        Some(multi) => multi.suspend(|| println!("{}", line)),
+        None => println!("{}", line),
+    }
+}
+
+fn lock_multi() -> std::sync::MutexGuard<'static, Option<MultiProgress>> {
+    MULTI
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
 /// Progress indicator for long-running operations
 pub struct Progress {
     bar: Option<IndicatifProgressBar>,
@@ -12,8 +69,9 @@ pub struct Progress {
 impl Progress {
     /// Create a new progress indicator with a message
     pub fn new(ctx: &UiContext, message: &str) -> Self {
+        emit_progress(message);
         let bar = if ctx.interactive {
-            let progress_bar = IndicatifProgressBar::new_spinner();
+            let progress_bar = attach_bar(IndicatifProgressBar::new_spinner());
             progress_bar.set_style(
                 ProgressStyle::default_spinner()
                     .template("{spinner:.cyan} {msg}")
@@ -24,7 +82,9 @@ impl Progress {
             progress_bar.enable_steady_tick(Duration::from_millis(80));
             Some(progress_bar)
         } else {
-            println!("{} {}...", "→".cyan(), message);
+            if !output::is_quiet() {
+                println!("{} {}...", "→".cyan(), message);
+            }
             None
         };
 
@@ -36,9 +96,10 @@ impl Progress {
 
     /// Update the progress message
     pub fn set_message(&self, message: &str) {
+        emit_progress(message);
         if let Some(bar) = &self.bar {
             bar.set_message(message.to_string());
-        } else if !self.ctx.interactive {
+        } else if !self.ctx.interactive && !output::is_quiet() {
             println!("{} {}...", "→".cyan(), message);
         }
     }
@@ -51,3 +112,9 @@ impl Progress {
         success(message);
     }
 }
+
+fn emit_progress(message: &str) {
+    output::emit(Event::Progress {
+        message: output::strip_ansi(message),
+    });
+}
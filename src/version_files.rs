@@ -1,9 +1,12 @@
 use crate::error::Result;
+use crate::output::{self, Event};
 use std::fs;
 use std::path::Path;
 
 enum VersionFileFormat {
     ToolVersions,
+    /// The `[tools]` table of `.vex.toml`
+    ProjectConfig,
     SingleValue,
 }
 
@@ -12,6 +15,9 @@ pub fn write_tool_version(file_path: &Path, tool_name: &str, version: &str) -> R
         VersionFileFormat::ToolVersions => {
             write_tool_versions_content(file_path, tool_name, version)
         }
+        VersionFileFormat::ProjectConfig => {
+            write_project_tools_content(file_path, tool_name, version)
+        }
         VersionFileFormat::SingleValue => format!("{}\n", version),
     };
 
@@ -19,6 +25,11 @@ pub fn write_tool_version(file_path: &Path, tool_name: &str, version: &str) -> R
         fs::create_dir_all(parent)?;
     }
     fs::write(file_path, content)?;
+    output::emit(Event::VersionFileWritten {
+        path: file_path.display().to_string(),
+        tool: tool_name.to_string(),
+        version: version.to_string(),
+    });
     Ok(())
 }
 
@@ -63,9 +74,53 @@ fn write_tool_versions_content(file_path: &Path, tool_name: &str, version: &str)
     }
 }
 
+/// Set `tool_name` in the `[tools]` table of a `.vex.toml`, editing only that line so the rest
+/// of the file keeps its comments and layout.
+fn write_project_tools_content(file_path: &Path, tool_name: &str, version: &str) -> String {
+    let existing = fs::read_to_string(file_path).unwrap_or_default();
+    let mut lines = existing.lines().map(str::to_string).collect::<Vec<_>>();
+    let entry = format!("{} = \"{}\"", tool_name, version);
+
+    let Some(header) = lines.iter().position(|line| line.trim() == "[tools]") else {
+        if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+            lines.push(String::new());
+        }
+        lines.push("[tools]".to_string());
+        lines.push(entry);
+        return lines.join("\n") + "\n";
+    };
+
+    let section_end = lines[header + 1..]
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .map_or(lines.len(), |offset| header + 1 + offset);
+    let existing_entry = (header + 1..section_end).find(|&index| {
+        lines[index]
+            .split_once('=')
+            .is_some_and(|(key, _)| key.trim().trim_matches('"') == tool_name)
+    });
+
+    match existing_entry {
+        Some(index) => {
+            let leading_len =
+                lines[index].len() - lines[index].trim_start_matches(char::is_whitespace).len();
+            lines[index] = format!("{}{}", &lines[index][..leading_len], entry);
+        }
+        None => {
+            let insert_at = (header + 1..section_end)
+                .rev()
+                .find(|&index| !lines[index].trim().is_empty())
+                .map_or(header + 1, |index| index + 1);
+            lines.insert(insert_at, entry);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
 fn version_file_format(file_path: &Path) -> VersionFileFormat {
     match file_path.file_name().and_then(|name| name.to_str()) {
         Some(".tool-versions" | "tool-versions") => VersionFileFormat::ToolVersions,
+        Some(".vex.toml") => VersionFileFormat::ProjectConfig,
         _ => VersionFileFormat::SingleValue,
     }
 }
@@ -196,6 +251,38 @@ mod tests {
         assert_eq!(content, "node 22.0.0 # lts\n");
     }
 
+    #[test]
+    fn test_write_tool_version_updates_vex_toml_tools_table() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join(".vex.toml");
+
+        fs::write(
+            &file_path,
+            "# project\n[tools]\nnode = \"20\" # lts\n\n[commands]\ntest = \"npm test\"\n",
+        )
+        .unwrap();
+        write_tool_version(&file_path, "node", "22").unwrap();
+        write_tool_version(&file_path, "go", "1.23").unwrap();
+
+        let content = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(
+            content,
+            "# project\n[tools]\nnode = \"22\"\ngo = \"1.23\"\n\n[commands]\ntest = \"npm test\"\n"
+        );
+    }
+
+    #[test]
+    fn test_write_tool_version_adds_vex_toml_tools_table() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join(".vex.toml");
+
+        fs::write(&file_path, "[env]\nA = \"1\"\n").unwrap();
+        write_tool_version(&file_path, "node", "20").unwrap();
+
+        let content = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(content, "[env]\nA = \"1\"\n\n[tools]\nnode = \"20\"\n");
+    }
+
     #[test]
     fn test_write_tool_version_preserves_single_value_file_format() {
         let temp_dir = TempDir::new().unwrap();
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::registry;
 use crate::requested_versions;
 use crate::resolver;
 use std::collections::HashMap;
@@ -54,5 +55,19 @@ pub fn retained_versions(vex_dir: &Path, cwd: &Path) -> Result<HashMap<(String,
             .or_insert_with(|| "current project".to_string());
     }
 
+    for project in registry::load(vex_dir) {
+        if project.is_stale() {
+            continue;
+        }
+        for (tool, version) in resolver::resolve_project_versions(&project.root) {
+            let retained_version =
+                requested_versions::resolve_installed_version(vex_dir, &tool, &version)?
+                    .unwrap_or(version);
+            retained
+                .entry((tool, retained_version))
+                .or_insert_with(|| format!("project {}", project.root.display()));
+        }
+    }
+
     Ok(retained)
 }
@@ -9,3 +9,17 @@ pub(crate) fn version_sort_key(version: &str) -> Vec<u32> {
         .filter_map(|segment| segment.parse::<u32>().ok())
         .collect()
 }
+
+/// The release line a version belongs to: the major version for Node.js, Java and Rust, and
+/// `major.minor` for Python and Go, whose maintained lines are minor releases.
+pub(crate) fn release_line(tool: &str, version: &str) -> String {
+    let segments = match tool {
+        "python" | "go" => 2,
+        _ => 1,
+    };
+    normalize_version(version)
+        .split('.')
+        .take(segments)
+        .collect::<Vec<_>>()
+        .join(".")
+}
@@ -0,0 +1,66 @@
+use std::cell::RefCell;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::rc::Rc;
+
+fn write_executable_script(path: &std::path::Path, body: &str) {
+    fs::write(path, body).unwrap();
+    let mut perms = fs::metadata(path).unwrap().permissions();
+    perms.set_mode(0o755);
+    fs::set_permissions(path, perms).unwrap();
+}
+
+// One test per process: the library reads HOME, which is process-wide.
+#[test]
+fn test_library_resolves_plans_switches_and_lists_without_the_cli() {
+    let root = tempfile::tempdir().unwrap();
+    let home = root.path().join("home");
+    let project = root.path().join("project");
+    fs::create_dir_all(&project).unwrap();
+    for version in ["18.20.4", "20.11.0"] {
+        let bin = home.join(".vex/toolchains/node").join(version).join("bin");
+        fs::create_dir_all(&bin).unwrap();
+        write_executable_script(&bin.join("node"), "#!/bin/sh\necho node\n");
+    }
+    fs::write(project.join(".tool-versions"), "node 20.11.0\n").unwrap();
+    std::env::set_var("HOME", &home);
+
+    let context = vex::resolve_context(&project);
+    assert_eq!(context.project_root.as_deref(), Some(project.as_path()));
+    assert_eq!(context.requests.len(), 1);
+    assert_eq!(context.requests[0].tool, "node");
+    assert_eq!(context.requests[0].version, "20.11.0");
+    assert_eq!(context.requests[0].source, project.join(".tool-versions"));
+
+    let activation = vex::plan_activation(&project).unwrap();
+    assert_eq!(activation.versions["node"], "20.11.0");
+    assert!(activation
+        .path_entries
+        .contains(&home.join(".vex/toolchains/node/20.11.0/bin")));
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = events.clone();
+    let switched = vex::switch("node", "18", move |event| {
+        sink.borrow_mut().push(event.clone());
+    })
+    .unwrap();
+    assert_eq!(switched, "18.20.4");
+    assert!(events.borrow().iter().any(|event| matches!(
+        event,
+        vex::Event::Switched { tool, version } if tool == "node" && version == "18.20.4"
+    )));
+
+    let installed = vex::list_installed("node").unwrap();
+    let versions = installed
+        .iter()
+        .map(|entry| (entry.version.as_str(), entry.is_current))
+        .collect::<Vec<_>>();
+    assert_eq!(versions, [("20.11.0", false), ("18.20.4", true)]);
+
+    let err = vex::switch("node", "22", |_| {}).unwrap_err();
+    assert_eq!(err.code(), "version_not_found");
+    assert!(matches!(
+        vex::list_installed("ruby"),
+        Err(vex::VexError::ToolNotFound(_))
+    ));
+}
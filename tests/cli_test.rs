@@ -49,6 +49,42 @@ fn seed_remote_cache(home: &std::path::Path, tool: &str, versions: &[&str]) {
     fs::write(cache_dir.join(format!("remote-{}.json", tool)), json).unwrap();
 }
 
+/// Serve fixed response bodies by path from a local stand-in server; returns its base URL.
+fn serve_stand_in(routes: Vec<(&'static str, String)>) -> String {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            let mut header = String::new();
+            while reader.read_line(&mut header).is_ok() && header.trim() != "" {
+                header.clear();
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+            let (status, body) = routes
+                .iter()
+                .find(|(route, _)| *route == path)
+                .map(|(_, body)| ("200 OK", body.as_str()))
+                .unwrap_or(("404 Not Found", ""));
+            let mut stream = reader.into_inner();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+        }
+    });
+    base
+}
+
 #[test]
 fn test_help() {
     let output = vex_bin().arg("--help").output().unwrap();
@@ -141,6 +177,213 @@ fn test_init_template_add_only_merges_safe_files() {
     let _ = std::fs::remove_dir_all(&project);
 }
 
+#[test]
+fn test_init_template_add_only_dry_run_reports_merge_conflicts() {
+    let project = fresh_temp_dir("vex_test_template_merge_conflicts");
+    let package_json =
+        "{\n  \"name\": \"web\",\n  \"scripts\": {\n    \"build\": \"vite build\"\n  }\n}\n";
+    fs::write(project.join("package.json"), package_json).unwrap();
+    fs::write(project.join(".vex.toml"), "[commands]\ntest = \"vitest\"\n").unwrap();
+
+    let output = vex_bin()
+        .args([
+            "init",
+            "--template",
+            "node-typescript",
+            "--add-only",
+            "--dry-run",
+        ])
+        .current_dir(&project)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("package.json"));
+    assert!(stdout.contains("Existing values kept"));
+    assert!(stdout.contains("scripts.build"));
+    assert!(stdout.contains("\"vite build\""));
+    assert!(stdout.contains("commands.test"));
+    assert_eq!(
+        fs::read_to_string(project.join("package.json")).unwrap(),
+        package_json
+    );
+
+    let output = vex_bin()
+        .args(["init", "--template", "node-typescript", "--add-only"])
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let merged = fs::read_to_string(project.join("package.json")).unwrap();
+    assert!(merged.contains("\"build\": \"vite build\""));
+    assert!(merged.contains("\"typescript\""));
+
+    let _ = std::fs::remove_dir_all(&project);
+}
+
+fn write_user_template(root: &std::path::Path) {
+    fs::create_dir_all(root.join("files")).unwrap();
+    fs::write(
+        root.join("template.toml"),
+        r#"description = "Team service"
+
+[[variables]]
+name = "owner"
+prompt = "Owning team"
+
+[[variables]]
+name = "service_name"
+default = "{{ project_name }}-svc"
+
+[[files]]
+path = "SERVICE.md"
+
+[[files]]
+path = ".tool-versions"
+merge = "tool_versions"
+"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("files/SERVICE.md"),
+        "# {{service_name}}\nowner: {{owner}}\n",
+    )
+    .unwrap();
+    fs::write(root.join("files/.tool-versions"), "node 22\n").unwrap();
+}
+
+#[test]
+fn test_init_user_template_with_variables_dry_run_and_add_only() {
+    let home = fresh_temp_dir("vex_test_user_template_home");
+    let project = fresh_temp_dir("vex_test_user_template_project").join("billing");
+    fs::create_dir_all(&project).unwrap();
+    write_user_template(&home.join(".vex/templates/service"));
+
+    let output = vex_bin()
+        .args(["init", "--list-templates"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("service"));
+    assert!(stdout.contains("Team service"));
+
+    let output = vex_bin()
+        .args(["init", "--template", "service", "--dry-run"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--var owner=<value>"));
+
+    let output = vex_bin()
+        .args([
+            "init",
+            "--template",
+            "service",
+            "--var",
+            "owner=payments",
+            "--dry-run",
+        ])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("SERVICE.md"));
+    assert!(!project.join("SERVICE.md").exists());
+
+    fs::write(project.join(".tool-versions"), "go 1.23\n").unwrap();
+    let output = vex_bin()
+        .args([
+            "init",
+            "--template",
+            "service",
+            "--var",
+            "owner=payments",
+            "--add-only",
+        ])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        fs::read_to_string(project.join("SERVICE.md")).unwrap(),
+        "# billing-svc\nowner: payments\n"
+    );
+    let tool_versions = fs::read_to_string(project.join(".tool-versions")).unwrap();
+    assert!(tool_versions.contains("go 1.23"));
+    assert!(tool_versions.contains("node 22"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(project.parent().unwrap());
+}
+
+#[test]
+fn test_init_template_from_git_repo_named_by_team_config() {
+    let home = fresh_temp_dir("vex_test_git_template_home");
+    let workspace = fresh_temp_dir("vex_test_git_template_workspace");
+    let repo = workspace.join("templates.git");
+    write_user_template(&repo.join("service"));
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .env("GIT_AUTHOR_NAME", "vex")
+            .env("GIT_AUTHOR_EMAIL", "vex@example.com")
+            .env("GIT_COMMITTER_NAME", "vex")
+            .env("GIT_COMMITTER_EMAIL", "vex@example.com")
+            .output()
+            .unwrap();
+        assert!(status.status.success(), "{:?}", status);
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "-m", "templates"]);
+
+    fs::write(
+        workspace.join("vex-config.toml"),
+        format!(
+            "version = 1\n\n[tools]\nnode = \"22\"\n\n[templates]\nservice = \"{}#:service\"\n",
+            repo.display()
+        ),
+    )
+    .unwrap();
+    let project = workspace.join("ledger");
+    fs::create_dir_all(&project).unwrap();
+
+    let output = vex_bin()
+        .args([
+            "init",
+            "--template",
+            "service",
+            "--from",
+            "../vex-config.toml",
+            "--var",
+            "owner=finance",
+        ])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        fs::read_to_string(project.join("SERVICE.md")).unwrap(),
+        "# ledger-svc\nowner: finance\n"
+    );
+    assert_eq!(
+        fs::read_to_string(project.join(".tool-versions")).unwrap(),
+        "node 22\n"
+    );
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&workspace);
+}
+
 #[test]
 fn test_current() {
     let output = vex_bin().arg("current").output().unwrap();
@@ -275,6 +518,50 @@ fn test_repair_migrate_home_dry_run_and_apply() {
     let _ = fs::remove_dir_all(&home);
 }
 
+#[test]
+fn test_import_nvm_dry_run_and_apply_adopts_installs() {
+    let home = fresh_temp_dir("vex_test_import_nvm");
+    let nvm = home.join(".nvm");
+    let node_bin = nvm.join("versions/node/v20.11.0/bin");
+    fs::create_dir_all(&node_bin).unwrap();
+    write_executable_script(&node_bin.join("node"), "#!/bin/sh\necho v20.11.0\n");
+    fs::create_dir_all(nvm.join("alias")).unwrap();
+    fs::write(nvm.join("alias/default"), "20\n").unwrap();
+
+    let dry_run = vex_bin()
+        .args(["import", "nvm"])
+        .env("HOME", &home)
+        .env("NVM_DIR", &nvm)
+        .output()
+        .unwrap();
+    assert!(dry_run.status.success(), "{:?}", dry_run);
+    let dry_stdout = String::from_utf8_lossy(&dry_run.stdout);
+    assert!(dry_stdout.contains("would adopt"));
+    assert!(dry_stdout.contains("node@20.11.0"));
+    assert!(dry_stdout.contains("node 20.11.0 in"));
+    assert!(dry_stdout.contains("vex import nvm --apply"));
+    assert!(!home.join(".vex/toolchains/node/20.11.0").exists());
+
+    let apply = vex_bin()
+        .args(["import", "nvm", "--apply"])
+        .env("HOME", &home)
+        .env("NVM_DIR", &nvm)
+        .output()
+        .unwrap();
+    assert!(apply.status.success(), "{:?}", apply);
+    assert!(home.join(".vex/toolchains/node/20.11.0/bin/node").exists());
+    assert!(home
+        .join(".vex/toolchains/node/20.11.0/.vex-metadata.json")
+        .exists());
+    assert_eq!(
+        fs::read_to_string(home.join(".vex/tool-versions")).unwrap(),
+        "node 20.11.0\n"
+    );
+    assert!(node_bin.join("node").exists());
+
+    let _ = fs::remove_dir_all(&home);
+}
+
 #[test]
 fn test_doctor_reports_home_hygiene_and_repair_hint() {
     let home = fresh_temp_dir("vex_test_doctor_home");
@@ -500,6 +787,623 @@ fn test_run_requires_project_task_definition() {
     let _ = fs::remove_dir_all(&project);
 }
 
+#[test]
+fn test_run_structured_tasks_with_dependencies_skip_and_list() {
+    let home = fresh_temp_dir("vex_test_run_tasks_home");
+    let project = fresh_temp_dir("vex_test_run_tasks_project");
+    fs::create_dir_all(project.join("app")).unwrap();
+    fs::create_dir_all(project.join("schema")).unwrap();
+    fs::write(project.join("schema/api.json"), "{}").unwrap();
+    fs::write(
+        project.join(".vex.toml"),
+        r#"
+[commands]
+lint = "echo lint-ok"
+fail = "exit 3"
+
+[commands.gen]
+run = "mkdir -p out && echo generated > out/api.txt && echo gen-done"
+description = "Generate API bindings"
+sources = ["schema/*.json"]
+outputs = ["out"]
+
+[commands.build]
+run = "echo build $MODE $(basename \"$PWD\")"
+description = "Build the app"
+depends_on = ["lint", "gen"]
+env = { MODE = "release" }
+cwd = "app"
+
+[commands.broken]
+run = "echo should-not-run"
+depends_on = ["fail"]
+"#,
+    )
+    .unwrap();
+    let run = |args: &[&str]| {
+        vex_bin()
+            .arg("run")
+            .args(args)
+            .env("HOME", &home)
+            .env("SHELL", "/bin/sh")
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+
+    let output = run(&["build", "extra"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[lint]"), "{}", stdout);
+    assert!(stdout.contains("lint-ok"));
+    assert!(stdout.contains("gen-done"));
+    assert!(stdout.contains("build release app extra"));
+    assert!(project.join("out/api.txt").exists());
+
+    let output = run(&["build"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("gen-done"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("is up to date, skipping"));
+
+    let output = run(&["--force", "build"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("gen-done"));
+
+    let output = run(&["broken"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("should-not-run"));
+
+    let output = run(&["--list"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Build the app"));
+    assert!(stdout.contains("Generate API bindings"));
+    assert!(stdout.contains("echo lint-ok"));
+    assert!(stdout.contains("(after lint, gen)"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_exec_and_run_with_ad_hoc_versions_override_pins() {
+    let home = fresh_temp_dir("vex_test_adhoc_home");
+    let project = fresh_temp_dir("vex_test_adhoc_project");
+    for version in ["18.20.4", "20.11.0"] {
+        let bin = home.join(format!(".vex/toolchains/node/{}/bin", version));
+        fs::create_dir_all(&bin).unwrap();
+        write_executable_script(
+            &bin.join("node"),
+            &format!("#!/bin/sh\necho node-{} \"$@\"\n", version),
+        );
+    }
+    fs::write(project.join(".tool-versions"), "node 20.11.0\n").unwrap();
+    fs::write(
+        project.join(".vex.toml"),
+        "[commands]\ntest = \"node\"\n\n[commands.pinned]\nrun = \"node\"\ntools = { node = \"20\" }\n",
+    )
+    .unwrap();
+
+    let vex = |args: &[&str]| {
+        vex_bin()
+            .args(args)
+            .env("HOME", &home)
+            .env("SHELL", "/bin/sh")
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+
+    let output = vex(&["exec", "node@18", "--", "node", "-v"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-18.20.4 -v"));
+
+    let output = vex(&["exec", "--", "node"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-20.11.0"));
+
+    let output = vex(&["run", "--with", "node@18", "test"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-18.20.4"));
+
+    let output = vex(&["run", "--with", "node@18", "pinned"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-18.20.4"));
+
+    // Piped runs without `non_interactive` never install; with it, `--offline` only uses the cache.
+    let output = vex(&["exec", "--offline", "node@16.20.2", "--", "node"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Install it first"), "{}", stderr);
+
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[behavior]\nnon_interactive = true\n",
+    )
+    .unwrap();
+    let output = vex(&["run", "--offline", "--with", "node@16.20.2", "test"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Offline mode"), "{}", stderr);
+    assert!(!home.join(".vex/toolchains/node/16.20.2").exists());
+
+    let output = vex(&["exec", "node@", "--", "node"]);
+    assert!(!output.status.success());
+    assert!(!home.join(".vex/current").exists());
+    assert_eq!(
+        fs::read_to_string(project.join(".tool-versions")).unwrap(),
+        "node 20.11.0\n"
+    );
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_which_and_where_explain_resolution() {
+    let home = fresh_temp_dir("vex_test_which_home");
+    let project = fresh_temp_dir("vex_test_which_project");
+    let external = fresh_temp_dir("vex_test_which_external");
+    fake_node_toolchain(&home, "18.20.4");
+    fake_node_toolchain(&home, "20.11.0");
+    write_executable_script(&external.join("node"), "#!/bin/sh\n");
+    fs::write(project.join(".tool-versions"), "node 20.11.0\n").unwrap();
+    let path = format!("{}:/usr/bin:/bin", external.display());
+
+    let vex = |args: &[&str]| {
+        vex_bin()
+            .args(args)
+            .env("HOME", &home)
+            .env("PATH", &path)
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+
+    let output = vex(&["which", "node", "--all"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let winner = home.join(".vex/toolchains/node/20.11.0/bin/node");
+    assert_eq!(stdout.lines().next(), Some(winner.to_str().unwrap()));
+    assert!(stdout.contains("node 20.11.0 toolchain"), "{}", stdout);
+    assert!(stdout.contains(".tool-versions"), "{}", stdout);
+    assert!(stdout.contains("Shadowed:"), "{}", stdout);
+    assert!(stdout.contains(external.join("node").to_str().unwrap()));
+    assert!(stdout.contains("outside vex"));
+
+    let output = vex(&["which", "node", "--json"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"kind\": \"toolchain\""), "{}", stdout);
+    assert!(stdout.contains("\"shadowed\": []"), "{}", stdout);
+
+    let output = vex(&["which", "no-such-binary"]);
+    assert!(!output.status.success());
+
+    let output = vex(&["where", "node"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        home.join(".vex/toolchains/node/20.11.0").to_str().unwrap()
+    );
+
+    let output = vex(&["where", "node@18"]);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        home.join(".vex/toolchains/node/18.20.4").to_str().unwrap()
+    );
+
+    let output = vex(&["where", "node@16"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("vex install node@16"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+    let _ = fs::remove_dir_all(&external);
+}
+
+#[test]
+fn test_sbom_and_verify_use_recorded_provenance() {
+    let home = fresh_temp_dir("vex_test_sbom_home");
+    let project = fresh_temp_dir("vex_test_sbom_project");
+    fake_node_toolchain(&home, "20.11.0");
+    let archive_dir = home.join(".vex/cache/archives/node/20.11.0");
+    fs::create_dir_all(&archive_dir).unwrap();
+    fs::write(archive_dir.join("node-20.11.0.tar.gz"), "archive").unwrap();
+    // sha256("archive")
+    let checksum = "0eb3e36bfb24dcd9bb1d1bece1531216b59539a8fde17ee80224af0653c92aa3";
+    fs::write(
+        home.join(".vex/toolchains/node/20.11.0/.vex-metadata.json"),
+        format!(
+            r#"{{"tool":"node","version":"20.11.0","provenance":{{"source_url":"https://nodejs.org/dist/v20.11.0/node.tar.gz","mirror_url":null,"checksum":"{}","installed_at":"2026-01-01T00:00:00Z"}},"ownership":{{}}}}"#,
+            checksum
+        ),
+    )
+    .unwrap();
+    fs::write(project.join(".tool-versions"), "node 20.11.0\n").unwrap();
+    fs::write(project.join("requirements.lock"), "requests==2.32.3\n").unwrap();
+
+    let vex = |args: &[&str]| {
+        vex_bin()
+            .args(args)
+            .env("HOME", &home)
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+
+    let output = vex(&["sbom", "--project"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("\"bomFormat\": \"CycloneDX\""),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("pkg:generic/node@20.11.0"), "{}", stdout);
+    assert!(stdout.contains(checksum), "{}", stdout);
+    assert!(stdout.contains("pkg:pypi/requests@2.32.3"), "{}", stdout);
+
+    let output = vex(&["sbom", "--all", "--format", "spdx"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("\"spdxVersion\": \"SPDX-2.3\""),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("https://nodejs.org/dist/v20.11.0/node.tar.gz"));
+    assert!(!stdout.contains("requests"), "{}", stdout);
+
+    let output = vex(&["verify", "node@20"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("archive matches its recorded checksum")
+    );
+
+    fs::write(archive_dir.join("node-20.11.0.tar.gz"), "tampered").unwrap();
+    let output = vex(&["verify"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("archive checksum mismatch"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("failed verification"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_verify_files_against_manifest_and_repair_skips_intact_toolchains() {
+    let home = fresh_temp_dir("vex_test_integrity_home");
+    fake_node_toolchain(&home, "20.11.0");
+    fake_node_toolchain(&home, "22.1.0");
+    let install_dir = home.join(".vex/toolchains/node/20.11.0");
+    // sha256("#!/bin/sh\n")
+    let entry = r#"{"type":"file","size":10,"sha256":"a8076d3d28d21e02012b20eaf7dbf75409a6277134439025f282e368e3305abf","executable":true}"#;
+    fs::write(
+        install_dir.join(".vex-manifest.json"),
+        format!(
+            r#"{{"version":1,"files":{{"bin/node":{0},"bin/npm":{0},"bin/npx":{0}}}}}"#,
+            entry
+        ),
+    )
+    .unwrap();
+
+    let vex = |args: &[&str]| {
+        vex_bin()
+            .args(args)
+            .env("HOME", &home)
+            .current_dir(&home)
+            .output()
+            .unwrap()
+    };
+
+    let output = vex(&["verify", "node@20.11.0"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("files match the install manifest"));
+
+    let output = vex(&["repair", "toolchain", "node"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("node@22.1.0 has no install manifest"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("All checked toolchains passed verification."),
+        "{}",
+        stdout
+    );
+
+    write_executable_script(&install_dir.join("bin/node"), "#!/bin/sh\nexit 1\n");
+    fs::remove_file(install_dir.join("bin/npx")).unwrap();
+    write_executable_script(&install_dir.join("bin/left-pad"), "#!/bin/sh\n");
+
+    let output = vex(&["verify", "node@20.11.0", "--json"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: Value = serde_json::from_str(&stdout).unwrap();
+    let files = &report["toolchains"][0]["files"];
+    assert_eq!(files["status"], "changed", "{}", stdout);
+    assert_eq!(files["modified"], serde_json::json!(["bin/node"]));
+    assert_eq!(files["missing"], serde_json::json!(["bin/npx"]));
+    assert_eq!(files["extra"], serde_json::json!(["bin/left-pad"]));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("vex repair toolchain"));
+
+    let output = vex(&["doctor", "--json"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"toolchain_integrity\""), "{}", stdout);
+    assert!(
+        stdout.contains("node@20.11.0: 1 modified, 1 missing, 1 extra files"),
+        "{}",
+        stdout
+    );
+
+    let _ = fs::remove_dir_all(&home);
+}
+
+#[test]
+fn test_completions_scripts_and_dynamic_candidates() {
+    let home = fresh_temp_dir("vex_test_completions_home");
+    let project = fresh_temp_dir("vex_test_completions_project");
+    fake_node_toolchain(&home, "20.11.0");
+    seed_remote_cache(&home, "node", &["22.1.0", "20.11.0"]);
+    fs::write(
+        home.join(".vex/aliases.toml"),
+        "[node]\nprod = \"20.11.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        project.join(".vex.toml"),
+        "[commands]\nlint = \"echo lint\"\n",
+    )
+    .unwrap();
+
+    let vex = |args: &[&str]| {
+        vex_bin()
+            .args(args)
+            .env("HOME", &home)
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+
+    for (shell, marker) in [
+        ("zsh", "compdef _vex vex"),
+        ("bash", "complete -o default -F _vex vex"),
+        ("fish", "complete -c vex"),
+        ("nu", "completions.external.completer"),
+    ] {
+        let output = vex(&["completions", shell]);
+        assert!(output.status.success(), "{:?}", output);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains(marker), "{}", stdout);
+        assert!(stdout.contains("vex __complete --"), "{}", stdout);
+    }
+    assert!(!vex(&["completions", "tcsh"]).status.success());
+
+    let output = vex(&["__complete", "--", "install", "node@"]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines.first(), Some(&"node@20.11.0"), "{}", stdout);
+    assert!(lines.contains(&"node@prod"), "{}", stdout);
+    assert!(lines.contains(&"node@lts"), "{}", stdout);
+    assert!(lines.contains(&"node@22.1.0"), "{}", stdout);
+    assert_eq!(
+        lines.iter().filter(|line| **line == "node@20.11.0").count(),
+        1
+    );
+
+    let output = vex(&["__complete", "--", "run", ""]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "lint\n");
+
+    let output = vex(&["__complete", "--", "init", "--template", "node"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-typescript"));
+
+    let output = vex(&["__complete", "--", "alias", "delete", "node", ""]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "prod\n");
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_run_task_tools_override_vex_toml_tools() {
+    let home = fresh_temp_dir("vex_test_task_tools_home");
+    let project = fresh_temp_dir("vex_test_task_tools_project");
+    for version in ["18.20.4", "20.11.0"] {
+        let bin = home.join(format!(".vex/toolchains/node/{}/bin", version));
+        fs::create_dir_all(&bin).unwrap();
+        write_executable_script(
+            &bin.join("node"),
+            &format!("#!/bin/sh\necho node-{}\n", version),
+        );
+    }
+    fs::create_dir_all(home.join(".vex/current")).unwrap();
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(
+        home.join(".vex/toolchains/node/20.11.0"),
+        home.join(".vex/current/node"),
+    )
+    .unwrap();
+    fs::write(
+        project.join(".vex.toml"),
+        r#"
+[tools]
+node = "20"
+
+[commands]
+test = "node"
+
+[commands.test-legacy]
+run = "node"
+tools = { node = "18" }
+
+[commands.test-ancient]
+run = "node"
+tools = { node = "16" }
+"#,
+    )
+    .unwrap();
+
+    let run = |task: &str| {
+        vex_bin()
+            .args(["run", task])
+            .env("HOME", &home)
+            .env("SHELL", "/bin/sh")
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+    let output = run("test");
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-20.11.0"));
+
+    let output = run("test-legacy");
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("node-18.20.4"));
+
+    let output = run("test-ancient");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("vex install node@16"));
+
+    fs::write(project.join(".tool-versions"), "node 20.11.0\n").unwrap();
+    let output = vex_bin()
+        .args(["current", "--verbose"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Requested by"));
+    assert!(stdout.contains(".tool-versions"));
+    assert!(stdout.contains("takes precedence"));
+    assert!(stdout.contains(".vex.toml"));
+    assert!(stdout.contains("shadowed"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_workspace_run_and_sync_cover_every_member() {
+    let home = fresh_temp_dir("vex_test_workspace_home");
+    let root = fresh_temp_dir("vex_test_workspace_root");
+    for version in ["20.11.0", "22.1.0"] {
+        fs::create_dir_all(home.join(format!(".vex/toolchains/node/{}/bin", version))).unwrap();
+    }
+    fs::create_dir_all(root.join("packages/web")).unwrap();
+    fs::create_dir_all(root.join("packages/api")).unwrap();
+    fs::create_dir_all(root.join("packages/docs")).unwrap();
+    fs::write(
+        root.join(".vex.toml"),
+        r#"
+[workspace]
+members = ["packages/*"]
+
+[env]
+STAGE = "ci"
+
+[commands]
+test = "echo test $STAGE $(basename \"$PWD\")"
+"#,
+    )
+    .unwrap();
+    fs::write(root.join("packages/web/.tool-versions"), "node 22.1.0\n").unwrap();
+    fs::write(root.join("packages/api/.tool-versions"), "node 20.11.0\n").unwrap();
+    fs::write(
+        root.join("packages/api/.vex.toml"),
+        "[env]\nSTAGE = \"api-ci\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("packages/docs/.vex.toml"),
+        "[commands]\ntest = \"exit 4\"\n",
+    )
+    .unwrap();
+
+    let output = vex_bin()
+        .args(["run", "--workspace", "test"])
+        .env("HOME", &home)
+        .env("SHELL", "/bin/sh")
+        .current_dir(root.join("packages/web"))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(4), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("test api-ci api"), "{}", stdout);
+    assert!(stdout.contains("test ci web"), "{}", stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("failed in 1 of 3 member(s): packages/docs"));
+
+    let output = vex_bin()
+        .args(["sync", "--workspace", "--offline"])
+        .env("HOME", &home)
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("3 member(s), 2 pinned version(s)"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("20.11.0"));
+    assert!(stdout.contains("22.1.0"));
+    let registry = fs::read_to_string(home.join(".vex/projects.json")).unwrap();
+    assert!(registry.contains("packages/api"));
+    assert!(registry.contains("packages/web"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn test_workspace_run_continues_after_member_with_missing_tool() {
+    let home = fresh_temp_dir("vex_test_workspace_missing_home");
+    let root = fresh_temp_dir("vex_test_workspace_missing_root");
+    fs::create_dir_all(home.join(".vex/toolchains/node/22.1.0/bin")).unwrap();
+    for member in ["api", "web"] {
+        fs::create_dir_all(root.join("packages").join(member)).unwrap();
+    }
+    fs::write(
+        root.join(".vex.toml"),
+        r#"
+[workspace]
+members = ["packages/*"]
+
+[commands]
+test = "echo test $(basename \"$PWD\")"
+"#,
+    )
+    .unwrap();
+    fs::write(root.join("packages/api/.tool-versions"), "node 18.0.0\n").unwrap();
+    fs::write(root.join("packages/web/.tool-versions"), "node 22.1.0\n").unwrap();
+
+    let output = vex_bin()
+        .args(["run", "--workspace", "test"])
+        .env("HOME", &home)
+        .env("SHELL", "/bin/sh")
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("test web"), "{}", stdout);
+    assert!(!stdout.contains("test api"), "{}", stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("18.0.0"), "{}", stderr);
+    assert!(
+        stderr.contains("failed in 1 of 2 member(s): packages/api"),
+        "{}",
+        stderr
+    );
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&root);
+}
+
 // --- 错误场景测试 ---
 
 #[test]
@@ -601,6 +1505,84 @@ fn test_list_installed_json() {
     let _ = std::fs::remove_dir_all(&home);
 }
 
+#[test]
+fn test_output_json_wraps_reports_and_error_codes() {
+    let home = fresh_temp_dir("vex_test_output_json");
+
+    let output = vex_bin()
+        .args(["--output", "json", "list", "node"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["schema"], "vex.list");
+    assert_eq!(parsed["schema_version"], 1);
+    assert_eq!(parsed["ok"], true);
+    assert_eq!(parsed["data"]["tool"], "node");
+
+    seed_remote_cache(&home, "node", &["20.11.0"]);
+    let output = vex_bin()
+        .args(["use", "node@99.99.99", "--output", "json"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["schema"], "vex.use");
+    assert_eq!(parsed["ok"], false);
+    assert_eq!(parsed["error"]["code"], "version_not_found");
+    assert_eq!(parsed["error"]["details"]["version"], "99.99.99");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Version not found"));
+
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn test_output_ndjson_streams_switch_and_json_reports_uninstall() {
+    let home = fresh_temp_dir("vex_test_output_ndjson");
+    let node_bin = home.join(".vex/toolchains/node/20.11.0/bin");
+    fs::create_dir_all(&node_bin).unwrap();
+    write_executable_script(&node_bin.join("node"), "#!/bin/sh\necho v20.11.0\n");
+    seed_remote_cache(&home, "node", &["20.11.0"]);
+
+    let output = vex_bin()
+        .args(["--output", "ndjson", "use", "node@20.11.0"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let events = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert!(events
+        .iter()
+        .any(|event| event["event"] == "switched" && event["version"] == "20.11.0"));
+    let result = events.last().unwrap();
+    assert_eq!(result["event"], "result");
+    assert_eq!(result["schema"], "vex.use");
+    assert_eq!(result["data"]["changes"][0]["tool"], "node");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Switched to node@20.11.0"));
+
+    let output = vex_bin()
+        .args(["--output", "json", "uninstall", "node@20.11.0"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["schema"], "vex.uninstall");
+    assert_eq!(
+        parsed["data"]["changes"],
+        serde_json::json!([{ "event": "uninstalled", "tool": "node", "version": "20.11.0" }])
+    );
+
+    let _ = std::fs::remove_dir_all(&home);
+}
+
 #[test]
 fn test_uninstall_nonexistent_version() {
     let output = vex_bin()
@@ -780,54 +1762,308 @@ fn test_local_invalid_tool() {
 }
 
 #[test]
-fn test_local_writes_tool_versions() {
-    let home = fresh_temp_dir("vex_test_local_write_home");
-    let dir = home.join("project");
-    std::fs::create_dir_all(&dir).unwrap();
-    seed_remote_cache(&home, "node", &["20.11.0"]);
+fn test_local_writes_tool_versions() {
+    let home = fresh_temp_dir("vex_test_local_write_home");
+    let dir = home.join("project");
+    std::fs::create_dir_all(&dir).unwrap();
+    seed_remote_cache(&home, "node", &["20.11.0"]);
+
+    // local 命令会调用 resolve_fuzzy_version，对完整版本号直接返回
+    let output = vex_bin()
+        .args(["local", "node@20.11.0"])
+        .env("HOME", &home)
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let tv = std::fs::read_to_string(dir.join(".tool-versions")).unwrap();
+    assert!(tv.contains("node 20.11.0"));
+
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+// --- doctor 命令测试 ---
+
+#[test]
+fn test_doctor_command() {
+    let output = vex_bin().arg("doctor").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Health Check") || stdout.contains("doctor"));
+}
+
+// --- upgrade 命令测试 ---
+
+#[test]
+fn test_upgrade_invalid_tool() {
+    let output = vex_bin().args(["upgrade", "ruby"]).output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Tool not found"));
+}
+
+#[test]
+fn test_upgrade_valid_tool_format() {
+    // upgrade 命令会尝试 list_remote，但我们只测试参数解析
+    let output = vex_bin().args(["upgrade", "node"]).output().unwrap();
+    // 可能成功（如果有网络）或失败（网络错误），但不应该是参数错误
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("Invalid spec format"));
+}
+
+/// Resolve `<tool>@latest` offline through a user alias backed by a seeded version cache.
+fn pin_latest_offline(home: &std::path::Path, tool: &str, latest: &str) {
+    seed_remote_cache(home, tool, &[latest]);
+    let aliases = home.join(".vex/aliases.toml");
+    let mut content = fs::read_to_string(&aliases).unwrap_or_default();
+    content.push_str(&format!("[{}]\nlatest = \"{}\"\n", tool, latest));
+    fs::write(aliases, content).unwrap();
+}
+
+fn fake_node_toolchain(home: &std::path::Path, version: &str) {
+    let bin = home.join(format!(".vex/toolchains/node/{}/bin", version));
+    fs::create_dir_all(&bin).unwrap();
+    for name in ["node", "npm", "npx"] {
+        write_executable_script(&bin.join(name), "#!/bin/sh\n");
+    }
+}
+
+#[test]
+fn test_upgrade_all_dry_run_shows_plan_without_changes() {
+    let home = fresh_temp_dir("vex_test_upgrade_plan_home");
+    let project = fresh_temp_dir("vex_test_upgrade_plan_project");
+    fake_node_toolchain(&home, "20.11.0");
+    fake_node_toolchain(&home, "22.1.0");
+    fs::create_dir_all(home.join(".vex/toolchains/go/1.22.0/bin")).unwrap();
+    pin_latest_offline(&home, "node", "22.1.0");
+    pin_latest_offline(&home, "go", "1.23.5");
+    fs::write(project.join(".tool-versions"), "node 20.11.0\ngo 1.22.0\n").unwrap();
+
+    let arch = if cfg!(target_arch = "aarch64") {
+        "arm64"
+    } else {
+        "amd64"
+    };
+    let route: &'static str =
+        Box::leak(format!("/dl/go1.23.5.darwin-{}.tar.gz", arch).into_boxed_str());
+    let mirror = serve_stand_in(vec![(route, "x".repeat(3 * 1024))]);
+
+    let output = vex_bin()
+        .args(["upgrade", "--all", "--dry-run"])
+        .env("HOME", &home)
+        .env("VEX_MIRROR_GO", &mirror)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Upgrade plan (dry run)"));
+    assert!(stdout.contains("1.23.5"));
+    assert!(stdout.contains("22.1.0"));
+    assert!(stdout.contains(".tool-versions"));
+    assert!(stdout.contains("already installed"));
+    assert!(stdout.contains("3.00 KiB"), "{}", stdout);
+
+    assert_eq!(
+        fs::read_to_string(project.join(".tool-versions")).unwrap(),
+        "node 20.11.0\ngo 1.22.0\n"
+    );
+    assert!(!home.join(".vex/current").exists());
+    assert!(!home.join(".vex/toolchains/go/1.23.5").exists());
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_upgrade_keeps_partial_pins_on_their_line_unless_major_is_requested() {
+    let home = fresh_temp_dir("vex_test_upgrade_partial_pin_home");
+    let project = fresh_temp_dir("vex_test_upgrade_partial_pin_project");
+    fake_node_toolchain(&home, "20.11.0");
+    seed_remote_cache(&home, "node", &["22.1.0", "20.19.5", "20.11.0"]);
+    fs::write(
+        home.join(".vex/aliases.toml"),
+        "[node]\nlatest = \"22.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(project.join(".tool-versions"), "node 20\n").unwrap();
+
+    let upgrade = |args: &[&str]| {
+        let output = vex_bin()
+            .args(["upgrade", "--all", "--dry-run"])
+            .args(args)
+            .env("HOME", &home)
+            .env("VEX_MIRROR_NODE", "http://127.0.0.1:9")
+            .current_dir(&project)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{:?}", output);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (_, plan) = stdout.split_once("Upgrade plan").unwrap();
+        plan.to_string()
+    };
+
+    let stdout = upgrade(&[]);
+    assert!(stdout.contains("20.19.5"), "{}", stdout);
+    assert!(!stdout.contains("22.1.0"), "{}", stdout);
+
+    let stdout = upgrade(&["--strategy", "major"]);
+    assert!(stdout.contains("22.1.0"), "{}", stdout);
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_sync_reports_every_failed_install_without_aborting() {
+    let home = fresh_temp_dir("vex_test_sync_parallel_home");
+    let project = fresh_temp_dir("vex_test_sync_parallel_project");
+    fake_node_toolchain(&home, "20.11.0");
+    seed_remote_cache(&home, "go", &["1.23.5"]);
+    seed_remote_cache(&home, "rust", &["1.95.0"]);
+    fs::write(
+        project.join(".tool-versions"),
+        "node 20.11.0\ngo 1.23.5\nrust 1.95.0\n",
+    )
+    .unwrap();
 
-    // local 命令会调用 resolve_fuzzy_version，对完整版本号直接返回
     let output = vex_bin()
-        .args(["local", "node@20.11.0"])
+        .args(["sync", "--offline"])
         .env("HOME", &home)
-        .current_dir(&dir)
+        .current_dir(&project)
         .output()
         .unwrap();
-    assert!(output.status.success());
-
-    let tv = std::fs::read_to_string(dir.join(".tool-versions")).unwrap();
-    assert!(tv.contains("node 20.11.0"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Sync Summary:"), "{}", stdout);
+    assert!(stdout.contains("(already installed)"), "{}", stdout);
+    assert!(
+        stdout.contains("No cached archive found for go@1.23.5"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("No cached archive found for rust@1.95.0"),
+        "{}",
+        stdout
+    );
+    assert!(!home.join(".vex/locks/go-1.23.5.lock").exists());
 
-    let _ = std::fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
 }
 
-// --- doctor 命令测试 ---
-
 #[test]
-fn test_doctor_command() {
-    let output = vex_bin().arg("doctor").output().unwrap();
-    assert!(output.status.success());
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(stdout.contains("Health Check") || stdout.contains("doctor"));
-}
+fn test_upgrade_all_rolls_back_links_and_pins_when_a_switch_fails() {
+    let home = fresh_temp_dir("vex_test_upgrade_rollback_home");
+    let project = fresh_temp_dir("vex_test_upgrade_rollback_project");
+    fake_node_toolchain(&home, "20.11.0");
+    fake_node_toolchain(&home, "22.1.0");
+    fs::create_dir_all(home.join(".vex/toolchains/rust/1.90.0/rustc/bin")).unwrap();
+    fs::create_dir_all(home.join(".vex/toolchains/rust/1.95.0/rustc/bin")).unwrap();
+    pin_latest_offline(&home, "node", "22.1.0");
+    pin_latest_offline(&home, "rust", "1.95.0");
+    let pins = "node 20.11.0 # team baseline\nrust 1.90.0\n";
+    fs::write(project.join(".tool-versions"), pins).unwrap();
 
-// --- upgrade 命令测试 ---
+    let switched = vex_bin()
+        .args(["use", "node@20.11.0"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(switched.status.success(), "{:?}", switched);
+    // A directory where the rust link belongs makes the second switch fail after node switched.
+    fs::create_dir_all(home.join(".vex/current/rust/blocker")).unwrap();
 
-#[test]
-fn test_upgrade_invalid_tool() {
-    let output = vex_bin().args(["upgrade", "ruby"]).output().unwrap();
-    assert!(!output.status.success());
+    let output = vex_bin()
+        .args(["upgrade", "--all"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "{:?}", output);
     let stderr = String::from_utf8_lossy(&output.stderr);
-    assert!(stderr.contains("Tool not found"));
+    assert!(stderr.contains("restoring previous versions"), "{}", stderr);
+
+    assert_eq!(
+        fs::read_to_string(project.join(".tool-versions")).unwrap(),
+        pins
+    );
+    assert_eq!(
+        fs::read_link(home.join(".vex/current/node")).unwrap(),
+        home.join(".vex/toolchains/node/20.11.0")
+    );
+    assert_eq!(
+        fs::read_link(home.join(".vex/bin/node")).unwrap(),
+        home.join(".vex/toolchains/node/20.11.0/bin/node")
+    );
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
 }
 
 #[test]
-fn test_upgrade_valid_tool_format() {
-    // upgrade 命令会尝试 list_remote，但我们只测试参数解析
-    let output = vex_bin().args(["upgrade", "node"]).output().unwrap();
-    // 可能成功（如果有网络）或失败（网络错误），但不应该是参数错误
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    assert!(!stderr.contains("Invalid spec format"));
+fn test_upgrade_strategy_stays_in_line_and_keeps_pin_precision() {
+    let home = fresh_temp_dir("vex_test_upgrade_strategy_home");
+    let project = fresh_temp_dir("vex_test_upgrade_strategy_project");
+    fake_node_toolchain(&home, "20.11.0");
+    seed_remote_cache(&home, "node", &["25.1.0", "24.11.1", "20.19.5", "20.11.0"]);
+    fs::write(
+        home.join(".vex/aliases.toml"),
+        "[node]\nlatest = \"25.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[upgrade.tools]\nnode = \"minor\"\n",
+    )
+    .unwrap();
+    fs::write(project.join(".tool-versions"), "node 20\n").unwrap();
+    let mirror = serve_stand_in(Vec::new());
+    let upgrade = |args: &[&str]| {
+        vex_bin()
+            .args(args)
+            .env("HOME", &home)
+            .env("VEX_MIRROR_NODE", &mirror)
+            .current_dir(&project)
+            .output()
+            .unwrap()
+    };
+
+    let minor = upgrade(&["upgrade", "--all", "--dry-run"]);
+    assert!(minor.status.success(), "{:?}", minor);
+    let stdout = String::from_utf8_lossy(&minor.stdout);
+    assert!(stdout.contains("20.19.5"), "{}", stdout);
+    assert!(stdout.contains("(minor)"));
+    assert!(!stdout.contains("25.1.0"));
+    assert!(!stdout.contains("Rewrites"));
+
+    let major = upgrade(&["upgrade", "node", "--strategy", "major", "--dry-run"]);
+    assert!(major.status.success(), "{:?}", major);
+    let stdout = String::from_utf8_lossy(&major.stdout);
+    assert!(stdout.contains("25.1.0"), "{}", stdout);
+    let rewrite = stdout
+        .lines()
+        .find(|line| line.contains("Rewrites"))
+        .unwrap();
+    assert!(rewrite.contains("25"), "{}", rewrite);
+    assert!(!rewrite.contains("25.1.0"), "{}", rewrite);
+
+    fake_node_toolchain(&home, "20.19.5");
+    fs::write(project.join(".tool-versions"), "node 20.11.0\n").unwrap();
+    let output = upgrade(&["upgrade", "--all"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        fs::read_to_string(project.join(".tool-versions")).unwrap(),
+        "node 20.19.5\n"
+    );
+    assert_eq!(
+        fs::read_link(home.join(".vex/current/node")).unwrap(),
+        home.join(".vex/toolchains/node/20.19.5")
+    );
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
 }
 
 #[test]
@@ -936,6 +2172,109 @@ fn test_prune_removes_only_unmanaged_items() {
     let _ = std::fs::remove_dir_all(&project);
 }
 
+#[test]
+fn test_prune_retains_versions_pinned_by_registered_projects() {
+    let home = fresh_temp_dir("vex_test_projects_home");
+    let project = fresh_temp_dir("vex_test_projects_app");
+    let elsewhere = fresh_temp_dir("vex_test_projects_elsewhere");
+    fs::create_dir_all(home.join(".vex/toolchains/node/2.0.0/bin")).unwrap();
+    fs::create_dir_all(home.join(".vex/toolchains/node/3.0.0/bin")).unwrap();
+    fs::write(project.join(".tool-versions"), "node 2.0.0\n").unwrap();
+
+    let hook = vex_bin()
+        .args(["use", "--auto"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(hook.status.success(), "{:?}", hook);
+
+    let listed = vex_bin()
+        .args(["projects", "list", "--json"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(listed.status.success(), "{:?}", listed);
+    let parsed: Value = serde_json::from_slice(&listed.stdout).unwrap();
+    assert_eq!(parsed[0]["versions"]["node"], "2.0.0");
+    assert_eq!(parsed[0]["stale"], false);
+
+    let output = vex_bin()
+        .args(["prune"])
+        .env("HOME", &home)
+        .current_dir(&elsewhere)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert!(home.join(".vex/toolchains/node/2.0.0").exists());
+    assert!(!home.join(".vex/toolchains/node/3.0.0").exists());
+
+    let forget = vex_bin()
+        .args(["projects", "forget"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(forget.status.success(), "{:?}", forget);
+    let dry_run = vex_bin()
+        .args(["prune", "--dry-run"])
+        .env("HOME", &home)
+        .current_dir(&elsewhere)
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&dry_run.stdout).contains("2.0.0"));
+
+    let _ = std::fs::remove_dir_all(&home);
+    let _ = std::fs::remove_dir_all(&project);
+    let _ = std::fs::remove_dir_all(&elsewhere);
+}
+
+#[test]
+fn test_prune_retention_rules_explain_decisions_and_use_exec_history() {
+    let home = fresh_temp_dir("vex_test_prune_retention_home");
+    let project = fresh_temp_dir("vex_test_prune_retention_project");
+    let elsewhere = fresh_temp_dir("vex_test_prune_retention_elsewhere");
+    for version in ["18.0.0", "20.1.0", "20.2.0"] {
+        let bin = home.join(".vex/toolchains/node").join(version).join("bin");
+        fs::create_dir_all(&bin).unwrap();
+        write_executable_script(&bin.join("node"), "#!/bin/sh\nexit 0\n");
+    }
+    fs::write(project.join(".tool-versions"), "node 18.0.0\n").unwrap();
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[prune]\nkeep_latest_per_major = 1\n",
+    )
+    .unwrap();
+
+    let exec = vex_bin()
+        .args(["exec", "--", "node"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+    assert!(exec.status.success(), "{:?}", exec);
+    let usage: Value =
+        serde_json::from_str(&fs::read_to_string(home.join(".vex/toolchain-usage.json")).unwrap())
+            .unwrap();
+    assert!(usage["node"]["18.0.0"].is_string());
+
+    let output = vex_bin()
+        .args(["prune", "--dry-run"])
+        .env("HOME", &home)
+        .current_dir(&elsewhere)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("not among the newest 1 in node 20.x"));
+    assert!(stdout.contains("newest 1 in node 18.x"));
+    assert!(home.join(".vex/toolchains/node/20.1.0").exists());
+
+    let _ = std::fs::remove_dir_all(&home);
+    let _ = std::fs::remove_dir_all(&project);
+    let _ = std::fs::remove_dir_all(&elsewhere);
+}
+
 // --- alias 命令测试 ---
 
 #[test]
@@ -2099,3 +3438,177 @@ fn test_sync_frozen_without_lockfile() {
 
     let _ = std::fs::remove_dir_all(&home);
 }
+
+#[test]
+fn test_use_is_blocked_by_enforced_policy() {
+    let home = fresh_temp_dir("vex_test_use_policy");
+    fs::create_dir_all(home.join(".vex/toolchains/node/18.20.4/bin")).unwrap();
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[strict]\npolicy = \"enforce\"\n\n[policy.node]\nmin = \"20\"\n",
+    )
+    .unwrap();
+
+    let output = vex_bin()
+        .args(["use", "node@18"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("node@18.20.4 is below the minimum version 20"));
+    assert!(!home.join(".vex/current/node").exists());
+
+    let _ = fs::remove_dir_all(&home);
+}
+
+#[test]
+fn test_exec_is_blocked_by_project_policy() {
+    let home = fresh_temp_dir("vex_test_exec_policy_home");
+    let project = fresh_temp_dir("vex_test_exec_policy_project");
+    let node_bin = home.join(".vex/toolchains/node/21.7.3/bin");
+    fs::create_dir_all(&node_bin).unwrap();
+    write_executable_script(&node_bin.join("node"), "#!/bin/sh\nprintf 'ran'\n");
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[strict]\npolicy = \"enforce\"\n",
+    )
+    .unwrap();
+    fs::write(project.join(".tool-versions"), "node 21.7.3\n").unwrap();
+    fs::write(
+        project.join(".vex.toml"),
+        "[policy.node]\ndeny = [\"21\"]\n",
+    )
+    .unwrap();
+
+    let output = vex_bin()
+        .args(["exec", "--", "node"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("ran"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("node@21.7.3 is denied by rule '21'"));
+    assert!(stderr.contains(".vex.toml"));
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_sync_reports_structured_policy_error() {
+    let home = fresh_temp_dir("vex_test_sync_policy_home");
+    let project = fresh_temp_dir("vex_test_sync_policy_project");
+    fs::create_dir_all(home.join(".vex/toolchains/node/21.7.3/bin")).unwrap();
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[strict]\npolicy = \"enforce\"\n\n[policy.node]\ndeny = [\"21\"]\n",
+    )
+    .unwrap();
+    fs::write(project.join(".tool-versions"), "node 21.7.3\n").unwrap();
+
+    let output = vex_bin()
+        .args(["--output", "json", "sync"])
+        .env("HOME", &home)
+        .current_dir(&project)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["error"]["code"], "policy_denied", "{}", parsed);
+    assert_eq!(parsed["error"]["details"]["tool"], "node", "{}", parsed);
+
+    let _ = fs::remove_dir_all(&home);
+    let _ = fs::remove_dir_all(&project);
+}
+
+#[test]
+fn test_doctor_json_lists_policy_violations() {
+    let home = fresh_temp_dir("vex_test_doctor_policy");
+    fs::create_dir_all(home.join(".vex/toolchains/python/3.7.17")).unwrap();
+    fs::write(
+        home.join(".vex/config.toml"),
+        "[policy.python]\ndeny_eol = true\n",
+    )
+    .unwrap();
+
+    let output = vex_bin()
+        .args(["doctor", "--json"])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let policy = parsed["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|check| check["id"] == "policy")
+        .unwrap();
+    assert_eq!(policy["status"], "warn");
+    assert!(policy["details"][0]
+        .as_str()
+        .unwrap()
+        .contains("python@3.7.17: end-of-life"));
+
+    let _ = fs::remove_dir_all(&home);
+}
+
+#[test]
+fn test_doctor_reports_vulnerabilities_from_feed() {
+    let home = fresh_temp_dir("vex_test_vulnerability_feed");
+    fs::create_dir_all(home.join(".vex/toolchains/node/20.11.0/bin")).unwrap();
+    fs::create_dir_all(home.join(".vex/toolchains/node/20.12.1/bin")).unwrap();
+    let feed = serve_stand_in(vec![(
+        "/feed/node.json",
+        r#"{"vulnerabilities": [{
+            "id": "CVE-2024-27983",
+            "severity": "high",
+            "summary": "HTTP/2 server crash",
+            "affected": [{"introduced": "20.0.0", "fixed": "20.12.1"}]
+        }]}"#
+            .to_string(),
+    )]);
+
+    let output = vex_bin()
+        .args(["doctor", "--json"])
+        .env("HOME", &home)
+        .env("VEX_VULNERABILITY_FEED", format!("{}/feed", feed))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let findings = parsed["vulnerabilities"].as_array().unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0]["tool"], "node");
+    assert_eq!(findings[0]["version"], "20.11.0");
+    assert_eq!(findings[0]["id"], "CVE-2024-27983");
+    assert_eq!(findings[0]["severity"], "high");
+    assert_eq!(findings[0]["fixed_in"], "20.12.1");
+    let check = parsed["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|check| check["id"] == "vulnerabilities")
+        .unwrap();
+    assert_eq!(check["status"], "warn");
+    assert!(home.join(".vex/cache/vulnerabilities-node.json").exists());
+
+    // The cached feed is used when the mirror is unreachable.
+    let output = vex_bin()
+        .args(["doctor", "--json"])
+        .env("HOME", &home)
+        .env("VEX_VULNERABILITY_FEED", "http://127.0.0.1:9/feed")
+        .output()
+        .unwrap();
+    let parsed: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["vulnerabilities"][0]["id"], "CVE-2024-27983");
+
+    let _ = fs::remove_dir_all(&home);
+}